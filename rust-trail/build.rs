@@ -0,0 +1,52 @@
+// Lists every asset the game ships with, with its size and hash, so the game
+// can tell a player which of their files are missing or damaged. The list is
+// written to OUT_DIR and included by engine::integrity.
+
+use std::fmt::Write;
+use std::path::Path;
+
+#[path = "src/utils/fnv.rs"]
+mod fnv;
+
+/// Folder holding the assets, relative to the manifest
+const ASSETS_DIR: &str = "assets";
+/// Entries under the assets folder that aren't loaded from it: notes, and
+/// the fallbacks built into the binary
+const SKIPPED: [&str; 2] = ["README.md", "fallback"];
+
+/// Paths of every file under a folder, relative to the assets folder
+fn collect(dir: &Path, files: &mut Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let relative = path.strip_prefix(ASSETS_DIR).unwrap().to_string_lossy().replace('\\', "/");
+        if SKIPPED.contains(&relative.as_str()) {
+            continue;
+        }
+        if path.is_dir() {
+            collect(&path, files);
+        } else {
+            files.push(relative);
+        }
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", ASSETS_DIR);
+
+    let mut files = Vec::new();
+    collect(Path::new(ASSETS_DIR), &mut files);
+    files.sort();
+
+    let mut list = String::from("&[\n");
+    for file in files {
+        let bytes = std::fs::read(Path::new(ASSETS_DIR).join(&file)).unwrap();
+        writeln!(list, "    ({:?}, {}, {:#018x}),", file, bytes.len(), fnv::hash(&bytes)).unwrap();
+    }
+    list.push(']');
+
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).join("asset_integrity.rs");
+    std::fs::write(out, list).unwrap();
+}
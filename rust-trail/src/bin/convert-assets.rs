@@ -0,0 +1,226 @@
+// Converts the original game's files into the assets folder the game loads.
+//
+// Usage: convert-assets <original game dir> [--output DIR] [--overwrite]
+//
+// Every file under the original game's folder is read, along with everything
+// packed inside any .GXL archive there. Pictures become images/NAME.png,
+// sounds audio/sounds/NAME.wav, music audio/music/NAME.mid (NAME_2.mid and
+// so on for files holding more than one song) and animations
+// animations/NAME.json. Files already converted are left alone unless
+// --overwrite is given.
+
+use rust_trail::utils::format_converters::{ani, pcx, snd, xmi};
+use rust_trail::utils::gxl::GxlArchive;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Where converted files go unless told otherwise
+const DEFAULT_OUTPUT: &str = "assets";
+/// The file holding the palette .PC4 pictures are drawn with
+const PALETTE_FILE: &str = "COLOR16.PCX";
+
+/// Converted files: each one's path under the assets folder and its bytes
+type Outputs = Vec<(String, Vec<u8>)>;
+
+/// Options parsed from the command line
+struct Options {
+    input: PathBuf,
+    output: PathBuf,
+    overwrite: bool,
+}
+
+/// Parse command-line arguments
+fn parse_args() -> Result<Options, String> {
+    let mut input = None;
+    let mut output = PathBuf::from(DEFAULT_OUTPUT);
+    let mut overwrite = false;
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" => output = args.next().ok_or("Missing value for --output")?.into(),
+            "--overwrite" => overwrite = true,
+            other if other.starts_with("--") => return Err(format!("Unknown argument '{}'", other)),
+            other if input.is_none() => input = Some(PathBuf::from(other)),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+
+    let input = input.ok_or("No original game folder given")?;
+    Ok(Options { input, output, overwrite })
+}
+
+/// Every file under a folder, however deep
+fn walk(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            walk(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// The original game's files by upper-case name. Loose files win over ones of
+/// the same name inside an archive, as they do in the game itself
+fn collect(dir: &Path) -> Result<BTreeMap<String, Vec<u8>>, String> {
+    let mut paths = Vec::new();
+    walk(dir, &mut paths).map_err(|e| format!("Couldn't read {}: {}", dir.display(), e))?;
+    paths.sort();
+
+    let mut files = BTreeMap::new();
+    let mut packed = BTreeMap::new();
+    for path in paths {
+        let Some(name) = path.file_name().map(|n| n.to_string_lossy().to_ascii_uppercase()) else {
+            continue;
+        };
+        let data = std::fs::read(&path).map_err(|e| format!("Couldn't read {}: {}", path.display(), e))?;
+        if name.ends_with(".GXL") {
+            let archive = GxlArchive::parse(data).map_err(|e| format!("{}: {}", path.display(), e))?;
+            println!("{}: {} files", path.display(), archive.entries().len());
+            for entry in archive.entries() {
+                if let Some(bytes) = archive.get(&entry.name) {
+                    packed.entry(entry.name.to_ascii_uppercase()).or_insert_with(|| bytes.to_vec());
+                }
+            }
+        } else {
+            files.insert(name, data);
+        }
+    }
+
+    for (name, data) in packed {
+        files.entry(name).or_insert(data);
+    }
+    Ok(files)
+}
+
+/// Name without its extension, and the extension
+fn split_name(name: &str) -> (&str, &str) {
+    name.rsplit_once('.').unwrap_or((name, ""))
+}
+
+/// What a file converts into, or None for files that aren't converted
+fn convert(
+    name: &str,
+    data: &[u8],
+    files: &BTreeMap<String, Vec<u8>>,
+    palette: Option<&[[u8; 3]; 16]>,
+) -> Option<Result<Outputs, String>> {
+    let (stem, extension) = split_name(name);
+    let result = match extension {
+        "PC8" | "256" => pcx::decode_pc8(name, data).and_then(|p| p.to_png()).map(|png| vec![(format!("images/{}.png", stem), png)]),
+        "PC4" => {
+            // The 256 colour version of a picture is the better one when both exist
+            if files.contains_key(&format!("{}.PC8", stem)) {
+                return None;
+            }
+            let palette = palette.ok_or_else(|| format!("{} is needed for .PC4 pictures", PALETTE_FILE));
+            palette
+                .and_then(|palette| pcx::decode_pc4(data, palette))
+                .and_then(|p| p.to_png())
+                .map(|png| vec![(format!("images/{}.png", stem), png)])
+        }
+        "SND" => snd::decode(data).map(|clip| vec![(format!("audio/sounds/{}.wav", stem), clip.to_wav())]),
+        "XMI" => xmi::to_midi(data).map(|songs| {
+            songs
+                .into_iter()
+                .enumerate()
+                .map(|(i, midi)| match i {
+                    0 => (format!("audio/music/{}.mid", stem), midi),
+                    _ => (format!("audio/music/{}_{}.mid", stem, i + 1), midi),
+                })
+                .collect()
+        }),
+        "ANI" => {
+            // Animations that don't name their sheet use NAMEANI.PC8 if there is one
+            let sheet = if files.contains_key(&format!("{}ANI.PC8", stem)) {
+                format!("{}ANI.png", stem)
+            } else {
+                format!("{}.png", stem)
+            };
+            ani::to_animation(data, &sheet)
+                .and_then(|animation| animation.to_json())
+                .map(|json| vec![(format!("animations/{}.json", stem), json.into_bytes())])
+        }
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn main() {
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("Usage: convert-assets <original game dir> [--output DIR] [--overwrite]");
+            std::process::exit(2);
+        }
+    };
+
+    let files = match collect(&options.input) {
+        Ok(files) => files,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    let palette = match files.get(PALETTE_FILE).map(|data| pcx::palette16(data)) {
+        Some(Ok(palette)) => Some(palette),
+        Some(Err(e)) => {
+            eprintln!("{}: {}", PALETTE_FILE, e);
+            None
+        }
+        None => None,
+    };
+
+    let mut written = 0;
+    let mut kept = 0;
+    let mut skipped = 0;
+    let mut failed = Vec::new();
+    for (name, data) in &files {
+        let outputs = match convert(name, data, &files, palette.as_ref()) {
+            Some(Ok(outputs)) => outputs,
+            Some(Err(e)) => {
+                failed.push(format!("{}: {}", name, e));
+                continue;
+            }
+            None => {
+                skipped += 1;
+                continue;
+            }
+        };
+
+        for (path, bytes) in outputs {
+            let path = options.output.join(path);
+            if path.exists() && !options.overwrite {
+                kept += 1;
+                continue;
+            }
+            let result = path
+                .parent()
+                .map_or(Ok(()), std::fs::create_dir_all)
+                .and_then(|_| std::fs::write(&path, bytes));
+            match result {
+                Ok(()) => written += 1,
+                Err(e) => failed.push(format!("{}: {}", path.display(), e)),
+            }
+        }
+    }
+
+    println!(
+        "Converted {} files into {} ({} already there, {} of other kinds skipped)",
+        written,
+        options.output.display(),
+        kept,
+        skipped
+    );
+    if !failed.is_empty() {
+        eprintln!("{} failed:", failed.len());
+        for failure in &failed {
+            eprintln!("  {}", failure);
+        }
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,182 @@
+// Looks inside the original game's GXL archives.
+//
+// Usage: gxl-tool list <archive>
+//        gxl-tool info <archive> [NAME]
+//        gxl-tool extract <archive> [NAME...] [--output DIR]
+//
+// `list` prints every entry's name, offset, size and what its contents look
+// like. `info` sums up the archive, or with a NAME, runs that entry through
+// the game's own decoders and says what came out. `extract` writes entries
+// out unconverted, all of them unless some are named.
+
+use rust_trail::utils::format_converters::{pcx, snd, xmi};
+use rust_trail::utils::gxl::{GxlArchive, GxlEntry};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Usage printed when the arguments are wrong
+const USAGE: &str = "Usage: gxl-tool list <archive> | info <archive> [NAME] | extract <archive> [NAME...] [--output DIR]";
+/// The file holding the palette .PC4 pictures are drawn with
+const PALETTE_FILE: &str = "COLOR16.PCX";
+
+/// What to do with the archive
+enum Command {
+    List,
+    Info(Option<String>),
+    Extract { names: Vec<String>, output: PathBuf },
+}
+
+/// Parse command-line arguments into a command and the archive it's for
+fn parse_args() -> Result<(Command, PathBuf), String> {
+    let mut args = std::env::args().skip(1);
+    let command = args.next().ok_or("No command given")?;
+    let archive = PathBuf::from(args.next().ok_or("No archive given")?);
+    let mut rest = Vec::new();
+    let mut output = PathBuf::from(".");
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" if command == "extract" => output = args.next().ok_or("Missing value for --output")?.into(),
+            other if other.starts_with("--") => return Err(format!("Unknown argument '{}'", other)),
+            _ => rest.push(arg),
+        }
+    }
+
+    let command = match command.as_str() {
+        "list" if rest.is_empty() => Command::List,
+        "info" if rest.len() <= 1 => Command::Info(rest.pop()),
+        "extract" => Command::Extract { names: rest, output },
+        "list" | "info" => return Err(format!("Too many arguments for {}", command)),
+        other => return Err(format!("Unknown command '{}'", other)),
+    };
+    Ok((command, archive))
+}
+
+/// What an entry's contents look like, going by its first bytes and falling
+/// back on its extension
+fn detect(entry: &GxlEntry, data: &[u8]) -> String {
+    let extension = entry.name.rsplit_once('.').map_or("", |(_, e)| e);
+    match data {
+        [0x0a, 5, 1, 8, ..] => "PCX picture, 256 colours".to_string(),
+        [0x0a, 5, 1, 1, ..] if data.get(65) == Some(&4) => "PCX picture, 16 colours".to_string(),
+        [0x0a, 5, 1, bits, ..] => format!("PCX picture, {} bits per pixel", bits),
+        _ if data.starts_with(b"FORM") && data.get(8..12) == Some(b"XDIR") => "XMI music".to_string(),
+        _ if data.starts_with(b"Creative Voice File") => "Creative Voice sound".to_string(),
+        _ if extension == "SND" => "8-bit sound".to_string(),
+        _ if !data.is_empty() && data.iter().all(|&b| b.is_ascii_graphic() || b.is_ascii_whitespace()) => "text".to_string(),
+        _ => format!("unknown .{}", extension),
+    }
+}
+
+/// Open an archive from disk
+fn open(path: &PathBuf) -> Result<GxlArchive, String> {
+    let data = std::fs::read(path).map_err(|e| format!("Couldn't read {}: {}", path.display(), e))?;
+    GxlArchive::parse(data).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Print every entry in table order
+fn list(archive: &GxlArchive) {
+    println!("{:<14} {:>10} {:>10}  type", "name", "offset", "size");
+    for entry in archive.entries() {
+        let data = archive.get(&entry.name).unwrap_or_default();
+        println!("{:<14} {:>#10x} {:>10}  {}", entry.name, entry.offset, entry.size, detect(entry, data));
+    }
+}
+
+/// What the game's decoders make of an entry
+fn decode(archive: &GxlArchive, entry: &GxlEntry, data: &[u8]) -> Result<String, String> {
+    let extension = entry.name.rsplit_once('.').map_or("", |(_, e)| e);
+    match extension {
+        "PC8" | "256" => pcx::decode_pc8(&entry.name, data).map(|p| format!("{}x{} picture", p.width, p.height)),
+        "PC4" => {
+            let color16 = archive.get(PALETTE_FILE).ok_or_else(|| format!("{} isn't in the archive", PALETTE_FILE))?;
+            let picture = pcx::decode_pc4(data, &pcx::palette16(color16)?)?;
+            Ok(format!("{}x{} picture", picture.width, picture.height))
+        }
+        "SND" => snd::decode(data).map(|clip| format!("{:.2} seconds at {} Hz", clip.duration(), clip.sample_rate)),
+        "XMI" => xmi::to_midi(data).map(|songs| format!("{} song(s)", songs.len())),
+        _ => Ok("no decoder for this kind of file".to_string()),
+    }
+}
+
+/// Sum up the archive, or describe one entry in detail
+fn info(archive: &GxlArchive, name: Option<&str>) -> Result<(), String> {
+    let Some(name) = name else {
+        let mut kinds: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+        for entry in archive.entries() {
+            let kind = detect(entry, archive.get(&entry.name).unwrap_or_default());
+            let totals = kinds.entry(kind).or_default();
+            totals.0 += 1;
+            totals.1 += entry.size;
+        }
+        let total: usize = archive.entries().iter().map(|e| e.size).sum();
+        println!("{} entries, {} bytes", archive.entries().len(), total);
+        for (kind, (count, size)) in kinds {
+            println!("  {:>4} x {:<28} {:>10} bytes", count, kind, size);
+        }
+        return Ok(());
+    };
+
+    let entry = archive
+        .entries()
+        .iter()
+        .find(|entry| entry.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("{} isn't in the archive", name))?;
+    let data = archive.get(&entry.name).unwrap_or_default();
+    println!("name:    {}", entry.name);
+    println!("offset:  {:#x}", entry.offset);
+    println!("size:    {}", entry.size);
+    println!("type:    {}", detect(entry, data));
+    match decode(archive, entry, data) {
+        Ok(result) => println!("decodes: {}", result),
+        Err(e) => println!("decodes: failed, {}", e),
+    }
+    Ok(())
+}
+
+/// Write entries out as they're stored
+fn extract(archive: &GxlArchive, names: &[String], output: &PathBuf) -> Result<(), String> {
+    for name in names {
+        if archive.get(name).is_none() {
+            return Err(format!("{} isn't in the archive", name));
+        }
+    }
+    std::fs::create_dir_all(output).map_err(|e| format!("Couldn't create {}: {}", output.display(), e))?;
+
+    let mut written = 0;
+    for entry in archive.entries() {
+        if !names.is_empty() && !names.iter().any(|name| name.eq_ignore_ascii_case(&entry.name)) {
+            continue;
+        }
+        let path = output.join(&entry.name);
+        let data = archive.get(&entry.name).unwrap_or_default();
+        std::fs::write(&path, data).map_err(|e| format!("Couldn't write {}: {}", path.display(), e))?;
+        written += 1;
+    }
+    println!("Extracted {} files into {}", written, output.display());
+    Ok(())
+}
+
+fn main() {
+    let (command, path) = match parse_args() {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let result = open(&path).and_then(|archive| match command {
+        Command::List => {
+            list(&archive);
+            Ok(())
+        }
+        Command::Info(name) => info(&archive, name.as_deref()),
+        Command::Extract { names, output } => extract(&archive, &names, &output),
+    });
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,135 @@
+// Reads a saved game, checks it, and makes the odd change to it, for looking
+// into saves players send in.
+//
+// Usage: save-tool <save file> [--money DOLLARS] [--heal] [--landmark ID] [--output PATH] [--json]
+//
+// The save is checked against the current save version and printed as a
+// summary, or as the full file with --json. Edits are made with the debug
+// console's commands and written back over the save, or to --output. Saves
+// from older versions are written back at the current one. Anything but the
+// save itself is printed to stderr, so --json can be piped elsewhere.
+
+use rust_trail::game_logic::commands::CommandRegistry;
+use rust_trail::game_logic::journey::Journey;
+use rust_trail::game_logic::save;
+use rust_trail::utils::rng::GameRng;
+
+/// Usage printed when the arguments are wrong
+const USAGE: &str = "Usage: save-tool <save file> [--money DOLLARS] [--heal] [--landmark ID] [--output PATH] [--json]";
+
+/// Options parsed from the command line
+struct Options {
+    file: String,
+    /// Console commands making the requested edits, in order
+    edits: Vec<String>,
+    output: Option<String>,
+    json: bool,
+}
+
+/// Parse command-line arguments
+fn parse_args() -> Result<Options, String> {
+    let mut file = None;
+    let mut options = Options { file: String::new(), edits: Vec::new(), output: None, json: false };
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("Missing value for {}", arg));
+        match arg.as_str() {
+            "--money" => {
+                let dollars = value()?;
+                dollars.parse::<u32>().map_err(|e| format!("Invalid --money: {}", e))?;
+                options.edits.push(format!("set money {}", dollars));
+            }
+            "--heal" => options.edits.push("heal".to_string()),
+            "--landmark" => options.edits.push(format!("teleport {}", value()?)),
+            "--output" => options.output = Some(value()?),
+            "--json" => options.json = true,
+            other if other.starts_with("--") => return Err(format!("Unknown argument '{}'", other)),
+            other if file.is_none() => file = Some(other.to_string()),
+            other => return Err(format!("Unexpected argument '{}'", other)),
+        }
+    }
+
+    options.file = file.ok_or("No save file given")?;
+    Ok(options)
+}
+
+/// Print the parts of a journey worth knowing at a glance
+fn print_summary(journey: &Journey, seed: u64) {
+    let player = &journey.player;
+    println!("Seed:      {}", seed);
+    println!("Date:      {} (day {})", journey.date(), journey.days);
+    println!("Location:  {}, {:.0} miles ({})", player.location, player.miles_traveled, journey.last_landmark().id);
+    println!("Money:     {}", player.money);
+    println!("Pace:      {}, {} rations", player.pace.name(), player.rations.name());
+    println!("Morale:    {:.0}", journey.morale.value());
+    if let Some(student) = &journey.student {
+        println!("Student:   {}", student);
+    }
+    if journey.cheated {
+        println!("Cheats were used on this journey");
+    }
+
+    println!("Party:");
+    for member in &player.party {
+        let diseases: Vec<&str> = member.diseases.iter().map(|d| d.name()).collect();
+        let diseases = if diseases.is_empty() { String::new() } else { format!(", {}", diseases.join(", ")) };
+        println!("  {:<16} {}{}", member.name, member.health.name(), diseases);
+    }
+
+    println!("Supplies:");
+    for item in journey.inventory.get_all_items() {
+        println!("  {:<16} {}", item.item_type.name(), item.quantity);
+    }
+}
+
+/// Check, edit and print the save
+fn run(options: &Options) -> Result<(), String> {
+    let json = std::fs::read_to_string(&options.file).map_err(|e| format!("Couldn't read {}: {}", options.file, e))?;
+    let (version, current) = (save::version_of(&json)?, save::current_version());
+    let saved = save::from_json(&json)?;
+    if version < current {
+        eprintln!("Save version {}, older than the current {}; it will be upgraded", version, current);
+    } else {
+        eprintln!("Save version {}, the current one", version);
+    }
+
+    let mut journey = saved.journey;
+    if !options.edits.is_empty() {
+        let registry = CommandRegistry::with_defaults();
+        let mut rng = GameRng::new(saved.seed);
+        for edit in &options.edits {
+            eprintln!("{}", registry.run(edit, &mut journey, &mut rng)?);
+        }
+    }
+
+    let written = save::to_json(&journey, saved.seed)?;
+    if options.json {
+        println!("{}", written);
+    } else {
+        print_summary(&journey, saved.seed);
+    }
+
+    if !options.edits.is_empty() || options.output.is_some() {
+        let output = options.output.as_deref().unwrap_or(&options.file);
+        std::fs::write(output, written).map_err(|e| format!("Couldn't write {}: {}", output, e))?;
+        eprintln!("Wrote {}", output);
+    }
+    Ok(())
+}
+
+fn main() {
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!("{}", USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(e) = run(&options) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,162 @@
+// Runs simulated journeys without opening a window and prints the results.
+//
+// Usage: simulate [--trips N] [--seed S] [--pace NAME] [--rations NAME] [--month N]
+//                 [--crossing ford|caulk|ferry|guide] [--hunt-below POUNDS]
+//                 [--rest-at HEALTH|never] [--tables PATH] [--json]
+//
+// Trip i uses seed S + i, so any single journey can be replayed in the game
+// with `--seed`.
+
+use rust_trail::game_logic::player::{HealthStatus, Pace, Rations};
+use rust_trail::game_logic::river::CrossingMethod;
+use rust_trail::game_logic::simulation::{simulate, Outcome, Policy, SimulationConfig};
+use rust_trail::game_logic::tables::{self, TABLES_FILE};
+
+/// Options parsed from the command line
+struct Options {
+    trips: u64,
+    seed: u64,
+    policy: Policy,
+    tables: String,
+    json: bool,
+}
+
+/// Parse command-line arguments
+fn parse_args() -> Result<Options, String> {
+    let mut options = Options {
+        trips: 1000,
+        seed: 0,
+        policy: Policy::default(),
+        tables: TABLES_FILE.to_string(),
+        json: false,
+    };
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("Missing value for {}", arg));
+        match arg.as_str() {
+            "--trips" => options.trips = value()?.parse().map_err(|e| format!("Invalid --trips: {}", e))?,
+            "--seed" => options.seed = value()?.parse().map_err(|e| format!("Invalid --seed: {}", e))?,
+            "--pace" => {
+                let name = value()?;
+                options.policy.pace = *Pace::ALL
+                    .iter()
+                    .find(|p| p.name() == name)
+                    .ok_or_else(|| format!("Unknown pace '{}'", name))?;
+            }
+            "--rations" => {
+                let name = value()?.replace('-', " ");
+                options.policy.rations = *Rations::ALL
+                    .iter()
+                    .find(|r| r.name() == name)
+                    .ok_or_else(|| format!("Unknown rations '{}'", name))?;
+            }
+            "--month" => {
+                let month: u8 = value()?.parse().map_err(|e| format!("Invalid --month: {}", e))?;
+                if !(1..=12).contains(&month) {
+                    return Err(format!("Invalid --month: {}", month));
+                }
+                options.policy.departure_month = month;
+            }
+            "--crossing" => {
+                options.policy.crossing = match value()?.as_str() {
+                    "ford" => CrossingMethod::Ford,
+                    "caulk" => CrossingMethod::CaulkAndFloat,
+                    "ferry" => CrossingMethod::Ferry,
+                    "guide" => CrossingMethod::Guide,
+                    other => return Err(format!("Unknown crossing '{}'", other)),
+                };
+            }
+            "--hunt-below" => {
+                options.policy.hunt_below = value()?.parse().map_err(|e| format!("Invalid --hunt-below: {}", e))?;
+            }
+            "--rest-at" => {
+                let name = value()?.replace('-', " ");
+                options.policy.rest_at = match name.as_str() {
+                    "never" => None,
+                    _ => Some(
+                        *[HealthStatus::Fair, HealthStatus::Poor, HealthStatus::VeryPoor]
+                            .iter()
+                            .find(|h| h.name() == name)
+                            .ok_or_else(|| format!("Unknown health '{}'", name))?,
+                    ),
+                };
+            }
+            "--tables" => options.tables = value()?,
+            "--json" => options.json = true,
+            other => return Err(format!("Unknown argument '{}'", other)),
+        }
+    }
+    Ok(options)
+}
+
+fn main() {
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            eprintln!(
+                "Usage: simulate [--trips N] [--seed S] [--pace NAME] [--rations NAME] [--month N] \
+                 [--crossing ford|caulk|ferry|guide] [--hunt-below POUNDS] [--rest-at HEALTH|never] [--tables PATH] [--json]"
+            );
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(e) = tables::init(&options.tables) {
+        eprintln!("{}, using built-in tables", e);
+    }
+
+    let mut arrived = 0;
+    let mut died = 0;
+    let mut snowed_in = 0;
+    let mut timed_out = 0;
+    let mut arrival_days = 0u64;
+    let mut survivors = 0u64;
+
+    for i in 0..options.trips {
+        let config = SimulationConfig {
+            seed: options.seed.wrapping_add(i),
+            policy: options.policy,
+            ..Default::default()
+        };
+        let result = simulate(&config);
+
+        if options.json {
+            match serde_json::to_string(&result) {
+                Ok(line) => println!("{}", line),
+                Err(e) => eprintln!("Failed to serialize result: {}", e),
+            }
+        }
+
+        match result.outcome {
+            Outcome::Arrived => {
+                arrived += 1;
+                arrival_days += result.days as u64;
+                survivors += result.survivors as u64;
+            }
+            Outcome::PartyDied => died += 1,
+            Outcome::SnowedIn => snowed_in += 1,
+            Outcome::TimedOut => timed_out += 1,
+        }
+    }
+
+    let percent = |n: u64| n as f64 * 100.0 / options.trips.max(1) as f64;
+    println!(
+        "{} trips at a {} pace on {} rations leaving in month {} (seeds {}..{})",
+        options.trips,
+        options.policy.pace.name(),
+        options.policy.rations.name(),
+        options.policy.departure_month,
+        options.seed,
+        options.seed.wrapping_add(options.trips),
+    );
+    println!("  Arrived:    {:5.1}%", percent(arrived));
+    println!("  Party died: {:5.1}%", percent(died));
+    println!("  Snowed in:  {:5.1}%", percent(snowed_in));
+    println!("  Timed out:  {:5.1}%", percent(timed_out));
+    if arrived > 0 {
+        println!("  Average days to Oregon: {:.1}", arrival_days as f64 / arrived as f64);
+        println!("  Average survivors:      {:.2}", survivors as f64 / arrived as f64);
+    }
+}
@@ -0,0 +1,106 @@
+use macroquad::prelude::*;
+use crate::engine::display::screen_width;
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::text_input::{TextInput, TextInputEvent};
+use crate::engine::ui::style;
+use crate::game::GameContext;
+use crate::engine::logging;
+use crate::debug::parity;
+use crate::game_logic::commands::CommandRegistry;
+
+/// Height of the console when it's dropped down
+const HEIGHT: f32 = 220.0;
+/// Height of one line of output
+const LINE_HEIGHT: f32 = 18.0;
+/// Lines of output kept
+const MAX_LINES: usize = 100;
+/// Longest command line
+const MAX_INPUT: usize = 60;
+
+/// Drop-down console for running developer commands on the journey, which
+/// also shows what the game has logged
+///
+/// Only available in debug builds, where backtick opens and closes it.
+pub struct Console {
+    /// Whether the console is down
+    open: bool,
+    /// Command line being typed
+    input: TextInput,
+    /// Commands typed and their replies, oldest first
+    lines: Vec<String>,
+    /// Commands the console understands
+    registry: CommandRegistry,
+}
+
+impl Console {
+    /// Create a closed console with the standard commands and `parity`
+    pub fn new() -> Self {
+        let input = TextInput::new(Rect::new(8.0, HEIGHT - 32.0, screen_width() - 16.0, 24.0), MAX_INPUT);
+        let mut registry = CommandRegistry::with_defaults();
+        parity::register(&mut registry);
+        Self {
+            open: false,
+            input,
+            lines: vec!["Type 'help' for a list of commands.".to_string()],
+            registry,
+        }
+    }
+
+    /// Open or close the console on backtick, returning whether it is open
+    pub fn toggle(&mut self) -> bool {
+        if cfg!(debug_assertions) && is_key_pressed(KeyCode::GraveAccent) {
+            self.open = !self.open;
+            self.input.set_focused(self.open);
+            // Don't let the backtick land in the command line
+            while get_char_pressed().is_some() {}
+        }
+        self.open
+    }
+
+    /// Add anything logged since the last frame to the output
+    pub fn collect_logs(&mut self) {
+        self.lines.extend(logging::take_screen_lines());
+        self.trim();
+    }
+
+    /// Forget the oldest output beyond what's kept
+    fn trim(&mut self) {
+        let excess = self.lines.len().saturating_sub(MAX_LINES);
+        self.lines.drain(..excess);
+    }
+
+    /// Take typing and run commands when Enter is pressed
+    pub fn update(&mut self, ctx: &mut GameContext, dt: f32) {
+        if self.input.update(dt) != Some(TextInputEvent::Submitted) {
+            return;
+        }
+        let line = self.input.text().trim().to_string();
+        self.input.set_text("");
+        self.input.set_focused(true);
+        if line.is_empty() {
+            return;
+        }
+        self.lines.push(format!("> {}", line));
+        let reply = match self.registry.run(&line, &mut ctx.journey, &mut ctx.rng) {
+            Ok(reply) => reply,
+            Err(e) => e,
+        };
+        self.lines.extend(reply.lines().map(str::to_string));
+        self.trim();
+    }
+
+    /// Draw the console over the top of the screen
+    pub fn draw(&self) {
+        if !self.open {
+            return;
+        }
+        draw_rectangle(0.0, 0.0, screen_width(), HEIGHT, style::theme().panel);
+        draw_line(0.0, HEIGHT, screen_width(), HEIGHT, 1.0, style::theme().border);
+        let visible = ((HEIGHT - 44.0) / LINE_HEIGHT) as usize;
+        let shown = &self.lines[self.lines.len().saturating_sub(visible)..];
+        for (i, line) in shown.iter().enumerate() {
+            draw_text(line, 8.0, 18.0 + i as f32 * LINE_HEIGHT, 16.0, style::theme().text);
+        }
+        self.input.draw();
+    }
+}
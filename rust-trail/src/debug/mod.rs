@@ -0,0 +1,5 @@
+// Debug module - developer tools that aren't part of normal play
+
+pub mod console;
+pub mod overlay;
+pub mod parity;
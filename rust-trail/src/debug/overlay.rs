@@ -0,0 +1,90 @@
+use macroquad::prelude::*;
+use crate::engine::display::screen_height;
+use crate::engine::renderer::{self, draw_text};
+use crate::engine::ui::style;
+use crate::game::{GameContext, GameState};
+use crate::game_logic::events::part_name;
+use crate::game_logic::journey::Journey;
+
+/// Height of one line of text
+const LINE_HEIGHT: f32 = 16.0;
+/// Size of the overlay's text
+const FONT_SIZE: f32 = 16.0;
+
+/// Developer overlay, toggled with F3, showing the frame rate, what the last
+/// frame drew, the game state and the journey's numbers for bug reports
+#[derive(Debug, Default)]
+pub struct DebugOverlay {
+    /// Whether the overlay is shown
+    visible: bool,
+}
+
+impl DebugOverlay {
+    /// Create a hidden overlay
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show or hide the overlay on F3
+    pub fn update(&mut self) {
+        if is_key_pressed(KeyCode::F3) {
+            self.visible = !self.visible;
+        }
+    }
+
+    /// Conditions holding the party up or slowing it down
+    fn active_events(journey: &Journey) -> String {
+        let mut active = Vec::new();
+        if journey.lost_days > 0 {
+            active.push(format!("lost {}d", journey.lost_days));
+        }
+        if journey.snowed_in_days > 0 {
+            active.push(format!("snowed in {}d", journey.snowed_in_days));
+        }
+        if journey.injured_oxen > 0 {
+            active.push(format!("{} injured oxen", journey.injured_oxen));
+        }
+        active.extend(journey.broken_parts.iter().map(|&part| format!("broken {}", part_name(part))));
+        match active.is_empty() {
+            true => "none".to_string(),
+            false => active.join(", "),
+        }
+    }
+
+    /// Draw the overlay down the left of the screen
+    pub fn draw(&self, ctx: &GameContext, state: Option<GameState>) {
+        if !self.visible {
+            return;
+        }
+        let journey = &ctx.journey;
+        let inventory = &journey.inventory;
+        let drawn = renderer::stats();
+        let mut lines = vec![
+            format!("FPS: {}", get_fps()),
+            format!("Draw calls: {}  Sprites: {}  Glyphs: {}", drawn.draw_calls, drawn.sprites, drawn.glyphs),
+            format!("State: {}", state.map_or("none".to_string(), |s| format!("{:?}", s))),
+            format!("Day {}  {}  {:.1} miles", journey.days, journey.date(), journey.player.miles_traveled),
+            format!("Landmark: {} ({})", journey.last_landmark().id, journey.landmark),
+            format!("Weather: {}  Morale: {:.0}", journey.weather.name(), journey.morale.value()),
+            format!("Active: {}", Self::active_events(journey)),
+            format!("Weight: {:.0} / {:.0} lb", inventory.total_weight(), inventory.capacity()),
+        ];
+        for member in &journey.player.party {
+            let diseases: Vec<&str> = member.diseases.iter().map(|d| d.name()).collect();
+            lines.push(format!(
+                "  {}: {} ({:.1}) {}",
+                member.name,
+                member.health.name(),
+                member.general_health,
+                diseases.join(", ")
+            ));
+        }
+
+        let height = lines.len() as f32 * LINE_HEIGHT + 8.0;
+        let y = screen_height() - height;
+        draw_rectangle(0.0, y, 360.0, height, Color::new(0.0, 0.0, 0.0, 0.7));
+        for (i, line) in lines.iter().enumerate() {
+            draw_text(line, 6.0, y + LINE_HEIGHT * (i + 1) as f32, FONT_SIZE, style::theme().focus);
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use serde::Deserialize;
+use std::fmt;
+use crate::game_logic::commands::CommandRegistry;
+use crate::game_logic::events::Event;
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::journey::Journey;
+use crate::game_logic::landmarks;
+use crate::utils::rng::GameRng;
+
+/// Canonical list of content from the original game
+const ORIGINAL_CONTENT: &str = include_str!("../../assets/data/original_content.json");
+
+/// One piece of content from the original game
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContentEntry {
+    /// Identifier matched against the rewrite's implementation
+    pub id: String,
+    /// Display name
+    pub name: String,
+}
+
+/// The original game's content, grouped by category
+#[derive(Debug, Clone, Deserialize)]
+pub struct OriginalContent {
+    pub events: Vec<ContentEntry>,
+    pub landmarks: Vec<ContentEntry>,
+    pub store_items: Vec<ContentEntry>,
+}
+
+/// Implementation status of one category
+#[derive(Debug, Clone)]
+pub struct ParitySection {
+    /// Category heading
+    pub category: &'static str,
+    /// Original entries and whether each is implemented
+    pub entries: Vec<(ContentEntry, bool)>,
+    /// Implemented ids that aren't in the original list
+    pub extras: Vec<String>,
+}
+
+impl ParitySection {
+    /// Compare the original entries against the implemented ids
+    fn compare(category: &'static str, original: &[ContentEntry], implemented: &[&str]) -> Self {
+        let entries = original
+            .iter()
+            .map(|entry| (entry.clone(), implemented.contains(&entry.id.as_str())))
+            .collect();
+        let extras = implemented
+            .iter()
+            .filter(|id| !original.iter().any(|entry| entry.id == **id))
+            .map(|id| id.to_string())
+            .collect();
+        Self { category, entries, extras }
+    }
+
+    /// Number of original entries that are implemented
+    pub fn implemented_count(&self) -> usize {
+        self.entries.iter().filter(|(_, done)| *done).count()
+    }
+}
+
+/// Parity report covering every category
+#[derive(Debug, Clone)]
+pub struct ParityReport {
+    pub sections: Vec<ParitySection>,
+}
+
+impl fmt::Display for ParityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Original content parity")?;
+        for section in &self.sections {
+            writeln!(
+                f,
+                "\n{}: {}/{} implemented",
+                section.category,
+                section.implemented_count(),
+                section.entries.len()
+            )?;
+            for (entry, done) in &section.entries {
+                writeln!(f, "  [{}] {}", if *done { "x" } else { " " }, entry.name)?;
+            }
+            if !section.extras.is_empty() {
+                writeln!(f, "  Not in the original: {}", section.extras.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Load the canonical content list
+pub fn original_content() -> OriginalContent {
+    serde_json::from_str(ORIGINAL_CONTENT).expect("original_content.json is malformed")
+}
+
+/// Get the parity id for a store item
+fn item_id(item: ItemType) -> &'static str {
+    match item {
+        ItemType::Food => "food",
+        ItemType::Clothing => "clothing",
+        ItemType::Ammunition => "ammunition",
+        ItemType::OxenPair => "oxen",
+        ItemType::SpareWheel => "wagon_wheel",
+        ItemType::SpareAxle => "wagon_axle",
+        ItemType::SpareTongue => "wagon_tongue",
+        ItemType::MedicalSupply => "medical_supplies",
+    }
+}
+
+/// Event ids the rewrite can currently trigger
+fn implemented_events() -> Vec<&'static str> {
+    Event::ALL.iter().map(|event| event.id()).collect()
+}
+
+/// Landmark ids the rewrite currently has on its trail
+fn implemented_landmarks() -> Vec<&'static str> {
+    landmarks::all().iter().map(|landmark| landmark.id.as_str()).collect()
+}
+
+/// Store item ids the rewrite currently sells
+fn implemented_store_items() -> Vec<&'static str> {
+    ItemType::ALL.iter().map(|item| item_id(*item)).collect()
+}
+
+/// Build the parity report
+pub fn report() -> ParityReport {
+    let original = original_content();
+    ParityReport {
+        sections: vec![
+            ParitySection::compare("Events", &original.events, &implemented_events()),
+            ParitySection::compare("Landmarks", &original.landmarks, &implemented_landmarks()),
+            ParitySection::compare("Store items", &original.store_items, &implemented_store_items()),
+        ],
+    }
+}
+
+/// Add the `parity` command, which prints the report, to a console's commands
+pub fn register(registry: &mut CommandRegistry) {
+    registry.register("parity", "parity", print_report);
+}
+
+/// Print the parity report
+fn print_report(_args: &[&str], _journey: &mut Journey, _rng: &mut GameRng) -> Result<String, String> {
+    Ok(report().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_console_prints_the_report() {
+        let mut registry = CommandRegistry::with_defaults();
+        register(&mut registry);
+        let reply = registry.run("parity", &mut Journey::default(), &mut GameRng::new(1)).unwrap();
+        assert_eq!(reply, report().to_string());
+        assert!(reply.contains("Landmarks"));
+    }
+}
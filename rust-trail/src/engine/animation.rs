@@ -0,0 +1,116 @@
+// Frame animations played from sprite sheets, either laid out in code as a
+// row of equal frames or described by an animation file
+
+use macroquad::prelude::*;
+use rust_trail::utils::sprites::Frame;
+use crate::engine::renderer::draw_texture_ex;
+
+/// A row of equally sized animation frames in one image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpriteSheet {
+    /// Image holding the frames, left to right
+    pub texture: &'static str,
+    /// Width of one frame (in pixels)
+    pub frame_width: f32,
+    /// Height of one frame (in pixels)
+    pub frame_height: f32,
+    /// Number of frames
+    pub frames: usize,
+}
+
+impl SpriteSheet {
+    /// Area of the image holding a frame
+    pub fn source(&self, frame: usize) -> Rect {
+        let frame = frame % self.frames.max(1);
+        Rect::new(frame as f32 * self.frame_width, 0.0, self.frame_width, self.frame_height)
+    }
+
+    /// Draw a frame with its top-left corner at a point, scaled up by a whole factor
+    pub fn draw(&self, texture: Texture2D, frame: usize, x: f32, y: f32, scale: f32) {
+        draw_texture_ex(
+            texture,
+            x,
+            y,
+            WHITE,
+            DrawTextureParams {
+                dest_size: Some(vec2(self.frame_width * scale, self.frame_height * scale)),
+                source: Some(self.source(frame)),
+                ..Default::default()
+            },
+        );
+    }
+}
+
+/// Area of the sheet holding an animation file's frame
+pub fn frame_source(frame: &Frame) -> Rect {
+    let [x, y, w, h] = frame.rect;
+    Rect::new(x, y, w, h)
+}
+
+/// Draw an animation file's frame with its top-left corner at a point, scaled up by a whole factor
+pub fn draw_frame(texture: Texture2D, frame: &Frame, x: f32, y: f32, scale: f32) {
+    let (width, height) = frame.size();
+    draw_texture_ex(
+        texture,
+        x,
+        y,
+        WHITE,
+        DrawTextureParams {
+            dest_size: Some(vec2(width * scale, height * scale)),
+            source: Some(frame_source(frame)),
+            ..Default::default()
+        },
+    );
+}
+
+/// Playback position in a looping animation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Animation {
+    /// Number of frames in the loop
+    frames: usize,
+    /// Frames shown per second at normal speed
+    fps: f32,
+    /// Seconds played, scaled by speed
+    time: f32,
+}
+
+impl Animation {
+    /// Create an animation stopped on its first frame
+    pub const fn new(frames: usize, fps: f32) -> Self {
+        Self { frames, fps, time: 0.0 }
+    }
+
+    /// Play the animation on, faster or slower than normal
+    pub fn update(&mut self, dt: f32, speed: f32) {
+        self.time += dt * speed.max(0.0);
+    }
+
+    /// Stop on the first frame, the resting pose
+    pub fn halt(&mut self) {
+        self.time = 0.0;
+    }
+
+    /// Frame to show now
+    pub fn frame(&self) -> usize {
+        (self.time * self.fps) as usize % self.frames.max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animations_loop_and_halt() {
+        let mut walk = Animation::new(4, 8.0);
+        walk.update(0.25, 1.0);
+        assert_eq!(walk.frame(), 2);
+        walk.update(0.25, 2.0);
+        assert_eq!(walk.frame(), 2);
+        walk.halt();
+        assert_eq!(walk.frame(), 0);
+
+        let sheet = SpriteSheet { texture: "WAGONS.png", frame_width: 67.0, frame_height: 25.0, frames: 5 };
+        assert_eq!(sheet.source(6).x, 67.0);
+    }
+}
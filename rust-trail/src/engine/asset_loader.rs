@@ -1,6 +1,44 @@
 use macroquad::prelude::*;
-use std::collections::HashMap;
+use macroquad::audio::{load_sound_from_bytes, Sound};
+use macroquad::file::FileError;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 use std::path::Path;
+use rust_trail::utils::format_converters::snd;
+use rust_trail::utils::gxl::GxlArchive;
+use rust_trail::utils::pak::PakArchive;
+use rust_trail::utils::sprites::{Atlas, SpriteAnimation};
+use crate::engine::fallback;
+use crate::engine::integrity::{self, IntegrityReport};
+use crate::engine::palette::Palette;
+
+/// Assets shipped with the game
+pub const BUNDLED_DIR: &str = "assets";
+/// Folder players drop replacement assets into, searched before everything else
+pub const OVERRIDE_DIR: &str = "overrides";
+/// The bundled assets packed into one zip file, used where the folder doesn't have a file
+pub const BUNDLE_FILE: &str = "assets.pak";
+/// Folder mods are installed into, searched after the player's own replacements
+pub const MODS_DIR: &str = "mods";
+/// The original game's archive, searched last
+pub const ORIGINAL_ARCHIVE: &str = "original_game/OREGON.GXL";
+
+/// Manifest file listing the assets each scene needs, relative to the asset base path
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// A place assets are looked for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetRoot {
+    /// A folder laid out like the bundled assets, with `images/`, `audio/sounds/` and so on
+    Dir(String),
+    /// A zip bundle holding a folder laid out like the bundled assets
+    Pak(String),
+    /// An original game archive, whose files are looked up by name alone. Only
+    /// files the game reads as they are, like `.TXT`, can be used from one,
+    /// along with sound effects, which are decoded from the original `.SND`
+    Gxl(String),
+}
 
 /// Represents the different types of assets
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -10,87 +48,961 @@ pub enum AssetType {
     Music,
     Text,
     Animation,
+    Atlas,
     Font,
 }
 
+impl AssetType {
+    /// File extensions this asset type can be loaded from
+    fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            AssetType::Image => &["png", "tga"],
+            AssetType::Sound | AssetType::Music => &["wav", "ogg"],
+            AssetType::Text => &["txt", "json"],
+            AssetType::Animation | AssetType::Atlas => &["json"],
+            AssetType::Font => &["ttf"],
+        }
+    }
+}
+
+/// Why an asset failed to load
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetError {
+    /// The file or manifest entry doesn't exist
+    NotFound { path: String },
+    /// The file was read but its contents couldn't be decoded
+    DecodeFailed { path: String, reason: String },
+    /// Reading the file failed
+    IoError { path: String, reason: String },
+    /// The file type can't be used for this kind of asset
+    UnsupportedFormat { path: String, asset_type: AssetType },
+}
+
+impl AssetError {
+    /// Classify a macroquad file error
+    fn from_file_error(error: FileError) -> Self {
+        match error.kind {
+            miniquad::fs::Error::IOError(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                AssetError::NotFound { path: error.path }
+            }
+            kind => AssetError::IoError { path: error.path, reason: kind.to_string() },
+        }
+    }
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetError::NotFound { path } => write!(f, "Asset not found: {}", path),
+            AssetError::DecodeFailed { path, reason } => write!(f, "Failed to decode '{}': {}", path, reason),
+            AssetError::IoError { path, reason } => write!(f, "Failed to read '{}': {}", path, reason),
+            AssetError::UnsupportedFormat { path, asset_type } => {
+                write!(f, "Unsupported format for {:?} asset '{}'", asset_type, path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AssetError {}
+
+/// What to do when an asset fails to load
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorAction {
+    /// Log the error and carry on, drawing placeholder art for missing images
+    Fallback,
+    /// Stop the game and show the error screen
+    Fatal,
+}
+
+/// Decides how each asset failure is handled
+pub type ErrorPolicy = Box<dyn Fn(&AssetError) -> ErrorAction>;
+
+/// Assets needed by one scene or area of the game
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AssetGroup {
+    /// Image files
+    #[serde(default)]
+    pub textures: Vec<String>,
+    /// Sound effect files
+    #[serde(default)]
+    pub sounds: Vec<String>,
+    /// Music tracks
+    #[serde(default)]
+    pub music: Vec<String>,
+    /// TTF fonts
+    #[serde(default)]
+    pub fonts: Vec<String>,
+    /// Text and data files
+    #[serde(default)]
+    pub texts: Vec<String>,
+    /// Sprite animation files
+    #[serde(default)]
+    pub animations: Vec<String>,
+    /// Sprite atlas files, each loading the image it names
+    #[serde(default)]
+    pub atlases: Vec<String>,
+    /// Whether the group's assets are freed once no scene is using them,
+    /// for big pictures only seen now and then
+    #[serde(default)]
+    pub release: bool,
+}
+
+impl AssetGroup {
+    /// Every asset in the group, textures first
+    fn entries(&self) -> impl Iterator<Item = (AssetType, &String)> {
+        self.textures.iter().map(|name| (AssetType::Image, name))
+            .chain(self.music.iter().map(|name| (AssetType::Music, name)))
+            .chain(self.sounds.iter().map(|name| (AssetType::Sound, name)))
+            .chain(self.fonts.iter().map(|name| (AssetType::Font, name)))
+            .chain(self.texts.iter().map(|name| (AssetType::Text, name)))
+            .chain(self.animations.iter().map(|name| (AssetType::Animation, name)))
+            .chain(self.atlases.iter().map(|name| (AssetType::Atlas, name)))
+    }
+}
+
+/// A sprite: the texture it's in and the area of it that it takes up
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sprite {
+    pub texture: Texture2D,
+    pub source: Rect,
+}
+
+/// Asset groups and the order they are preloaded in
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AssetManifest {
+    /// Groups loaded at startup, in order
+    #[serde(default)]
+    pub preload: Vec<String>,
+    /// Asset groups by name
+    #[serde(default)]
+    pub groups: HashMap<String, AssetGroup>,
+}
+
+impl AssetManifest {
+    /// Assets other groups still need: all of a group that's kept loaded, and
+    /// those of a released group some scene is using
+    fn needed_by_others(&self, name: &str, users: &HashMap<String, usize>) -> HashSet<(AssetType, &String)> {
+        self.groups
+            .iter()
+            .filter(|(other, g)| *other != name && (!g.release || users.get(*other).is_some_and(|&n| n > 0)))
+            .flat_map(|(_, g)| g.entries())
+            .collect()
+    }
+
+    /// Assets of a group that no other group still needs: none in a group
+    /// that's kept loaded, or in a released group some scene is using
+    fn unneeded(&self, name: &str, users: &HashMap<String, usize>) -> Vec<(AssetType, String)> {
+        let Some(group) = self.groups.get(name) else {
+            return Vec::new();
+        };
+        let needed = self.needed_by_others(name, users);
+        group
+            .entries()
+            .filter(|entry| !needed.contains(entry))
+            .map(|(asset_type, asset)| (asset_type, asset.clone()))
+            .collect()
+    }
+}
+
+/// Loaded assets of one type, keyed by file name
+struct AssetCache<T: Copy> {
+    /// Assets by file name
+    items: HashMap<String, T>,
+}
+
+impl<T: Copy> AssetCache<T> {
+    /// Create an empty cache
+    fn new() -> Self {
+        Self { items: HashMap::new() }
+    }
+
+    /// Get a cached asset by name
+    fn get(&self, name: &str) -> Option<T> {
+        self.items.get(name).copied()
+    }
+
+    /// Cache an asset under a name
+    fn insert(&mut self, name: &str, asset: T) {
+        self.items.insert(name.to_string(), asset);
+    }
+
+    /// Whether an asset is cached under a name
+    fn contains(&self, name: &str) -> bool {
+        self.items.contains_key(name)
+    }
+
+    /// Take an asset out of the cache
+    fn remove(&mut self, name: &str) -> Option<T> {
+        self.items.remove(name)
+    }
+}
+
 /// Asset manager for loading and caching game assets
 pub struct AssetManager {
     /// Cache of loaded textures
-    textures: HashMap<String, Texture2D>,
-    /// Base path for assets
+    textures: AssetCache<Texture2D>,
+    /// Colour tables of loaded textures that have one, by texture name
+    palettes: HashMap<String, Palette>,
+    /// Cache of loaded sound effects
+    sounds: AssetCache<Sound>,
+    /// Cache of loaded music tracks
+    music: AssetCache<Sound>,
+    /// Cache of loaded fonts
+    fonts: AssetCache<Font>,
+    /// Cache of loaded text files
+    texts: HashMap<String, String>,
+    /// Cache of loaded sprite animations
+    animations: HashMap<String, SpriteAnimation>,
+    /// Cache of loaded sprite atlases
+    atlases: HashMap<String, Atlas>,
+    /// Each sprite in a loaded atlas: the atlas's texture and where it is
+    sprites: HashMap<String, (String, Rect)>,
+    /// Base path for the bundled assets and the manifest
     asset_base_path: String,
+    /// Places each asset is looked for, the first that has it winning
+    roots: Vec<AssetRoot>,
+    /// Archive roots that opened, by path
+    archives: HashMap<String, GxlArchive>,
+    /// Bundle roots that opened, by path
+    paks: HashMap<String, PakArchive>,
+    /// Asset groups from the manifest
+    manifest: AssetManifest,
+    /// Assets waiting to be loaded by `load_next`
+    queue: VecDeque<(AssetType, String)>,
+    /// Assets queued since the queue was last empty
+    queued_total: usize,
+    /// How load failures are handled
+    error_policy: ErrorPolicy,
+    /// First failure the policy treated as fatal
+    fatal_error: Option<AssetError>,
+    /// Placeholder for images that failed to load, created on first use
+    fallback_texture: Option<Texture2D>,
+    /// Beep played for sounds that failed to load, made on first use
+    fallback_sound: Option<Sound>,
+    /// Assets that failed to load and were replaced by a built-in placeholder
+    missing: Vec<String>,
+    /// Number of scenes using each group
+    group_users: HashMap<String, usize>,
 }
 
 impl AssetManager {
     /// Create a new asset manager
     pub fn new(base_path: &str) -> Self {
         Self {
-            textures: HashMap::new(),
+            textures: AssetCache::new(),
+            palettes: HashMap::new(),
+            sounds: AssetCache::new(),
+            music: AssetCache::new(),
+            fonts: AssetCache::new(),
+            texts: HashMap::new(),
+            animations: HashMap::new(),
+            atlases: HashMap::new(),
+            sprites: HashMap::new(),
             asset_base_path: base_path.to_string(),
+            roots: vec![AssetRoot::Dir(base_path.to_string())],
+            archives: HashMap::new(),
+            paks: HashMap::new(),
+            manifest: AssetManifest::default(),
+            queue: VecDeque::new(),
+            queued_total: 0,
+            error_policy: Box::new(|_| ErrorAction::Fallback),
+            fatal_error: None,
+            fallback_texture: None,
+            fallback_sound: None,
+            missing: Vec::new(),
+            group_users: HashMap::new(),
+        }
+    }
+
+    /// Set the places assets are looked for, in the order they're searched
+    ///
+    /// Archives and bundles are read into memory here; one that can't be
+    /// read is skipped.
+    pub fn set_roots(&mut self, roots: Vec<AssetRoot>) {
+        self.archives.clear();
+        self.paks.clear();
+        for root in &roots {
+            let opened = match root {
+                AssetRoot::Dir(_) => continue,
+                AssetRoot::Gxl(path) => std::fs::read(path)
+                    .map_err(|e| e.to_string())
+                    .and_then(GxlArchive::parse)
+                    .map(|archive| {
+                        self.archives.insert(path.clone(), archive);
+                    }),
+                AssetRoot::Pak(path) => std::fs::read(path)
+                    .map_err(|e| e.to_string())
+                    .and_then(PakArchive::parse)
+                    .map(|pak| {
+                        self.paks.insert(path.clone(), pak);
+                    }),
+            };
+            if let Err(e) = opened {
+                log::debug!("Not using {:?}: {}", root, e);
+            }
         }
+        log::info!("Asset search roots: {:?}", roots);
+        self.roots = roots;
     }
 
-    /// Get the full path for an asset
-    pub fn get_asset_path(&self, asset_type: AssetType, asset_name: &str) -> String {
+    /// Path of an asset within a folder or bundle
+    fn relative_path(asset_type: AssetType, asset_name: &str) -> String {
         let type_folder = match asset_type {
             AssetType::Image => "images",
             AssetType::Sound => "audio/sounds",
             AssetType::Music => "audio/music",
             AssetType::Text => "text",
             AssetType::Animation => "animations",
+            AssetType::Atlas => "atlases",
             AssetType::Font => "fonts",
         };
 
-        format!("{}/{}/{}", self.asset_base_path, type_folder, asset_name)
+        format!("{}/{}", type_folder, asset_name)
+    }
+
+    /// Path of an asset within a folder root
+    fn path_in(dir: &str, asset_type: AssetType, asset_name: &str) -> String {
+        format!("{}/{}", dir, Self::relative_path(asset_type, asset_name))
+    }
+
+    /// A file from the first bundle holding it, by its path inside
+    fn read_pak(&self, path: &str) -> Option<(String, Result<Vec<u8>, String>)> {
+        self.roots.iter().find_map(|root| match root {
+            AssetRoot::Pak(pak) => {
+                let bytes = self.paks.get(pak)?.get(path)?;
+                Some((format!("{}/{}", pak, path), bytes))
+            }
+            _ => None,
+        })
+    }
+
+
+    /// Check the bundled assets, in their folder or else a bundle, against
+    /// the ones the game shipped with. A file replaced in an earlier folder
+    /// root counts as fine
+    ///
+    /// The web build fetches its assets as it goes, so there's nothing to check.
+    pub fn verify_integrity(&self) -> IntegrityReport {
+        if cfg!(target_arch = "wasm32") {
+            return IntegrityReport::default();
+        }
+
+        let base = Path::new(&self.asset_base_path);
+        let replacements: Vec<&str> = self
+            .roots
+            .iter()
+            .map_while(|root| match root {
+                AssetRoot::Dir(dir) if *dir == self.asset_base_path => None,
+                AssetRoot::Dir(dir) => Some(Some(dir.as_str())),
+                AssetRoot::Pak(_) | AssetRoot::Gxl(_) => Some(None),
+            })
+            .flatten()
+            .collect();
+        let report = integrity::verify(
+            |file| {
+                std::fs::read(base.join(file))
+                    .ok()
+                    .or_else(|| self.read_pak(file).and_then(|(_, bytes)| bytes.ok()))
+            },
+            |file| replacements.iter().any(|dir| Path::new(dir).join(file).exists()),
+        );
+        for file in &report.missing {
+            log::warn!("Asset {} is missing", file);
+        }
+        for file in &report.corrupt {
+            log::warn!("Asset {} doesn't match the one the game shipped with", file);
+        }
+        report
+    }
+
+    /// Name of the original file an asset can be decoded from, for the kinds
+    /// of asset that can be: "BEEP.wav" comes from "BEEP.SND"
+    fn original_name(asset_type: AssetType, name: &str) -> Option<String> {
+        let (stem, _) = name.rsplit_once('.')?;
+        match asset_type {
+            AssetType::Sound => Some(format!("{}.SND", stem)),
+            _ => None,
+        }
+    }
+
+    /// Read an asset's bytes from the first root that has it, along with the
+    /// path it was read from
+    async fn read_asset(&self, asset_type: AssetType, name: &str) -> Result<(String, Vec<u8>), AssetError> {
+        for root in &self.roots {
+            match root {
+                AssetRoot::Dir(dir) => {
+                    let path = Self::path_in(dir, asset_type, name);
+                    if Path::new(&path).exists() {
+                        return Self::read_file(asset_type, &path).await.map(|bytes| (path, bytes));
+                    }
+                }
+                AssetRoot::Pak(pak) => {
+                    let relative = Self::relative_path(asset_type, name);
+                    if let Some(bytes) = self.paks.get(pak).and_then(|p| p.get(&relative)) {
+                        let path = format!("{}/{}", pak, relative);
+                        Self::check_format(asset_type, &path)?;
+                        let bytes = bytes.map_err(|reason| AssetError::DecodeFailed { path: path.clone(), reason })?;
+                        return Ok((path, bytes));
+                    }
+                }
+                AssetRoot::Gxl(archive) => {
+                    let Some(files) = self.archives.get(archive) else {
+                        continue;
+                    };
+                    if let Some(bytes) = files.get(name) {
+                        let path = format!("{}/{}", archive, name);
+                        Self::check_format(asset_type, &path)?;
+                        return Ok((path, bytes.to_vec()));
+                    }
+                    if let Some(original) = Self::original_name(asset_type, name) {
+                        if let Some(bytes) = files.get(&original) {
+                            let path = format!("{}/{}", archive, original);
+                            let clip = snd::decode(bytes).map_err(|reason| AssetError::DecodeFailed { path: path.clone(), reason })?;
+                            return Ok((path, clip.to_wav()));
+                        }
+                    }
+                }
+            }
+        }
+
+        // Nothing on disk says so (always the case on the web), so ask for
+        // the bundled copy and let that report what's wrong
+        let path = Self::path_in(&self.asset_base_path, asset_type, name);
+        Self::read_file(asset_type, &path).await.map(|bytes| (path, bytes))
+    }
+
+    /// Set how asset load failures are handled
+    pub fn set_error_policy(&mut self, policy: impl Fn(&AssetError) -> ErrorAction + 'static) {
+        self.error_policy = Box::new(policy);
+    }
+
+    /// The failure that stopped the game, if the policy treated one as fatal
+    pub fn fatal_error(&self) -> Option<&AssetError> {
+        self.fatal_error.as_ref()
+    }
+
+    /// Apply the error policy to a failure, returning whether to fall back
+    fn handle_error(&mut self, error: &AssetError) -> bool {
+        match (self.error_policy)(error) {
+            ErrorAction::Fallback => true,
+            ErrorAction::Fatal => {
+                self.fatal_error.get_or_insert_with(|| error.clone());
+                false
+            }
+        }
+    }
+
+    /// Check a file's extension is one the asset type can be loaded from
+    fn check_format(asset_type: AssetType, path: &str) -> Result<(), AssetError> {
+        let extension = Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase())
+            .unwrap_or_default();
+        if asset_type.extensions().contains(&extension.as_str()) {
+            Ok(())
+        } else {
+            Err(AssetError::UnsupportedFormat { path: path.to_string(), asset_type })
+        }
+    }
+
+    /// Read a file's bytes
+    async fn read_file(asset_type: AssetType, path: &str) -> Result<Vec<u8>, AssetError> {
+        Self::check_format(asset_type, path)?;
+        load_file(path).await.map_err(AssetError::from_file_error)
+    }
+
+    /// Magenta checkerboard drawn in place of images that failed to load
+    fn fallback_texture(&mut self) -> Texture2D {
+        *self.fallback_texture.get_or_insert_with(fallback::placeholder_texture)
+    }
+
+    /// Beep played in place of sounds that failed to load
+    async fn fallback_sound(&mut self) -> Option<Sound> {
+        if self.fallback_sound.is_none() {
+            self.fallback_sound = fallback::beep().await;
+        }
+        self.fallback_sound
+    }
+
+    /// Note an asset replaced by a placeholder, so the player can be told
+    fn mark_missing(&mut self, name: &str) {
+        if !self.missing.iter().any(|missing| missing == name) {
+            self.missing.push(name.to_string());
+        }
+    }
+
+    /// Assets replaced by a built-in placeholder, in the order they failed
+    pub fn missing(&self) -> &[String] {
+        &self.missing
+    }
+
+    /// Decode a texture without touching the cache
+    fn decode_texture(path: &str, bytes: &[u8]) -> Result<Texture2D, AssetError> {
+        let image = image::load_from_memory(bytes).map_err(|e| match e {
+            image::ImageError::Unsupported(_) => AssetError::UnsupportedFormat {
+                path: path.to_string(),
+                asset_type: AssetType::Image,
+            },
+            e => AssetError::DecodeFailed { path: path.to_string(), reason: e.to_string() },
+        })?;
+        let image = image.to_rgba8();
+        Ok(Texture2D::from_rgba8(image.width() as u16, image.height() as u16, image.as_raw()))
     }
 
     /// Load a texture from file
-    pub async fn load_texture(&mut self, name: &str) -> Result<Texture2D, String> {
+    ///
+    /// If loading fails and the error policy allows it, the placeholder
+    /// texture is cached under the name and returned instead.
+    pub async fn load_texture(&mut self, name: &str) -> Result<Texture2D, AssetError> {
         // Check if texture is already loaded
         if let Some(texture) = self.textures.get(name) {
-            return Ok(*texture);
+            return Ok(texture);
         }
 
-        // Get path for the image
-        let path = self.get_asset_path(AssetType::Image, name);
-        
-        // Attempt to load the texture
-        let texture = load_texture(&path).await.map_err(|e| {
-            format!("Failed to load texture '{}': {}", path, e)
-        })?;
+        let result = match self.read_asset(AssetType::Image, name).await {
+            Ok((path, bytes)) => {
+                if let Some(palette) = Palette::from_png(&bytes) {
+                    self.palettes.insert(name.to_string(), palette);
+                }
+                Self::decode_texture(&path, &bytes)
+            }
+            Err(e) => Err(e),
+        };
+        let texture = match result {
+            Ok(texture) => texture,
+            Err(e) if self.handle_error(&e) => {
+                log::warn!("{}, using placeholder art", e);
+                self.mark_missing(name);
+                self.fallback_texture()
+            }
+            Err(e) => return Err(e),
+        };
 
         // Store in cache
-        self.textures.insert(name.to_string(), texture);
-        
+        self.textures.insert(name, texture);
+
         Ok(texture)
     }
 
-    /// Load a text file
-    pub async fn load_text(&self, name: &str) -> Result<String, String> {
-        let path = self.get_asset_path(AssetType::Text, name);
-        
-        // Use load_string to load text content
-        load_string(&path).await.map_err(|e| {
-            format!("Failed to load text '{}': {}", path, e)
+    /// Find and decode a sound file
+    async fn decode_sound(&self, asset_type: AssetType, name: &str) -> Result<Sound, AssetError> {
+        let (path, bytes) = self.read_asset(asset_type, name).await?;
+        load_sound_from_bytes(&bytes).await.map_err(|e| AssetError::DecodeFailed {
+            path,
+            reason: e.kind.to_string(),
         })
     }
 
-    /// Check if an asset file exists
-    pub fn asset_exists(&self, asset_type: AssetType, name: &str) -> bool {
-        let path = self.get_asset_path(asset_type, name);
-        Path::new(&path).exists()
+    /// Load a sound effect from file
+    ///
+    /// If loading fails and the error policy allows it, the built-in beep is
+    /// cached under the name and returned instead.
+    pub async fn load_sound(&mut self, name: &str) -> Result<Sound, AssetError> {
+        if let Some(sound) = self.sounds.get(name) {
+            return Ok(sound);
+        }
+
+        let sound = match self.decode_sound(AssetType::Sound, name).await {
+            Ok(sound) => sound,
+            Err(e) if self.handle_error(&e) => {
+                log::warn!("{}, using a beep", e);
+                self.mark_missing(name);
+                self.fallback_sound().await.ok_or(e)?
+            }
+            Err(e) => return Err(e),
+        };
+
+        self.sounds.insert(name, sound);
+        Ok(sound)
     }
 
-    /// Preload a list of textures
-    pub async fn preload_textures(&mut self, names: &[&str]) -> Result<(), String> {
-        for name in names {
-            self.load_texture(name).await?;
+    /// Load a music track from file
+    pub async fn load_music(&mut self, name: &str) -> Result<Sound, AssetError> {
+        if let Some(track) = self.music.get(name) {
+            return Ok(track);
         }
-        Ok(())
+
+        // A beep would make poor music, so a missing track is only reported
+        let track = self.decode_sound(AssetType::Music, name).await.inspect_err(|e| {
+            if self.handle_error(e) {
+                self.mark_missing(name);
+            }
+        })?;
+
+        self.music.insert(name, track);
+        Ok(track)
+    }
+
+    /// Load a TTF font from file, falling back to the built-in font if the
+    /// error policy allows it
+    pub async fn load_font(&mut self, name: &str) -> Result<Font, AssetError> {
+        if let Some(font) = self.fonts.get(name) {
+            return Ok(font);
+        }
+
+        let result = match self.read_asset(AssetType::Font, name).await {
+            Ok((path, bytes)) => load_ttf_font_from_bytes(&bytes).map_err(|e| AssetError::DecodeFailed {
+                path,
+                reason: e.0.to_string(),
+            }),
+            Err(e) => Err(e),
+        };
+        let font = match result {
+            Ok(font) => font,
+            Err(e) if self.handle_error(&e) => {
+                log::warn!("{}, using the built-in font", e);
+                self.mark_missing(name);
+                fallback::font()
+            }
+            Err(e) => return Err(e),
+        };
+
+        self.fonts.insert(name, font);
+        Ok(font)
+    }
+
+    /// Load a text file
+    pub async fn load_text(&mut self, name: &str) -> Result<String, AssetError> {
+        if let Some(text) = self.texts.get(name) {
+            return Ok(text.clone());
+        }
+
+        let result = match self.read_asset(AssetType::Text, name).await {
+            Ok((path, bytes)) => String::from_utf8(bytes).map_err(|e| AssetError::DecodeFailed { path, reason: e.to_string() }),
+            Err(e) => Err(e),
+        };
+        let text = result.inspect_err(|e| {
+            self.handle_error(e);
+        })?;
+
+        self.texts.insert(name.to_string(), text.clone());
+        Ok(text)
+    }
+
+    /// Load a sprite animation file
+    pub async fn load_animation(&mut self, name: &str) -> Result<&SpriteAnimation, AssetError> {
+        if !self.animations.contains_key(name) {
+            let result = match self.read_asset(AssetType::Animation, name).await {
+                Ok((path, bytes)) => String::from_utf8(bytes)
+                    .map_err(|e| e.to_string())
+                    .and_then(|json| SpriteAnimation::from_json(&json))
+                    .map_err(|reason| AssetError::DecodeFailed { path, reason }),
+                Err(e) => Err(e),
+            };
+            let animation = result.inspect_err(|e| {
+                self.handle_error(e);
+            })?;
+            self.animations.insert(name.to_string(), animation);
+        }
+        Ok(&self.animations[name])
+    }
+
+    /// Load a sprite atlas file along with its image
+    pub async fn load_atlas(&mut self, name: &str) -> Result<&Atlas, AssetError> {
+        if !self.atlases.contains_key(name) {
+            let result = match self.read_asset(AssetType::Atlas, name).await {
+                Ok((path, bytes)) => String::from_utf8(bytes)
+                    .map_err(|e| e.to_string())
+                    .and_then(|json| Atlas::from_json(&json))
+                    .map_err(|reason| AssetError::DecodeFailed { path, reason }),
+                Err(e) => Err(e),
+            };
+            let atlas = result.inspect_err(|e| {
+                self.handle_error(e);
+            })?;
+            self.load_texture(&atlas.texture).await?;
+
+            for (sprite, &[x, y, w, h]) in &atlas.regions {
+                let previous = self.sprites.insert(sprite.clone(), (atlas.texture.clone(), Rect::new(x, y, w, h)));
+                if previous.is_some() {
+                    log::warn!("Sprite '{}' in {} is also in another atlas; using this one", sprite, name);
+                }
+            }
+            self.atlases.insert(name.to_string(), atlas);
+        }
+        Ok(&self.atlases[name])
+    }
+
+    /// Forget an atlas and its sprites, and free its image unless told to
+    /// keep it or another atlas uses it
+    fn remove_atlas(&mut self, name: &str, keep_texture: bool) -> Option<()> {
+        let atlas = self.atlases.remove(name)?;
+        self.sprites.retain(|_, (texture, _)| *texture != atlas.texture);
+        if !keep_texture && !self.atlases.values().any(|other| other.texture == atlas.texture) {
+            self.remove_texture(&atlas.texture);
+        }
+        Some(())
+    }
+
+    /// Free a texture and forget its palette
+    fn remove_texture(&mut self, name: &str) -> Option<()> {
+        let texture = self.textures.remove(name)?;
+        self.palettes.remove(name);
+        // The placeholder is shared by every image that failed
+        if Some(texture) != self.fallback_texture {
+            texture.delete();
+        }
+        Some(())
     }
 
     /// Get a loaded texture by name
     pub fn get_texture(&self, name: &str) -> Option<Texture2D> {
-        self.textures.get(name).copied()
+        self.textures.get(name)
+    }
+
+    /// Get the colour table of a loaded texture, for pictures converted from
+    /// the original game, which keep theirs
+    pub fn get_palette(&self, name: &str) -> Option<&Palette> {
+        self.palettes.get(name)
+    }
+
+    /// Get a loaded sound effect by name
+    pub fn get_sound(&self, name: &str) -> Option<Sound> {
+        self.sounds.get(name)
+    }
+
+    /// Get a loaded music track by name
+    pub fn get_music(&self, name: &str) -> Option<Sound> {
+        self.music.get(name)
+    }
+
+    /// Get a loaded text file by name
+    pub fn get_text(&self, name: &str) -> Option<&str> {
+        self.texts.get(name).map(|text| text.as_str())
+    }
+
+    /// Get a loaded sprite animation by name
+    pub fn get_animation(&self, name: &str) -> Option<&SpriteAnimation> {
+        self.animations.get(name)
+    }
+
+    /// Get a sprite from a loaded atlas by name, or failing that a whole
+    /// loaded texture of that name, so art can be packed or left loose
+    pub fn get_sprite(&self, name: &str) -> Option<Sprite> {
+        match self.sprites.get(name) {
+            Some((texture, source)) => Some(Sprite { texture: self.textures.get(texture)?, source: *source }),
+            None => {
+                let texture = self.textures.get(name)?;
+                Some(Sprite { texture, source: Rect::new(0.0, 0.0, texture.width(), texture.height()) })
+            }
+        }
+    }
+
+    /// Whether an asset is in its cache
+    pub fn is_loaded(&self, asset_type: AssetType, name: &str) -> bool {
+        match asset_type {
+            AssetType::Image => self.textures.contains(name),
+            AssetType::Sound => self.sounds.contains(name),
+            AssetType::Music => self.music.contains(name),
+            AssetType::Font => self.fonts.contains(name),
+            AssetType::Text => self.texts.contains_key(name),
+            AssetType::Animation => self.animations.contains_key(name),
+            AssetType::Atlas => self.atlases.contains_key(name),
+        }
+    }
+
+    /// Read the asset manifest, from the bundled assets folder or, when
+    /// that isn't there, the first bundle holding one
+    pub async fn load_manifest(&mut self) -> Result<(), AssetError> {
+        let mut path = format!("{}/{}", self.asset_base_path, MANIFEST_FILE);
+        let bytes = match self.read_pak(MANIFEST_FILE) {
+            Some((pak_path, bytes)) if !Path::new(&path).exists() => {
+                path = pak_path;
+                bytes.map_err(|reason| AssetError::DecodeFailed { path: path.clone(), reason })?
+            }
+            _ => load_file(&path).await.map_err(AssetError::from_file_error)?,
+        };
+        self.manifest = serde_json::from_slice(&bytes).map_err(|e| AssetError::DecodeFailed {
+            path,
+            reason: e.to_string(),
+        })?;
+        Ok(())
     }
-}
\ No newline at end of file
+
+    /// Get an asset group from the manifest
+    pub fn group(&self, name: &str) -> Option<&AssetGroup> {
+        self.manifest.groups.get(name)
+    }
+
+    /// Error for a group missing from the manifest
+    fn unknown_group(name: &str) -> AssetError {
+        AssetError::NotFound { path: format!("{} group '{}'", MANIFEST_FILE, name) }
+    }
+
+    /// Load an asset of any type into its cache
+    async fn load_entry(&mut self, asset_type: AssetType, name: &str) -> Result<(), AssetError> {
+        match asset_type {
+            AssetType::Image => self.load_texture(name).await.map(|_| ()),
+            AssetType::Sound => self.load_sound(name).await.map(|_| ()),
+            AssetType::Music => self.load_music(name).await.map(|_| ()),
+            AssetType::Font => self.load_font(name).await.map(|_| ()),
+            AssetType::Text => self.load_text(name).await.map(|_| ()),
+            AssetType::Animation => self.load_animation(name).await.map(|_| ()),
+            AssetType::Atlas => self.load_atlas(name).await.map(|_| ()),
+        }
+    }
+
+    /// Queue a group's assets for loading with `load_next`
+    pub fn queue_group(&mut self, name: &str) -> Result<(), AssetError> {
+        let Some(group) = self.group(name) else {
+            return Err(Self::unknown_group(name));
+        };
+
+        let entries: Vec<_> = group.entries().map(|(t, n)| (t, n.clone())).collect();
+        if self.queue.is_empty() {
+            self.queued_total = 0;
+        }
+        self.queued_total += entries.len();
+        self.queue.extend(entries);
+        Ok(())
+    }
+
+    /// Queue every group the manifest asks to preload
+    pub fn queue_preload(&mut self) -> Result<(), AssetError> {
+        for name in self.manifest.preload.clone() {
+            self.queue_group(&name)?;
+        }
+        Ok(())
+    }
+
+    /// Load the next queued asset, returning None once the queue is empty
+    pub async fn load_next(&mut self) -> Option<Result<(), AssetError>> {
+        let (asset_type, name) = self.queue.front().cloned()?;
+        let result = self.load_entry(asset_type, &name).await;
+        self.queue.pop_front();
+        Some(result)
+    }
+
+    /// Whether queued assets are still loading
+    pub fn is_loading(&self) -> bool {
+        !self.queue.is_empty()
+    }
+
+    /// Fraction of the queued assets that have loaded, from 0.0 to 1.0
+    pub fn progress(&self) -> f32 {
+        if self.queued_total == 0 {
+            return 1.0;
+        }
+        1.0 - self.queue.len() as f32 / self.queued_total as f32
+    }
+
+    /// Whether every asset in a group is already cached
+    pub fn is_group_loaded(&self, name: &str) -> bool {
+        match self.group(name) {
+            Some(group) => group.entries().all(|(t, n)| self.is_loaded(t, n)),
+            None => true,
+        }
+    }
+
+    /// Note that a scene entered using a group's assets
+    pub fn acquire_group(&mut self, name: &str) {
+        *self.group_users.entry(name.to_string()).or_insert(0) += 1;
+    }
+
+    /// Note that a scene using a group's assets has left. Once the last one
+    /// has, a group marked for release has its assets freed, except those
+    /// another group still needs. Returns the number of assets freed.
+    pub fn release_group(&mut self, name: &str) -> usize {
+        let users = self.group_users.entry(name.to_string()).or_insert(0);
+        *users = users.saturating_sub(1);
+        if *users > 0 || !self.group(name).is_some_and(|group| group.release) {
+            return 0;
+        }
+
+        let mut freed = 0;
+        for (asset_type, asset) in self.manifest.unneeded(name, &self.group_users) {
+            let removed = match asset_type {
+                AssetType::Image => self.remove_texture(&asset),
+                // macroquad can't free a sound once made, so it's only forgotten
+                AssetType::Sound => self.sounds.remove(&asset).map(|_| ()),
+                AssetType::Music => self.music.remove(&asset).map(|_| ()),
+                AssetType::Font => self.fonts.remove(&asset).map(|_| ()),
+                AssetType::Text => self.texts.remove(&asset).map(|_| ()),
+                AssetType::Animation => self.animations.remove(&asset).map(|_| ()),
+                AssetType::Atlas => {
+                    let needed = self.manifest.needed_by_others(name, &self.group_users);
+                    let keep = self.atlases.get(&asset).is_some_and(|a| needed.contains(&(AssetType::Image, &a.texture)));
+                    self.remove_atlas(&asset, keep)
+                }
+            };
+            freed += removed.map_or(0, |()| 1);
+        }
+        log::debug!("Released {} assets of the '{}' group", freed, name);
+        freed
+    }
+
+    /// Whether none of a group's assets are still waiting in the queue
+    pub fn is_group_ready(&self, name: &str) -> bool {
+        match self.group(name) {
+            Some(group) => !group.entries().any(|(t, n)| self.queue.iter().any(|(qt, qn)| *qt == t && qn == n)),
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn released_groups_keep_assets_other_groups_need() {
+        let manifest: AssetManifest = serde_json::from_str(
+            r#"{
+                "groups": {
+                    "travel": { "textures": ["TRAIL.png", "VALLEY.png"] },
+                    "landmarks": { "textures": ["LAND0.png", "VALLEY.png", "RIVERX.png"], "release": true },
+                    "river": { "textures": ["RIVERX.png"], "release": true }
+                }
+            }"#,
+        )
+        .unwrap();
+        let mut users = HashMap::new();
+        let names = |unneeded: Vec<(AssetType, String)>| unneeded.into_iter().map(|(_, name)| name).collect::<Vec<_>>();
+        assert_eq!(names(manifest.unneeded("landmarks", &users)), ["LAND0.png", "RIVERX.png"]);
+
+        users.insert("river".to_string(), 1);
+        assert_eq!(names(manifest.unneeded("landmarks", &users)), ["LAND0.png"]);
+        assert!(manifest.unneeded("missing", &users).is_empty());
+    }
+
+    #[test]
+    fn bundled_animations_and_atlases_read() {
+        let files = |folder| {
+            let entries = std::fs::read_dir(Path::new(BUNDLED_DIR).join(folder)).unwrap();
+            entries.map(|entry| std::fs::read_to_string(entry.unwrap().path()).unwrap())
+        };
+        for json in files("animations") {
+            SpriteAnimation::from_json(&json).unwrap();
+        }
+        for json in files("atlases") {
+            Atlas::from_json(&json).unwrap();
+        }
+    }
+
+    #[test]
+    fn bundled_files_come_from_the_paks_among_the_roots() {
+        let base = std::env::temp_dir().join(format!("rust-trail-roots-{}", std::process::id()));
+        let dir = |name: &str| base.join(name).to_string_lossy().into_owned();
+        std::fs::create_dir_all(&base).unwrap();
+        let mut pak = zip::ZipWriter::new(std::fs::File::create(base.join("assets.pak")).unwrap());
+        pak.start_file("images/MAP.png", zip::write::SimpleFileOptions::default()).unwrap();
+        pak.finish().unwrap();
+
+        let mut assets = AssetManager::new(&dir("assets"));
+        assets.set_roots(vec![
+            AssetRoot::Dir(dir("mods")),
+            AssetRoot::Dir(dir("assets")),
+            AssetRoot::Pak(dir("assets.pak")),
+            AssetRoot::Gxl(dir("OREGON.GXL")),
+        ]);
+        let (path, bytes) = assets.read_pak("images/MAP.png").unwrap();
+        assert_eq!(path, format!("{}/images/MAP.png", dir("assets.pak")));
+        assert_eq!(bytes, Ok(Vec::new()));
+        assert!(assets.read_pak("images/TITLE.png").is_none());
+        assert_eq!(AssetManager::original_name(AssetType::Sound, "BEEP.wav").as_deref(), Some("BEEP.SND"));
+        assert_eq!(AssetManager::original_name(AssetType::Image, "TITLE.png"), None);
+
+        std::fs::remove_dir_all(base).unwrap();
+    }
+}
@@ -0,0 +1,88 @@
+// Audio playback with the player's volume settings applied
+
+use std::cell::RefCell;
+use macroquad::audio::{self, PlaySoundParams, Sound};
+use macroquad::input::{get_last_key_pressed, is_mouse_button_pressed, MouseButton};
+
+/// Volume levels and the currently playing music track
+struct AudioState {
+    /// Music volume from 0.0 to 1.0
+    music_volume: f32,
+    /// Sound effect volume from 0.0 to 1.0
+    sfx_volume: f32,
+    /// Looping music track, if any
+    music: Option<Sound>,
+    /// Whether sound can be heard yet
+    ///
+    /// Browsers hold audio back until the player first presses a key or
+    /// clicks; effects played before then would all sound at once.
+    unlocked: bool,
+}
+
+thread_local! {
+    static AUDIO: RefCell<AudioState> = const { RefCell::new(AudioState {
+        music_volume: 1.0,
+        sfx_volume: 1.0,
+        music: None,
+        unlocked: !cfg!(target_arch = "wasm32"),
+    }) };
+}
+
+/// Set the music volume, adjusting the track that is already playing
+pub fn set_music_volume(volume: f32) {
+    let volume = volume.clamp(0.0, 1.0);
+    let music = AUDIO.with(|a| {
+        let mut a = a.borrow_mut();
+        a.music_volume = volume;
+        a.music
+    });
+    if let Some(music) = music {
+        audio::set_sound_volume(music, volume);
+    }
+}
+
+/// Set the volume used for sound effects
+pub fn set_sfx_volume(volume: f32) {
+    AUDIO.with(|a| a.borrow_mut().sfx_volume = volume.clamp(0.0, 1.0));
+}
+
+/// Current sound effect volume
+pub fn sfx_volume() -> f32 {
+    AUDIO.with(|a| a.borrow().sfx_volume)
+}
+
+/// Watch for the first key press or click, which lets a browser play sound
+pub fn update() {
+    if AUDIO.with(|a| a.borrow().unlocked) {
+        return;
+    }
+    if get_last_key_pressed().is_some() || is_mouse_button_pressed(MouseButton::Left) {
+        AUDIO.with(|a| a.borrow_mut().unlocked = true);
+    }
+}
+
+/// Play a sound effect once
+pub fn play_sfx(sound: Sound) {
+    if !AUDIO.with(|a| a.borrow().unlocked) {
+        return;
+    }
+    audio::play_sound(sound, PlaySoundParams { looped: false, volume: sfx_volume() });
+}
+
+/// Start a looping music track, replacing any track already playing
+pub fn play_music(sound: Sound) {
+    stop_music();
+    let volume = AUDIO.with(|a| {
+        let mut a = a.borrow_mut();
+        a.music = Some(sound);
+        a.music_volume
+    });
+    audio::play_sound(sound, PlaySoundParams { looped: true, volume });
+}
+
+/// Stop the current music track
+pub fn stop_music() {
+    if let Some(music) = AUDIO.with(|a| a.borrow_mut().music.take()) {
+        audio::stop_sound(music);
+    }
+}
@@ -0,0 +1,164 @@
+// The local interface streaming tools talk to. It listens on localhost only,
+// on a thread of its own, and takes either plain lines (as from netcat) or
+// HTTP requests whose body holds the command (as from curl or a chat bot).
+// Commands are checked as they arrive and passed to the game to queue.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use crate::game_logic::control::{self, Command, MAX_COMMAND_LEN};
+
+/// How often the listener checks whether it should stop
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Longest a client may take to send its request
+const READ_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Most header lines read from an HTTP request
+const MAX_HEADERS: usize = 32;
+
+/// Reply to a request for the interface itself
+const USAGE: &str = "Oregon Trail control\n\
+    vote pace <steady|strenuous|grueling|resting> [voter]\n\
+    vote river <ford|float|ferry|guide> [voter]\n\
+    event <fruit|spirits|sunshine>\n";
+
+/// The control interface, listening until it is dropped
+pub struct ControlServer {
+    /// Commands checked and waiting for the game
+    commands: Receiver<Command>,
+    /// Tells the listener to stop
+    stop: Arc<AtomicBool>,
+    /// Port being listened on
+    port: u16,
+}
+
+impl ControlServer {
+    /// Start listening on a local port
+    pub fn start(port: u16) -> Result<Self, String> {
+        if cfg!(target_arch = "wasm32") {
+            return Err("The control interface isn't available on the web".to_string());
+        }
+        let listener =
+            TcpListener::bind(("127.0.0.1", port)).map_err(|e| format!("Failed to open control port {}: {}", port, e))?;
+        listener.set_nonblocking(true).map_err(|e| format!("Failed to open control port {}: {}", port, e))?;
+        let (sender, commands) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopping = Arc::clone(&stop);
+        thread::spawn(move || listen(listener, sender, stopping));
+        log::info!("Control interface listening on 127.0.0.1:{}", port);
+        Ok(Self { commands, stop, port })
+    }
+
+    /// Port being listened on
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Commands that have arrived since last asked
+    pub fn take_commands(&self) -> Vec<Command> {
+        self.commands.try_iter().collect()
+    }
+}
+
+impl Drop for ControlServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Accept clients until told to stop
+fn listen(listener: TcpListener, sender: Sender<Command>, stop: Arc<AtomicBool>) {
+    while !stop.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                // Each client gets a thread, so a bot can stay connected
+                let sender = sender.clone();
+                thread::spawn(move || {
+                    if let Err(e) = serve(stream, &sender) {
+                        log::debug!("Control client: {}", e);
+                    }
+                });
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => thread::sleep(POLL_INTERVAL),
+            Err(e) => {
+                log::warn!("Control interface stopped: {}", e);
+                return;
+            }
+        }
+    }
+}
+
+/// Check a command and pass it on to the game
+fn pass_on(line: &str, sender: &Sender<Command>) -> Result<(), String> {
+    let command = control::parse(line)?;
+    sender.send(command).map_err(|_| "The game isn't taking commands".to_string())
+}
+
+/// Handle one client: an HTTP request, or lines of commands until it hangs up
+fn serve(stream: TcpStream, sender: &Sender<Command>) -> std::io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+
+    let mut first = String::new();
+    reader.by_ref().take(MAX_COMMAND_LEN as u64 + 2).read_line(&mut first)?;
+    let Some(method) = ["GET ", "POST "].into_iter().find(|m| first.starts_with(m)) else {
+        // Plain lines, one reply each, for as long as the client stays
+        reader.get_ref().set_read_timeout(None)?;
+        let mut line = first;
+        // An empty read means the client hung up
+        while !line.is_empty() {
+            if !line.trim().is_empty() {
+                let reply = match pass_on(&line, sender) {
+                    Ok(()) => "ok".to_string(),
+                    Err(e) => format!("error: {}", e),
+                };
+                writeln!(writer, "{}", reply)?;
+            }
+            line.clear();
+            reader.by_ref().take(MAX_COMMAND_LEN as u64 + 2).read_line(&mut line)?;
+        }
+        return Ok(());
+    };
+
+    // HTTP: the body, if any, is the command
+    let mut length = 0;
+    for _ in 0..MAX_HEADERS {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        let header = header.trim();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+    let (status, reply) = if method == "GET " {
+        ("200 OK", USAGE.to_string())
+    } else if length > MAX_COMMAND_LEN {
+        ("413 Payload Too Large", "Command too long\n".to_string())
+    } else {
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+        match pass_on(&String::from_utf8_lossy(&body), sender) {
+            Ok(()) => ("200 OK", "ok\n".to_string()),
+            Err(e) => ("400 Bad Request", format!("{}\n", e)),
+        }
+    };
+    write!(
+        writer,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reply.len(),
+        reply
+    )
+}
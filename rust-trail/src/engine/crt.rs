@@ -0,0 +1,73 @@
+// CRT look for the canvas: scanlines, a slightly curved picture and a soft
+// phosphor glow, applied as the canvas is drawn to the window. The shaders are
+// GLSL 100 so they run on WebGL too.
+
+use macroquad::prelude::*;
+
+const VERTEX_SHADER: &str = r#"#version 100
+attribute vec3 position;
+attribute vec2 texcoord;
+
+varying mediump vec2 uv;
+
+uniform mat4 Model;
+uniform mat4 Projection;
+
+void main() {
+    gl_Position = Projection * Model * vec4(position, 1);
+    uv = texcoord;
+}
+"#;
+
+const FRAGMENT_SHADER: &str = r#"#version 100
+precision mediump float;
+
+varying vec2 uv;
+
+uniform sampler2D Texture;
+
+// Canvas size, one scanline per canvas row
+const vec2 CANVAS = vec2(640.0, 480.0);
+// How far the picture bows out toward the corners
+const float CURVATURE = 0.06;
+// How dark the gaps between scanlines get
+const float SCANLINE_DEPTH = 0.3;
+// How much neighbouring pixels bleed into each other
+const float GLOW = 0.25;
+// How much the corners darken
+const float VIGNETTE = 0.25;
+
+void main() {
+    // Bend the picture like the glass of a curved tube
+    vec2 centred = uv * 2.0 - 1.0;
+    centred *= 1.0 + CURVATURE * centred.yx * centred.yx;
+    vec2 pos = centred * 0.5 + 0.5;
+    if (pos.x < 0.0 || pos.x > 1.0 || pos.y < 0.0 || pos.y > 1.0) {
+        gl_FragColor = vec4(0.0, 0.0, 0.0, 1.0);
+        return;
+    }
+
+    vec3 color = texture2D(Texture, pos).rgb;
+
+    // Phosphor glow from the pixels around this one
+    vec2 texel = 1.0 / CANVAS;
+    vec3 around = texture2D(Texture, pos + vec2(texel.x, 0.0)).rgb
+        + texture2D(Texture, pos - vec2(texel.x, 0.0)).rgb
+        + texture2D(Texture, pos + vec2(0.0, texel.y)).rgb
+        + texture2D(Texture, pos - vec2(0.0, texel.y)).rgb;
+    color += around * 0.25 * GLOW;
+
+    // Brightest through the middle of each row, dark between them
+    float row = 0.5 + 0.5 * cos((pos.y * CANVAS.y - 0.5) * 6.2831853);
+    color *= mix(1.0 - SCANLINE_DEPTH, 1.0, row);
+
+    color *= 1.0 - VIGNETTE * dot(centred, centred) * 0.5;
+    gl_FragColor = vec4(color, 1.0);
+}
+"#;
+
+/// Build the CRT material
+pub fn material() -> Result<Material, String> {
+    load_material(VERTEX_SHADER, FRAGMENT_SHADER, MaterialParams::default())
+        .map_err(|e| format!("Failed to build the CRT shader: {:?}", e))
+}
@@ -0,0 +1,96 @@
+// The game's own mouse cursors. The system cursor is hidden while it's over
+// the canvas and the cursor the scene asks for is drawn in its place, on the
+// canvas itself, so it's scaled and filtered along with everything else.
+
+use std::cell::Cell;
+use macroquad::prelude::*;
+use crate::engine::animation::SpriteSheet;
+use crate::engine::asset_loader::AssetManager;
+use crate::engine::display::{self, VIRTUAL_HEIGHT, VIRTUAL_WIDTH};
+use crate::engine::input;
+
+/// The arrow, crosshair and hourglass, in that order
+pub const CURSORS: SpriteSheet = SpriteSheet { texture: "CURSORS.png", frame_width: 16.0, frame_height: 16.0, frames: 3 };
+
+/// Cursor drawn over a scene
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cursor {
+    /// Pointing at buttons and menus
+    Arrow,
+    /// Aiming a shot
+    Crosshair,
+    /// Waiting for something to load
+    Hourglass,
+}
+
+impl Cursor {
+    /// Frame of the sprite sheet holding the cursor
+    fn frame(&self) -> usize {
+        match self {
+            Cursor::Arrow => 0,
+            Cursor::Crosshair => 1,
+            Cursor::Hourglass => 2,
+        }
+    }
+
+    /// Point of the cursor that sits on the position, from its top-left corner
+    fn hotspot(&self) -> Vec2 {
+        match self {
+            Cursor::Arrow => Vec2::ZERO,
+            Cursor::Crosshair => vec2(7.0, 7.0),
+            Cursor::Hourglass => vec2(7.0, 8.0),
+        }
+    }
+}
+
+thread_local! {
+    /// Whether the system cursor was last shown, so it's only changed when it needs to be
+    static SYSTEM_SHOWN: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Show or hide the system cursor
+fn show_system_cursor(shown: bool) {
+    if SYSTEM_SHOWN.with(|s| s.replace(Some(shown))) != Some(shown) {
+        show_mouse(shown);
+    }
+}
+
+/// Draw a cursor, at the mouse or at a position the scene chose. The system
+/// cursor comes back outside the canvas, over the borders around it
+pub fn draw(cursor: Cursor, position: Option<Vec2>, assets: &AssetManager) {
+    let mouse = Vec2::from(display::mouse_position());
+    let over_canvas = Rect::new(0.0, 0.0, VIRTUAL_WIDTH, VIRTUAL_HEIGHT).contains(mouse);
+    show_system_cursor(!over_canvas);
+
+    // A finger needs no arrow under it, but the scene may still show where it's aiming
+    let position = match position {
+        Some(position) => position,
+        None if over_canvas && !input::is_touch() => mouse,
+        None => return,
+    };
+    let corner = position - cursor.hotspot();
+    match assets.get_texture(CURSORS.texture) {
+        Some(texture) => CURSORS.draw(texture, cursor.frame(), corner.x.round(), corner.y.round(), 1.0),
+        None => draw_fallback(cursor, position),
+    }
+}
+
+/// Draw a cursor with shapes, until the sprite sheet has loaded
+fn draw_fallback(cursor: Cursor, at: Vec2) {
+    match cursor {
+        Cursor::Arrow => {
+            draw_triangle(at, at + vec2(0.0, 14.0), at + vec2(9.0, 10.0), WHITE);
+            draw_triangle_lines(at, at + vec2(0.0, 14.0), at + vec2(9.0, 10.0), 1.0, BLACK);
+        }
+        Cursor::Crosshair => {
+            draw_line(at.x - 7.0, at.y, at.x + 7.0, at.y, 1.0, BLACK);
+            draw_line(at.x, at.y - 7.0, at.x, at.y + 7.0, 1.0, BLACK);
+        }
+        Cursor::Hourglass => {
+            draw_triangle(at + vec2(-5.0, -7.0), at + vec2(5.0, -7.0), at, WHITE);
+            draw_triangle(at + vec2(-5.0, 7.0), at + vec2(5.0, 7.0), at, WHITE);
+            draw_triangle_lines(at + vec2(-5.0, -7.0), at + vec2(5.0, -7.0), at, 1.0, BLACK);
+            draw_triangle_lines(at + vec2(-5.0, 7.0), at + vec2(5.0, 7.0), at, 1.0, BLACK);
+        }
+    }
+}
@@ -0,0 +1,264 @@
+// Display handling. Scenes draw onto a fixed 640x480 canvas, the resolution of
+// the original game, which is then scaled to the window according to the
+// player's scaling mode. Scenes should use `screen_width`, `screen_height` and
+// `mouse_position` from this module rather than the macroquad versions so that
+// layout and hit-testing stay in canvas coordinates.
+
+use std::cell::RefCell;
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::engine::crt;
+
+/// Width of the virtual canvas
+pub const VIRTUAL_WIDTH: f32 = 640.0;
+/// Height of the virtual canvas
+pub const VIRTUAL_HEIGHT: f32 = 480.0;
+
+/// How the canvas is scaled to fill the window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScalingMode {
+    /// Fill the whole window, ignoring aspect ratio
+    Stretch,
+    /// Scale as large as possible while keeping the 4:3 aspect ratio
+    Fit,
+    /// Scale by whole numbers only, so every canvas pixel is the same size
+    Integer,
+}
+
+impl ScalingMode {
+    /// All scaling modes in menu order
+    pub const ALL: [ScalingMode; 3] = [ScalingMode::Stretch, ScalingMode::Fit, ScalingMode::Integer];
+
+    /// Display name of the scaling mode
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScalingMode::Stretch => "Stretch",
+            ScalingMode::Fit => "Fit",
+            ScalingMode::Integer => "Pixel perfect",
+        }
+    }
+}
+
+/// How the game's window is shown
+///
+/// miniquad has no exclusive video mode: going fullscreen always gives a
+/// borderless window covering the screen. Fullscreen differs from Borderless
+/// by keeping the cursor inside the window, as an exclusive mode would.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowMode {
+    /// A normal window with a title bar
+    Windowed,
+    /// Covering the screen, with the cursor free to move to other monitors
+    Borderless,
+    /// Covering the screen, with the cursor kept inside it
+    Fullscreen,
+}
+
+impl WindowMode {
+    /// All window modes in menu order
+    pub const ALL: [WindowMode; 3] = [WindowMode::Windowed, WindowMode::Borderless, WindowMode::Fullscreen];
+
+    /// Display name of the window mode
+    pub fn name(&self) -> &'static str {
+        match self {
+            WindowMode::Windowed => "Windowed",
+            WindowMode::Borderless => "Borderless",
+            WindowMode::Fullscreen => "Fullscreen",
+        }
+    }
+
+    /// Mode Alt+Enter switches to from this one
+    pub fn toggled(&self) -> WindowMode {
+        match self {
+            WindowMode::Windowed => WindowMode::Fullscreen,
+            WindowMode::Borderless | WindowMode::Fullscreen => WindowMode::Windowed,
+        }
+    }
+}
+
+/// Display state shared across the frame
+struct DisplayState {
+    /// Current scaling mode
+    scaling: ScalingMode,
+    /// How the window is shown
+    window_mode: WindowMode,
+    /// Canvas render target, created on first use
+    canvas: Option<RenderTarget>,
+    /// Whether the canvas is drawn with the CRT look
+    crt: bool,
+    /// CRT material, built the first time it's turned on
+    crt_material: Option<Material>,
+}
+
+thread_local! {
+    static DISPLAY: RefCell<DisplayState> = const { RefCell::new(DisplayState {
+        scaling: ScalingMode::Fit,
+        window_mode: WindowMode::Windowed,
+        canvas: None,
+        crt: false,
+        crt_material: None,
+    }) };
+}
+
+/// Width of the canvas scenes draw on
+pub fn screen_width() -> f32 {
+    VIRTUAL_WIDTH
+}
+
+/// Height of the canvas scenes draw on
+pub fn screen_height() -> f32 {
+    VIRTUAL_HEIGHT
+}
+
+/// Convert a position in window pixels to canvas coordinates
+pub fn to_canvas(position: Vec2) -> Vec2 {
+    let view = viewport();
+    vec2(
+        (position.x - view.x) / view.w * VIRTUAL_WIDTH,
+        (position.y - view.y) / view.h * VIRTUAL_HEIGHT,
+    )
+}
+
+/// Mouse position in canvas coordinates
+pub fn mouse_position() -> (f32, f32) {
+    to_canvas(macroquad::input::mouse_position().into()).into()
+}
+
+/// Current scaling mode
+pub fn scaling() -> ScalingMode {
+    DISPLAY.with(|d| d.borrow().scaling)
+}
+
+/// Change the scaling mode
+pub fn set_scaling(mode: ScalingMode) {
+    DISPLAY.with(|d| d.borrow_mut().scaling = mode);
+}
+
+/// Change how the window is shown
+///
+/// The canvas is letterboxed again on the next frame, whatever size the window ends up.
+pub fn set_window_mode(mode: WindowMode) {
+    let previous = DISPLAY.with(|d| std::mem::replace(&mut d.borrow_mut().window_mode, mode));
+    if previous == mode {
+        return;
+    }
+    let fullscreen = mode != WindowMode::Windowed;
+    if fullscreen != (previous != WindowMode::Windowed) {
+        macroquad::window::set_fullscreen(fullscreen);
+    }
+    set_cursor_grab(mode == WindowMode::Fullscreen);
+}
+
+/// Turn the CRT look on or off
+pub fn set_crt(enabled: bool) {
+    DISPLAY.with(|d| {
+        let mut d = d.borrow_mut();
+        d.crt = enabled;
+        if enabled && d.crt_material.is_none() {
+            // Without the shader the canvas is just drawn plain
+            match crt::material() {
+                Ok(material) => d.crt_material = Some(material),
+                Err(e) => log::error!("{}", e),
+            }
+        }
+    });
+}
+
+/// Area of the window the canvas is drawn to
+pub fn viewport() -> Rect {
+    viewport_for(scaling(), macroquad::window::screen_width(), macroquad::window::screen_height())
+}
+
+/// Area of a window of the given size the canvas is drawn to in a scaling mode
+fn viewport_for(mode: ScalingMode, window_w: f32, window_h: f32) -> Rect {
+    let scale = (window_w / VIRTUAL_WIDTH).min(window_h / VIRTUAL_HEIGHT);
+    let scale = match mode {
+        ScalingMode::Stretch => return Rect::new(0.0, 0.0, window_w, window_h),
+        ScalingMode::Fit => scale,
+        // A window smaller than the canvas still shows it at full size, cropped
+        ScalingMode::Integer => scale.floor().max(1.0),
+    };
+    let w = VIRTUAL_WIDTH * scale;
+    let h = VIRTUAL_HEIGHT * scale;
+    // Whole pixels, so the canvas lines up with the screen's
+    Rect::new(((window_w - w) / 2.0).floor(), ((window_h - h) / 2.0).floor(), w, h)
+}
+
+/// Start drawing onto the canvas
+pub fn begin_frame() {
+    let canvas = DISPLAY.with(|d| {
+        *d.borrow_mut().canvas.get_or_insert_with(|| {
+            let target = render_target(VIRTUAL_WIDTH as u32, VIRTUAL_HEIGHT as u32);
+            target.texture.set_filter(FilterMode::Nearest);
+            target
+        })
+    });
+
+    set_camera(&Camera2D {
+        render_target: Some(canvas),
+        ..Camera2D::from_display_rect(Rect::new(0.0, 0.0, VIRTUAL_WIDTH, VIRTUAL_HEIGHT))
+    });
+}
+
+/// Save the last frame drawn on the canvas as a PNG
+pub fn save_canvas(path: &str) -> Result<(), String> {
+    let Some(canvas) = DISPLAY.with(|d| d.borrow().canvas) else {
+        return Err("Nothing has been drawn yet".to_string());
+    };
+    let frame = canvas.texture.get_texture_data();
+    let (width, height) = (frame.width as usize, frame.height as usize);
+    // Render targets are stored bottom-up
+    let bytes: Vec<u8> = frame.bytes.chunks(width * 4).rev().flatten().copied().collect();
+    image::save_buffer(path, &bytes, width as u32, height as u32, image::ColorType::Rgba8)
+        .map_err(|e| format!("Failed to write '{}': {}", path, e))
+}
+
+/// Finish the frame by drawing the canvas to the window
+pub fn end_frame() {
+    set_default_camera();
+    clear_background(BLACK);
+
+    let Some(canvas) = DISPLAY.with(|d| d.borrow().canvas) else {
+        return;
+    };
+    let crt = DISPLAY.with(|d| {
+        let d = d.borrow();
+        d.crt_material.filter(|_| d.crt)
+    });
+    if let Some(material) = crt {
+        gl_use_material(material);
+    }
+    let view = viewport();
+    draw_texture_ex(
+        canvas.texture,
+        view.x,
+        view.y,
+        WHITE,
+        DrawTextureParams {
+            dest_size: Some(Vec2::new(view.w, view.h)),
+            // Render targets are stored bottom-up
+            flip_y: true,
+            ..Default::default()
+        },
+    );
+    if crt.is_some() {
+        gl_use_default_material();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pixel_perfect_scaling_uses_whole_numbers() {
+        let view = viewport_for(ScalingMode::Integer, 1920.0, 1080.0);
+        assert_eq!(view, Rect::new(320.0, 60.0, 1280.0, 960.0));
+        let fit = viewport_for(ScalingMode::Fit, 1920.0, 1080.0);
+        assert_eq!((fit.w, fit.h), (1440.0, 1080.0));
+
+        // Too small for even one to one: full size, centred and cropped
+        let view = viewport_for(ScalingMode::Integer, 600.0, 400.0);
+        assert_eq!(view, Rect::new(-20.0, -40.0, 640.0, 480.0));
+    }
+}
@@ -0,0 +1,99 @@
+// Entity storage for scenes with many short-lived moving objects
+// (hunting animals, river obstacles, particles). Slots are reused after
+// despawning, so a scene can spawn and remove entities every frame without
+// reallocating once the pool has grown to its working size.
+
+/// Pooled storage for a scene's entities
+pub struct EntityPool<T> {
+    slots: Vec<Option<T>>,
+    /// Indices of empty slots, reused before growing
+    free: Vec<usize>,
+    /// Number of live entities
+    len: usize,
+}
+
+impl<T> EntityPool<T> {
+    /// Create an empty pool
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create an empty pool with room for `capacity` entities
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            free: Vec::with_capacity(capacity),
+            len: 0,
+        }
+    }
+
+    /// Number of live entities
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the pool has no live entities
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Add an entity, reusing a free slot if there is one
+    pub fn spawn(&mut self, value: T) {
+        self.len += 1;
+        match self.free.pop() {
+            Some(index) => self.slots[index] = Some(value),
+            None => self.slots.push(Some(value)),
+        }
+    }
+
+    /// Iterate over live entities
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.slots.iter().filter_map(|slot| slot.as_ref())
+    }
+
+    /// Iterate mutably over live entities
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.slots.iter_mut().filter_map(|slot| slot.as_mut())
+    }
+
+    /// Despawn every entity for which `keep` returns false
+    pub fn retain(&mut self, mut keep: impl FnMut(&mut T) -> bool) {
+        for (i, slot) in self.slots.iter_mut().enumerate() {
+            let Some(value) = slot.as_mut() else { continue };
+            if !keep(value) {
+                *slot = None;
+                self.free.push(i);
+                self.len -= 1;
+            }
+        }
+    }
+}
+
+impl<T> Default for EntityPool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retained_entities_stay_and_the_rest_free_their_slots() {
+        let mut pool = EntityPool::new();
+        for i in 0..10 {
+            pool.spawn(i);
+        }
+        pool.retain(|v| *v % 2 == 0);
+        assert_eq!(pool.len(), 5);
+        assert!(pool.values().all(|v| v % 2 == 0));
+
+        pool.retain(|_| false);
+        assert!(pool.is_empty());
+        assert_eq!(pool.values().count(), 0);
+        pool.spawn(42);
+        assert_eq!(pool.slots.len(), 10);
+        assert_eq!(pool.values().collect::<Vec<_>>(), [&42]);
+    }
+}
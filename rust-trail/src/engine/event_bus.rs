@@ -0,0 +1,100 @@
+// Message bus letting game logic announce what happened without knowing who listens
+
+use std::collections::VecDeque;
+
+/// Most events kept for one subscriber before the oldest are dropped
+const MAX_PENDING: usize = 64;
+
+/// Handle a subscriber uses to read its events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Subscription(usize);
+
+/// Queue of published events, copied to every subscriber
+///
+/// Subscribers poll for their events when it suits them rather than being
+/// called back, so no one has to hold borrows across the publisher's code.
+#[derive(Debug, Clone)]
+pub struct EventBus<E> {
+    /// Pending events for each subscriber, or None once unsubscribed
+    queues: Vec<Option<VecDeque<E>>>,
+}
+
+impl<E> Default for EventBus<E> {
+    fn default() -> Self {
+        Self { queues: Vec::new() }
+    }
+}
+
+impl<E: Clone> EventBus<E> {
+    /// Create a bus with no subscribers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start receiving events published from now on
+    pub fn subscribe(&mut self) -> Subscription {
+        let queue = Some(VecDeque::new());
+        match self.queues.iter().position(|q| q.is_none()) {
+            Some(index) => {
+                self.queues[index] = queue;
+                Subscription(index)
+            }
+            None => {
+                self.queues.push(queue);
+                Subscription(self.queues.len() - 1)
+            }
+        }
+    }
+
+    /// Stop receiving events, dropping any not yet read
+    pub fn unsubscribe(&mut self, subscription: Subscription) {
+        if let Some(queue) = self.queues.get_mut(subscription.0) {
+            *queue = None;
+        }
+    }
+
+    /// Send an event to every subscriber
+    pub fn publish(&mut self, event: E) {
+        for queue in self.queues.iter_mut().flatten() {
+            if queue.len() == MAX_PENDING {
+                queue.pop_front();
+            }
+            queue.push_back(event.clone());
+        }
+    }
+
+    /// Take the events a subscriber hasn't read yet, oldest first
+    pub fn poll(&mut self, subscription: Subscription) -> Vec<E> {
+        match self.queues.get_mut(subscription.0) {
+            Some(Some(queue)) => queue.drain(..).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_subscriber_gets_its_own_copy() {
+        let mut bus = EventBus::new();
+        let audio = bus.subscribe();
+        bus.publish(1);
+        let scene = bus.subscribe();
+        bus.publish(2);
+
+        assert_eq!(bus.poll(audio), vec![1, 2]);
+        assert_eq!(bus.poll(audio), Vec::<i32>::new());
+        assert_eq!(bus.poll(scene), vec![2]);
+
+        bus.unsubscribe(scene);
+        bus.publish(3);
+        assert_eq!(bus.poll(scene), Vec::<i32>::new());
+        assert_eq!(bus.subscribe(), scene);
+        for i in 0..100 {
+            bus.publish(i);
+        }
+        assert_eq!(bus.poll(audio).len(), MAX_PENDING);
+    }
+}
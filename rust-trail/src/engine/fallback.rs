@@ -0,0 +1,59 @@
+// Assets built into the game itself, stood in for any that are missing or
+// broken. A bad install then shows a checkerboard and beeps where the art and
+// sounds should be, rather than quietly drawing nothing, and the names of the
+// missing files are reported on screen.
+
+use macroquad::audio::{load_sound_from_bytes, Sound};
+use macroquad::prelude::*;
+
+/// Magenta checkerboard drawn in place of images that failed to load
+const PLACEHOLDER_PNG: &[u8] = include_bytes!("../../assets/fallback/MISSING.png");
+/// Short beep played in place of sounds that failed to load
+const BEEP_WAV: &[u8] = include_bytes!("../../assets/fallback/BEEP.wav");
+
+/// Most missing asset names listed in one report
+const MAX_LISTED: usize = 3;
+
+/// The placeholder image
+pub fn placeholder_texture() -> Texture2D {
+    let texture = Texture2D::from_file_with_format(PLACEHOLDER_PNG, Some(ImageFormat::Png));
+    texture.set_filter(FilterMode::Nearest);
+    texture
+}
+
+/// The placeholder beep, if the audio backend can play it
+pub async fn beep() -> Option<Sound> {
+    load_sound_from_bytes(BEEP_WAV).await.inspect_err(|e| log::warn!("Fallback beep didn't load: {}", e.kind)).ok()
+}
+
+/// The font drawn with in place of fonts that failed to load. It's macroquad's
+/// own, which is always built in
+pub fn font() -> Font {
+    Font::default()
+}
+
+/// Message telling the player which assets are missing, naming the first few
+pub fn missing_report(names: &[String]) -> Option<String> {
+    let (listed, rest) = names.split_at(names.len().min(MAX_LISTED));
+    if listed.is_empty() {
+        return None;
+    }
+    let mut report = format!("Missing game files: {}", listed.join(", "));
+    if !rest.is_empty() {
+        report.push_str(&format!(" and {} more", rest.len()));
+    }
+    Some(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_name_the_first_few_missing_assets() {
+        let names: Vec<String> = ["TITLE.png", "BEEP.wav", "MAP.png", "TRAIL.png", "RIVERX.png"].map(String::from).into();
+        assert_eq!(missing_report(&[]), None);
+        assert_eq!(missing_report(&names[..1]).unwrap(), "Missing game files: TITLE.png");
+        assert_eq!(missing_report(&names).unwrap(), "Missing game files: TITLE.png, BEEP.wav, MAP.png and 2 more");
+    }
+}
@@ -0,0 +1,637 @@
+use macroquad::prelude::*;
+use serde::{Serialize, Deserialize};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
+use crate::engine::display;
+use crate::utils::storage::Storage;
+use crate::utils::versioned::{self, Migration};
+
+/// How far a stick must be pushed before it counts as a direction
+const STICK_DEADZONE: f32 = 0.5;
+
+/// File the player's key bindings are saved to
+pub const BINDINGS_FILE: &str = "controls.json";
+
+/// Named actions that scenes respond to, independent of the device used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Action {
+    MenuUp,
+    MenuDown,
+    MenuLeft,
+    MenuRight,
+    Confirm,
+    Cancel,
+    Pause,
+    Shoot,
+    AimUp,
+    AimDown,
+    AimLeft,
+    AimRight,
+}
+
+impl Action {
+    /// All actions in the order shown on the controls screen
+    pub const ALL: [Action; 12] = [
+        Action::MenuUp,
+        Action::MenuDown,
+        Action::MenuLeft,
+        Action::MenuRight,
+        Action::Confirm,
+        Action::Cancel,
+        Action::Pause,
+        Action::Shoot,
+        Action::AimUp,
+        Action::AimDown,
+        Action::AimLeft,
+        Action::AimRight,
+    ];
+
+    /// Display name of the action
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::MenuUp => "Menu up",
+            Action::MenuDown => "Menu down",
+            Action::MenuLeft => "Menu left",
+            Action::MenuRight => "Menu right",
+            Action::Confirm => "Confirm",
+            Action::Cancel => "Cancel",
+            Action::Pause => "Pause",
+            Action::Shoot => "Shoot",
+            Action::AimUp => "Aim up",
+            Action::AimDown => "Aim down",
+            Action::AimLeft => "Aim left",
+            Action::AimRight => "Aim right",
+        }
+    }
+}
+
+/// Gamepad buttons, named by position like the standard layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PadButton {
+    South,          // A on Xbox-style pads
+    East,           // B on Xbox-style pads
+    North,
+    West,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    Start,
+    Select,
+    LeftShoulder,
+    RightShoulder,
+    LeftTrigger,
+    RightTrigger,
+}
+
+impl PadButton {
+    /// All gamepad buttons
+    pub const ALL: [PadButton; 14] = [
+        PadButton::South,
+        PadButton::East,
+        PadButton::North,
+        PadButton::West,
+        PadButton::DPadUp,
+        PadButton::DPadDown,
+        PadButton::DPadLeft,
+        PadButton::DPadRight,
+        PadButton::Start,
+        PadButton::Select,
+        PadButton::LeftShoulder,
+        PadButton::RightShoulder,
+        PadButton::LeftTrigger,
+        PadButton::RightTrigger,
+    ];
+}
+
+/// Keys that can be bound to actions
+const BINDABLE_KEYS: [KeyCode; 76] = [
+    KeyCode::A, KeyCode::B, KeyCode::C, KeyCode::D, KeyCode::E, KeyCode::F, KeyCode::G,
+    KeyCode::H, KeyCode::I, KeyCode::J, KeyCode::K, KeyCode::L, KeyCode::M, KeyCode::N,
+    KeyCode::O, KeyCode::P, KeyCode::Q, KeyCode::R, KeyCode::S, KeyCode::T, KeyCode::U,
+    KeyCode::V, KeyCode::W, KeyCode::X, KeyCode::Y, KeyCode::Z,
+    KeyCode::Key0, KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4,
+    KeyCode::Key5, KeyCode::Key6, KeyCode::Key7, KeyCode::Key8, KeyCode::Key9,
+    KeyCode::Up, KeyCode::Down, KeyCode::Left, KeyCode::Right,
+    KeyCode::Space, KeyCode::Enter, KeyCode::Escape, KeyCode::Tab, KeyCode::Backspace,
+    KeyCode::LeftShift, KeyCode::RightShift, KeyCode::LeftControl, KeyCode::RightControl,
+    KeyCode::LeftAlt, KeyCode::RightAlt,
+    KeyCode::Home, KeyCode::End, KeyCode::PageUp, KeyCode::PageDown, KeyCode::Insert, KeyCode::Delete,
+    KeyCode::F1, KeyCode::F2, KeyCode::F3, KeyCode::F4, KeyCode::F5, KeyCode::F6,
+    KeyCode::F7, KeyCode::F8, KeyCode::F9, KeyCode::F10, KeyCode::F11, KeyCode::F12,
+    KeyCode::KpEnter, KeyCode::Kp2, KeyCode::Kp4, KeyCode::Kp6, KeyCode::Kp8,
+    KeyCode::Minus, KeyCode::Equal,
+];
+
+/// A single physical input bound to an action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Pad(PadButton),
+}
+
+impl Binding {
+    /// Get the name used in the bindings file and on the controls screen
+    pub fn name(&self) -> String {
+        match self {
+            Binding::Key(key) => format!("Key:{:?}", key),
+            Binding::Mouse(button) => format!("Mouse:{:?}", button),
+            Binding::Pad(button) => format!("Pad:{:?}", button),
+        }
+    }
+
+    /// Get a short label for showing the binding to the player
+    pub fn label(&self) -> String {
+        match self {
+            Binding::Key(key) => format!("{:?}", key),
+            Binding::Mouse(button) => format!("Mouse {:?}", button),
+            Binding::Pad(button) => format!("Pad {:?}", button),
+        }
+    }
+
+    /// Parse a binding name written by Binding::name
+    pub fn from_name(name: &str) -> Option<Self> {
+        let (device, input) = name.split_once(':')?;
+        match device {
+            "Key" => BINDABLE_KEYS
+                .iter()
+                .find(|key| format!("{:?}", key) == input)
+                .map(|key| Binding::Key(*key)),
+            "Mouse" => [MouseButton::Left, MouseButton::Right, MouseButton::Middle]
+                .iter()
+                .find(|button| format!("{:?}", button) == input)
+                .map(|button| Binding::Mouse(*button)),
+            "Pad" => PadButton::ALL
+                .iter()
+                .find(|button| format!("{:?}", button) == input)
+                .map(|button| Binding::Pad(*button)),
+            _ => None,
+        }
+    }
+
+    /// Check whether this binding and another come from the same kind of device
+    pub fn same_device(&self, other: &Binding) -> bool {
+        matches!(
+            (self, other),
+            (Binding::Pad(_), Binding::Pad(_))
+                | (Binding::Key(_) | Binding::Mouse(_), Binding::Key(_) | Binding::Mouse(_))
+        )
+    }
+}
+
+/// Default bindings for an action
+fn default_bindings(action: Action) -> Vec<Binding> {
+    use Binding::*;
+    match action {
+        Action::MenuUp => vec![Key(KeyCode::Up), Pad(PadButton::DPadUp)],
+        Action::MenuDown => vec![Key(KeyCode::Down), Pad(PadButton::DPadDown)],
+        Action::MenuLeft => vec![Key(KeyCode::Left), Pad(PadButton::DPadLeft)],
+        Action::MenuRight => vec![Key(KeyCode::Right), Pad(PadButton::DPadRight)],
+        Action::Confirm => vec![Key(KeyCode::Enter), Key(KeyCode::KpEnter), Pad(PadButton::South)],
+        Action::Cancel => vec![Key(KeyCode::Escape), Pad(PadButton::East)],
+        Action::Pause => vec![Key(KeyCode::Escape), Key(KeyCode::P), Pad(PadButton::Start)],
+        Action::Shoot => vec![Key(KeyCode::Space), Mouse(MouseButton::Left), Pad(PadButton::RightTrigger)],
+        Action::AimUp => vec![Key(KeyCode::Up), Key(KeyCode::W)],
+        Action::AimDown => vec![Key(KeyCode::Down), Key(KeyCode::S)],
+        Action::AimLeft => vec![Key(KeyCode::Left), Key(KeyCode::A)],
+        Action::AimRight => vec![Key(KeyCode::Right), Key(KeyCode::D)],
+    }
+}
+
+/// Menu action a stick direction stands in for
+fn stick_action(stick: Vec2) -> Option<Action> {
+    if stick.length() < STICK_DEADZONE {
+        return None;
+    }
+    Some(if stick.x.abs() > stick.y.abs() {
+        if stick.x > 0.0 { Action::MenuRight } else { Action::MenuLeft }
+    } else if stick.y > 0.0 {
+        Action::MenuDown
+    } else {
+        Action::MenuUp
+    })
+}
+
+/// Per-frame input snapshot shared by all scenes
+#[derive(Default)]
+struct InputState {
+    /// Gamepad backend, None if no controller support is available
+    #[cfg(all(feature = "gamepad", not(target_arch = "wasm32")))]
+    gilrs: Option<gilrs::Gilrs>,
+    /// Current bindings for each action
+    bindings: BTreeMap<Action, Vec<Binding>>,
+    /// Buttons pressed this frame
+    pad_pressed: HashSet<PadButton>,
+    /// Buttons currently held
+    pad_down: HashSet<PadButton>,
+    /// Left stick position (-1.0 to 1.0, y pointing down)
+    left_stick: Vec2,
+    /// Right stick position (-1.0 to 1.0, y pointing down)
+    right_stick: Vec2,
+    /// Menu direction the left stick was pushed last frame
+    stick_direction: Option<Action>,
+    /// Menu direction the left stick was newly pushed this frame
+    stick_pressed: Option<Action>,
+    /// Whether a global shortcut used this frame's input, hiding it from scenes
+    suppressed: bool,
+    /// Whether the player is using a touchscreen rather than a mouse
+    touch: bool,
+    /// Canvas position of the finger on the screen, if any
+    touch_held: Option<Vec2>,
+    /// Canvas position a finger was lifted from this frame
+    touch_lifted: Option<Vec2>,
+    /// Mouse position last frame in window pixels, to notice a real mouse moving
+    last_mouse: Vec2,
+}
+
+thread_local! {
+    static STATE: RefCell<InputState> = RefCell::new(InputState::new());
+}
+
+impl InputState {
+    fn new() -> Self {
+        let mut state = Self {
+            #[cfg(all(feature = "gamepad", not(target_arch = "wasm32")))]
+            gilrs: match gilrs::Gilrs::new() {
+                Ok(gilrs) => Some(gilrs),
+                Err(e) => {
+                    log::warn!("Gamepad support unavailable: {}", e);
+                    None
+                }
+            },
+            ..Default::default()
+        };
+        state.reset_bindings();
+        state
+    }
+
+    /// Restore every action to its default bindings
+    fn reset_bindings(&mut self) {
+        self.bindings = Action::ALL
+            .iter()
+            .map(|action| (*action, default_bindings(*action)))
+            .collect();
+    }
+
+    /// Read gamepad events and refresh the snapshot
+    fn poll(&mut self) {
+        self.pad_pressed.clear();
+        self.suppressed = false;
+        self.poll_gamepad();
+        self.poll_touch();
+
+        // Turn the left stick into single menu presses
+        let direction = stick_action(self.left_stick);
+        self.stick_pressed = if direction != self.stick_direction { direction } else { None };
+        self.stick_direction = direction;
+    }
+
+    /// Follow fingers on a touchscreen. macroquad also reports a touch as the
+    /// left mouse button, so taps click widgets as they are
+    fn poll_touch(&mut self) {
+        let touches = touches();
+        self.touch_held = touches
+            .iter()
+            .find(|t| matches!(t.phase, TouchPhase::Started | TouchPhase::Stationary | TouchPhase::Moved))
+            .map(|t| display::to_canvas(t.position));
+        self.touch_lifted =
+            touches.iter().find(|t| t.phase == TouchPhase::Ended).map(|t| display::to_canvas(t.position));
+
+        // The mouse stays where the last touch was, so only a mouse moving on
+        // its own means the player went back to it
+        let mouse = Vec2::from(mouse_position());
+        if !touches.is_empty() {
+            self.touch = true;
+        } else if mouse != self.last_mouse {
+            self.touch = false;
+        }
+        self.last_mouse = mouse;
+    }
+
+    #[cfg(all(feature = "gamepad", not(target_arch = "wasm32")))]
+    fn poll_gamepad(&mut self) {
+        let Some(gilrs) = self.gilrs.as_mut() else {
+            return;
+        };
+
+        while let Some(gilrs::Event { event, .. }) = gilrs.next_event() {
+            match event {
+                gilrs::EventType::ButtonPressed(button, _) => {
+                    if let Some(button) = pad_button(button) {
+                        self.pad_pressed.insert(button);
+                        self.pad_down.insert(button);
+                    }
+                }
+                gilrs::EventType::ButtonReleased(button, _) => {
+                    if let Some(button) = pad_button(button) {
+                        self.pad_down.remove(&button);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Use the first connected pad for stick positions
+        self.left_stick = Vec2::ZERO;
+        self.right_stick = Vec2::ZERO;
+        if let Some((_, pad)) = gilrs.gamepads().next() {
+            use gilrs::Axis;
+            self.left_stick = Vec2::new(pad.value(Axis::LeftStickX), -pad.value(Axis::LeftStickY));
+            self.right_stick = Vec2::new(pad.value(Axis::RightStickX), -pad.value(Axis::RightStickY));
+        }
+    }
+
+    #[cfg(not(all(feature = "gamepad", not(target_arch = "wasm32"))))]
+    fn poll_gamepad(&mut self) {}
+
+    /// Check if a binding was pressed this frame
+    fn binding_pressed(&self, binding: &Binding) -> bool {
+        match binding {
+            Binding::Key(key) => is_key_pressed(*key),
+            // A tap isn't a mouse button, or touching the hunting ground would fire
+            Binding::Mouse(button) => !self.touch && is_mouse_button_pressed(*button),
+            Binding::Pad(button) => self.pad_pressed.contains(button),
+        }
+    }
+
+    /// Check if a binding is held
+    fn binding_down(&self, binding: &Binding) -> bool {
+        match binding {
+            Binding::Key(key) => is_key_down(*key),
+            Binding::Mouse(button) => !self.touch && is_mouse_button_down(*button),
+            Binding::Pad(button) => self.pad_down.contains(button),
+        }
+    }
+}
+
+/// Map a gilrs button onto our layout-independent names
+#[cfg(all(feature = "gamepad", not(target_arch = "wasm32")))]
+fn pad_button(button: gilrs::Button) -> Option<PadButton> {
+    use gilrs::Button;
+    Some(match button {
+        Button::South => PadButton::South,
+        Button::East => PadButton::East,
+        Button::North => PadButton::North,
+        Button::West => PadButton::West,
+        Button::DPadUp => PadButton::DPadUp,
+        Button::DPadDown => PadButton::DPadDown,
+        Button::DPadLeft => PadButton::DPadLeft,
+        Button::DPadRight => PadButton::DPadRight,
+        Button::Start => PadButton::Start,
+        Button::Select => PadButton::Select,
+        Button::LeftTrigger => PadButton::LeftShoulder,
+        Button::RightTrigger => PadButton::RightShoulder,
+        Button::LeftTrigger2 => PadButton::LeftTrigger,
+        Button::RightTrigger2 => PadButton::RightTrigger,
+        _ => return None,
+    })
+}
+
+/// Poll input devices, call once per frame before scenes update
+pub fn update() {
+    STATE.with(|state| state.borrow_mut().poll());
+}
+
+/// Ignore every action for the rest of the frame, once a global shortcut has handled the input
+pub fn suppress() {
+    STATE.with(|state| state.borrow_mut().suppressed = true);
+}
+
+/// Check if an action was triggered this frame on any device
+pub fn is_action_pressed(action: Action) -> bool {
+    STATE.with(|state| {
+        let state = state.borrow();
+        if state.suppressed {
+            return false;
+        }
+        state.stick_pressed == Some(action)
+            || state
+                .bindings
+                .get(&action)
+                .is_some_and(|bindings| bindings.iter().any(|b| state.binding_pressed(b)))
+    })
+}
+
+/// Check if an action is being held on any device
+pub fn is_action_down(action: Action) -> bool {
+    STATE.with(|state| {
+        let state = state.borrow();
+        if state.suppressed {
+            return false;
+        }
+        state.stick_direction == Some(action)
+            || state
+                .bindings
+                .get(&action)
+                .is_some_and(|bindings| bindings.iter().any(|b| state.binding_down(b)))
+    })
+}
+
+/// Get the first key, mouse button, or pad button pressed this frame
+pub fn last_pressed_binding() -> Option<Binding> {
+    if let Some(key) = get_last_key_pressed() {
+        if BINDABLE_KEYS.contains(&key) {
+            return Some(Binding::Key(key));
+        }
+    }
+    for button in [MouseButton::Left, MouseButton::Right, MouseButton::Middle] {
+        if !is_touch() && is_mouse_button_pressed(button) {
+            return Some(Binding::Mouse(button));
+        }
+    }
+    STATE.with(|state| {
+        let state = state.borrow();
+        PadButton::ALL
+            .iter()
+            .find(|button| state.pad_pressed.contains(button))
+            .map(|button| Binding::Pad(*button))
+    })
+}
+
+/// Get the bindings for an action
+pub fn bindings(action: Action) -> Vec<Binding> {
+    STATE.with(|state| state.borrow().bindings.get(&action).cloned().unwrap_or_default())
+}
+
+/// Bind an input to an action, replacing its other bindings from the same device
+pub fn rebind(action: Action, binding: Binding) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let bindings = state.bindings.entry(action).or_default();
+        bindings.retain(|b| !b.same_device(&binding));
+        bindings.insert(0, binding);
+    });
+}
+
+/// Remove every binding for an action
+pub fn clear_bindings(action: Action) {
+    STATE.with(|state| {
+        state.borrow_mut().bindings.insert(action, Vec::new());
+    });
+}
+
+/// Restore every action to its default bindings
+pub fn reset_bindings() {
+    STATE.with(|state| state.borrow_mut().reset_bindings());
+}
+
+/// Contents of the bindings file
+#[derive(Debug, Serialize, Deserialize)]
+struct BindingsFile {
+    /// Names of the inputs bound to each action
+    bindings: BTreeMap<Action, Vec<String>>,
+}
+
+/// Changes to the bindings file, one per version
+const BINDINGS_MIGRATIONS: &[Migration] = &[nest_bindings];
+
+/// Version 1 moved the bindings under a key of their own, next to the version
+fn nest_bindings(value: serde_json::Value) -> Result<serde_json::Value, String> {
+    Ok(serde_json::json!({ "bindings": value }))
+}
+
+/// Save the current bindings to the bindings file
+pub fn save_bindings(storage: &mut dyn Storage) -> Result<(), String> {
+    let bindings: BTreeMap<Action, Vec<String>> = STATE.with(|state| {
+        state
+            .borrow()
+            .bindings
+            .iter()
+            .map(|(action, bindings)| (*action, bindings.iter().map(|b| b.name()).collect()))
+            .collect()
+    });
+
+    let json = versioned::to_json(&BindingsFile { bindings }, BINDINGS_MIGRATIONS)
+        .map_err(|e| format!("Failed to encode bindings: {}", e))?;
+    storage.write(BINDINGS_FILE, &json)
+}
+
+/// Load bindings from the bindings file, keeping defaults for anything missing
+pub fn load_bindings(storage: &dyn Storage) -> Result<(), String> {
+    // No saved bindings yet, the defaults apply
+    let Some(json) = storage.read(BINDINGS_FILE)? else {
+        return Ok(());
+    };
+    let file: BindingsFile = versioned::from_json(&json, BINDINGS_MIGRATIONS)
+        .map_err(|e| format!("Failed to parse '{}': {}", BINDINGS_FILE, e))?;
+
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        for (action, names) in file.bindings {
+            let bindings = names.iter().filter_map(|name| Binding::from_name(name)).collect();
+            state.bindings.insert(action, bindings);
+        }
+    });
+    Ok(())
+}
+
+/// Get the left stick position (y points down, like screen coordinates)
+pub fn left_stick() -> Vec2 {
+    STATE.with(|state| state.borrow().left_stick)
+}
+
+/// Get the right stick position
+pub fn right_stick() -> Vec2 {
+    STATE.with(|state| state.borrow().right_stick)
+}
+
+/// Get the aim direction from the aim actions and either stick combined
+pub fn aim_vector() -> Vec2 {
+    let mut aim = right_stick() + left_stick();
+    if is_action_down(Action::AimLeft) {
+        aim.x -= 1.0;
+    }
+    if is_action_down(Action::AimRight) {
+        aim.x += 1.0;
+    }
+    if is_action_down(Action::AimUp) {
+        aim.y -= 1.0;
+    }
+    if is_action_down(Action::AimDown) {
+        aim.y += 1.0;
+    }
+    aim.clamp_length_max(1.0)
+}
+
+/// Check if the player is using a touchscreen, so scenes can offer larger
+/// targets and on-screen buttons
+pub fn is_touch() -> bool {
+    STATE.with(|state| state.borrow().touch)
+}
+
+/// Canvas position of the finger on the screen, if any
+pub fn touch_held() -> Option<Vec2> {
+    STATE.with(|state| state.borrow().touch_held)
+}
+
+/// Canvas position a finger was lifted from this frame, if any
+pub fn touch_lifted() -> Option<Vec2> {
+    STATE.with(|state| state.borrow().touch_lifted)
+}
+
+/// Watches typed characters for any of a set of phrases, like the cheat codes
+/// typed at the keyboard in the classic games
+#[derive(Debug, Clone)]
+pub struct SequenceMatcher {
+    /// Phrases to watch for, in lowercase
+    phrases: Vec<String>,
+    /// Most recent characters typed, no longer than the longest phrase
+    typed: String,
+}
+
+impl SequenceMatcher {
+    /// Create a matcher for a set of phrases; case is ignored
+    pub fn new(phrases: &[&str]) -> Self {
+        Self { phrases: phrases.iter().map(|p| p.to_lowercase()).collect(), typed: String::new() }
+    }
+
+    /// Take one typed character, returning the index of a phrase it completes
+    pub fn feed(&mut self, c: char) -> Option<usize> {
+        self.typed.extend(c.to_lowercase());
+        let longest = self.phrases.iter().map(|p| p.chars().count()).max().unwrap_or(0);
+        let excess = self.typed.chars().count().saturating_sub(longest);
+        self.typed = self.typed.chars().skip(excess).collect();
+
+        let found = self.phrases.iter().position(|p| self.typed.ends_with(p.as_str()));
+        if found.is_some() {
+            self.typed.clear();
+        }
+        found
+    }
+
+    /// Take the characters typed this frame, returning the index of a phrase they complete
+    pub fn update(&mut self) -> Option<usize> {
+        let mut found = None;
+        while let Some(c) = get_char_pressed() {
+            found = self.feed(c).or(found);
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequences_match_however_they_are_typed() {
+        let mut matcher = SequenceMatcher::new(&["Westward", "ho"]);
+        let typed: Vec<Option<usize>> = "xxwestWARD".chars().map(|c| matcher.feed(c)).collect();
+        assert_eq!(typed.last(), Some(&Some(0)));
+        assert_eq!(typed.iter().flatten().count(), 1);
+        assert_eq!(matcher.feed('h'), None);
+        assert_eq!(matcher.feed('o'), Some(1));
+    }
+
+    #[test]
+    fn bindings_saved_before_versioning_still_load() {
+        let old = r#"{ "Confirm": ["Key:Enter", "Key:Space"] }"#;
+        let file: BindingsFile = versioned::from_json(old, BINDINGS_MIGRATIONS).unwrap();
+        assert_eq!(file.bindings[&Action::Confirm], ["Key:Enter", "Key:Space"]);
+
+        let json = versioned::to_json(&file, BINDINGS_MIGRATIONS).unwrap();
+        let again: BindingsFile = versioned::from_json(&json, BINDINGS_MIGRATIONS).unwrap();
+        assert_eq!(again.bindings, file.bindings);
+    }
+}
@@ -0,0 +1,83 @@
+// Checking the installed assets against the list the build script made of
+// the ones the game shipped with. Converting the original game's files by
+// hand goes wrong in all sorts of ways, and a list of exactly which files are
+// missing or damaged saves a lot of guessing.
+
+use rust_trail::utils::fnv;
+
+/// Every shipped asset's path under the assets folder, size and hash
+const EXPECTED: &[(&str, usize, u64)] = include!(concat!(env!("OUT_DIR"), "/asset_integrity.rs"));
+
+/// Shipped assets that aren't installed as they should be
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// Files that aren't there at all
+    pub missing: Vec<String>,
+    /// Files that are there but differ from the ones shipped
+    pub corrupt: Vec<String>,
+}
+
+impl IntegrityReport {
+    /// Whether every file is as shipped
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.corrupt.is_empty()
+    }
+}
+
+/// Check the shipped assets, reading each one's installed bytes with `read`.
+/// Files for which `replaced` is true have a deliberate replacement and are
+/// left alone
+pub fn verify(read: impl Fn(&str) -> Option<Vec<u8>>, replaced: impl Fn(&str) -> bool) -> IntegrityReport {
+    check(EXPECTED, read, replaced)
+}
+
+/// Check a list of files against their expected sizes and hashes
+fn check(
+    expected: &[(&str, usize, u64)],
+    read: impl Fn(&str) -> Option<Vec<u8>>,
+    replaced: impl Fn(&str) -> bool,
+) -> IntegrityReport {
+    let mut report = IntegrityReport::default();
+    for &(file, size, hash) in expected {
+        if replaced(file) {
+            continue;
+        }
+        match read(file) {
+            None => report.missing.push(file.to_string()),
+            Some(bytes) if bytes.len() != size || fnv::hash(&bytes) != hash => report.corrupt.push(file.to_string()),
+            Some(_) => {}
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn files_are_missing_corrupt_or_replaced() {
+        let expected = [
+            ("images/TITLE.png", 5, fnv::hash(b"title")),
+            ("images/MAP.png", 3, fnv::hash(b"map")),
+            ("text/hints.txt", 5, fnv::hash(b"hints")),
+            ("images/TRAIL.png", 5, fnv::hash(b"trail")),
+        ];
+        let installed = |file: &str| match file {
+            "images/TITLE.png" => Some(b"title".to_vec()),
+            "images/MAP.png" => Some(b"mop".to_vec()),
+            "images/TRAIL.png" => Some(b"my trail".to_vec()),
+            _ => None,
+        };
+        let report = check(&expected, installed, |file| file == "images/TRAIL.png");
+        assert_eq!(report.missing, ["text/hints.txt"]);
+        assert_eq!(report.corrupt, ["images/MAP.png"]);
+        assert!(!report.is_clean());
+    }
+
+    #[test]
+    fn the_shipped_list_is_built() {
+        assert!(EXPECTED.iter().any(|&(file, _, _)| file == "images/TITLE.png"));
+        assert!(EXPECTED.iter().all(|&(file, _, _)| !file.starts_with("fallback/")));
+    }
+}
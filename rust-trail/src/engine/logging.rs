@@ -0,0 +1,182 @@
+// Logging. The game reports through the `log` macros, and everything ends up
+// here: in a log file that is rotated as it grows, so players can attach it
+// to bug reports, on standard output, and in debug builds in a buffer the
+// developer console shows on screen. In a browser there is no file, and
+// standard output is the page's console.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use log::{LevelFilter, Log, Metadata, Record};
+use macroquad::miniquad::date;
+
+/// File the log is written to
+pub const LOG_FILE: &str = "game.log";
+
+/// Size the log file may reach before it is rotated (in bytes)
+pub const MAX_LOG_BYTES: u64 = 512 * 1024;
+
+/// Rotated log files kept, as game.log.1 (newest) to game.log.3
+pub const LOG_BACKUPS: u32 = 3;
+
+/// Lines kept for the on-screen console until it collects them
+const SCREEN_LINES: usize = 200;
+
+/// Open log file and how much has been written to it
+struct LogFile {
+    file: Option<File>,
+    size: u64,
+}
+
+/// Logger sending records to the file, standard output and the screen
+struct GameLogger {
+    /// When logging started (in seconds), for timestamps
+    started: f64,
+    /// Log file being written
+    file: Mutex<LogFile>,
+    /// Lines waiting for the on-screen console
+    screen: Mutex<VecDeque<String>>,
+}
+
+impl GameLogger {
+    /// Write a line to the log file, rotating it first if it has grown too big
+    fn write_file(&self, line: &str) {
+        let Ok(mut log) = self.file.lock() else {
+            return;
+        };
+        if log.size + line.len() as u64 > MAX_LOG_BYTES {
+            log.file = None;
+            if let Err(e) = rotate(LOG_FILE, LOG_BACKUPS) {
+                eprintln!("{}", e);
+            }
+            log.file = open(LOG_FILE);
+            log.size = 0;
+        }
+        if let Some(file) = log.file.as_mut() {
+            if writeln!(file, "{}", line).is_ok() {
+                log.size += line.len() as u64 + 1;
+            }
+        }
+    }
+}
+
+impl Log for GameLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let seconds = date::now() - self.started;
+        let line = format!("[{:9.3}] {:<5} {}: {}", seconds, record.level(), record.target(), record.args());
+        print(&line);
+        self.write_file(&line);
+        if cfg!(debug_assertions) {
+            if let Ok(mut screen) = self.screen.lock() {
+                screen.push_back(format!("{:<5} {}", record.level(), record.args()));
+                if screen.len() > SCREEN_LINES {
+                    screen.pop_front();
+                }
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut log) = self.file.lock() {
+            if let Some(file) = log.file.as_mut() {
+                let _ = file.flush();
+            }
+        }
+    }
+}
+
+/// The logger, once installed
+static LOGGER: std::sync::OnceLock<GameLogger> = std::sync::OnceLock::new();
+
+/// Write a line to standard output, or the browser's console on the web
+fn print(line: &str) {
+    #[cfg(not(target_arch = "wasm32"))]
+    println!("{}", line);
+    #[cfg(target_arch = "wasm32")]
+    rust_trail::utils::web::console_log(line);
+}
+
+/// Open the log file for appending
+fn open(path: &str) -> Option<File> {
+    match OpenOptions::new().create(true).append(true).open(path) {
+        Ok(file) => Some(file),
+        Err(e) => {
+            eprintln!("Failed to open '{}': {}", path, e);
+            None
+        }
+    }
+}
+
+/// Shift the log file and its backups along one, dropping the oldest
+pub fn rotate(path: &str, backups: u32) -> Result<(), String> {
+    for n in (1..backups).rev() {
+        let from = format!("{}.{}", path, n);
+        if Path::new(&from).exists() {
+            let to = format!("{}.{}", path, n + 1);
+            fs::rename(&from, &to).map_err(|e| format!("Failed to rotate '{}': {}", from, e))?;
+        }
+    }
+    if backups == 0 {
+        return fs::remove_file(path).or(Ok(()));
+    }
+    if Path::new(path).exists() {
+        fs::rename(path, format!("{}.1", path)).map_err(|e| format!("Failed to rotate '{}': {}", path, e))?;
+    }
+    Ok(())
+}
+
+/// Install the logger, showing records up to a level
+pub fn init(level: LevelFilter) -> Result<(), String> {
+    let file = if cfg!(target_arch = "wasm32") { None } else { open(LOG_FILE) };
+    let size = file.as_ref().and_then(|f| f.metadata().ok()).map_or(0, |m| m.len());
+    let logger = LOGGER.get_or_init(|| GameLogger {
+        started: date::now(),
+        file: Mutex::new(LogFile { file, size }),
+        screen: Mutex::new(VecDeque::new()),
+    });
+    log::set_logger(logger).map_err(|e| format!("Failed to start logging: {}", e))?;
+    log::set_max_level(level);
+    Ok(())
+}
+
+/// Take the lines logged since the last call, for the on-screen console
+///
+/// Always empty in release builds.
+pub fn take_screen_lines() -> Vec<String> {
+    let Some(logger) = LOGGER.get() else {
+        return Vec::new();
+    };
+    logger.screen.lock().map(|mut screen| screen.drain(..).collect()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation_keeps_the_newest_backups() {
+        let dir = std::env::temp_dir().join(format!("rust-trail-logs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("test.log");
+        let path = path.to_str().unwrap();
+
+        for generation in 0..4 {
+            fs::write(path, generation.to_string()).unwrap();
+            rotate(path, 2).unwrap();
+        }
+        assert!(!Path::new(path).exists());
+        assert_eq!(fs::read_to_string(format!("{}.1", path)).unwrap(), "3");
+        assert_eq!(fs::read_to_string(format!("{}.2", path)).unwrap(), "2");
+        assert!(!Path::new(&format!("{}.3", path)).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -2,13 +2,23 @@
 
 // Export asset_loader module
 pub mod asset_loader;
-// These will be implemented in the future
-// pub mod renderer;
-// pub mod audio;
-// pub mod input;
-// pub mod ui;
-
-/// Initializes the game engine components
-pub fn initialize() {
-    println!("Engine initialized");
-}
+pub mod animation;
+pub mod audio;
+pub mod control_server;
+pub mod crt;
+pub mod cursor;
+pub mod display;
+pub mod entities;
+pub mod event_bus;
+pub mod fallback;
+pub mod input;
+pub mod integrity;
+pub mod logging;
+pub mod narration;
+pub mod network;
+pub mod palette;
+pub mod parallax;
+pub mod renderer;
+pub mod tint;
+pub mod transition;
+pub mod ui;
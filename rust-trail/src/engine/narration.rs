@@ -0,0 +1,198 @@
+// Narration for players who can't see the screen. Widgets and scenes hand over
+// what they show as plain text: messages as they appear, the menu entry under
+// the cursor, and changes on the trail. Each line is added to a narration file
+// that a screen reader can follow as it grows. If the player asks, it is also
+// spoken with the system's own speech: `say` on macOS, Speech Dispatcher on
+// Linux, the speech API through PowerShell on Windows, and the page's speech
+// synthesis on the web.
+
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::Write;
+use std::sync::mpsc::Sender;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc::{self, Receiver};
+use macroquad::miniquad::date;
+use serde::{Deserialize, Serialize};
+use crate::engine::ui::rich_text;
+
+/// File narration is written to, started afresh each time the game runs
+pub const NARRATION_FILE: &str = "narration.txt";
+
+/// Seconds within which the same line isn't narrated twice, as when a
+/// screen is laid out again
+const REPEAT_SECONDS: f64 = 1.0;
+
+/// Where narration goes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Narration {
+    /// Nowhere
+    Off,
+    /// The narration file (the console on the web)
+    File,
+    /// The narration file, and spoken aloud
+    Speech,
+}
+
+impl Narration {
+    /// All narration modes in menu order
+    pub const ALL: [Narration; 3] = [Narration::Off, Narration::File, Narration::Speech];
+
+    /// Display name of the narration mode
+    pub fn name(&self) -> &'static str {
+        match self {
+            Narration::Off => "Off",
+            Narration::File => "To file",
+            Narration::Speech => "Spoken",
+        }
+    }
+}
+
+/// What narration is going to
+struct Narrator {
+    mode: Narration,
+    /// Open narration file, once narration has been turned on
+    file: Option<File>,
+    /// Whether the file has been started this run, so turning narration off
+    /// and on again adds to it
+    started: bool,
+    /// Last line narrated and when
+    last: Option<(String, f64)>,
+    /// Lines waiting to be spoken, while speech is on
+    speaker: Option<Sender<String>>,
+}
+
+thread_local! {
+    static NARRATOR: RefCell<Narrator> = const { RefCell::new(Narrator {
+        mode: Narration::Off,
+        file: None,
+        started: false,
+        last: None,
+        speaker: None,
+    }) };
+}
+
+/// Choose where narration goes
+pub fn set_mode(mode: Narration) {
+    NARRATOR.with(|n| {
+        let mut n = n.borrow_mut();
+        if n.mode == mode {
+            return;
+        }
+        n.mode = mode;
+        if mode == Narration::Off {
+            n.file = None;
+        } else if n.file.is_none() && !cfg!(target_arch = "wasm32") {
+            let file = if n.started {
+                File::options().append(true).create(true).open(NARRATION_FILE)
+            } else {
+                File::create(NARRATION_FILE)
+            };
+            match file {
+                Ok(file) => n.file = Some(file),
+                Err(e) => log::error!("Failed to open '{}': {}", NARRATION_FILE, e),
+            }
+            n.started = true;
+        }
+        // Dropping the sender lets the speaking thread finish
+        n.speaker = None;
+        #[cfg(not(target_arch = "wasm32"))]
+        if mode == Narration::Speech {
+            let (sender, lines) = mpsc::channel();
+            std::thread::spawn(move || speak_lines(lines));
+            n.speaker = Some(sender);
+        }
+    });
+}
+
+/// Whether anything is being narrated
+pub fn is_on() -> bool {
+    NARRATOR.with(|n| n.borrow().mode != Narration::Off)
+}
+
+/// Narrate text as it's shown, on one line without its color markup
+pub fn say(text: &str) {
+    if !is_on() {
+        return;
+    }
+    let line = rich_text::strip(text).split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.is_empty() {
+        return;
+    }
+    NARRATOR.with(|n| {
+        let mut n = n.borrow_mut();
+        let now = date::now();
+        if n.last.as_ref().is_some_and(|(last, at)| *last == line && now - at < REPEAT_SECONDS) {
+            return;
+        }
+        n.last = Some((line.clone(), now));
+
+        // Flushed line by line, so a screen reader following the file is never behind
+        if let Some(file) = n.file.as_mut() {
+            if let Err(e) = writeln!(file, "{}", line).and_then(|()| file.flush()) {
+                log::error!("Failed to write '{}': {}", NARRATION_FILE, e);
+                n.file = None;
+            }
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            crate::utils::web::console_log(&format!("Narration: {}", line));
+            if n.mode == Narration::Speech {
+                crate::utils::web::speak(&line);
+            }
+        }
+        if let Some(speaker) = &n.speaker {
+            let _ = speaker.send(line);
+        }
+    });
+}
+
+/// Speak lines as they arrive until speech is turned off, running together
+/// any that came in while the last was being spoken
+#[cfg(not(target_arch = "wasm32"))]
+fn speak_lines(lines: Receiver<String>) {
+    while let Ok(first) = lines.recv() {
+        let text = std::iter::once(first).chain(lines.try_iter()).collect::<Vec<_>>().join(". ");
+        if let Err(e) = speak(&text) {
+            log::warn!("Speech stopped: {}", e);
+            return;
+        }
+    }
+}
+
+/// Speak text with the system's speech, waiting until it's done
+#[cfg(not(target_arch = "wasm32"))]
+fn speak(text: &str) -> Result<(), String> {
+    use std::process::{Command, Stdio};
+
+    // Text goes in on standard input, so nothing in it is read as an option
+    let mut command = if cfg!(target_os = "macos") {
+        let mut command = Command::new("say");
+        command.args(["-f", "-"]);
+        command
+    } else if cfg!(target_os = "windows") {
+        let mut command = Command::new("powershell");
+        command.args([
+            "-NoProfile",
+            "-Command",
+            "Add-Type -AssemblyName System.Speech; \
+             (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak([Console]::In.ReadToEnd())",
+        ]);
+        command
+    } else {
+        let mut command = Command::new("spd-say");
+        command.args(["--wait", "--pipe-mode"]);
+        command
+    };
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to start speech: {}", e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "{}", text).map_err(|e| format!("Failed to send text to speech: {}", e))?;
+    }
+    child.wait().map_err(|e| format!("Failed to wait for speech: {}", e))?;
+    Ok(())
+}
@@ -0,0 +1,102 @@
+// Talking to the online leaderboard. Each request runs on a thread of its own
+// so the game keeps drawing while it waits, and hands back a Pending that is
+// checked once a frame. Builds without the leaderboard feature, and the web
+// build, fail every request straight away.
+
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use crate::game_logic::leaderboard::{self, Submission};
+use crate::game_logic::top_ten::TopTenEntry;
+
+/// A request still on its way
+pub struct Pending<T> {
+    /// Where the result arrives, until it has been taken
+    receiver: Option<Receiver<Result<T, String>>>,
+}
+
+impl<T> Pending<T> {
+    /// Take the result if it has arrived; it is handed over once
+    pub fn poll(&mut self) -> Option<Result<T, String>> {
+        let result = match self.receiver.as_ref()?.try_recv() {
+            Ok(result) => result,
+            Err(TryRecvError::Empty) => return None,
+            Err(TryRecvError::Disconnected) => Err("The request was lost".to_string()),
+        };
+        self.receiver = None;
+        Some(result)
+    }
+}
+
+/// Run a request in the background
+#[cfg(all(feature = "leaderboard", not(target_arch = "wasm32")))]
+fn request<T: Send + 'static>(work: impl FnOnce() -> Result<T, String> + Send + 'static) -> Pending<T> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        // The game may have moved on and dropped the receiver
+        let _ = sender.send(work());
+    });
+    Pending { receiver: Some(receiver) }
+}
+
+/// Run a request at once; without a client it fails without waiting
+#[cfg(not(all(feature = "leaderboard", not(target_arch = "wasm32"))))]
+fn request<T: Send + 'static>(work: impl FnOnce() -> Result<T, String> + Send + 'static) -> Pending<T> {
+    let (sender, receiver) = mpsc::channel();
+    let _ = sender.send(work());
+    Pending { receiver: Some(receiver) }
+}
+
+#[cfg(all(feature = "leaderboard", not(target_arch = "wasm32")))]
+mod client {
+    use std::time::Duration;
+
+    /// Longest a request may take before it's given up on
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Agent for one request
+    fn agent() -> ureq::Agent {
+        ureq::AgentBuilder::new().timeout(TIMEOUT).build()
+    }
+
+    /// Post a JSON body
+    pub fn post(url: &str, body: &str) -> Result<(), String> {
+        agent().post(url).set("Content-Type", "application/json").send_string(body).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Fetch a page as text
+    pub fn get(url: &str) -> Result<String, String> {
+        agent().get(url).call().map_err(|e| e.to_string())?.into_string().map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(not(all(feature = "leaderboard", not(target_arch = "wasm32"))))]
+mod client {
+    /// Why every request fails
+    const UNAVAILABLE: &str = "this build has no online leaderboard";
+
+    /// Post a JSON body
+    pub fn post(_url: &str, _body: &str) -> Result<(), String> {
+        Err(UNAVAILABLE.to_string())
+    }
+
+    /// Fetch a page as text
+    pub fn get(_url: &str) -> Result<String, String> {
+        Err(UNAVAILABLE.to_string())
+    }
+}
+
+/// Post a finished journey's score to a leaderboard server
+pub fn submit(server: &str, submission: &Submission) -> Pending<()> {
+    let url = leaderboard::scores_url(server);
+    let body = submission.to_json();
+    request(move || client::post(&url, &body?).map_err(|e| format!("Failed to send score to {}: {}", url, e)))
+}
+
+/// Fetch the global Top Ten from a leaderboard server
+pub fn fetch_top_ten(server: &str) -> Pending<Vec<TopTenEntry>> {
+    let url = leaderboard::top_ten_url(server);
+    request(move || {
+        let json = client::get(&url).map_err(|e| format!("Failed to fetch the online Top Ten from {}: {}", url, e))?;
+        leaderboard::parse_top_ten(&json)
+    })
+}
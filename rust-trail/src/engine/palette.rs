@@ -0,0 +1,308 @@
+// Palette effects in the manner of the VGA original, which faded pictures in
+// and out and changed their light by rewriting the 256 colour table rather
+// than the pixels. The converted pictures keep their tables, so a picture can
+// be drawn through a shader that finds each pixel's entry in its own palette
+// and puts out the same entry of another: a darker copy to fade, a tinted one
+// for the time of day. The shaders are GLSL 100 so they run on WebGL too.
+
+use std::cell::RefCell;
+use macroquad::miniquad::{BlendFactor, BlendState, BlendValue, Equation, PipelineParams};
+use macroquad::prelude::*;
+use crate::engine::renderer::draw_texture_ex;
+use crate::engine::tint;
+
+/// Entries in a palette
+pub const PALETTE_SIZE: usize = 256;
+/// Highest level a channel of the VGA's colour table could be set to
+const DAC_MAX: f32 = 63.0;
+/// Signature at the start of every PNG file
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+const VERTEX_SHADER: &str = r#"#version 100
+attribute vec3 position;
+attribute vec2 texcoord;
+attribute vec4 color0;
+
+varying mediump vec2 uv;
+varying lowp vec4 color;
+
+uniform mat4 Model;
+uniform mat4 Projection;
+
+void main() {
+    gl_Position = Projection * Model * vec4(position, 1);
+    uv = texcoord;
+    color = color0 / 255.0;
+}
+"#;
+
+const FRAGMENT_SHADER: &str = r#"#version 100
+precision mediump float;
+
+varying vec2 uv;
+varying lowp vec4 color;
+
+uniform sampler2D Texture;
+// The picture's own palette, and the one to draw it with, 256 pixels wide
+uniform sampler2D Source;
+uniform sampler2D Target;
+
+void main() {
+    vec4 pixel = texture2D(Texture, uv);
+
+    // The entry nearest the pixel, which is exact unless the picture is scaled
+    float index = 0.0;
+    float nearest = 4.0;
+    for (int i = 0; i < 256; i++) {
+        vec3 difference = texture2D(Source, vec2((float(i) + 0.5) / 256.0, 0.5)).rgb - pixel.rgb;
+        float distance = dot(difference, difference);
+        if (distance < nearest) {
+            nearest = distance;
+            index = float(i);
+        }
+    }
+
+    vec3 swapped = texture2D(Target, vec2((index + 0.5) / 256.0, 0.5)).rgb;
+    gl_FragColor = vec4(swapped, pixel.a) * color;
+}
+"#;
+
+/// A 256 colour table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    /// Red, green and blue of each entry
+    colors: [[u8; 3]; PALETTE_SIZE],
+}
+
+impl Palette {
+    /// Create a palette from its colours; missing entries are black
+    pub fn new(colors: &[[u8; 3]]) -> Self {
+        let mut palette = [[0; 3]; PALETTE_SIZE];
+        for (entry, color) in palette.iter_mut().zip(colors) {
+            *entry = *color;
+        }
+        Self { colors: palette }
+    }
+
+    /// Read the palette of a paletted PNG, or None if it has no palette
+    pub fn from_png(bytes: &[u8]) -> Option<Self> {
+        let mut at = PNG_SIGNATURE.len();
+        if !bytes.starts_with(PNG_SIGNATURE) {
+            return None;
+        }
+        // Chunks are a big-endian length, a type, the data and a checksum
+        while let Some(header) = bytes.get(at..at + 8) {
+            let length = u32::from_be_bytes(header[..4].try_into().ok()?) as usize;
+            let data = bytes.get(at + 8..at + 8 + length)?;
+            match &header[4..] {
+                b"PLTE" => {
+                    let colors: Vec<[u8; 3]> = data.chunks_exact(3).map(|c| [c[0], c[1], c[2]]).collect();
+                    return Some(Self::new(&colors));
+                }
+                // The palette has to come before the pixels
+                b"IDAT" | b"IEND" => return None,
+                _ => at += 12 + length,
+            }
+        }
+        None
+    }
+
+    /// Each colour scaled toward black, in the 64 steps the VGA's colour table had
+    pub fn faded(&self, brightness: f32) -> Self {
+        let level = (brightness.clamp(0.0, 1.0) * DAC_MAX).round() / DAC_MAX;
+        self.map(|channel, _| channel * level)
+    }
+
+    /// Each colour mixed toward a tint by its alpha, as if the tint were laid over it
+    pub fn tinted(&self, tint: Color) -> Self {
+        let over = [tint.r, tint.g, tint.b];
+        self.map(|channel, i| channel * (1.0 - tint.a) + over[i] * tint.a)
+    }
+
+    /// The palette lit for an hour of the day, the way `tint::daylight` tints the trail
+    pub fn at_hour(&self, hour: f32) -> Self {
+        self.tinted(tint::daylight(hour))
+    }
+
+    /// Change each channel of each colour, given as 0.0 to 1.0 along with the channel's index
+    fn map(&self, change: impl Fn(f32, usize) -> f32) -> Self {
+        let mut colors = self.colors;
+        for color in colors.iter_mut() {
+            for (i, channel) in color.iter_mut().enumerate() {
+                *channel = (change(*channel as f32 / 255.0, i).clamp(0.0, 1.0) * 255.0).round() as u8;
+            }
+        }
+        Self { colors }
+    }
+
+    /// The palette as a row of pixels
+    fn to_bytes(&self) -> Vec<u8> {
+        self.colors.iter().flat_map(|&[r, g, b]| [r, g, b, 255]).collect()
+    }
+}
+
+/// A fade up through the palette from black
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaletteFade {
+    /// Seconds the fade takes
+    duration: f32,
+    /// Seconds played so far
+    elapsed: f32,
+}
+
+impl PaletteFade {
+    /// Fade up from black over a number of seconds
+    pub fn fade_in(duration: f32) -> Self {
+        Self { duration: duration.max(0.0), elapsed: 0.0 }
+    }
+
+    /// Advance the fade
+    pub fn update(&mut self, dt: f32) {
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+    }
+
+    /// Whether the fade has finished
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// How bright the palette is, from 0.0 for black to 1.0 for full colour
+    pub fn brightness(&self) -> f32 {
+        if self.duration <= 0.0 { 1.0 } else { self.elapsed / self.duration }
+    }
+}
+
+thread_local! {
+    /// Palette material, built the first time a picture is drawn with one
+    static MATERIAL: RefCell<Option<Result<Material, String>>> = const { RefCell::new(None) };
+}
+
+/// The shared palette material, or None if the shader couldn't be built
+fn material() -> Option<Material> {
+    MATERIAL.with(|m| {
+        m.borrow_mut()
+            .get_or_insert_with(|| {
+                let params = MaterialParams {
+                    textures: vec!["Source".to_string(), "Target".to_string()],
+                    pipeline_params: PipelineParams {
+                        color_blend: Some(BlendState::new(
+                            Equation::Add,
+                            BlendFactor::Value(BlendValue::SourceAlpha),
+                            BlendFactor::OneMinusValue(BlendValue::SourceAlpha),
+                        )),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                };
+                load_material(VERTEX_SHADER, FRAGMENT_SHADER, params).map_err(|e| {
+                    let e = format!("Failed to build the palette shader: {:?}", e);
+                    log::error!("{}", e);
+                    e
+                })
+            })
+            .as_ref()
+            .ok()
+            .copied()
+    })
+}
+
+/// Draws pictures with one palette swapped for another
+pub struct PaletteShader {
+    /// The picture's own palette, as a texture
+    source: Texture2D,
+    /// The palette to draw it with, as a texture
+    target: Texture2D,
+    /// Palettes the textures hold, so they're only uploaded when they change
+    current: Option<(Palette, Palette)>,
+}
+
+impl PaletteShader {
+    /// Create the textures the palettes are passed in, or None if the shader
+    /// couldn't be built and pictures have to be drawn plain
+    pub fn new() -> Option<Self> {
+        material()?;
+        let texture = || {
+            let texture = Texture2D::from_rgba8(PALETTE_SIZE as u16, 1, &[0; PALETTE_SIZE * 4]);
+            texture.set_filter(FilterMode::Nearest);
+            texture
+        };
+        Some(Self { source: texture(), target: texture(), current: None })
+    }
+
+    /// Set the picture's own palette and the one to draw it with
+    pub fn set_palettes(&mut self, source: &Palette, target: &Palette) {
+        let (old_source, old_target) = match &self.current {
+            Some((s, t)) => (Some(s), Some(t)),
+            None => (None, None),
+        };
+        let upload = |texture: &Texture2D, palette: &Palette| {
+            texture.update(&Image { bytes: palette.to_bytes(), width: PALETTE_SIZE as u16, height: 1 });
+        };
+        if old_source != Some(source) {
+            upload(&self.source, source);
+        }
+        if old_target != Some(target) {
+            upload(&self.target, target);
+        }
+        self.current = Some((source.clone(), target.clone()));
+    }
+
+    /// Draw a picture with the palettes swapped, like `draw_texture_ex`
+    pub fn draw_texture(&self, texture: Texture2D, x: f32, y: f32, color: Color, params: DrawTextureParams) {
+        let Some(material) = material().filter(|_| self.current.is_some()) else {
+            draw_texture_ex(texture, x, y, color, params);
+            return;
+        };
+        material.set_texture("Source", self.source);
+        material.set_texture("Target", self.target);
+        gl_use_material(material);
+        draw_texture_ex(texture, x, y, color, params);
+        gl_use_default_material();
+    }
+}
+
+impl Drop for PaletteShader {
+    fn drop(&mut self) {
+        self.source.delete();
+        self.target.delete();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fades_step_like_the_vga_and_tints_mix_in() {
+        let palette = Palette::new(&[[252, 128, 0], [0, 0, 0]]);
+        assert_eq!(palette.faded(1.0), palette);
+        assert_eq!(palette.faded(0.0).colors[0], [0, 0, 0]);
+        // Brightnesses closer together than a VGA step look the same
+        assert_eq!(palette.faded(0.5), palette.faded(0.505));
+        assert_eq!(palette.faded(0.5).colors[0], [128, 65, 0]);
+
+        let night = palette.tinted(Color::new(0.0, 0.0, 1.0, 0.5));
+        assert_eq!(night.colors[1], [0, 0, 128]);
+        assert_eq!(palette.at_hour(13.0), palette);
+    }
+
+    #[test]
+    fn fades_come_up_from_black() {
+        let mut fade = PaletteFade::fade_in(1.0);
+        assert_eq!(fade.brightness(), 0.0);
+        fade.update(0.25);
+        assert_eq!(fade.brightness(), 0.25);
+        fade.update(1.0);
+        assert!(fade.is_finished());
+        assert_eq!(fade.brightness(), 1.0);
+        assert_eq!(PaletteFade::fade_in(0.0).brightness(), 1.0);
+    }
+
+    #[test]
+    fn png_palettes_are_read() {
+        let landmark = std::fs::read("assets/images/LAND3.png").unwrap();
+        let palette = Palette::from_png(&landmark).unwrap();
+        assert_eq!(palette.colors.len(), PALETTE_SIZE);
+        assert_eq!(Palette::from_png(b"not a png"), None);
+    }
+}
@@ -0,0 +1,89 @@
+// Scrolling backdrops built from layers of scenery. Layers further away scroll
+// more slowly than near ones, giving a sense of depth as the wagon moves.
+
+use std::f32::consts::TAU;
+use macroquad::prelude::*;
+use crate::engine::display::screen_width;
+
+/// Width of the vertical strips each layer is drawn in
+const STRIP_WIDTH: f32 = 4.0;
+
+/// Outline of a layer of scenery
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Silhouette {
+    /// Level ground
+    Flat,
+    /// Rolling hills
+    Hills,
+    /// Jagged mountain peaks
+    Peaks,
+    /// Flat-topped buttes
+    Mesas,
+    /// A line of pointed treetops
+    Trees,
+}
+
+impl Silhouette {
+    /// Height of the outline at a point, from 0.0 to 1.0, repeating every period
+    pub fn height(&self, u: f32, period: f32) -> f32 {
+        let t = u / period;
+        // A slower wave keeps the repeats from looking identical
+        let swell = 0.75 + 0.25 * (t * TAU / 3.7).sin();
+        match self {
+            Silhouette::Flat => 0.0,
+            Silhouette::Hills => (0.5 + 0.5 * (t * TAU).sin()) * swell,
+            Silhouette::Peaks => (1.0 - (t.rem_euclid(1.0) * 2.0 - 1.0).abs()) * swell,
+            Silhouette::Mesas => {
+                if (t * TAU).sin() > 0.3 { swell } else { 0.15 }
+            }
+            Silhouette::Trees => 1.0 - (t.rem_euclid(1.0) * 2.0 - 1.0).abs() * 0.6,
+        }
+    }
+}
+
+/// One layer of a parallax backdrop
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Layer {
+    /// Outline of the layer's top edge
+    pub silhouette: Silhouette,
+    /// Tallest the outline rises above the horizon (in pixels)
+    pub height: f32,
+    /// Width of one repeat of the outline (in pixels)
+    pub period: f32,
+    /// How fast the layer scrolls compared to the ground, 1.0 for the ground itself
+    pub rate: f32,
+    /// Fill color
+    pub color: Color,
+}
+
+/// Draw layers back to front, scrolled by a distance in pixels
+///
+/// Each layer rises from the horizon and is filled down to `bottom`, so nearer
+/// layers cover the lower part of the ones behind them.
+pub fn draw(layers: &[Layer], scroll: f32, horizon: f32, bottom: f32) {
+    for layer in layers {
+        let shift = scroll * layer.rate;
+        let mut x = 0.0;
+        while x < screen_width() {
+            let top = horizon - layer.silhouette.height(x - shift, layer.period) * layer.height;
+            draw_rectangle(x, top, STRIP_WIDTH, bottom - top, layer.color);
+            x += STRIP_WIDTH;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outlines_stay_in_range() {
+        let shapes = [Silhouette::Flat, Silhouette::Hills, Silhouette::Peaks, Silhouette::Mesas, Silhouette::Trees];
+        for shape in shapes {
+            for u in -200..200 {
+                let h = shape.height(u as f32 * 3.1, 90.0);
+                assert!((0.0..=1.0).contains(&h), "{:?} at {} is {}", shape, u, h);
+            }
+        }
+    }
+}
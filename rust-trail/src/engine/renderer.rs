@@ -0,0 +1,161 @@
+// Draw batching and render statistics. macroquad merges draws one after
+// another from the same texture into a single draw call, so sprites whose
+// order among themselves doesn't matter, such as the pieces of one animation,
+// are queued in a SpriteBatch and drawn grouped by texture. Textured draws and
+// text also go through here to be counted, and the debug overlay shows the
+// totals for the last frame. Draw calls are estimated as changes of texture
+// between counted draws; shapes aren't counted.
+
+use std::cell::RefCell;
+use macroquad::prelude::*;
+use crate::engine::asset_loader::Sprite;
+
+/// Counts of what was drawn in a frame
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Times the texture drawn from changed, each starting a new draw call
+    pub draw_calls: usize,
+    /// Textured rectangles drawn
+    pub sprites: usize,
+    /// Characters of text drawn
+    pub glyphs: usize,
+}
+
+/// What a draw takes its pixels from
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Source {
+    Texture(Texture2D),
+    Font(Font),
+}
+
+/// Counts for the frame being drawn and the last one finished
+#[derive(Debug, Default)]
+struct Counter {
+    current: RenderStats,
+    last: RenderStats,
+    /// Source of the previous draw this frame
+    source: Option<Source>,
+}
+
+impl Counter {
+    /// Count a draw from a source
+    fn record(&mut self, source: Source, sprites: usize, glyphs: usize) {
+        if self.source != Some(source) {
+            self.current.draw_calls += 1;
+            self.source = Some(source);
+        }
+        self.current.sprites += sprites;
+        self.current.glyphs += glyphs;
+    }
+
+    /// Keep the finished frame's counts and start again
+    fn finish_frame(&mut self) {
+        self.last = std::mem::take(&mut self.current);
+        self.source = None;
+    }
+}
+
+thread_local! {
+    static COUNTER: RefCell<Counter> = RefCell::new(Counter::default());
+}
+
+fn record(source: Source, sprites: usize, glyphs: usize) {
+    COUNTER.with(|c| c.borrow_mut().record(source, sprites, glyphs));
+}
+
+/// Start counting a new frame
+pub fn begin_frame() {
+    COUNTER.with(|c| c.borrow_mut().finish_frame());
+}
+
+/// What the last whole frame drew
+pub fn stats() -> RenderStats {
+    COUNTER.with(|c| c.borrow().last)
+}
+
+/// Characters in a string that put ink on the screen
+fn glyphs(text: &str) -> usize {
+    text.chars().filter(|c| !c.is_whitespace()).count()
+}
+
+/// Draw a texture like macroquad's `draw_texture_ex`, counting it
+pub fn draw_texture_ex(texture: Texture2D, x: f32, y: f32, color: Color, params: DrawTextureParams) {
+    record(Source::Texture(texture), 1, 0);
+    macroquad::prelude::draw_texture_ex(texture, x, y, color, params);
+}
+
+/// Draw text like macroquad's `draw_text`, counting its glyphs
+pub fn draw_text(text: &str, x: f32, y: f32, font_size: f32, color: Color) {
+    record(Source::Font(Font::default()), 0, glyphs(text));
+    macroquad::prelude::draw_text(text, x, y, font_size, color);
+}
+
+/// One queued draw
+struct SpriteDraw {
+    texture: Texture2D,
+    position: Vec2,
+    color: Color,
+    params: DrawTextureParams,
+}
+
+/// Draws queued up and then made together, grouped by texture
+#[derive(Default)]
+pub struct SpriteBatch {
+    draws: Vec<SpriteDraw>,
+}
+
+impl SpriteBatch {
+    /// Create an empty batch
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a texture draw, as `draw_texture_ex` would make it
+    pub fn push(&mut self, texture: Texture2D, x: f32, y: f32, color: Color, params: DrawTextureParams) {
+        self.draws.push(SpriteDraw { texture, position: vec2(x, y), color, params });
+    }
+
+    /// Queue a sprite, stretched to a size
+    pub fn push_sprite(&mut self, sprite: Sprite, x: f32, y: f32, color: Color, size: Vec2) {
+        let params = DrawTextureParams { source: Some(sprite.source), dest_size: Some(size), ..Default::default() };
+        self.push(sprite.texture, x, y, color, params);
+    }
+
+    /// Make every queued draw, each texture's together in the order the
+    /// textures were first queued, and empty the batch
+    pub fn flush(&mut self) {
+        let mut textures: Vec<Texture2D> = Vec::new();
+        for draw in &self.draws {
+            if !textures.contains(&draw.texture) {
+                textures.push(draw.texture);
+            }
+        }
+        for texture in textures {
+            for draw in self.draws.iter().filter(|d| d.texture == texture) {
+                draw_texture_ex(draw.texture, draw.position.x, draw.position.y, draw.color, draw.params.clone());
+            }
+        }
+        self.draws.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_calls_are_counted_when_the_source_changes() {
+        let mut counter = Counter::default();
+        let font = Source::Font(Font::default());
+        counter.record(font, 0, 5);
+        counter.record(font, 0, 3);
+        assert_eq!(counter.current, RenderStats { draw_calls: 1, sprites: 0, glyphs: 8 });
+
+        counter.finish_frame();
+        assert_eq!(counter.last.glyphs, 8);
+        assert_eq!(counter.current, RenderStats::default());
+        counter.record(font, 0, 1);
+        assert_eq!(counter.current.draw_calls, 1);
+        assert_eq!(glyphs("Fort Kearney \n"), 11);
+    }
+}
@@ -0,0 +1,64 @@
+// Screen tints laid over a scene's backdrop for the time of day and the weather
+
+use macroquad::prelude::*;
+
+/// Hour the sun rises
+pub const SUNRISE: f32 = 6.0;
+/// Hour the sun sets
+pub const SUNSET: f32 = 20.0;
+
+/// Hours either side of sunrise and sunset that the sky stays colored
+const TWILIGHT_HOURS: f32 = 2.5;
+
+/// Warm glow of the low sun
+const TWILIGHT: Color = Color::new(0.85, 0.4, 0.2, 0.3);
+/// Deep blue of night
+const NIGHT: Color = Color::new(0.02, 0.03, 0.15, 0.65);
+
+/// No tint at all
+pub const CLEAR: Color = Color::new(0.0, 0.0, 0.0, 0.0);
+
+/// Tint for an hour of the day (0-24): clear at midday, glowing at dawn and dusk, dark at night
+pub fn daylight(hour: f32) -> Color {
+    let hour = hour.rem_euclid(24.0);
+    if !(SUNRISE..=SUNSET).contains(&hour) {
+        return NIGHT;
+    }
+    // How close the sun is to the horizon, 1.0 at sunrise or sunset
+    let low = 1.0 - ((hour - SUNRISE).min(SUNSET - hour) / TWILIGHT_HOURS).min(1.0);
+    Color::new(TWILIGHT.r, TWILIGHT.g, TWILIGHT.b, TWILIGHT.a * low)
+}
+
+/// Lay one tint over another, as if both were drawn in turn
+pub fn combine(under: Color, over: Color) -> Color {
+    let a = over.a + under.a * (1.0 - over.a);
+    if a <= 0.0 {
+        return CLEAR;
+    }
+    let mix = |u: f32, o: f32| (o * over.a + u * under.a * (1.0 - over.a)) / a;
+    Color::new(mix(under.r, over.r), mix(under.g, over.g), mix(under.b, over.b), a)
+}
+
+/// Draw a tint over part of the screen
+pub fn draw(tint: Color, area: Rect) {
+    if tint.a > 0.0 {
+        draw_rectangle(area.x, area.y, area.w, area.h, tint);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_sky_colors_at_the_ends_of_the_day() {
+        assert_eq!(daylight(13.0).a, 0.0);
+        assert!(daylight(SUNRISE).a > daylight(SUNRISE + 1.0).a);
+        assert!(daylight(SUNSET - 0.5).a > 0.0);
+        assert_eq!(daylight(23.0), NIGHT);
+
+        assert_eq!(combine(CLEAR, NIGHT), NIGHT);
+        assert_eq!(combine(TWILIGHT, CLEAR), TWILIGHT);
+        assert!(combine(TWILIGHT, NIGHT).a > NIGHT.a);
+    }
+}
@@ -0,0 +1,143 @@
+// Screen effects played between scenes. The effect covers the screen, the
+// scenes are swapped while it is fully covered, then the effect uncovers the
+// new scene.
+
+use std::f32::consts::TAU;
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::engine::display::{screen_height, screen_width};
+
+/// Seconds a scene transition takes when none is given
+pub const DEFAULT_DURATION: f32 = 0.5;
+
+/// Number of segments in the iris circle
+const IRIS_SEGMENTS: usize = 64;
+
+/// How the screen changes between scenes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransitionEffect {
+    /// Swap scenes at once
+    Cut,
+    /// Fade to black and back
+    Fade,
+    /// Close a circle on the middle of the screen, then open it again
+    IrisWipe,
+}
+
+impl TransitionEffect {
+    /// All effects in menu order
+    pub const ALL: [TransitionEffect; 3] = [TransitionEffect::Cut, TransitionEffect::Fade, TransitionEffect::IrisWipe];
+
+    /// Display name of the effect
+    pub fn name(&self) -> &'static str {
+        match self {
+            TransitionEffect::Cut => "Off",
+            TransitionEffect::Fade => "Fade",
+            TransitionEffect::IrisWipe => "Iris wipe",
+        }
+    }
+}
+
+/// A transition effect in progress
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScreenTransition {
+    /// Effect being played
+    effect: TransitionEffect,
+    /// Seconds the whole effect takes, covering and uncovering
+    duration: f32,
+    /// Seconds played so far
+    elapsed: f32,
+}
+
+impl ScreenTransition {
+    /// Start an effect lasting the given number of seconds
+    pub fn new(effect: TransitionEffect, duration: f32) -> Self {
+        let duration = if effect == TransitionEffect::Cut { 0.0 } else { duration.max(0.0) };
+        Self { effect, duration, elapsed: 0.0 }
+    }
+
+    /// Advance the effect
+    pub fn update(&mut self, dt: f32) {
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+    }
+
+    /// Jump to the end of the effect
+    pub fn skip(&mut self) {
+        self.elapsed = self.duration;
+    }
+
+    /// Whether the screen has been fully covered, so the scenes can be swapped
+    pub fn is_covered(&self) -> bool {
+        self.elapsed >= self.duration / 2.0
+    }
+
+    /// Whether the effect has finished
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// How much of the screen is hidden, from 0.0 to 1.0
+    pub fn coverage(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 0.0;
+        }
+        let half = self.duration / 2.0;
+        if self.elapsed < half {
+            self.elapsed / half
+        } else {
+            1.0 - (self.elapsed - half) / half
+        }
+    }
+
+    /// Draw the effect over the scene
+    pub fn draw(&self) {
+        let coverage = self.coverage();
+        match self.effect {
+            TransitionEffect::Cut => {}
+            TransitionEffect::Fade => {
+                draw_rectangle(0.0, 0.0, screen_width(), screen_height(), Color::new(0.0, 0.0, 0.0, coverage));
+            }
+            TransitionEffect::IrisWipe => draw_iris(coverage),
+        }
+    }
+}
+
+/// Black out everything outside a circle that shrinks as coverage grows
+fn draw_iris(coverage: f32) {
+    let center = vec2(screen_width() / 2.0, screen_height() / 2.0);
+    let outer = center.length() + 2.0;
+    let inner = outer * (1.0 - coverage);
+
+    for i in 0..IRIS_SEGMENTS {
+        let a = Vec2::from_angle(i as f32 / IRIS_SEGMENTS as f32 * TAU);
+        let b = Vec2::from_angle((i + 1) as f32 / IRIS_SEGMENTS as f32 * TAU);
+        let (a_in, a_out) = (center + a * inner, center + a * outer);
+        let (b_in, b_out) = (center + b * inner, center + b * outer);
+        draw_triangle(a_in, a_out, b_out, BLACK);
+        draw_triangle(a_in, b_out, b_in, BLACK);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn effects_cover_then_uncover_the_screen() {
+        let mut fade = ScreenTransition::new(TransitionEffect::Fade, 1.0);
+        fade.update(0.25);
+        assert_eq!(fade.coverage(), 0.5);
+        assert!(!fade.is_covered());
+        fade.update(0.25);
+        assert!(fade.is_covered());
+        assert_eq!(fade.coverage(), 1.0);
+        fade.update(0.25);
+        assert_eq!(fade.coverage(), 0.5);
+        fade.skip();
+        assert!(fade.is_finished());
+        assert_eq!(fade.coverage(), 0.0);
+
+        let cut = ScreenTransition::new(TransitionEffect::Cut, 1.0);
+        assert!(cut.is_covered() && cut.is_finished());
+    }
+}
@@ -0,0 +1,315 @@
+use macroquad::audio::Sound;
+use macroquad::prelude::*;
+use crate::engine::asset_loader::Sprite;
+use crate::engine::audio;
+use crate::engine::narration;
+use crate::engine::renderer::SpriteBatch;
+use crate::engine::ui::focus::{FocusAxis, FocusRing};
+use crate::engine::ui::{draw_focus_outline, draw_text_button, style, ClickRegion};
+
+/// Sound the original game plays when a button is pressed
+pub const PRESS_SOUND: &str = "BEEP.wav";
+
+/// Width of a title button on the original picture
+pub const SPRITE_WIDTH: f32 = 113.0;
+/// Height of a title button on the original picture
+pub const SPRITE_HEIGHT: f32 = 20.0;
+
+/// Button state (normal, hover, clicked, disabled)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonState {
+    Normal,
+    Hover,
+    Clicked,
+    /// Greyed out and unclickable
+    Disabled,
+}
+
+/// Button action that can be triggered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ButtonAction {
+    Introduction,
+    Options,
+    Quit,
+    TravelTrail,
+}
+
+/// A clickable button with different states, triggering an action
+pub struct Button<A = ButtonAction> {
+    /// Clickable area of the button
+    region: ClickRegion,
+    /// Current state of the button
+    state: ButtonState,
+    /// Associated action
+    action: A,
+    /// Button label
+    label: String,
+    /// Sprites drawn normally and while pressed
+    sprites: Option<(Sprite, Sprite)>,
+    /// Whether the button has keyboard focus
+    focused: bool,
+    /// Whether the button can be pressed
+    enabled: bool,
+    /// Whether the button was under the pointer or focused last update,
+    /// None before the first update
+    highlighted: Option<bool>,
+    /// Played when the pointer or focus comes onto the button
+    hover_sound: Option<Sound>,
+    /// Played when the button is pressed
+    press_sound: Option<Sound>,
+}
+
+impl Button<ButtonAction> {
+    /// Create a new button, drawn with its normal and pressed sprites if they loaded
+    pub fn new(
+        button_type: ButtonAction,
+        position: Vec2,
+        sprites: Option<(Sprite, Sprite)>,
+        scale_x: f32,
+        scale_y: f32,
+    ) -> Self {
+        // Scale the original button's dimensions to the screen
+        let button_width = SPRITE_WIDTH * scale_x;
+        let button_height = SPRITE_HEIGHT * scale_y;
+
+        // Create label based on button type
+        let label = match button_type {
+            ButtonAction::Introduction => "Introduction".to_string(),
+            ButtonAction::Options => "Options".to_string(),
+            ButtonAction::Quit => "Quit".to_string(),
+            ButtonAction::TravelTrail => "Travel the Trail".to_string(),
+        };
+
+        Self {
+            sprites,
+            ..Button::text(button_type, Rect::new(position.x, position.y, button_width, button_height), &label)
+        }
+    }
+}
+
+impl<A: Copy> Button<A> {
+    /// Create a button drawn as a labelled box rather than from a sprite sheet
+    pub fn text(action: A, rect: Rect, label: &str) -> Self {
+        Self {
+            region: ClickRegion::new(rect),
+            state: ButtonState::Normal,
+            action,
+            label: label.to_string(),
+            sprites: None,
+            focused: false,
+            enabled: true,
+            highlighted: None,
+            hover_sound: None,
+            press_sound: None,
+        }
+    }
+
+    /// Get the button's label
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Check if the mouse is over the button
+    pub fn is_hovered(&self) -> bool {
+        self.region.is_hovered()
+    }
+
+    /// Give or take away keyboard focus
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Check whether the button can be pressed
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Grey the button out, or make it pressable again
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.region.reset();
+            self.state = ButtonState::Disabled;
+        } else if self.state == ButtonState::Disabled {
+            self.state = ButtonState::Normal;
+        }
+    }
+
+    /// Set the sounds played when the button is hovered or focused, and when it's pressed
+    pub fn set_sounds(&mut self, hover: Option<Sound>, press: Option<Sound>) {
+        self.hover_sound = hover;
+        self.press_sound = press;
+    }
+
+    /// Press the button from the keyboard, returning its action unless it's disabled
+    pub fn activate(&mut self) -> Option<A> {
+        if !self.enabled {
+            return None;
+        }
+        if let Some(sound) = self.press_sound {
+            audio::play_sfx(sound);
+        }
+        Some(self.action)
+    }
+
+    /// Update button state based on mouse position and clicks
+    pub fn update(&mut self, _dt: f32) -> Option<A> {
+        if !self.enabled {
+            self.highlighted = Some(false);
+            return None;
+        }
+
+        // A click counts when the mouse was pressed and released over the button
+        let clicked = self.region.update();
+
+        self.state = if self.region.is_held() {
+            ButtonState::Clicked
+        } else if self.region.is_hovered() {
+            ButtonState::Hover
+        } else {
+            ButtonState::Normal
+        };
+
+        // The hover sound plays as the pointer or focus arrives, not when the screen opens
+        let highlighted = self.region.is_hovered() || self.focused;
+        if highlighted && self.highlighted == Some(false) {
+            if let Some(sound) = self.hover_sound {
+                audio::play_sfx(sound);
+            }
+        }
+        self.highlighted = Some(highlighted);
+
+        if clicked {
+            return self.activate();
+        }
+
+        None
+    }
+
+    /// Queue the button's sprite, if it's drawn from one
+    pub fn push_sprite(&self, batch: &mut SpriteBatch) {
+        if let Some((normal, pressed)) = self.sprites {
+            let sprite = match self.state {
+                ButtonState::Normal | ButtonState::Hover | ButtonState::Disabled => normal,
+                ButtonState::Clicked => pressed,
+            };
+
+            // Greyed out if it can't be pressed
+            let rect = self.region.rect;
+            let tint = if self.enabled { WHITE } else { style::theme().text_disabled };
+            batch.push_sprite(sprite, rect.x, rect.y, tint, rect.size());
+        }
+    }
+
+    /// Draw everything but the sprite: the box and label of a button without
+    /// one, and the focus outline
+    pub fn draw_over_sprite(&self) {
+        if self.sprites.is_none() {
+            draw_text_button(&self.region, &self.label, self.enabled);
+        }
+        if self.focused && self.enabled {
+            draw_focus_outline(self.region.rect);
+        }
+    }
+}
+
+/// A row or column of buttons sharing keyboard focus
+///
+/// Arrow keys along the group's axis and Tab move focus between the enabled
+/// buttons, and Enter or Space presses the focused one. Pointing at a button
+/// also focuses it, so the mouse and keyboard never disagree.
+pub struct ButtonGroup<A = ButtonAction> {
+    /// Buttons in focus order
+    buttons: Vec<Button<A>>,
+    /// Keyboard focus over the buttons
+    focus: FocusRing,
+    /// Whether the group responds to the keyboard
+    keyboard: bool,
+    /// Button last narrated as focused
+    narrated: Option<usize>,
+}
+
+impl<A: Copy> ButtonGroup<A> {
+    /// Group buttons, focusing the first
+    pub fn new(buttons: Vec<Button<A>>, axis: FocusAxis) -> Self {
+        let mut group = Self { focus: FocusRing::new(buttons.len(), axis), buttons, keyboard: true, narrated: None };
+        group.sync();
+        group
+    }
+
+    /// Let the keyboard move between and press the buttons, or leave it to another widget
+    pub fn set_keyboard(&mut self, keyboard: bool) {
+        self.keyboard = keyboard;
+        self.sync();
+    }
+
+    /// Focus a button, if it's enabled
+    pub fn set_focus(&mut self, index: usize) {
+        self.focus.set_focus(index);
+        self.sync();
+    }
+
+    /// Grey a button out, or make it pressable again; focus moves off a greyed button
+    pub fn set_enabled(&mut self, index: usize, enabled: bool) {
+        if let Some(button) = self.buttons.get_mut(index) {
+            button.set_enabled(enabled);
+        }
+        self.focus.set_enabled(index, enabled);
+        self.sync();
+    }
+
+    /// Set the same hover and press sounds on every button
+    pub fn set_sounds(&mut self, hover: Option<Sound>, press: Option<Sound>) {
+        for button in &mut self.buttons {
+            button.set_sounds(hover, press);
+        }
+    }
+
+    /// Show focus on the focused button, if the keyboard is the group's
+    fn sync(&mut self) {
+        for (i, button) in self.buttons.iter_mut().enumerate() {
+            button.set_focused(self.keyboard && self.focus.is_focused(i));
+        }
+    }
+
+    /// Handle the keyboard and mouse, returning the action of a button pressed
+    pub fn update(&mut self, dt: f32) -> Option<A> {
+        if self.keyboard {
+            if let Some(index) = self.focus.update() {
+                self.sync();
+                return self.buttons[index].activate();
+            }
+        }
+
+        let mut pressed = None;
+        for (i, button) in self.buttons.iter_mut().enumerate() {
+            if let Some(action) = button.update(dt) {
+                pressed = Some(action);
+            }
+            if button.is_hovered() && button.is_enabled() {
+                self.focus.set_focus(i);
+            }
+        }
+        self.sync();
+
+        if self.keyboard && self.focus.focused() != self.narrated {
+            self.narrated = self.focus.focused();
+            if let Some(button) = self.narrated.and_then(|i| self.buttons.get(i)) {
+                narration::say(&format!("{} button", button.label()));
+            }
+        }
+        pressed
+    }
+
+    /// Draw the buttons, their sprites together as one batch
+    pub fn draw(&self) {
+        let mut batch = SpriteBatch::new();
+        for button in &self.buttons {
+            button.push_sprite(&mut batch);
+        }
+        batch.flush();
+        for button in &self.buttons {
+            button.draw_over_sprite();
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::narration;
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::focus::{FocusAxis, FocusRing};
+use crate::engine::ui::rich_text;
+use crate::engine::ui::text_box::{self, TextBox};
+use crate::engine::ui::{draw_focus_outline, draw_panel, draw_text_button, scaled, style, text_scale, ClickRegion};
+
+/// Width of a dialog button
+const BUTTON_WIDTH: f32 = 110.0;
+/// Height of a dialog button
+const BUTTON_HEIGHT: f32 = 30.0;
+/// Space between dialog buttons
+const BUTTON_GAP: f32 = 20.0;
+/// Height of one line of message text
+const LINE_HEIGHT: f32 = 24.0;
+/// Padding inside the dialog panel
+const PADDING: f32 = 20.0;
+/// Least space kept between the dialog and the edges of the screen
+const MARGIN: f32 = 10.0;
+/// Narrowest a dialog's contents get
+const MIN_WIDTH: f32 = 300.0;
+
+/// A modal message box with a row of buttons
+pub struct Dialog {
+    /// Heading text
+    title: String,
+    /// Message, which may color words with markup
+    message: String,
+    /// Message lines without their markup, wrapped to the screen, for sizing the panel
+    lines: Vec<String>,
+    /// Text scale the message was laid out at
+    scale: f32,
+    /// Characters of the message revealed per second, or None to show it at once
+    chars_per_second: Option<f32>,
+    /// The message as drawn, revealed as the text speed allows
+    text: TextBox,
+    /// Button labels
+    buttons: Vec<String>,
+    /// Click regions for each button
+    regions: Vec<ClickRegion>,
+    /// Keyboard focus across the buttons
+    focus: FocusRing,
+    /// Button chosen when Escape is pressed
+    cancel: Option<usize>,
+    /// Whether the title has been narrated
+    announced: bool,
+    /// Button last narrated as focused
+    narrated_focus: Option<usize>,
+}
+
+impl Dialog {
+    /// Create a dialog with a title, message, and button labels
+    pub fn new(title: &str, message: &str, buttons: &[&str]) -> Self {
+        let mut dialog = Self {
+            title: title.to_string(),
+            message: message.to_string(),
+            lines: Vec::new(),
+            scale: text_scale(),
+            chars_per_second: None,
+            text: TextBox::new(Rect::default()),
+            buttons: buttons.iter().map(|b| b.to_string()).collect(),
+            regions: Vec::new(),
+            focus: FocusRing::new(buttons.len(), FocusAxis::Horizontal),
+            cancel: None,
+            announced: false,
+            narrated_focus: None,
+        };
+        dialog.set_text_speed(None);
+        dialog.layout();
+        dialog
+    }
+
+    /// Create a dialog with a single OK button
+    pub fn message(title: &str, message: &str) -> Self {
+        Self::new(title, message, &["OK"]).with_cancel(0)
+    }
+
+    /// Create a Yes/No confirmation dialog (Yes is 0, No is 1)
+    pub fn confirm(title: &str, message: &str) -> Self {
+        Self::new(title, message, &["Yes", "No"]).with_cancel(1)
+    }
+
+    /// Set which button Escape activates
+    pub fn with_cancel(mut self, index: usize) -> Self {
+        self.cancel = Some(index);
+        self
+    }
+
+    /// Set how many characters of the message are revealed per second, or
+    /// None to show it at once, starting the message over
+    pub fn set_text_speed(&mut self, chars_per_second: Option<f32>) {
+        self.chars_per_second = chars_per_second;
+        self.scale = text_scale();
+        // Wrap the message to the widest panel the screen allows, then size
+        // the panel to what's left; a message too long for the screen pages
+        let font_size = scaled(style::FONT_SIZE);
+        let max_width = screen_width() - (MARGIN + PADDING) * 2.0;
+        self.lines = text_box::wrap_lines(&rich_text::strip(&self.message), max_width, |line| {
+            measure_text(line, None, font_size as u16, 1.0).width
+        })
+        .iter()
+        .map(|line| line.iter().map(|span| span.text.as_str()).collect())
+        .collect();
+
+        let panel = self.panel_rect();
+        let area = Rect::new(
+            panel.x + PADDING,
+            panel.y + PADDING + scaled(LINE_HEIGHT),
+            panel.w - PADDING * 2.0,
+            scaled(LINE_HEIGHT) * self.shown_lines() as f32 + 1.0,
+        );
+        self.text = TextBox::new(area).with_font(font_size, scaled(LINE_HEIGHT)).with_speed(chars_per_second);
+        self.text.set_text(&self.message);
+    }
+
+    /// Lines of the message shown at once, as many as fit on the screen
+    fn shown_lines(&self) -> usize {
+        let room = screen_height() - MARGIN * 2.0 - PADDING * 3.0 - scaled(BUTTON_HEIGHT) - scaled(LINE_HEIGHT);
+        let fit = ((room / scaled(LINE_HEIGHT)).floor() as usize).max(1);
+        self.lines.len().clamp(1, fit)
+    }
+
+    /// Width of the row of buttons
+    fn buttons_width(&self) -> f32 {
+        self.buttons.len() as f32 * (scaled(BUTTON_WIDTH) + BUTTON_GAP) - BUTTON_GAP
+    }
+
+    /// Get the panel rectangle for the current screen size
+    fn panel_rect(&self) -> Rect {
+        let text_width = self
+            .lines
+            .iter()
+            .chain(std::iter::once(&self.title))
+            .map(|l| measure_text(l, None, scaled(style::FONT_SIZE) as u16, 1.0).width)
+            .fold(0.0, f32::max);
+
+        let width = (self.buttons_width().max(text_width).max(MIN_WIDTH) + PADDING * 2.0).min(screen_width() - MARGIN * 2.0);
+        let height = PADDING * 3.0 + scaled(LINE_HEIGHT) * (self.shown_lines() as f32 + 1.0) + scaled(BUTTON_HEIGHT);
+
+        Rect::new(
+            (screen_width() - width) / 2.0,
+            (screen_height() - height) / 2.0,
+            width,
+            height,
+        )
+    }
+
+    /// Recompute button positions for the current screen size
+    fn layout(&mut self) {
+        let panel = self.panel_rect();
+        let (button_width, button_height) = (scaled(BUTTON_WIDTH), scaled(BUTTON_HEIGHT));
+        let start_x = panel.x + (panel.w - self.buttons_width()) / 2.0;
+        let y = panel.y + panel.h - PADDING - button_height;
+
+        let old = std::mem::take(&mut self.regions);
+        self.regions = (0..self.buttons.len())
+            .map(|i| {
+                let rect = Rect::new(start_x + i as f32 * (button_width + BUTTON_GAP), y, button_width, button_height);
+                match old.get(i) {
+                    Some(region) => ClickRegion { rect, ..*region },
+                    None => ClickRegion::new(rect),
+                }
+            })
+            .collect();
+    }
+
+    /// Update the dialog, returning the index of the chosen button
+    ///
+    /// While the message is still being revealed, or has pages to come, Enter
+    /// or a click moves it along instead of choosing.
+    pub fn update(&mut self, dt: f32) -> Option<usize> {
+        if self.scale != text_scale() {
+            // The text size changed while the dialog was up
+            self.set_text_speed(self.chars_per_second);
+        }
+        self.layout();
+
+        // Narrated once it's up: the title, the message as the text box shows
+        // it, then each button as it's focused
+        if !std::mem::replace(&mut self.announced, true) {
+            narration::say(&self.title);
+        }
+        self.text.update(dt);
+        let focused = self.focus.focused();
+        if focused != self.narrated_focus {
+            if let Some(label) = focused.and_then(|i| self.buttons.get(i)) {
+                narration::say(&format!("{} button", label));
+            }
+            self.narrated_focus = focused;
+        }
+        if (self.text.is_revealing() || self.text.has_more())
+            && (input::is_action_pressed(Action::Confirm) || is_mouse_button_pressed(MouseButton::Left))
+        {
+            self.text.advance();
+            return None;
+        }
+
+        if let Some(index) = self.focus.update() {
+            return Some(index);
+        }
+        if input::is_action_pressed(Action::Cancel) {
+            if let Some(cancel) = self.cancel {
+                return Some(cancel);
+            }
+        }
+
+        let mut chosen = None;
+        for (i, region) in self.regions.iter_mut().enumerate() {
+            if region.update() {
+                chosen = Some(i);
+            }
+            if region.is_hovered() {
+                self.focus.set_focus(i);
+            }
+        }
+        chosen
+    }
+
+    /// Draw the dialog over whatever is already on screen
+    pub fn draw(&self) {
+        // Dim the scene behind the dialog
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), style::theme().dim);
+
+        let panel = self.panel_rect();
+        draw_panel(panel);
+
+        let y = panel.y + PADDING + scaled(LINE_HEIGHT) * 0.75;
+        draw_text(&self.title, panel.x + PADDING, y, scaled(style::FONT_SIZE + 4.0), style::theme().text);
+        self.text.draw();
+
+        for (i, (region, label)) in self.regions.iter().zip(&self.buttons).enumerate() {
+            draw_text_button(region, label, true);
+            if self.focus.is_focused(i) {
+                draw_focus_outline(region.rect);
+            }
+        }
+    }
+}
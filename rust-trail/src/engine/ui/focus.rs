@@ -0,0 +1,106 @@
+use macroquad::prelude::*;
+use crate::engine::input::{self, Action};
+
+/// Which arrow keys move focus between entries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusAxis {
+    /// Up/Down move focus (menus and lists)
+    Vertical,
+    /// Left/Right move focus (button rows)
+    Horizontal,
+}
+
+/// Keyboard focus over an ordered set of widgets
+///
+/// Tab/Shift+Tab and the arrow keys (or d-pad/stick) along the ring's axis
+/// move focus, skipping disabled entries. Enter, Space, or the gamepad's
+/// confirm button activates the focused entry.
+#[derive(Debug, Clone)]
+pub struct FocusRing {
+    /// Whether each entry can take focus
+    enabled: Vec<bool>,
+    /// Focused entry
+    focused: Option<usize>,
+    /// Arrow keys used for navigation
+    axis: FocusAxis,
+}
+
+impl FocusRing {
+    /// Create a focus ring over a number of entries, focusing the first
+    pub fn new(count: usize, axis: FocusAxis) -> Self {
+        Self {
+            enabled: vec![true; count],
+            focused: if count > 0 { Some(0) } else { None },
+            axis,
+        }
+    }
+
+    /// Get the focused entry
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Check whether an entry has focus
+    pub fn is_focused(&self, index: usize) -> bool {
+        self.focused == Some(index)
+    }
+
+    /// Focus an entry if it exists and is enabled
+    pub fn set_focus(&mut self, index: usize) {
+        if self.enabled.get(index).copied().unwrap_or(false) {
+            self.focused = Some(index);
+        }
+    }
+
+    /// Allow or prevent an entry from taking focus
+    pub fn set_enabled(&mut self, index: usize, enabled: bool) {
+        if let Some(flag) = self.enabled.get_mut(index) {
+            *flag = enabled;
+        }
+        if !enabled && self.focused == Some(index) {
+            self.move_focus(1);
+        }
+    }
+
+    /// Move focus forward (positive) or backward (negative), wrapping around
+    pub fn move_focus(&mut self, direction: i32) {
+        let count = self.enabled.len();
+        if count == 0 {
+            self.focused = None;
+            return;
+        }
+
+        let start = self.focused.unwrap_or(if direction >= 0 { count - 1 } else { 0 });
+        let step = if direction >= 0 { 1 } else { count - 1 };
+        let mut index = start;
+        for _ in 0..count {
+            index = (index + step) % count;
+            if self.enabled[index] {
+                self.focused = Some(index);
+                return;
+            }
+        }
+        self.focused = None;
+    }
+
+    /// Handle navigation keys, returning the entry activated with Enter/Space
+    pub fn update(&mut self) -> Option<usize> {
+        let (prev, next) = match self.axis {
+            FocusAxis::Vertical => (Action::MenuUp, Action::MenuDown),
+            FocusAxis::Horizontal => (Action::MenuLeft, Action::MenuRight),
+        };
+        let shift = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+
+        if input::is_action_pressed(next) || (is_key_pressed(KeyCode::Tab) && !shift) {
+            self.move_focus(1);
+        }
+        if input::is_action_pressed(prev) || (is_key_pressed(KeyCode::Tab) && shift) {
+            self.move_focus(-1);
+        }
+
+        if input::is_action_pressed(Action::Confirm) || is_key_pressed(KeyCode::Space) {
+            return self.focused.filter(|&i| self.enabled[i]);
+        }
+        None
+    }
+}
@@ -0,0 +1,295 @@
+use macroquad::prelude::*;
+use crate::engine::input::{self, Action};
+use crate::engine::narration;
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::{draw_focus_outline, fit_text, mouse_in_rect, scaled, style, ClickRegion, MIN_TOUCH_SIZE};
+
+/// Height of one list row at normal text size
+const ROW_HEIGHT: f32 = 24.0;
+/// Width of the scroll bar
+const SCROLLBAR_WIDTH: f32 = 8.0;
+
+/// A scrollable list of selectable text entries
+pub struct ListBox {
+    /// Area the list occupies
+    rect: Rect,
+    /// Entry labels
+    items: Vec<String>,
+    /// Whether each entry can be selected
+    enabled: Vec<bool>,
+    /// Currently selected entry
+    selected: Option<usize>,
+    /// Index of the first visible entry
+    scroll: usize,
+    /// Click region for each visible row
+    rows: Vec<ClickRegion>,
+    /// Entry last narrated as selected, with its label then
+    narrated: Option<(usize, String)>,
+    /// Height a finger dragging the list has reached, and whether it has scrolled
+    drag: Option<(f32, bool)>,
+}
+
+impl ListBox {
+    /// Create a list box in the given area
+    pub fn new(rect: Rect, items: Vec<String>) -> Self {
+        let mut list = Self {
+            rect,
+            enabled: vec![true; items.len()],
+            items,
+            selected: None,
+            scroll: 0,
+            rows: Vec::new(),
+            narrated: None,
+            drag: None,
+        };
+        list.layout();
+        list
+    }
+
+    /// Height of one row at the text scale, and never too short to tap
+    fn row_height() -> f32 {
+        let height = scaled(ROW_HEIGHT);
+        if input::is_touch() {
+            height.max(MIN_TOUCH_SIZE)
+        } else {
+            height
+        }
+    }
+
+    /// Number of rows that fit in the list area
+    pub fn visible_rows(&self) -> usize {
+        ((self.rect.h / Self::row_height()).floor() as usize).max(1)
+    }
+
+    /// Move the list to a new area
+    pub fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+        self.layout();
+    }
+
+    /// Replace the entry labels, keeping the selection and enabled flags
+    pub fn set_labels(&mut self, items: Vec<String>) {
+        if items.len() != self.items.len() {
+            self.set_items(items);
+        } else {
+            self.items = items;
+        }
+    }
+
+    /// Replace all entries, clearing the selection
+    pub fn set_items(&mut self, items: Vec<String>) {
+        self.enabled = vec![true; items.len()];
+        self.items = items;
+        self.selected = None;
+        self.scroll = 0;
+    }
+
+    /// Enable or disable an entry
+    pub fn set_enabled(&mut self, index: usize, enabled: bool) {
+        if let Some(flag) = self.enabled.get_mut(index) {
+            *flag = enabled;
+        }
+    }
+
+    /// Get the selected entry index
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Select an entry and scroll it into view
+    pub fn select(&mut self, index: usize) {
+        if index >= self.items.len() {
+            return;
+        }
+        self.selected = Some(index);
+        if index < self.scroll {
+            self.scroll = index;
+        } else if index >= self.scroll + self.visible_rows() {
+            self.scroll = index + 1 - self.visible_rows();
+        }
+    }
+
+    /// Move the selection to the next enabled entry in a direction
+    fn move_selection(&mut self, direction: i32) {
+        if self.items.is_empty() {
+            return;
+        }
+        let mut index = match self.selected {
+            Some(i) => i as i32,
+            None if direction > 0 => -1,
+            None => self.items.len() as i32,
+        };
+        loop {
+            index += direction.signum();
+            if index < 0 || index >= self.items.len() as i32 {
+                return;
+            }
+            if self.enabled[index as usize] {
+                self.select(index as usize);
+                return;
+            }
+        }
+    }
+
+    /// Scroll by a number of rows (negative scrolls up)
+    pub fn scroll_by(&mut self, rows: i32) {
+        let max_scroll = self.items.len().saturating_sub(self.visible_rows());
+        let target = self.scroll as i32 + rows;
+        self.scroll = target.clamp(0, max_scroll as i32) as usize;
+    }
+
+    /// Recompute row click regions, keeping press state for rows that remain
+    /// and the selection in view
+    fn layout(&mut self) {
+        let width = self.rect.w - SCROLLBAR_WIDTH;
+        let row_height = Self::row_height();
+        let old = std::mem::take(&mut self.rows);
+        self.rows = (0..self.visible_rows())
+            .map(|i| {
+                let rect = Rect::new(self.rect.x, self.rect.y + i as f32 * row_height, width, row_height);
+                match old.get(i) {
+                    Some(region) => ClickRegion { rect, ..*region },
+                    None => ClickRegion::new(rect),
+                }
+            })
+            .collect();
+        self.scroll_by(0);
+        if let Some(index) = self.selected {
+            self.select(index);
+        }
+    }
+
+    /// Narrate the selected entry if it, or its label, changed since last narrated
+    fn narrate(&mut self) {
+        let current = self.selected.and_then(|i| self.items.get(i).map(|label| (i, label)));
+        if current == self.narrated.as_ref().map(|(i, label)| (*i, label)) {
+            return;
+        }
+        if let Some((index, label)) = current {
+            let unavailable = if self.enabled[index] { "" } else { ", unavailable" };
+            narration::say(&format!("{}{}, {} of {}", label, unavailable, index + 1, self.items.len()));
+        }
+        self.narrated = current.map(|(i, label)| (i, label.clone()));
+    }
+
+    /// Update the list, returning the index of an entry that was clicked
+    /// or activated with Enter
+    pub fn update(&mut self) -> Option<usize> {
+        self.narrate();
+        if self.rows.first().is_some_and(|row| row.rect.h != Self::row_height()) {
+            // The text size changed, so the rows did too
+            self.layout();
+        }
+        let page = self.visible_rows() as i32;
+        if input::is_action_pressed(Action::MenuDown) {
+            self.move_selection(1);
+        }
+        if input::is_action_pressed(Action::MenuUp) {
+            self.move_selection(-1);
+        }
+        if is_key_pressed(KeyCode::PageDown) {
+            for _ in 0..page {
+                self.move_selection(1);
+            }
+        }
+        if is_key_pressed(KeyCode::PageUp) {
+            for _ in 0..page {
+                self.move_selection(-1);
+            }
+        }
+        if is_key_pressed(KeyCode::Home) {
+            self.selected = None;
+            self.move_selection(1);
+        }
+        if is_key_pressed(KeyCode::End) {
+            self.selected = None;
+            self.move_selection(-1);
+        }
+        if input::is_action_pressed(Action::Confirm) {
+            if let Some(index) = self.selected.filter(|&i| self.enabled[i]) {
+                return Some(index);
+            }
+        }
+
+        if mouse_in_rect(self.rect) {
+            let (_, wheel_y) = mouse_wheel();
+            if wheel_y > 0.0 {
+                self.scroll_by(-1);
+            } else if wheel_y < 0.0 {
+                self.scroll_by(1);
+            }
+        }
+
+        // A finger dragged a row's height scrolls by a row; lifting it after
+        // scrolling doesn't choose the entry under it
+        if let Some(touch) = input::touch_held() {
+            let row_height = Self::row_height();
+            match self.drag {
+                Some((last, scrolled)) => {
+                    let rows = ((touch.y - last) / row_height).trunc();
+                    self.drag = Some((last + rows * row_height, scrolled || rows != 0.0));
+                    self.scroll_by(-(rows as i32));
+                }
+                None if self.rect.contains(touch) => self.drag = Some((touch.y, false)),
+                None => {}
+            }
+        }
+        let dragged = self.drag.is_some_and(|(_, scrolled)| scrolled);
+        if input::touch_held().is_none() {
+            self.drag = None;
+        }
+
+        let mut clicked = None;
+        for (row, region) in self.rows.iter_mut().enumerate() {
+            let index = self.scroll + row;
+            if dragged {
+                region.reset();
+            } else if region.update() && index < self.items.len() && self.enabled[index] {
+                clicked = Some(index);
+            }
+        }
+
+        if let Some(index) = clicked {
+            self.selected = Some(index);
+        }
+        clicked
+    }
+
+    /// Draw the list
+    pub fn draw(&self) {
+        let theme = style::theme();
+        let rect = self.rect;
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, theme.panel);
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, theme.border);
+        draw_focus_outline(rect);
+
+        for (row, region) in self.rows.iter().enumerate() {
+            let index = self.scroll + row;
+            let Some(label) = self.items.get(index) else {
+                break;
+            };
+
+            let r = region.rect;
+            if self.selected == Some(index) {
+                draw_rectangle(r.x, r.y, r.w, r.h, theme.highlight);
+            } else if region.is_hovered() && self.enabled[index] {
+                draw_rectangle(r.x, r.y, r.w, r.h, theme.pressed);
+            }
+
+            let color = if self.enabled[index] { theme.text } else { theme.text_disabled };
+            let font_size = scaled(style::FONT_SIZE);
+            let label = fit_text(label, r.w - 12.0, font_size);
+            draw_text(&label, r.x + 6.0, r.y + r.h * 0.75, font_size, color);
+        }
+
+        // Scroll bar thumb
+        let visible = self.visible_rows();
+        if self.items.len() > visible {
+            let track_x = rect.x + rect.w - SCROLLBAR_WIDTH;
+            let thumb_h = rect.h * visible as f32 / self.items.len() as f32;
+            let thumb_y = rect.y + (rect.h - thumb_h) * self.scroll as f32
+                / (self.items.len() - visible) as f32;
+            draw_rectangle(track_x, thumb_y, SCROLLBAR_WIDTH, thumb_h, theme.border);
+        }
+    }
+}
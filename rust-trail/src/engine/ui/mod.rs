@@ -0,0 +1,194 @@
+// UI module - reusable widgets shared by all scenes
+
+pub mod button;
+pub mod dialog;
+pub mod focus;
+pub mod list_box;
+pub mod rich_text;
+pub mod spinner;
+pub mod style;
+pub mod text_box;
+pub mod text_input;
+pub mod toast;
+
+use std::cell::Cell;
+use macroquad::prelude::*;
+use crate::engine::display::mouse_position;
+use crate::engine::input;
+use crate::engine::renderer::draw_text;
+
+/// Smallest text scale
+pub const MIN_TEXT_SCALE: f32 = 1.0;
+/// Largest text scale
+pub const MAX_TEXT_SCALE: f32 = 2.0;
+
+/// Smallest width and height a finger can reliably hit, in canvas pixels
+pub const MIN_TOUCH_SIZE: f32 = 36.0;
+
+thread_local! {
+    static TEXT_SCALE: Cell<f32> = const { Cell::new(MIN_TEXT_SCALE) };
+}
+
+/// Set how much larger than normal widget text is drawn, from 1 to 2
+pub fn set_text_scale(scale: f32) {
+    TEXT_SCALE.with(|s| s.set(scale.clamp(MIN_TEXT_SCALE, MAX_TEXT_SCALE)));
+}
+
+/// How much larger than normal widget text is drawn
+pub fn text_scale() -> f32 {
+    TEXT_SCALE.with(Cell::get)
+}
+
+/// A font size, or the height of a line or row holding text, at the text scale
+pub fn scaled(size: f32) -> f32 {
+    size * text_scale()
+}
+
+/// Shorten text with an ellipsis until it fits a width at a font size
+pub fn fit_text(text: &str, max_width: f32, font_size: f32) -> String {
+    let width = |t: &str| measure_text(t, None, font_size as u16, 1.0).width;
+    if width(text) <= max_width {
+        return text.to_string();
+    }
+    let mut chars: Vec<char> = text.chars().collect();
+    while !chars.is_empty() {
+        chars.pop();
+        let shortened = format!("{}...", chars.iter().collect::<String>().trim_end());
+        if width(&shortened) <= max_width {
+            return shortened;
+        }
+    }
+    String::new()
+}
+
+/// Check if the mouse cursor is inside a rectangle
+pub fn mouse_in_rect(rect: Rect) -> bool {
+    let (x, y) = mouse_position();
+    rect.contains(Vec2::new(x, y))
+}
+
+/// Area that responds to the pointer: on a touchscreen, small targets grow
+/// around their middle to a size a finger can hit
+pub fn hit_area(rect: Rect) -> Rect {
+    if !input::is_touch() {
+        return rect;
+    }
+    let w = rect.w.max(MIN_TOUCH_SIZE);
+    let h = rect.h.max(MIN_TOUCH_SIZE);
+    Rect::new(rect.x - (w - rect.w) / 2.0, rect.y - (h - rect.h) / 2.0, w, h)
+}
+
+/// Check if the pointer is over a rectangle's hit area. A finger is only over
+/// something while it touches the screen, or in the frame it's lifted
+pub fn pointer_in_rect(rect: Rect) -> bool {
+    let area = hit_area(rect);
+    if input::is_touch() {
+        input::touch_held().or_else(input::touch_lifted).is_some_and(|p| area.contains(p))
+    } else {
+        mouse_in_rect(area)
+    }
+}
+
+/// Tracks hover and press state for a clickable area
+///
+/// A click only counts when the mouse was pressed and released inside the
+/// same region, matching how the original game's buttons behave.
+#[derive(Debug, Clone, Copy)]
+pub struct ClickRegion {
+    /// Area that responds to the mouse
+    pub rect: Rect,
+    /// Whether the mouse is over the region
+    hovered: bool,
+    /// Whether the region was pressed and the mouse button is still held
+    pressed: bool,
+}
+
+impl ClickRegion {
+    /// Create a click region covering the given rectangle
+    pub fn new(rect: Rect) -> Self {
+        Self {
+            rect,
+            hovered: false,
+            pressed: false,
+        }
+    }
+
+    /// Update hover/press state, returning true when a click completes
+    pub fn update(&mut self) -> bool {
+        self.hovered = pointer_in_rect(self.rect);
+
+        if self.hovered && is_mouse_button_pressed(MouseButton::Left) {
+            self.pressed = true;
+        }
+
+        if is_mouse_button_released(MouseButton::Left) {
+            let clicked = self.pressed && self.hovered;
+            self.pressed = false;
+            return clicked;
+        }
+
+        false
+    }
+
+    /// Check if the mouse is over the region
+    pub fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+
+    /// Check if the region is being held down
+    pub fn is_held(&self) -> bool {
+        self.pressed && self.hovered && is_mouse_button_down(MouseButton::Left)
+    }
+
+    /// Clear any hover or press state
+    pub fn reset(&mut self) {
+        self.hovered = false;
+        self.pressed = false;
+    }
+}
+
+/// Draw a bordered panel
+pub fn draw_panel(rect: Rect) {
+    let theme = style::theme();
+    draw_rectangle(rect.x, rect.y, rect.w, rect.h, theme.panel);
+    draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, theme.line_width, theme.border);
+}
+
+/// Draw the keyboard focus highlight around a rectangle
+pub fn draw_focus_outline(rect: Rect) {
+    let theme = style::theme();
+    draw_rectangle_lines(rect.x - 3.0, rect.y - 3.0, rect.w + 6.0, rect.h + 6.0, theme.line_width, theme.focus);
+}
+
+/// Draw text centered inside a rectangle
+pub fn draw_text_centered(text: &str, rect: Rect, font_size: f32, color: Color) {
+    let size = measure_text(text, None, font_size as u16, 1.0);
+    draw_text(
+        text,
+        rect.x + (rect.w - size.width) / 2.0,
+        rect.y + (rect.h + size.height) / 2.0,
+        font_size,
+        color,
+    );
+}
+
+/// Draw a text button for a click region
+pub fn draw_text_button(region: &ClickRegion, label: &str, enabled: bool) {
+    let rect = region.rect;
+    let theme = style::theme();
+    let fill = if !enabled {
+        theme.panel
+    } else if region.is_held() {
+        theme.pressed
+    } else if region.is_hovered() {
+        theme.highlight
+    } else {
+        theme.panel
+    };
+    let text_color = if enabled { theme.text } else { theme.text_disabled };
+
+    draw_rectangle(rect.x, rect.y, rect.w, rect.h, fill);
+    draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, theme.line_width - 1.0, theme.border);
+    let font_size = scaled(style::FONT_SIZE);
+    draw_text_centered(&fit_text(label, rect.w - 4.0, font_size), rect, font_size, text_color);
+}
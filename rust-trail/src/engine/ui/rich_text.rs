@@ -0,0 +1,101 @@
+use macroquad::prelude::*;
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::style;
+
+/// Tag ending a colored run
+const CLOSE: &str = "{/}";
+
+/// A run of text in one color
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    /// The text
+    pub text: String,
+    /// Its color, or None for the color the text is drawn in
+    pub color: Option<Color>,
+}
+
+/// Color for a markup tag name, from the theme: red is drawn as danger,
+/// green as good news and so on, whatever the palette makes of them
+fn named_color(name: &str) -> Option<Color> {
+    let theme = style::theme();
+    match name {
+        "em" => Some(theme.focus),
+        "red" => Some(theme.danger),
+        "green" => Some(theme.good),
+        "yellow" => Some(theme.warning),
+        "blue" => Some(theme.info),
+        "gray" => Some(theme.text_disabled),
+        _ => None,
+    }
+}
+
+/// Split text with inline color markup into runs
+///
+/// `{red}40 pounds of food{/}` draws its contents in red; `{em}` uses the
+/// highlight color. Tags don't nest, and anything in braces that isn't a
+/// known tag is kept as it is.
+pub fn parse(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut color = None;
+    let mut rest = text;
+    while let Some(open) = rest.find('{') {
+        let tag = rest[open..].find('}').map(|end| &rest[open..=open + end]);
+        // The color the tag switches to, if it is a tag
+        let switch = match tag {
+            Some(CLOSE) => Some(None),
+            Some(tag) => named_color(&tag[1..tag.len() - 1]).map(Some),
+            None => None,
+        };
+        match (tag, switch) {
+            (Some(tag), Some(next)) => {
+                push_span(&mut spans, &rest[..open], color);
+                color = next;
+                rest = &rest[open + tag.len()..];
+            }
+            _ => {
+                push_span(&mut spans, &rest[..=open], color);
+                rest = &rest[open + 1..];
+            }
+        }
+    }
+    push_span(&mut spans, rest, color);
+    spans
+}
+
+/// Add text to the runs, joining it to the last one if it's the same color
+pub fn push_span(spans: &mut Vec<Span>, text: &str, color: Option<Color>) {
+    match spans.last_mut() {
+        Some(last) if last.color == color => last.text.push_str(text),
+        _ if text.is_empty() => {}
+        _ => spans.push(Span { text: text.to_string(), color }),
+    }
+}
+
+/// Text with its markup taken out
+pub fn strip(text: &str) -> String {
+    parse(text).into_iter().map(|span| span.text).collect()
+}
+
+/// Draw runs of text one after another, left to right from `x`
+pub fn draw_spans(spans: &[Span], x: f32, y: f32, font_size: f32, color: Color) {
+    let mut x = x;
+    for span in spans {
+        draw_text(&span.text, x, y, font_size, span.color.unwrap_or(color));
+        x += measure_text(&span.text, None, font_size as u16, 1.0).width;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn markup_colors_runs_of_text() {
+        let spans = parse("Lost: {red}40 pounds of food{/}.");
+        let runs: Vec<_> = spans.iter().map(|s| (s.text.as_str(), s.color.is_some())).collect();
+        assert_eq!(runs, [("Lost: ", false), ("40 pounds of food", true), (".", false)]);
+
+        assert_eq!(strip("{em}Fort Kearney{/} ahead"), "Fort Kearney ahead");
+        assert_eq!(strip("a {brace} and {unclosed"), "a {brace} and {unclosed");
+    }
+}
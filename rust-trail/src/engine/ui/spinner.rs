@@ -0,0 +1,114 @@
+use macroquad::prelude::*;
+use crate::engine::input::{self, Action};
+use crate::engine::ui::{draw_focus_outline, draw_text_button, draw_text_centered, scaled, style, ClickRegion};
+
+/// Width of the decrement/increment arrows
+const ARROW_WIDTH: f32 = 28.0;
+
+/// A numeric value with decrement and increment arrows
+pub struct Spinner {
+    /// Area the spinner occupies
+    rect: Rect,
+    /// Current value
+    value: i32,
+    /// Smallest allowed value
+    min: i32,
+    /// Largest allowed value
+    max: i32,
+    /// Decrement arrow
+    down: ClickRegion,
+    /// Increment arrow
+    up: ClickRegion,
+    /// Whether Left/Right change the value
+    focused: bool,
+}
+
+impl Spinner {
+    /// Create a spinner with a value range
+    pub fn new(rect: Rect, value: i32, min: i32, max: i32) -> Self {
+        let mut spinner = Self {
+            rect,
+            value: value.clamp(min, max),
+            min,
+            max,
+            down: ClickRegion::new(Rect::default()),
+            up: ClickRegion::new(Rect::default()),
+            focused: false,
+        };
+        spinner.layout();
+        spinner
+    }
+
+    /// Get the current value
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Set the value, clamped to the allowed range
+    pub fn set_value(&mut self, value: i32) {
+        self.value = value.clamp(self.min, self.max);
+    }
+
+    /// Change the allowed range, clamping the current value
+    pub fn set_range(&mut self, min: i32, max: i32) {
+        self.min = min;
+        self.max = max.max(min);
+        self.set_value(self.value);
+    }
+
+    /// Give or take away keyboard focus
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Step the value up or down, returning true if it changed
+    pub fn step_by(&mut self, steps: i32) -> bool {
+        let old = self.value;
+        self.set_value(self.value + steps);
+        self.value != old
+    }
+
+    /// Recompute arrow positions
+    fn layout(&mut self) {
+        let r = self.rect;
+        self.down.rect = Rect::new(r.x, r.y, ARROW_WIDTH, r.h);
+        self.up.rect = Rect::new(r.x + r.w - ARROW_WIDTH, r.y, ARROW_WIDTH, r.h);
+    }
+
+    /// Update the spinner, returning the new value when it changes
+    pub fn update(&mut self) -> Option<i32> {
+        let mut changed = false;
+        if self.down.update() {
+            changed |= self.step_by(-1);
+        }
+        if self.up.update() {
+            changed |= self.step_by(1);
+        }
+        if self.focused {
+            if input::is_action_pressed(Action::MenuLeft) {
+                changed |= self.step_by(-1);
+            }
+            if input::is_action_pressed(Action::MenuRight) {
+                changed |= self.step_by(1);
+            }
+        }
+        changed.then_some(self.value)
+    }
+
+    /// Draw the spinner
+    pub fn draw(&self) {
+        let theme = style::theme();
+        let r = self.rect;
+        draw_rectangle(r.x, r.y, r.w, r.h, theme.panel);
+        draw_rectangle_lines(r.x, r.y, r.w, r.h, 1.0, theme.border);
+        if self.focused {
+            draw_focus_outline(r);
+        }
+
+        draw_text_button(&self.down, "<", self.value > self.min);
+        draw_text_button(&self.up, ">", self.value < self.max);
+
+        let middle = Rect::new(r.x + ARROW_WIDTH, r.y, r.w - ARROW_WIDTH * 2.0, r.h);
+        draw_text_centered(&self.value.to_string(), middle, scaled(style::FONT_SIZE), theme.text);
+    }
+}
@@ -0,0 +1,179 @@
+use std::cell::Cell;
+use macroquad::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::game_logic::player::HealthStatus;
+
+/// Default font size for widget text
+pub const FONT_SIZE: f32 = 20.0;
+
+/// Colors chosen for the colors a player can tell apart
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Palette {
+    /// The game's own colors
+    Standard,
+    /// For red-green color blindness (deuteranopia and protanopia)
+    RedGreen,
+    /// For blue-yellow color blindness (tritanopia)
+    BlueYellow,
+}
+
+impl Palette {
+    /// All palettes in menu order
+    pub const ALL: [Palette; 3] = [Palette::Standard, Palette::RedGreen, Palette::BlueYellow];
+
+    /// Display name of the palette
+    pub fn name(&self) -> &'static str {
+        match self {
+            Palette::Standard => "Standard",
+            Palette::RedGreen => "Red-green safe",
+            Palette::BlueYellow => "Blue-yellow safe",
+        }
+    }
+
+    /// Theme drawn with the palette
+    fn theme(&self) -> Theme {
+        match self {
+            Palette::Standard => STANDARD,
+            Palette::RedGreen => Theme {
+                good: Color::new(0.34, 0.71, 0.91, 1.0),
+                warning: Color::new(0.94, 0.89, 0.26, 1.0),
+                danger: Color::new(0.9, 0.4, 0.0, 1.0),
+                info: Color::new(0.8, 0.6, 0.7, 1.0),
+                ..STANDARD
+            },
+            Palette::BlueYellow => Theme {
+                highlight: Color::new(0.1, 0.35, 0.4, 1.0),
+                pressed: Color::new(0.05, 0.18, 0.2, 1.0),
+                focus: Color::new(1.0, 0.5, 0.6, 1.0),
+                good: Color::new(0.3, 0.85, 0.95, 1.0),
+                warning: Color::new(1.0, 0.75, 0.8, 1.0),
+                danger: Color::new(1.0, 0.25, 0.25, 1.0),
+                info: Color::new(0.75, 0.75, 1.0, 1.0),
+                ..STANDARD
+            },
+        }
+    }
+}
+
+/// Colors everything is drawn in
+///
+/// Scenes ask the theme for "danger" or "good" rather than naming red or
+/// green, so the player's palette and contrast settings reach every screen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Panel background
+    pub panel: Color,
+    /// Panel and widget borders
+    pub border: Color,
+    /// Regular text
+    pub text: Color,
+    /// Text for unavailable entries and hints
+    pub text_disabled: Color,
+    /// Highlight behind hovered or selected entries
+    pub highlight: Color,
+    /// Fill for pressed widgets
+    pub pressed: Color,
+    /// Outline drawn around the widget with keyboard focus, and emphasized text
+    pub focus: Color,
+    /// Shade over the screen behind a dialog or menu
+    pub dim: Color,
+    /// Good news, such as a healthy party
+    pub good: Color,
+    /// Something to keep an eye on
+    pub warning: Color,
+    /// Losses, dangers and errors
+    pub danger: Color,
+    /// Links and other information
+    pub info: Color,
+    /// Thickness of panel borders and the focus outline
+    pub line_width: f32,
+}
+
+/// The game's own colors
+const STANDARD: Theme = Theme {
+    panel: Color::new(0.0, 0.0, 0.0, 0.9),
+    border: WHITE,
+    text: WHITE,
+    text_disabled: GRAY,
+    highlight: Color::new(0.2, 0.3, 0.6, 1.0),
+    pressed: Color::new(0.1, 0.15, 0.35, 1.0),
+    focus: YELLOW,
+    dim: Color::new(0.0, 0.0, 0.0, 0.5),
+    good: Color::new(0.4, 0.9, 0.4, 1.0),
+    warning: YELLOW,
+    danger: Color::new(1.0, 0.35, 0.3, 1.0),
+    info: Color::new(0.55, 0.75, 1.0, 1.0),
+    line_width: 2.0,
+};
+
+/// A color made as bright as it goes without changing its hue
+fn brightest(color: Color) -> Color {
+    let max = color.r.max(color.g).max(color.b);
+    if max <= 0.0 {
+        return color;
+    }
+    Color::new(color.r / max, color.g / max, color.b / max, 1.0)
+}
+
+impl Theme {
+    /// The theme with solid backgrounds, brighter text and colors, and thicker lines
+    pub fn high_contrast(self) -> Self {
+        Self {
+            panel: BLACK,
+            border: WHITE,
+            text: WHITE,
+            text_disabled: Color::new(0.75, 0.75, 0.75, 1.0),
+            dim: Color::new(0.0, 0.0, 0.0, 0.85),
+            focus: brightest(self.focus),
+            good: brightest(self.good),
+            warning: brightest(self.warning),
+            danger: brightest(self.danger),
+            info: brightest(self.info),
+            line_width: 3.0,
+            ..self
+        }
+    }
+
+    /// Color showing how healthy someone is
+    pub fn health(&self, health: HealthStatus) -> Color {
+        match health {
+            HealthStatus::Good => self.good,
+            HealthStatus::Fair => self.warning,
+            HealthStatus::Poor | HealthStatus::VeryPoor => self.danger,
+            HealthStatus::Deceased => self.text_disabled,
+        }
+    }
+}
+
+thread_local! {
+    static THEME: Cell<Theme> = const { Cell::new(STANDARD) };
+}
+
+/// Draw in a palette, in high contrast or not
+pub fn set_theme(palette: Palette, high_contrast: bool) {
+    let theme = palette.theme();
+    THEME.with(|t| t.set(if high_contrast { theme.high_contrast() } else { theme }));
+}
+
+/// Colors everything is drawn in now
+pub fn theme() -> Theme {
+    THEME.with(Cell::get)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palettes_keep_good_and_bad_news_apart() {
+        for palette in Palette::ALL {
+            for theme in [palette.theme(), palette.theme().high_contrast()] {
+                assert_ne!(theme.health(HealthStatus::Good), theme.health(HealthStatus::Poor));
+                assert_ne!(theme.good, theme.danger);
+            }
+        }
+        let contrast = Palette::RedGreen.theme().high_contrast();
+        assert_eq!(contrast.panel.a, 1.0);
+        assert_eq!(contrast.danger.r.max(contrast.danger.g).max(contrast.danger.b), 1.0);
+    }
+}
@@ -0,0 +1,261 @@
+use macroquad::prelude::*;
+use crate::engine::narration;
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::rich_text::{self, Span};
+use crate::engine::ui::{scaled, style};
+
+/// Height of one line of text
+const LINE_HEIGHT: f32 = 24.0;
+/// Size of the "more" indicator text
+const MORE_SIZE: f32 = 16.0;
+
+/// Break text with color markup into lines no wider than `max_width` as
+/// measured, between words
+///
+/// Each line of the text starts a new paragraph, and blank lines are kept.
+/// A word too wide for a line on its own gets a line to itself.
+pub fn wrap_lines(text: &str, max_width: f32, measure: impl Fn(&str) -> f32) -> Vec<Vec<Span>> {
+    let mut lines = Vec::new();
+    let mut line = Vec::new();
+    let mut width = 0.0;
+    // Whether the previous run ended between words
+    let mut after_space = false;
+
+    for span in rich_text::parse(text) {
+        for (i, paragraph) in span.text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(std::mem::take(&mut line));
+                width = 0.0;
+            }
+            let starts_with_space = paragraph.starts_with(char::is_whitespace);
+            for (j, word) in paragraph.split_whitespace().enumerate() {
+                // Text straight after a change of color stays attached to it
+                let spaced = j > 0 || starts_with_space || after_space;
+                let mut piece = if width > 0.0 && spaced { format!(" {}", word) } else { word.to_string() };
+                if width > 0.0 && width + measure(&piece) > max_width {
+                    lines.push(std::mem::take(&mut line));
+                    width = 0.0;
+                    piece = word.to_string();
+                }
+                width += measure(&piece);
+                rich_text::push_span(&mut line, &piece, span.color);
+            }
+            after_space = paragraph.ends_with(char::is_whitespace);
+        }
+    }
+    lines.push(line);
+    lines
+}
+
+/// Characters in a line
+fn line_chars(line: &[Span]) -> usize {
+    line.iter().map(|span| span.text.chars().count()).sum()
+}
+
+/// Word-wrapped text in a rectangle, shown a page at a time with an optional
+/// typewriter reveal
+///
+/// Text that doesn't fit flows onto further pages, with a "more" indicator
+/// while there are pages to come. The text can color words with the markup
+/// `rich_text::parse` reads.
+pub struct TextBox {
+    /// Area the text is drawn in
+    rect: Rect,
+    /// Size of the text
+    font_size: f32,
+    /// Height of one line of text
+    line_height: f32,
+    /// Color of the text
+    color: Color,
+    /// Wrapped lines, a page at a time
+    pages: Vec<Vec<Vec<Span>>>,
+    /// Page showing
+    page: usize,
+    /// Characters revealed per second, or None to show each page at once
+    chars_per_second: Option<f32>,
+    /// Characters of the page revealed so far
+    revealed: f32,
+    /// Page last narrated
+    narrated: Option<usize>,
+}
+
+impl TextBox {
+    /// Create an empty text box covering the given rectangle, with text at the
+    /// text scale
+    pub fn new(rect: Rect) -> Self {
+        Self {
+            rect,
+            font_size: scaled(style::FONT_SIZE),
+            line_height: scaled(LINE_HEIGHT),
+            color: style::theme().text,
+            pages: Vec::new(),
+            page: 0,
+            chars_per_second: None,
+            revealed: 0.0,
+            narrated: None,
+        }
+    }
+
+    /// Set the size of the text and the height of its lines
+    pub fn with_font(mut self, font_size: f32, line_height: f32) -> Self {
+        self.font_size = font_size;
+        self.line_height = line_height;
+        self
+    }
+
+    /// Set how many characters are revealed per second, or None to show pages at once
+    pub fn with_speed(mut self, chars_per_second: Option<f32>) -> Self {
+        self.chars_per_second = chars_per_second;
+        self
+    }
+
+    /// Lay out new text from its first page
+    pub fn set_text(&mut self, text: &str) {
+        let font_size = self.font_size as u16;
+        let lines = wrap_lines(text, self.rect.w, |line| measure_text(line, None, font_size, 1.0).width);
+        let per_page = ((self.rect.h / self.line_height).floor() as usize).max(1);
+        self.pages = lines.chunks(per_page).map(|page| page.to_vec()).collect();
+        // Blank lines left at the top of a page would only push its text down
+        for page in self.pages.iter_mut().skip(1) {
+            while page.first().is_some_and(|line| line.is_empty()) {
+                page.remove(0);
+            }
+        }
+        self.pages.retain(|page| !page.is_empty());
+        self.narrated = None;
+        self.turn_to(0);
+    }
+
+    /// Characters on the page showing
+    fn page_chars(&self) -> usize {
+        self.pages.get(self.page).map_or(0, |page| page.iter().map(|line| line_chars(line)).sum())
+    }
+
+    /// Show a page from the start of its reveal
+    fn turn_to(&mut self, page: usize) {
+        self.page = page;
+        self.revealed = 0.0;
+        if self.chars_per_second.is_none() {
+            self.finish();
+        }
+    }
+
+    /// Reveal more of the page, narrating it whole when it's first shown
+    pub fn update(&mut self, dt: f32) {
+        if self.narrated != Some(self.page) {
+            if let Some(page) = self.pages.get(self.page) {
+                let lines: Vec<String> = page.iter().map(|line| line.iter().map(|span| span.text.as_str()).collect()).collect();
+                narration::say(&lines.join(" "));
+            }
+            self.narrated = Some(self.page);
+        }
+        if let Some(speed) = self.chars_per_second {
+            self.revealed = (self.revealed + speed * dt).min(self.page_chars() as f32);
+        }
+    }
+
+    /// Whether the page is still being revealed
+    pub fn is_revealing(&self) -> bool {
+        (self.revealed as usize) < self.page_chars()
+    }
+
+    /// Reveal the rest of the page at once
+    pub fn finish(&mut self) {
+        self.revealed = self.page_chars() as f32;
+    }
+
+    /// Whether there are pages after this one
+    pub fn has_more(&self) -> bool {
+        self.page + 1 < self.pages.len()
+    }
+
+    /// Page showing, from 0
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Number of pages
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Go back a page
+    pub fn previous(&mut self) {
+        if self.page > 0 {
+            self.turn_to(self.page - 1);
+        }
+    }
+
+    /// Move the text along: finish revealing the page, or turn to the next
+    ///
+    /// Returns false once the last page is fully shown and there is nothing
+    /// left to move on to.
+    pub fn advance(&mut self) -> bool {
+        if self.is_revealing() {
+            self.finish();
+            true
+        } else if self.has_more() {
+            self.turn_to(self.page + 1);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Draw the revealed part of the page
+    pub fn draw(&self) {
+        let Some(page) = self.pages.get(self.page) else {
+            return;
+        };
+        let mut left = self.revealed as usize;
+        for (i, line) in page.iter().enumerate() {
+            let y = self.rect.y + i as f32 * self.line_height + self.line_height * 0.75;
+            let mut x = self.rect.x;
+            for span in line {
+                if left == 0 {
+                    return self.draw_more();
+                }
+                let shown = match span.text.char_indices().nth(left) {
+                    Some((end, _)) => &span.text[..end],
+                    None => span.text.as_str(),
+                };
+                left = left.saturating_sub(span.text.chars().count());
+                draw_text(shown, x, y, self.font_size, span.color.unwrap_or(self.color));
+                x += measure_text(&span.text, None, self.font_size as u16, 1.0).width;
+            }
+        }
+        self.draw_more();
+    }
+
+    /// Draw the "more" indicator once the page is shown, if there are pages to come
+    fn draw_more(&self) {
+        if !self.has_more() || self.is_revealing() {
+            return;
+        }
+        let more = "more...";
+        let width = measure_text(more, None, MORE_SIZE as u16, 1.0).width;
+        let y = self.rect.y + self.rect.h + MORE_SIZE;
+        draw_text(more, self.rect.x + self.rect.w - width, y, MORE_SIZE, style::theme().focus);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_wraps_to_the_width_given() {
+        let measure = |line: &str| line.len() as f32;
+        let plain = |lines: Vec<Vec<Span>>| -> Vec<String> {
+            lines.iter().map(|line| line.iter().map(|span| span.text.as_str()).collect()).collect()
+        };
+        let lines = wrap_lines("Ford the river\n\nor take the ferry across", 10.0, measure);
+        assert_eq!(plain(lines), ["Ford the", "river", "", "or take", "the ferry", "across"]);
+        assert_eq!(plain(wrap_lines("Willamette", 4.0, measure)), ["Willamette"]);
+
+        // Markup takes no room, and colored words wrap like any others
+        let lines = wrap_lines("Lost {red}40 pounds{/}, sadly", 10.0, measure);
+        assert_eq!(plain(lines.clone()), ["Lost 40", "pounds,", "sadly"]);
+        assert!(lines[1][0].color.is_some() && lines[1][1].color.is_none());
+    }
+}
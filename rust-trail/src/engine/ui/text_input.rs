@@ -0,0 +1,175 @@
+use macroquad::prelude::*;
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::{draw_focus_outline, pointer_in_rect, scaled, style};
+
+/// How long the caret stays visible or hidden (in seconds)
+const CARET_BLINK: f32 = 0.5;
+/// Room above and below the text inside the field
+const TEXT_MARGIN: f32 = 8.0;
+
+/// Event produced by a text input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextInputEvent {
+    /// The text was edited
+    Changed,
+    /// Enter was pressed
+    Submitted,
+}
+
+/// A single-line text field with a caret
+pub struct TextInput {
+    /// Area the field occupies
+    rect: Rect,
+    /// Current contents
+    text: String,
+    /// Caret position in characters
+    caret: usize,
+    /// Maximum number of characters
+    max_len: usize,
+    /// Whether the field receives typed characters
+    focused: bool,
+    /// Time used to blink the caret
+    blink_timer: f32,
+}
+
+impl TextInput {
+    /// Create an empty text field
+    pub fn new(rect: Rect, max_len: usize) -> Self {
+        Self {
+            rect,
+            text: String::new(),
+            caret: 0,
+            max_len,
+            focused: false,
+            blink_timer: 0.0,
+        }
+    }
+
+    /// Get the current text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replace the text and move the caret to the end
+    pub fn set_text(&mut self, text: &str) {
+        self.text = text.chars().take(self.max_len).collect();
+        self.caret = self.text.chars().count();
+    }
+
+    /// Give or take away keyboard focus
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+        self.blink_timer = 0.0;
+    }
+
+    /// Convert a character index into a byte index
+    fn byte_index(&self, chars: usize) -> usize {
+        self.text.char_indices().nth(chars).map_or(self.text.len(), |(i, _)| i)
+    }
+
+    /// Insert typed characters at the caret while there's room, returning
+    /// whether any went in
+    fn type_chars(&mut self, chars: impl Iterator<Item = char>) -> bool {
+        let mut changed = false;
+        for c in chars {
+            if c.is_control() || self.text.chars().count() >= self.max_len {
+                continue;
+            }
+            let at = self.byte_index(self.caret);
+            self.text.insert(at, c);
+            self.caret += 1;
+            changed = true;
+        }
+        changed
+    }
+
+    /// Update the field, handling focus clicks and typing
+    pub fn update(&mut self, dt: f32) -> Option<TextInputEvent> {
+        self.blink_timer = (self.blink_timer + dt) % (CARET_BLINK * 2.0);
+
+        if is_mouse_button_pressed(MouseButton::Left) {
+            self.set_focused(pointer_in_rect(self.field_rect()));
+        }
+
+        if !self.focused {
+            return None;
+        }
+
+        let mut event = None;
+        if self.type_chars(std::iter::from_fn(get_char_pressed)) {
+            event = Some(TextInputEvent::Changed);
+        }
+
+        if is_key_pressed(KeyCode::Backspace) && self.caret > 0 {
+            self.caret -= 1;
+            let at = self.byte_index(self.caret);
+            self.text.remove(at);
+            event = Some(TextInputEvent::Changed);
+        }
+        if is_key_pressed(KeyCode::Delete) && self.caret < self.text.chars().count() {
+            let at = self.byte_index(self.caret);
+            self.text.remove(at);
+            event = Some(TextInputEvent::Changed);
+        }
+
+        if is_key_pressed(KeyCode::Left) {
+            self.caret = self.caret.saturating_sub(1);
+        }
+        if is_key_pressed(KeyCode::Right) {
+            self.caret = (self.caret + 1).min(self.text.chars().count());
+        }
+        if is_key_pressed(KeyCode::Home) {
+            self.caret = 0;
+        }
+        if is_key_pressed(KeyCode::End) {
+            self.caret = self.text.chars().count();
+        }
+
+        if is_key_pressed(KeyCode::Enter) {
+            event = Some(TextInputEvent::Submitted);
+        }
+
+        event
+    }
+
+    /// Area the field is drawn in, taller than laid out if the text scale needs it
+    fn field_rect(&self) -> Rect {
+        Rect { h: self.rect.h.max(scaled(style::FONT_SIZE) + TEXT_MARGIN * 2.0), ..self.rect }
+    }
+
+    /// Draw the field
+    pub fn draw(&self) {
+        let theme = style::theme();
+        let rect = self.field_rect();
+        let font_size = scaled(style::FONT_SIZE);
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, theme.panel);
+        let border = if self.focused { theme.border } else { theme.text_disabled };
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, border);
+        if self.focused {
+            draw_focus_outline(rect);
+        }
+
+        let baseline = rect.y + rect.h * 0.7;
+        draw_text(&self.text, rect.x + 6.0, baseline, font_size, theme.text);
+
+        if self.focused && self.blink_timer < CARET_BLINK {
+            let before = &self.text[..self.byte_index(self.caret)];
+            let x = rect.x + 6.0 + measure_text(before, None, font_size as u16, 1.0).width;
+            draw_line(x, rect.y + 4.0, x, rect.y + rect.h - 4.0, 1.0, theme.text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typing_stops_at_the_length_limit() {
+        let mut input = TextInput::new(Rect::default(), 5);
+        assert!(input.type_chars("Ezra\nMeeker".chars()));
+        assert_eq!(input.text(), "EzraM");
+        assert!(!input.type_chars("s".chars()));
+        assert_eq!(input.text(), "EzraM");
+    }
+}
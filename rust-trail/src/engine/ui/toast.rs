@@ -0,0 +1,66 @@
+use std::collections::VecDeque;
+use macroquad::prelude::*;
+use crate::engine::display::screen_width;
+use crate::engine::narration;
+use crate::engine::ui::{draw_panel, draw_text_centered, scaled, style, text_scale};
+
+/// Seconds each toast stays up
+const TOAST_SECONDS: f32 = 3.5;
+
+/// Seconds a toast takes to slide in and out
+const SLIDE_SECONDS: f32 = 0.3;
+
+/// Size of a toast banner
+const TOAST_SIZE: Vec2 = Vec2::new(420.0, 44.0);
+
+/// Short notices that slide in at the top of the screen over whatever scene is up
+///
+/// Toasts are shown one at a time, in the order they were raised, and
+/// never wait for input.
+#[derive(Debug, Default)]
+pub struct Toasts {
+    /// Notices waiting their turn, the first being shown
+    queue: VecDeque<String>,
+    /// Seconds the first notice has been up
+    shown: f32,
+}
+
+impl Toasts {
+    /// Create an empty toast queue
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Raise a notice
+    pub fn push(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        narration::say(&text);
+        self.queue.push_back(text);
+    }
+
+    /// Count down the notice being shown, moving on to the next once it's done
+    pub fn update(&mut self, dt: f32) {
+        if self.queue.is_empty() {
+            return;
+        }
+        self.shown += dt;
+        if self.shown >= TOAST_SECONDS {
+            self.queue.pop_front();
+            self.shown = 0.0;
+        }
+    }
+
+    /// Draw the notice being shown, if any
+    pub fn draw(&self) {
+        let Some(text) = self.queue.front() else {
+            return;
+        };
+        let slide = (self.shown.min(TOAST_SECONDS - self.shown) / SLIDE_SECONDS).clamp(0.0, 1.0);
+        // Larger text gets a larger banner, as wide as the screen at most
+        let size = vec2((TOAST_SIZE.x * text_scale()).min(screen_width() - 20.0), TOAST_SIZE.y * text_scale());
+        let y = -size.y + (size.y + 12.0) * slide;
+        let rect = Rect::new((screen_width() - size.x) / 2.0, y, size.x, size.y);
+        draw_panel(rect);
+        draw_text_centered(text, rect, scaled(style::FONT_SIZE), style::theme().focus);
+    }
+}
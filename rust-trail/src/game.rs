@@ -1,6 +1,33 @@
 use macroquad::prelude::*;
-use crate::engine::asset_loader::AssetManager;
-use crate::scenes::title_screen::{TitleScreen, TitleAction};
+use crate::debug::console::Console;
+use crate::debug::overlay::DebugOverlay;
+use crate::engine::asset_loader::{AssetError, AssetManager, AssetRoot, ErrorAction, BUNDLED_DIR};
+use crate::engine::audio;
+use crate::engine::control_server::ControlServer;
+use crate::engine::cursor::{self, Cursor};
+use crate::engine::display::{self, screen_height, screen_width};
+use crate::engine::event_bus::{EventBus, Subscription};
+use crate::engine::fallback;
+use crate::engine::input;
+use crate::engine::integrity::IntegrityReport;
+use crate::engine::network::{self, Pending};
+use crate::engine::renderer::{self, draw_text};
+use crate::engine::ui::style;
+use crate::engine::ui::toast::Toasts;
+use crate::game_logic::game_events::GameEvent;
+use crate::game_logic::control::ControlQueue;
+use crate::game_logic::journey::Journey;
+use crate::game_logic::leaderboard::Submission;
+use crate::game_logic::profiles;
+use crate::game_logic::speedrun::{self, SpeedrunTimer};
+use crate::game_logic::wagon_train::WagonTrain;
+use crate::scenes::loading;
+use crate::scenes::asset_check::AssetCheckScene;
+use crate::scenes::scene_manager::{Scene, SceneManager, SceneTransition};
+use crate::scenes::opening::OpeningScene;
+use crate::settings::{Settings, SETTINGS_FILE};
+use crate::utils::rng::GameRng;
+use crate::utils::storage::{self, Storage};
 
 /// Represents the different states the game can be in
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -14,267 +41,487 @@ pub enum GameState {
     Hunting,
     RiverCrossing,
     Trading,
-    Event,
     Landmark,
     GameOver,
+    Paused,
+}
+
+impl GameState {
+    /// Whether a scene in this state may hand over to, or push, a scene in the next
+    ///
+    /// Returning to the title screen is always allowed, as is staying in the
+    /// same state (dialogs and submenus share their parent's state).
+    pub fn can_transition_to(self, next: GameState) -> bool {
+        use GameState::*;
+        next == self
+            || next == TitleScreen
+            || matches!(
+                (self, next),
+                (TitleScreen, MainMenu | Introduction | Options)
+                    | (MainMenu, Setup | Introduction | Options)
+                    | (Setup, MainMenu | Travel)
+                    | (MainMenu, Travel)
+                    | (Travel, Landmark | RiverCrossing | Trading | Hunting | GameOver)
+                    | (Travel | Hunting, Paused)
+                    | (Paused, Options)
+                    | (Landmark, RiverCrossing | Travel)
+                    | (RiverCrossing | Trading | Hunting, Travel)
+            )
+    }
+}
+
+/// Shared state that scenes can read and modify
+pub struct GameContext {
+    /// Asset manager for loading and caching assets
+    pub assets: AssetManager,
+    /// Player settings, applied by the game whenever they change
+    pub settings: Settings,
+    /// Seeded random numbers for the current journey
+    pub rng: GameRng,
+    /// The journey in progress
+    pub journey: Journey,
+    /// Events published by the journey, for scenes and audio to react to
+    pub events: EventBus<GameEvent>,
+    /// Notices shown over whatever scene is up
+    pub toasts: Toasts,
+    /// Where saves, settings and records are kept between sessions
+    pub storage: Box<dyn Storage>,
+    /// Profile playing, or None for a guest
+    pub profile: Option<String>,
+    /// Wagons travelling together in a hot-seat game; the journey in play is the current wagon's
+    pub wagon_train: Option<WagonTrain>,
+    /// Scores on their way to the online leaderboard
+    pub submissions: Vec<Pending<()>>,
+    /// Votes and good luck sent by streaming tools, waiting for a decision
+    pub control: ControlQueue,
+    /// Whether the game is requesting to exit
+    pub exit_requested: bool,
+}
+
+impl GameContext {
+    /// Name a per-profile file is stored under for the profile playing
+    pub fn profile_file(&self, name: &str) -> String {
+        profiles::profile_file(self.profile.as_deref(), name)
+    }
+
+    /// Save the settings for the profile playing, logging any failure
+    pub fn save_settings(&mut self) {
+        let file = self.profile_file(SETTINGS_FILE);
+        if let Err(e) = self.settings.save(self.storage.as_mut(), &file) {
+            log::error!("{}", e);
+        }
+    }
+
+    /// Switch to a profile, or to playing as a guest, taking up its settings
+    ///
+    /// A profile with no settings saved yet starts from the ones in use.
+    pub fn switch_profile(&mut self, profile: Option<String>) {
+        self.profile = profile;
+        let file = self.profile_file(SETTINGS_FILE);
+        match self.storage.read(&file) {
+            Ok(Some(_)) => match Settings::load(self.storage.as_ref(), &file) {
+                Ok(settings) => self.settings = settings,
+                Err(e) => log::error!("{}", e),
+            },
+            Ok(None) => self.save_settings(),
+            Err(e) => log::error!("{}", e),
+        }
+    }
+
+    /// Send the finished journey's score to the online leaderboard, if the
+    /// player has opted in and the journey counts
+    pub fn submit_score(&mut self) {
+        let Some(server) = self.settings.leaderboard() else {
+            return;
+        };
+        if let Some(submission) = Submission::new(&self.journey, self.rng.seed()) {
+            self.submissions.push(network::submit(server, &submission));
+        }
+    }
 }
 
 /// Main game struct that manages the overall game state
 pub struct Game {
-    /// Current state of the game
-    state: GameState,
-    /// Whether the game is requesting to exit
-    exit_requested: bool,
-    /// Asset manager for loading and caching assets
-    asset_manager: AssetManager,
-    /// Title screen
-    title_screen: Option<TitleScreen>,
-    /// Whether assets are loaded
+    /// State shared with scenes
+    context: GameContext,
+    /// Stack of active scenes
+    scenes: SceneManager,
+    /// Settings currently applied to the display and audio
+    applied_settings: Option<Settings>,
+    /// Local interface for streaming tools, while the settings allow it
+    control_server: Option<ControlServer>,
+    /// Whether preloading has finished and the title screen is up
     assets_loaded: bool,
+    /// Number of missing assets the player has been told about
+    missing_reported: usize,
+    /// Shipped assets found missing or damaged at startup
+    integrity: IntegrityReport,
+    /// Audio's subscription to game events
+    sound_events: Subscription,
+    /// Real-time clock for the run in progress
+    speedrun: SpeedrunTimer,
+    /// Speedrun timer's subscription to game events
+    speedrun_events: Subscription,
+    /// Developer console, dropped down over the scenes in debug builds
+    console: Console,
+    /// Frame rate and journey numbers, toggled with F3
+    overlay: DebugOverlay,
 }
 
 impl Game {
-    /// Create a new game instance
-    pub fn new() -> Self {
+    /// Create a new game instance, seeding the journey's random numbers
+    pub fn new(rng: GameRng) -> Self {
+        let mut events = EventBus::new();
+        let sound_events = events.subscribe();
+        let speedrun_events = events.subscribe();
         Self {
-            state: GameState::TitleScreen,
-            exit_requested: false,
-            asset_manager: AssetManager::new("assets"),
-            title_screen: Some(TitleScreen::new()),
+            context: GameContext {
+                assets: AssetManager::new(BUNDLED_DIR),
+                settings: Settings::default(),
+                rng,
+                journey: Journey::default(),
+                events,
+                toasts: Toasts::new(),
+                storage: storage::platform(),
+                profile: None,
+                wagon_train: None,
+                submissions: Vec::new(),
+                control: ControlQueue::default(),
+                exit_requested: false,
+            },
+            scenes: SceneManager::new(),
+            applied_settings: None,
+            control_server: None,
             assets_loaded: false,
+            missing_reported: 0,
+            integrity: IntegrityReport::default(),
+            sound_events,
+            speedrun: SpeedrunTimer::new(),
+            speedrun_events,
+            console: Console::new(),
+            overlay: DebugOverlay::new(),
         }
     }
 
     /// Update game state based on delta time
-    pub fn update(&mut self, dt: f32) {
-        // Load assets if not already loaded
+    pub async fn update(&mut self, dt: f32) {
+        // Apply settings changed by a scene
+        if self.applied_settings.as_ref() != Some(&self.context.settings) {
+            self.context.settings.apply();
+            self.applied_settings = Some(self.context.settings.clone());
+            self.sync_control_server();
+        }
+        self.take_control_commands();
+
+        // A fatal asset error stops the game on the error screen
+        if self.context.assets.fatal_error().is_some() {
+            if is_key_pressed(KeyCode::Escape) {
+                self.context.exit_requested = true;
+            }
+            return;
+        }
+
+        // Keep preloading until everything in the manifest is in
         if !self.assets_loaded {
+            self.preload_step().await;
+            return;
+        }
+        self.report_missing_assets();
+
+        // Poll keyboard/gamepad state for this frame
+        input::update();
+        audio::update();
+
+        // Alt+Enter switches between windowed and fullscreen from anywhere
+        if (is_key_down(KeyCode::LeftAlt) || is_key_down(KeyCode::RightAlt)) && is_key_pressed(KeyCode::Enter) {
+            self.toggle_fullscreen();
+        }
+
+        self.overlay.update();
+        self.console.collect_logs();
+
+        // The console takes the keyboard while it's down, holding the scenes still
+        if self.console.toggle() {
+            self.console.update(&mut self.context, dt);
+        } else {
+            // Let the active scene update, then apply whatever transition it asked for
+            self.scenes.update(&mut self.context, dt);
+            self.scenes.apply_pending(&mut self.context).await;
+        }
+        self.context.toasts.update(dt);
+        self.poll_submissions();
+
+        // Hand what happened on the trail to the bus, then play its sounds
+        for event in self.context.journey.take_events() {
+            self.context.events.publish(event);
+        }
+        self.play_event_sounds();
+        self.update_speedrun(dt);
+    }
+
+    /// Start or stop the stream control interface to match the settings
+    fn sync_control_server(&mut self) {
+        let settings = &self.context.settings;
+        let wanted = Some(settings.control_port).filter(|_| settings.stream_control);
+        if self.control_server.as_ref().map(ControlServer::port) == wanted {
             return;
         }
-        
-        // Handle state-specific updates
-        match self.state {
-            GameState::TitleScreen => {
-                // Update title screen
-                if let Some(title_screen) = &mut self.title_screen {
-                    if let Some(action) = title_screen.update(dt) {
-                        // Handle the different actions with if statements instead of match
-                        if action == TitleAction::StartGame {
-                            self.transition_to(GameState::MainMenu);
-                        } else if action == TitleAction::Introduction {
-                            self.transition_to(GameState::Introduction);
-                        } else if action == TitleAction::Options {
-                            self.transition_to(GameState::Options);
-                        } else if action == TitleAction::Quit {
-                            self.exit_requested = true;
-                        }
-                    }
-                }
+        // Stop the old interface before opening a new one on the same port
+        self.control_server = None;
+        if let Some(port) = wanted {
+            self.control_server = ControlServer::start(port)
+                .map_err(|e| {
+                    log::error!("{}", e);
+                    self.context.toasts.push("Stream control couldn't be started.");
+                })
+                .ok();
+        }
+    }
+
+    /// Queue what streaming tools have sent
+    fn take_control_commands(&mut self) {
+        let Some(server) = &self.control_server else {
+            return;
+        };
+        for command in server.take_commands() {
+            if let Err(e) = self.context.control.push(command) {
+                log::info!("{}", e);
             }
-            GameState::Introduction => {
-                // Introduction screen logic
-                // For now, just allow space or click to return to title
-                if is_key_pressed(KeyCode::Space) || 
-                   is_key_pressed(KeyCode::Escape) || 
-                   is_mouse_button_pressed(MouseButton::Left) {
-                    self.transition_to(GameState::TitleScreen);
-                }
+        }
+    }
+
+    /// Report on scores sent to the online leaderboard once their replies arrive
+    fn poll_submissions(&mut self) {
+        let context = &mut self.context;
+        context.submissions.retain_mut(|pending| match pending.poll() {
+            Some(Ok(())) => {
+                context.toasts.push("Your score was sent to the online leaderboard.");
+                false
+            }
+            Some(Err(e)) => {
+                log::warn!("{}", e);
+                context.toasts.push("The online leaderboard couldn't be reached.");
+                false
             }
-            GameState::Options => {
-                // Options screen logic
-                // For now, just allow space or click to return to title
-                if is_key_pressed(KeyCode::Space) || 
-                   is_key_pressed(KeyCode::Escape) || 
-                   is_mouse_button_pressed(MouseButton::Left) {
-                    self.transition_to(GameState::TitleScreen);
-                }
+            None => true,
+        });
+    }
+
+    /// Run the speedrun clock while a journey is being played, splitting at each landmark
+    ///
+    /// The clock is cleared at the main menu, so it covers new and loaded games alike.
+    fn update_speedrun(&mut self, dt: f32) {
+        let journey = &self.context.journey;
+        match self.state() {
+            Some(GameState::MainMenu) => self.speedrun.reset(),
+            Some(GameState::TitleScreen | GameState::Introduction | GameState::Options | GameState::Setup) | None => {}
+            Some(_) if journey.has_arrived() || journey.party_died() || journey.snowbound_for_winter() => {
+                self.speedrun.finish(journey.date())
             }
-            GameState::MainMenu => {
-                // Main menu logic
-                // Placeholder for menu navigation and selection
-                
-                // For now, just allow escape to return to title
-                if is_key_pressed(KeyCode::Escape) {
-                    self.transition_to(GameState::TitleScreen);
-                }
+            Some(_) => self.speedrun.tick(dt),
+        }
+        for event in self.context.events.poll(self.speedrun_events) {
+            if let GameEvent::LandmarkReached { name } = event {
+                self.speedrun.split(&name);
             }
-            // Other state handling would go here
-            _ => {
-                // For other states, escape returns to title screen
-                if is_key_pressed(KeyCode::Escape) {
-                    self.transition_to(GameState::TitleScreen);
-                }
+        }
+    }
+
+    /// Play the sound effect for each game event since the last frame
+    fn play_event_sounds(&mut self) {
+        for event in self.context.events.poll(self.sound_events) {
+            if let Some(sound) = event_sound(&event).and_then(|name| self.context.assets.get_sound(name)) {
+                audio::play_sfx(sound);
             }
         }
     }
 
+    /// Load the next preloaded asset, playing the opening sequence once all are in
+    async fn preload_step(&mut self) {
+        if let Some(Err(e)) = self.context.assets.load_next().await {
+            log::error!("{}", e);
+        }
+
+        if !self.context.assets.is_loading() {
+            // Bad files are listed before anything else, so the player knows why art is missing
+            let first: Box<dyn Scene> = match self.integrity.is_clean() {
+                true => Box::new(OpeningScene::new()),
+                false => Box::new(AssetCheckScene::new(&self.integrity)),
+            };
+            self.scenes.request(SceneTransition::Push(first));
+            self.scenes.apply_pending(&mut self.context).await;
+            self.assets_loaded = true;
+        }
+    }
+
+    /// Tell the player about assets replaced by placeholders since last time
+    fn report_missing_assets(&mut self) {
+        let missing = self.context.assets.missing();
+        if let Some(report) = fallback::missing_report(&missing[self.missing_reported..]) {
+            self.context.toasts.push(report);
+        }
+        self.missing_reported = missing.len();
+    }
+
     /// Render the current game state
     pub fn render(&self) {
+        display::begin_frame();
+        renderer::begin_frame();
+        self.render_scene();
+        let (cursor, position) = self.cursor();
+        cursor::draw(cursor, position, &self.context.assets);
+        display::end_frame();
+    }
+
+    /// Cursor for what's on screen: the hourglass while loading, otherwise
+    /// whatever the active scene asks for
+    fn cursor(&self) -> (Cursor, Option<Vec2>) {
+        if self.context.assets.fatal_error().is_some() {
+            (Cursor::Arrow, None)
+        } else if !self.assets_loaded {
+            (Cursor::Hourglass, None)
+        } else {
+            self.scenes.cursor()
+        }
+    }
+
+    /// Render the loading screen or the scene stack onto the canvas
+    fn render_scene(&self) {
+        if let Some(error) = self.context.assets.fatal_error() {
+            self.draw_error_screen(&error.to_string());
+            return;
+        }
+
         if !self.assets_loaded {
-            // Display loading screen
-            let text = "Loading resources...";
-            let font_size = 30.0;
-            let text_size = measure_text(text, None, font_size as u16, 1.0);
-            
-            draw_text(
-                text,
-                screen_width() / 2.0 - text_size.width / 2.0,
-                screen_height() / 2.0,
-                font_size,
-                WHITE,
-            );
+            self.draw_loading_screen();
             return;
         }
-        
-        match self.state {
-            GameState::TitleScreen => {
-                // Render title screen
-                if let Some(title_screen) = &self.title_screen {
-                    title_screen.draw();
-                }
-            }
-            GameState::Introduction => {
-                // Render introduction screen (placeholder)
-                clear_background(BLACK);
-                draw_text(
-                    "Introduction Screen",
-                    screen_width() / 2.0 - 100.0,
-                    50.0,
-                    30.0,
-                    WHITE,
-                );
-                
-                draw_text(
-                    "Learn about the Oregon Trail and its history",
-                    screen_width() / 2.0 - 200.0,
-                    100.0,
-                    20.0,
-                    WHITE,
-                );
-                
-                draw_text(
-                    "Press any key to return to the title screen",
-                    screen_width() / 2.0 - 180.0,
-                    screen_height() - 50.0,
-                    20.0,
-                    GRAY,
-                );
-            }
-            GameState::Options => {
-                // Render options screen (placeholder)
-                clear_background(BLACK);
-                draw_text(
-                    "Options Screen",
-                    screen_width() / 2.0 - 80.0,
-                    50.0,
-                    30.0,
-                    WHITE,
-                );
-                
-                draw_text(
-                    "Adjust game settings here",
-                    screen_width() / 2.0 - 120.0,
-                    100.0,
-                    20.0,
-                    WHITE,
-                );
-                
-                draw_text(
-                    "Press any key to return to the title screen",
-                    screen_width() / 2.0 - 180.0,
-                    screen_height() - 50.0,
-                    20.0,
-                    GRAY,
-                );
-            }
-            GameState::MainMenu => {
-                // Render main menu
-                clear_background(BLACK);
-                draw_text(
-                    "Main Menu",
-                    screen_width() / 2.0 - 50.0,
-                    50.0,
-                    30.0,
-                    WHITE,
-                );
-                
-                draw_text(
-                    "1. Start New Game",
-                    screen_width() / 2.0 - 80.0,
-                    150.0,
-                    20.0,
-                    WHITE,
-                );
-                
-                draw_text(
-                    "2. Load Saved Game",
-                    screen_width() / 2.0 - 85.0,
-                    190.0,
-                    20.0,
-                    WHITE,
-                );
-                
-                draw_text(
-                    "3. Learn About The Trail",
-                    screen_width() / 2.0 - 110.0,
-                    230.0,
-                    20.0,
-                    WHITE,
-                );
-                
-                draw_text(
-                    "Press ESC to return to title screen",
-                    screen_width() / 2.0 - 150.0,
-                    screen_height() - 50.0,
-                    20.0,
-                    GRAY,
-                );
-            }
-            // Other state rendering would go here
-            _ => {
-                // Placeholder for other screens
-                clear_background(BLACK);
-                draw_text(
-                    &format!("{:?} Screen", self.state),
-                    screen_width() / 2.0 - 100.0,
-                    screen_height() / 2.0,
-                    30.0,
-                    WHITE,
-                );
-                
-                draw_text(
-                    "Press ESC to return to title screen",
-                    screen_width() / 2.0 - 150.0,
-                    screen_height() - 50.0,
-                    20.0,
-                    GRAY,
-                );
-            }
+
+        self.scenes.draw(&self.context);
+        if self.context.settings.speedrun {
+            self.draw_speedrun_overlay();
+        }
+        self.context.toasts.draw();
+        self.overlay.draw(&self.context, self.state());
+        self.console.draw();
+    }
+
+    /// Draw the speedrun clock, recent splits and the journey's seed in the corner
+    fn draw_speedrun_overlay(&self) {
+        const SPLITS_SHOWN: usize = 3;
+        let rect = Rect::new(screen_width() - 190.0, screen_height() - 130.0, 180.0, 120.0);
+        draw_rectangle(rect.x, rect.y, rect.w, rect.h, style::theme().panel);
+
+        let timer = &self.speedrun;
+        let mut y = rect.y + 24.0;
+        draw_text(&speedrun::format_time(timer.elapsed()), rect.x + 8.0, y, 26.0, style::theme().focus);
+        let splits = timer.splits();
+        for split in &splits[splits.len().saturating_sub(SPLITS_SHOWN)..] {
+            y += 18.0;
+            let name: String = split.landmark.chars().take(14).collect();
+            let line = format!("{:<14} {}", name, speedrun::format_time(split.time));
+            draw_text(&line, rect.x + 8.0, y, 16.0, style::theme().text);
         }
+        if let Some(date) = timer.finished_on() {
+            draw_text(&format!("Ended {}", date), rect.x + 8.0, rect.y + rect.h - 24.0, 16.0, style::theme().text);
+        }
+        let seed = format!("Seed {}", self.context.rng.seed());
+        draw_text(&seed, rect.x + 8.0, rect.y + rect.h - 8.0, 16.0, style::theme().text_disabled);
+    }
+
+    /// Draw the preload progress bar
+    fn draw_loading_screen(&self) {
+        loading::draw_loading_screen(self.context.assets.progress(), None);
+    }
+
+    /// Draw the screen shown after a fatal asset error
+    fn draw_error_screen(&self, message: &str) {
+        clear_background(BLACK);
+        draw_text("The game could not load its files.", 40.0, 80.0, 30.0, style::theme().text);
+        draw_text(message, 40.0, 130.0, style::FONT_SIZE, style::theme().danger);
+        draw_text(
+            "Check the assets directory, then press Esc to quit.",
+            40.0,
+            screen_height() - 40.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+
+    /// Get the state of the active scene
+    pub fn state(&self) -> Option<GameState> {
+        self.scenes.current_state()
+    }
+
+    /// Switch between a window and the screen, keeping the choice for next time
+    fn toggle_fullscreen(&mut self) {
+        let settings = &mut self.context.settings;
+        settings.window_mode = settings.window_mode.toggled();
+        self.context.save_settings();
+        // Enter would otherwise also confirm whatever menu is up
+        input::suppress();
+    }
+
+    /// Choose whether asset failures fall back to placeholder art or stop the game
+    pub fn set_asset_error_policy(&mut self, policy: impl Fn(&AssetError) -> ErrorAction + 'static) {
+        self.context.assets.set_error_policy(policy);
     }
 
-    /// Transition to a new game state
-    pub fn transition_to(&mut self, new_state: GameState) {
-        println!("Transitioning from {:?} to {:?}", self.state, new_state);
-        self.state = new_state;
-        
-        // Additional state transition logic could be added here
-        // For example, loading resources, playing transition sounds, etc.
+    /// Set the places assets are looked for, in search order
+    pub fn set_asset_roots(&mut self, roots: Vec<AssetRoot>) {
+        self.context.assets.set_roots(roots);
     }
 
     /// Check if the game is requesting to exit
     pub fn is_exit_requested(&self) -> bool {
-        self.exit_requested
+        self.context.exit_requested
     }
-    
-    /// Load all game assets
+
+    /// Load saved settings and start preloading the assets in the manifest
     pub async fn load_assets(&mut self) {
-        // Load title screen assets
-        if let Some(title_screen) = &mut self.title_screen {
-            title_screen.load_assets(&mut self.asset_manager).await;
+        // Load saved settings and control bindings
+        match Settings::load(self.context.storage.as_ref(), SETTINGS_FILE) {
+            Ok(settings) => self.context.settings = settings,
+            Err(e) => log::error!("{}", e),
+        }
+
+        if let Err(e) = input::load_bindings(self.context.storage.as_ref()) {
+            log::error!("{}", e);
         }
-        
-        // Mark assets as loaded
-        self.assets_loaded = true;
+
+        self.integrity = self.context.assets.verify_integrity();
+
+        // Queue the manifest's preload groups; `update` loads one per frame
+        let queued = match self.context.assets.load_manifest().await {
+            Ok(()) => self.context.assets.queue_preload(),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = queued {
+            log::error!("{}", e);
+        }
+    }
+}
+
+/// Sound effect played when a game event happens
+fn event_sound(event: &GameEvent) -> Option<&'static str> {
+    match event {
+        GameEvent::PartyMemberDied { .. } => Some("death.wav"),
+        GameEvent::RiverCrossed { .. } => Some("river.wav"),
+        GameEvent::LandmarkReached { .. } => Some("landmark.wav"),
+        GameEvent::SuppliesLow { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transitions_follow_the_flow_of_the_game() {
+        assert!(GameState::TitleScreen.can_transition_to(GameState::MainMenu));
+        assert!(GameState::Landmark.can_transition_to(GameState::RiverCrossing));
+        assert!(GameState::Trading.can_transition_to(GameState::TitleScreen));
+        assert!(!GameState::TitleScreen.can_transition_to(GameState::Travel));
+        assert!(!GameState::MainMenu.can_transition_to(GameState::RiverCrossing));
     }
 }
@@ -0,0 +1,147 @@
+// Achievements earned by finishing journeys in particular ways. They are
+// kept on disk so they carry over from one game to the next.
+
+use std::collections::BTreeSet;
+use serde::{Deserialize, Serialize};
+use crate::game_logic::journey::Journey;
+use crate::game_logic::occupation::Occupation;
+use crate::game_logic::river::CrossingMethod;
+use crate::utils::storage::Storage;
+use crate::utils::versioned::{self, Migration};
+
+/// File unlocked achievements are kept in between games
+pub const ACHIEVEMENTS_FILE: &str = "achievements.json";
+
+/// Changes to the format unlocked achievements are kept in, one per version
+const MIGRATIONS: &[Migration] = &[versioned::unversioned];
+
+/// Month by which the party must reach Oregon to arrive early
+pub const EARLY_ARRIVAL_MONTH: u8 = 10;
+
+/// Something a journey can be remembered for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Achievement {
+    ArrivedBeforeOctober,
+    NoDeaths,
+    FordedEveryRiver,
+    BankerOnHard,
+}
+
+impl Achievement {
+    /// All achievements in gallery order
+    pub const ALL: [Achievement; 4] = [
+        Achievement::ArrivedBeforeOctober,
+        Achievement::NoDeaths,
+        Achievement::FordedEveryRiver,
+        Achievement::BankerOnHard,
+    ];
+
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Achievement::ArrivedBeforeOctober => "Arrived before October",
+            Achievement::NoDeaths => "No deaths",
+            Achievement::FordedEveryRiver => "Crossed every river by fording",
+            Achievement::BankerOnHard => "Banker on Hard",
+        }
+    }
+
+    /// How the achievement is earned
+    pub fn description(&self) -> &'static str {
+        match self {
+            Achievement::ArrivedBeforeOctober => "Reach Oregon before the first of October.",
+            Achievement::NoDeaths => "Reach Oregon with the whole party alive.",
+            Achievement::FordedEveryRiver => "Reach Oregon having forded every river on the way.",
+            Achievement::BankerOnHard => "Reach Oregon as a banker on Hard difficulty.",
+        }
+    }
+
+    /// Whether a finished journey earns the achievement
+    fn earned_by(&self, journey: &Journey, hard: bool) -> bool {
+        match self {
+            Achievement::ArrivedBeforeOctober => journey.date().month() < EARLY_ARRIVAL_MONTH,
+            Achievement::NoDeaths => journey.player.party.iter().all(|m| m.is_alive()),
+            Achievement::FordedEveryRiver => {
+                !journey.crossings.is_empty() && journey.crossings.iter().all(|&m| m == CrossingMethod::Ford)
+            }
+            Achievement::BankerOnHard => hard && journey.player.occupation == Occupation::Banker,
+        }
+    }
+}
+
+/// Achievements a journey has earned, which is none until the party reaches
+/// Oregon, or at all if it used cheats
+pub fn earned(journey: &Journey, hard: bool) -> Vec<Achievement> {
+    if !journey.has_arrived() || journey.cheated {
+        return Vec::new();
+    }
+    Achievement::ALL.into_iter().filter(|a| a.earned_by(journey, hard)).collect()
+}
+
+/// Every achievement unlocked so far
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Achievements {
+    unlocked: BTreeSet<Achievement>,
+}
+
+impl Achievements {
+    /// Load the unlocked achievements, or start with none if there is nothing stored yet
+    pub fn load(storage: &dyn Storage, name: &str) -> Result<Self, String> {
+        match storage.read(name)? {
+            Some(json) => versioned::from_json(&json, MIGRATIONS).map_err(|e| format!("Failed to read achievements: {} ({})", e, name)),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Write the unlocked achievements to storage
+    pub fn save(&self, storage: &mut dyn Storage, name: &str) -> Result<(), String> {
+        let json = versioned::to_json(self, MIGRATIONS).map_err(|e| format!("Failed to write achievements: {}", e))?;
+        storage.write(name, &json)
+    }
+
+    /// Unlock an achievement, returning whether it is new
+    pub fn unlock(&mut self, achievement: Achievement) -> bool {
+        self.unlocked.insert(achievement)
+    }
+
+    /// Whether an achievement has been unlocked
+    pub fn is_unlocked(&self, achievement: Achievement) -> bool {
+        self.unlocked.contains(&achievement)
+    }
+
+    /// Number of achievements unlocked
+    pub fn len(&self) -> usize {
+        self.unlocked.len()
+    }
+
+    /// Whether nothing has been unlocked yet
+    pub fn is_empty(&self) -> bool {
+        self.unlocked.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::tables::tables;
+
+    #[test]
+    fn achievements_are_earned_on_arrival() {
+        let mut journey = Journey::default();
+        assert!(earned(&journey, true).is_empty());
+
+        journey.landmark = tables().landmarks.len() - 1;
+        journey.crossings = vec![CrossingMethod::Ford, CrossingMethod::Ferry];
+        journey.player.occupation = Occupation::Banker;
+        let all = earned(&journey, true);
+        assert!(all.contains(&Achievement::NoDeaths) && all.contains(&Achievement::BankerOnHard));
+        assert!(!all.contains(&Achievement::FordedEveryRiver));
+        assert!(!earned(&journey, false).contains(&Achievement::BankerOnHard));
+
+        let mut achievements = Achievements::default();
+        assert!(achievements.unlock(Achievement::NoDeaths));
+        assert!(!achievements.unlock(Achievement::NoDeaths));
+        assert_eq!(achievements.len(), 1);
+    }
+}
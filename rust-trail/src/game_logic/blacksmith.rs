@@ -0,0 +1,93 @@
+// Blacksmiths at the forts, who mend broken wagon parts for money so the
+// party doesn't have to use up its spares
+
+use serde::{Deserialize, Serialize};
+use crate::game_logic::diary::EntryKind;
+use crate::game_logic::events::part_name;
+use crate::game_logic::journey::Journey;
+use crate::game_logic::money::Money;
+use crate::utils::rng::GameRng;
+
+/// What a fort's blacksmith charges, from the tables
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BlacksmithBaseline {
+    /// Price of mending one part
+    pub cost: Money,
+    /// Days the party waits while the work is done
+    pub days: u32,
+}
+
+/// Price and wait for a job
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quote {
+    /// Price of mending every broken part
+    pub cost: Money,
+    /// Days the party waits
+    pub days: u32,
+}
+
+/// The blacksmith at the fort the party is stopped at, if there is one
+pub fn at(journey: &Journey) -> Option<BlacksmithBaseline> {
+    if !journey.at_fort() {
+        return None;
+    }
+    journey.last_landmark().blacksmith
+}
+
+/// What the blacksmith here would charge to mend the party's broken parts
+///
+/// None if there is no blacksmith here or nothing needs mending.
+pub fn quote(journey: &Journey) -> Option<Quote> {
+    let smith = at(journey)?;
+    if journey.broken_parts.is_empty() {
+        return None;
+    }
+    Some(Quote { cost: smith.cost * journey.broken_parts.len() as u32, days: smith.days })
+}
+
+/// Have the broken parts mended, paying up and waiting out the work
+pub fn repair(journey: &mut Journey, rng: &mut GameRng) -> Result<Quote, String> {
+    let Some(quote) = quote(journey) else {
+        return Err("Your wagon doesn't need any repairs.".to_string());
+    };
+    let Some(money) = journey.player.money.checked_sub(quote.cost) else {
+        return Err("You don't have enough money.".to_string());
+    };
+
+    journey.player.money = money;
+    journey.stats.money_spent += quote.cost;
+    for _ in 0..quote.days {
+        journey.camp_day(rng);
+    }
+    let parts: Vec<&str> = journey.broken_parts.drain(..).map(part_name).collect();
+    let text = format!("The blacksmith mended the broken {} for {}.", parts.join(" and "), quote.cost);
+    journey.record(EntryKind::Note, text);
+    Ok(quote)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::inventory::ItemType;
+    use crate::game_logic::landmarks;
+
+    #[test]
+    fn blacksmiths_mend_parts_for_money_and_time() {
+        let mut journey = Journey::default();
+        journey.landmark = landmarks::index_of("fort_bridger").unwrap();
+        journey.player.miles_traveled = journey.last_landmark().miles;
+        assert!(quote(&journey).is_none());
+
+        journey.broken_parts = vec![ItemType::SpareWheel, ItemType::SpareAxle];
+        let smith = at(&journey).unwrap();
+        let quote = quote(&journey).unwrap();
+        assert_eq!(quote.cost, smith.cost * 2);
+
+        let (money, days) = (journey.player.money, journey.days);
+        assert_eq!(repair(&mut journey, &mut GameRng::new(2)), Ok(quote));
+        assert!(journey.broken_parts.is_empty());
+        assert_eq!(journey.player.money, money.saturating_sub(quote.cost));
+        assert_eq!(journey.days, days + quote.days);
+        assert!(repair(&mut journey, &mut GameRng::new(2)).is_err());
+    }
+}
@@ -0,0 +1,72 @@
+// Cheat codes typed on the travel screen. They only work with cheats turned
+// on in the options, and a journey that uses one is kept off the records:
+// it earns no achievements and sets no best score.
+
+use crate::game_logic::journey::{Journey, DEFAULT_SUPPLIES};
+
+/// Something a cheat code does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cheat {
+    RefillSupplies,
+    CureParty,
+}
+
+impl Cheat {
+    /// All cheats
+    pub const ALL: [Cheat; 2] = [Cheat::RefillSupplies, Cheat::CureParty];
+
+    /// Phrase typed to use the cheat
+    ///
+    /// Phrases steer clear of the travel screen's own keys, D and Space.
+    pub fn phrase(&self) -> &'static str {
+        match self {
+            Cheat::RefillSupplies => "provisions",
+            Cheat::CureParty => "snakeoil",
+        }
+    }
+
+    /// Use the cheat on the journey, returning what it did
+    pub fn apply(&self, journey: &mut Journey) -> &'static str {
+        journey.cheated = true;
+        match self {
+            Cheat::RefillSupplies => {
+                for (item, quantity) in DEFAULT_SUPPLIES {
+                    let have = journey.inventory.get_quantity(item);
+                    journey.inventory.add_item(item, quantity.saturating_sub(have));
+                }
+                "Supplies refilled"
+            }
+            Cheat::CureParty => {
+                for member in journey.player.party.iter_mut() {
+                    member.diseases.clear();
+                    member.set_general_health(0.0);
+                }
+                "Party cured"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::inventory::ItemType;
+    use crate::game_logic::player::{Disease, HealthStatus};
+
+    #[test]
+    fn cheats_help_the_party_and_mark_the_journey() {
+        let mut journey = Journey::default();
+        journey.inventory.remove_item(ItemType::Food, 1000);
+        journey.player.party[0].contract_disease(Disease::Cholera);
+        journey.player.party[0].set_general_health(100.0);
+        assert!(!journey.cheated);
+
+        Cheat::RefillSupplies.apply(&mut journey);
+        assert_eq!(journey.inventory.get_quantity(ItemType::Food), 1600);
+        Cheat::CureParty.apply(&mut journey);
+        assert!(journey.player.party[0].diseases.is_empty());
+        assert_eq!(journey.player.party[0].health, HealthStatus::Good);
+        assert!(journey.cheated);
+        assert!(Cheat::ALL.iter().all(|c| !c.phrase().contains(['d', ' '])));
+    }
+}
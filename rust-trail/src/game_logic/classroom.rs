@@ -0,0 +1,169 @@
+// Classroom mode: a teacher keeps a class list, the students take turns
+// travelling the trail, and each finished journey is written up in a session
+// report for the student who played it.
+
+use serde::{Deserialize, Serialize};
+use crate::game_logic::journey::Journey;
+use crate::game_logic::score;
+use crate::utils::storage::{self, Storage};
+use crate::utils::versioned::{self, Migration};
+
+/// File the class list is kept in between games
+pub const CLASSROOM_FILE: &str = "classroom.json";
+
+/// Changes to the format the class list is kept in, one per version
+const MIGRATIONS: &[Migration] = &[];
+
+/// Most students on the class list
+pub const MAX_STUDENTS: usize = 40;
+
+/// Longest student name
+pub const MAX_NAME_LEN: usize = 20;
+
+/// Name of the file a student's session reports are written to
+pub fn report_file(student: &str) -> String {
+    format!("report_{}.txt", storage::safe_name(student))
+}
+
+/// A finished journey written up for the student who played it
+///
+/// `outcome` says how the journey ended. The diary, which records the
+/// student's choices along the way, follows the summary.
+pub fn session_report(student: &str, journey: &Journey, outcome: &str) -> String {
+    let player = &journey.player;
+    let survivors = player.party.iter().filter(|m| m.is_alive()).count();
+    let mut report = format!("Session report: {}\n{}\n", student, "=".repeat(16 + student.chars().count()));
+    report.push_str(&format!("Outcome: {}\n", outcome.replace('\n', " ")));
+    report.push_str(&format!("Occupation: {}\n", player.occupation.name()));
+    report.push_str(&format!("Final date: {} after {} days\n", player.date, journey.days));
+    report.push_str(&format!("Miles travelled: {:.0}\n", player.miles_traveled));
+    report.push_str(&format!("Survivors: {} of {}\n", survivors, player.party.len()));
+    report.push_str(&format!("Score: {}\n\n", score::final_score(journey)));
+    report.push_str(&journey.diary.report("Decisions and events"));
+    report
+}
+
+/// The class list, and whose turn it is
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Classroom {
+    students: Vec<String>,
+    /// Index of the student whose turn is next
+    turn: usize,
+    /// Whether tombstones keep their plain words instead of ones players write
+    #[serde(default)]
+    plain_tombstones: bool,
+}
+
+impl Classroom {
+    /// Load the class list, or start with an empty one if there is nothing stored yet
+    pub fn load(storage: &dyn Storage, name: &str) -> Result<Self, String> {
+        match storage.read(name)? {
+            Some(json) => versioned::from_json(&json, MIGRATIONS).map_err(|e| format!("Failed to read the class list: {} ({})", e, name)),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Write the class list to storage
+    pub fn save(&self, storage: &mut dyn Storage, name: &str) -> Result<(), String> {
+        let json = versioned::to_json(self, MIGRATIONS).map_err(|e| format!("Failed to write the class list: {}", e))?;
+        storage.write(name, &json)
+    }
+
+    /// Whether classroom mode is on, which it is while anyone is on the list
+    pub fn is_active(&self) -> bool {
+        !self.students.is_empty()
+    }
+
+    /// The students, in turn order
+    pub fn students(&self) -> &[String] {
+        &self.students
+    }
+
+    /// Put a student on the list, returning false if the name is blank, taken
+    /// or the list is full
+    pub fn add(&mut self, name: &str) -> bool {
+        let name: String = name.trim().chars().take(MAX_NAME_LEN).collect();
+        if name.is_empty() || self.students.len() >= MAX_STUDENTS || self.students.iter().any(|s| s.eq_ignore_ascii_case(&name)) {
+            return false;
+        }
+        self.students.push(name);
+        true
+    }
+
+    /// Take a student off the list
+    pub fn remove(&mut self, index: usize) {
+        if index >= self.students.len() {
+            return;
+        }
+        self.students.remove(index);
+        if index < self.turn {
+            self.turn -= 1;
+        }
+        if self.turn >= self.students.len() {
+            self.turn = 0;
+        }
+    }
+
+    /// Whether players may write the words on their leader's tombstone
+    pub fn epitaphs_allowed(&self) -> bool {
+        !self.plain_tombstones
+    }
+
+    /// Let players write on tombstones, or keep them to the plain words
+    pub fn set_epitaphs_allowed(&mut self, allowed: bool) {
+        self.plain_tombstones = !allowed;
+    }
+
+    /// Student whose turn is next, if there is a class
+    pub fn student_up(&self) -> Option<&str> {
+        self.students.get(self.turn).map(String::as_str)
+    }
+
+    /// Pass the turn to the next student on the list
+    pub fn finish_turn(&mut self) {
+        if !self.students.is_empty() {
+            self.turn = (self.turn + 1) % self.students.len();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::storage::MemoryStorage;
+
+    #[test]
+    fn students_take_turns() {
+        let mut class = Classroom::default();
+        assert!(!class.is_active());
+        assert!(class.add(" Ada "));
+        assert!(class.add("Ben"));
+        assert!(class.add("Cy"));
+        assert!(!class.add("ben"));
+        assert!(!class.add("   "));
+        assert_eq!(class.student_up(), Some("Ada"));
+        class.finish_turn();
+        class.finish_turn();
+        assert_eq!(class.student_up(), Some("Cy"));
+        class.remove(0);
+        assert_eq!(class.student_up(), Some("Cy"));
+        class.finish_turn();
+        assert_eq!(class.student_up(), Some("Ben"));
+        assert!(class.epitaphs_allowed());
+        class.set_epitaphs_allowed(false);
+
+        let mut storage = MemoryStorage::default();
+        class.save(&mut storage, CLASSROOM_FILE).unwrap();
+        assert_eq!(Classroom::load(&storage, CLASSROOM_FILE), Ok(class));
+        assert_eq!(report_file("Mary Ann"), "report_mary_ann.txt");
+    }
+
+    #[test]
+    fn report_sums_up_the_journey() {
+        let journey = Journey::new("Ada", &["Ben"], &[]);
+        let report = session_report("Ada", &journey, "Everyone in your party has died.");
+        assert!(report.starts_with("Session report: Ada\n"));
+        assert!(report.contains("Survivors: 2 of 2"));
+        assert!(report.contains("Decisions and events"));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::game_logic::landmarks::Zone;
+use crate::game_logic::weather::Weather;
+
+/// Sets of clothing each member needs to keep warm in the cold
+pub const WARM_SETS_PER_PERSON: f32 = 2.0;
+
+/// Daily chance of each member wearing out a set of clothing while travelling
+pub const WEAR_CHANCE: f32 = 0.002;
+
+/// Points of ill health added each day the party is cold without enough clothing
+pub const EXPOSURE_PENALTY: f32 = 3.0;
+
+/// Factor applied to the chance of falling ill while short of warm clothing
+pub const EXPOSURE_ILLNESS_FACTOR: f32 = 2.0;
+
+/// Whether a month (1-12) is cold enough to need warm clothing
+pub fn is_cold_month(month: u8) -> bool {
+    matches!(month, 1 | 2 | 11 | 12)
+}
+
+/// Whether the party needs warm clothing in the given month, weather and part of the trail
+pub fn needs_warm_clothing(month: u8, weather: Weather, zone: Zone) -> bool {
+    is_cold_month(month) || weather.is_cold() || zone == Zone::Mountains
+}
+
+/// Sets of clothing for each living member
+pub fn sets_per_person(sets: u32, living: usize) -> f32 {
+    if living == 0 {
+        return 0.0;
+    }
+    sets as f32 / living as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mountains_and_winter_need_warm_clothing() {
+        assert!(needs_warm_clothing(12, Weather::Warm, Zone::Plains));
+        assert!(needs_warm_clothing(7, Weather::Warm, Zone::Mountains));
+        assert!(needs_warm_clothing(7, Weather::Snow, Zone::Plains));
+        assert!(!needs_warm_clothing(7, Weather::Hot, Zone::Plains));
+        assert_eq!(sets_per_person(5, 2), 2.5);
+    }
+}
@@ -0,0 +1,222 @@
+// Developer commands that poke at a journey directly, for testing situations
+// that would take a long time to reach by playing. The debug console runs
+// them, but they live here so they work without a window.
+
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::journey::Journey;
+use crate::game_logic::landmarks;
+use crate::game_logic::money::Money;
+use crate::game_logic::weather::Weather;
+use crate::utils::rng::GameRng;
+
+/// Most days `advance` will travel in one go
+pub const MAX_ADVANCE_DAYS: u32 = 365;
+
+/// Runs a command's arguments against the journey, returning its reply
+pub type Handler = fn(&[&str], &mut Journey, &mut GameRng) -> Result<String, String>;
+
+/// A command the console understands
+#[derive(Debug, Clone, Copy)]
+pub struct Command {
+    /// Word that runs the command
+    pub name: &'static str,
+    /// Arguments it takes, for help and error messages
+    pub usage: &'static str,
+    /// What it does
+    handler: Handler,
+}
+
+/// Every command the console understands, looked up by name
+#[derive(Debug, Clone, Default)]
+pub struct CommandRegistry {
+    commands: Vec<Command>,
+}
+
+impl CommandRegistry {
+    /// Create a registry with no commands
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry with the standard debug commands
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register("give", "give <item> <quantity>", give);
+        registry.register("teleport", "teleport <landmark id>", teleport);
+        registry.register("set", "set <weather|money|morale> <value>", set);
+        registry.register("heal", "heal", heal);
+        registry.register("advance", "advance <days>", advance);
+        registry.register("seed", "seed <value>", seed);
+        registry
+    }
+
+    /// Add a command, replacing any with the same name
+    pub fn register(&mut self, name: &'static str, usage: &'static str, handler: Handler) {
+        self.commands.retain(|c| c.name != name);
+        self.commands.push(Command { name, usage, handler });
+    }
+
+    /// Registered commands, in the order they were added
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// Run a command line against the journey
+    pub fn run(&self, line: &str, journey: &mut Journey, rng: &mut GameRng) -> Result<String, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&name, args)) = words.split_first() else {
+            return Ok(String::new());
+        };
+        if name == "help" {
+            let usages: Vec<&str> = self.commands.iter().map(|c| c.usage).collect();
+            return Ok(usages.join("\n"));
+        }
+        let command = self
+            .commands
+            .iter()
+            .find(|c| c.name == name)
+            .ok_or_else(|| format!("Unknown command '{}'. Try 'help'.", name))?;
+        (command.handler)(args, journey, rng).map_err(|e| format!("{} (usage: {})", e, command.usage))
+    }
+}
+
+/// Parse a number argument
+fn number<T: std::str::FromStr>(arg: Option<&&str>) -> Result<T, String> {
+    let arg = arg.ok_or("Missing a number")?;
+    arg.parse().map_err(|_| format!("'{}' is not a number", arg))
+}
+
+/// Item named on the command line, by a short name like "food" or "oxen"
+fn item(name: &str) -> Option<ItemType> {
+    match name {
+        "food" => Some(ItemType::Food),
+        "clothing" | "clothes" => Some(ItemType::Clothing),
+        "ammo" | "ammunition" | "bullets" => Some(ItemType::Ammunition),
+        "oxen" => Some(ItemType::OxenPair),
+        "wheel" | "wheels" => Some(ItemType::SpareWheel),
+        "axle" | "axles" => Some(ItemType::SpareAxle),
+        "tongue" | "tongues" => Some(ItemType::SpareTongue),
+        "medicine" => Some(ItemType::MedicalSupply),
+        _ => None,
+    }
+}
+
+/// Put supplies in the wagon, whether or not they fit
+fn give(args: &[&str], journey: &mut Journey, _rng: &mut GameRng) -> Result<String, String> {
+    let name = args.first().ok_or("Missing an item")?;
+    let item = item(name).ok_or_else(|| format!("Unknown item '{}'", name))?;
+    let quantity: u32 = number(args.get(1))?;
+    journey.inventory.add_item(item, quantity);
+    Ok(format!("Gave {} {}.", quantity, item.name()))
+}
+
+/// Move the party straight to a landmark
+fn teleport(args: &[&str], journey: &mut Journey, _rng: &mut GameRng) -> Result<String, String> {
+    let id = args.first().ok_or("Missing a landmark")?;
+    let index = landmarks::index_of(id).ok_or_else(|| format!("Unknown landmark '{}'", id))?;
+    journey.landmark = index;
+    let landmark = journey.last_landmark();
+    let (miles, name) = (landmark.miles, landmark.name.clone());
+    journey.player.miles_traveled = miles;
+    journey.player.location = name.clone();
+    Ok(format!("Moved to {} ({:.0} miles).", name, miles))
+}
+
+/// Change one of the journey's values
+fn set(args: &[&str], journey: &mut Journey, _rng: &mut GameRng) -> Result<String, String> {
+    match args.first().copied() {
+        Some("weather") => {
+            let name = args[1..].join(" ").replace('_', " ");
+            let weather = Weather::ALL
+                .into_iter()
+                .find(|w| w.name() == name)
+                .ok_or_else(|| format!("Unknown weather '{}'", name))?;
+            journey.weather = weather;
+            Ok(format!("The weather is now {}.", weather.name()))
+        }
+        Some("money") => {
+            let dollars: u32 = number(args.get(1))?;
+            if dollars > Money::MAX_DOLLARS {
+                return Err(format!("Money can't be more than ${}", Money::MAX_DOLLARS));
+            }
+            journey.player.money = Money::from_dollars(dollars);
+            Ok(format!("You now have {}.", journey.player.money))
+        }
+        Some("morale") => {
+            let value: f32 = number(args.get(1))?;
+            journey.morale.change(value - journey.morale.value());
+            Ok(format!("Morale is now {:.0}.", journey.morale.value()))
+        }
+        Some(other) => Err(format!("Can't set '{}'", other)),
+        None => Err("Missing what to set".to_string()),
+    }
+}
+
+/// Cure everyone still alive and bring them back to good health
+fn heal(_args: &[&str], journey: &mut Journey, _rng: &mut GameRng) -> Result<String, String> {
+    let mut healed = 0;
+    for member in journey.player.party.iter_mut().filter(|m| m.is_alive()) {
+        member.diseases.clear();
+        member.set_general_health(0.0);
+        healed += 1;
+    }
+    Ok(format!("Healed {} of the party.", healed))
+}
+
+/// Travel a number of days, as if the wagon had been left to roll on
+fn advance(args: &[&str], journey: &mut Journey, rng: &mut GameRng) -> Result<String, String> {
+    let days: u32 = number(args.first())?;
+    let days = days.min(MAX_ADVANCE_DAYS);
+    let start = journey.player.miles_traveled;
+    for _ in 0..days {
+        if journey.has_arrived() || journey.party_died() {
+            break;
+        }
+        journey.travel_day(rng);
+    }
+    Ok(format!("Travelled {:.0} miles. It is now {}.", journey.player.miles_traveled - start, journey.date()))
+}
+
+/// Reseed the journey's random numbers
+fn seed(args: &[&str], _journey: &mut Journey, rng: &mut GameRng) -> Result<String, String> {
+    let text = args.first().ok_or("Missing a seed")?;
+    *rng = GameRng::from_user_seed(text);
+    Ok(format!("Seed is now {}.", rng.seed()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::player::Disease;
+
+    #[test]
+    fn commands_change_the_journey() {
+        let registry = CommandRegistry::with_defaults();
+        let mut journey = Journey::default();
+        let mut rng = GameRng::new(1);
+        let food = journey.inventory.get_quantity(ItemType::Food);
+
+        assert!(registry.run("give food 200", &mut journey, &mut rng).is_ok());
+        assert_eq!(journey.inventory.get_quantity(ItemType::Food), food + 200);
+        assert!(registry.run("teleport fort_laramie", &mut journey, &mut rng).is_ok());
+        assert_eq!(journey.last_landmark().id, "fort_laramie");
+        assert!(registry.run("set weather very_hot", &mut journey, &mut rng).is_ok());
+        assert_eq!(journey.weather, Weather::VeryHot);
+        journey.player.party[0].contract_disease(Disease::Dysentery);
+        assert!(registry.run("heal", &mut journey, &mut rng).is_ok());
+        assert!(!journey.player.party[0].is_sick());
+        let days = journey.days;
+        assert!(registry.run("advance 10", &mut journey, &mut rng).is_ok());
+        assert_eq!(journey.days, days + 10);
+        assert!(registry.run("seed 1234", &mut journey, &mut rng).is_ok());
+        assert_eq!(rng.seed(), GameRng::from_user_seed("1234").seed());
+
+        assert!(registry.run("set money 500", &mut journey, &mut rng).is_ok());
+        assert_eq!(journey.player.money, Money::from_dollars(500));
+        assert!(registry.run("set money 50000000", &mut journey, &mut rng).is_err());
+        assert_eq!(journey.player.money, Money::from_dollars(500));
+
+        assert!(registry.run("give gold 5", &mut journey, &mut rng).is_err());
+        assert!(registry.run("fly", &mut journey, &mut rng).is_err());
+    }
+}
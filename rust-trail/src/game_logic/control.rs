@@ -0,0 +1,248 @@
+// Outside control for streamers. Tools such as a chat bot send one-line
+// commands to vote on the party's decisions or to give it a small piece of
+// good luck; the game queues them and looks at the queue when it comes to a
+// decision. Nothing sent this way can harm the party.
+
+use std::collections::VecDeque;
+use crate::game_logic::diary::EntryKind;
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::journey::Journey;
+use crate::game_logic::player::Pace;
+use crate::game_logic::river::CrossingMethod;
+use crate::game_logic::weather::Weather;
+
+/// Port the control interface listens on unless the settings say otherwise
+pub const DEFAULT_PORT: u16 = 7878;
+
+/// Longest command read
+pub const MAX_COMMAND_LEN: usize = 200;
+
+/// Most good-luck events waiting at once; more are turned away
+pub const MAX_QUEUED_BOONS: usize = 5;
+
+/// Food found by the wild fruit event (in pounds)
+const WILD_FRUIT_FOOD: u32 = 20;
+
+/// Morale gained by the good spirits event
+const GOOD_SPIRITS_MORALE: f32 = 10.0;
+
+/// Crossing methods by the word voted for them
+const CROSSINGS: [(&str, CrossingMethod); 4] = [
+    ("ford", CrossingMethod::Ford),
+    ("float", CrossingMethod::CaulkAndFloat),
+    ("ferry", CrossingMethod::Ferry),
+    ("guide", CrossingMethod::Guide),
+];
+
+/// A decision outsiders can vote on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Pace,
+    River,
+}
+
+impl Decision {
+    /// All decisions
+    pub const ALL: [Decision; 2] = [Decision::Pace, Decision::River];
+
+    /// Word the decision is voted on by
+    pub fn id(&self) -> &'static str {
+        match self {
+            Decision::Pace => "pace",
+            Decision::River => "river",
+        }
+    }
+
+    /// Words that can be voted for
+    pub fn choices(&self) -> Vec<&'static str> {
+        match self {
+            Decision::Pace => Pace::ALL.iter().map(Pace::name).collect(),
+            Decision::River => CROSSINGS.iter().map(|(word, _)| *word).collect(),
+        }
+    }
+}
+
+/// Pace voted for by a word
+pub fn pace_vote(choice: &str) -> Option<Pace> {
+    Pace::ALL.into_iter().find(|pace| pace.name() == choice)
+}
+
+/// Crossing method voted for by a word
+pub fn crossing_vote(choice: &str) -> Option<CrossingMethod> {
+    CROSSINGS.iter().find(|(word, _)| *word == choice).map(|&(_, method)| method)
+}
+
+/// A small piece of good luck outsiders can send the party
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boon {
+    WildFruit,
+    GoodSpirits,
+    FairWeather,
+}
+
+impl Boon {
+    /// All good-luck events
+    pub const ALL: [Boon; 3] = [Boon::WildFruit, Boon::GoodSpirits, Boon::FairWeather];
+
+    /// Word the event is sent by
+    pub fn id(&self) -> &'static str {
+        match self {
+            Boon::WildFruit => "fruit",
+            Boon::GoodSpirits => "spirits",
+            Boon::FairWeather => "sunshine",
+        }
+    }
+
+    /// Give the party its luck, returning what happened
+    pub fn apply(&self, journey: &mut Journey) -> String {
+        let message = match self {
+            Boon::WildFruit => {
+                let food = WILD_FRUIT_FOOD.min(journey.inventory.room_for(ItemType::Food));
+                journey.inventory.add_item(ItemType::Food, food);
+                format!("You find wild fruit: {} pounds of food.", food)
+            }
+            Boon::GoodSpirits => {
+                journey.morale.change(GOOD_SPIRITS_MORALE);
+                "Songs around the campfire lift the party's spirits.".to_string()
+            }
+            Boon::FairWeather => {
+                journey.weather = Weather::Warm;
+                "The clouds part and the day turns fair.".to_string()
+            }
+        };
+        journey.record(EntryKind::Note, message.clone());
+        message
+    }
+}
+
+/// A command sent from outside the game
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// A vote on a decision, counted once per voter; votes without a voter
+    /// are each counted
+    Vote { decision: Decision, choice: String, voter: Option<String> },
+    /// A good-luck event for the party
+    Boon(Boon),
+}
+
+/// Read a command such as `vote pace grueling alice` or `event fruit`
+pub fn parse(line: &str) -> Result<Command, String> {
+    let line = line.trim().to_lowercase();
+    if line.len() > MAX_COMMAND_LEN {
+        return Err("Command too long".to_string());
+    }
+    let words: Vec<&str> = line.split_whitespace().collect();
+    match words.as_slice() {
+        ["vote", decision, choice, rest @ ..] if rest.len() <= 1 => {
+            let decision = Decision::ALL
+                .into_iter()
+                .find(|d| d.id() == *decision)
+                .ok_or_else(|| format!("No decision '{}'; try pace or river", decision))?;
+            if !decision.choices().contains(choice) {
+                return Err(format!("'{}' isn't a choice for {}; try {}", choice, decision.id(), decision.choices().join(", ")));
+            }
+            Ok(Command::Vote { decision, choice: choice.to_string(), voter: rest.first().map(|v| v.to_string()) })
+        }
+        ["event", boon] => Boon::ALL
+            .into_iter()
+            .find(|b| b.id() == *boon)
+            .map(Command::Boon)
+            .ok_or_else(|| format!("No event '{}'; try fruit, spirits or sunshine", boon)),
+        _ => Err("Commands are 'vote <decision> <choice> [voter]' and 'event <name>'".to_string()),
+    }
+}
+
+/// Votes and good-luck events waiting for the game to get to them
+#[derive(Debug, Clone, Default)]
+pub struct ControlQueue {
+    /// Votes in the order they came, with who cast them
+    votes: Vec<(Decision, String, Option<String>)>,
+    /// Good-luck events, oldest first
+    boons: VecDeque<Boon>,
+}
+
+impl ControlQueue {
+    /// Queue a command; a voter voting again changes their vote
+    pub fn push(&mut self, command: Command) -> Result<(), String> {
+        match command {
+            Command::Vote { decision, choice, voter } => {
+                if voter.is_some() {
+                    self.votes.retain(|(d, _, v)| !(*d == decision && *v == voter));
+                }
+                self.votes.push((decision, choice, voter));
+            }
+            Command::Boon(boon) => {
+                if self.boons.len() >= MAX_QUEUED_BOONS {
+                    return Err("Too many events waiting; try again later".to_string());
+                }
+                self.boons.push_back(boon);
+            }
+        }
+        Ok(())
+    }
+
+    /// Votes for each choice on a decision, most first; ties keep the order
+    /// the choices were first voted for
+    pub fn tally(&self, decision: Decision) -> Vec<(String, u32)> {
+        let mut counts: Vec<(String, u32)> = Vec::new();
+        for (_, choice, _) in self.votes.iter().filter(|(d, _, _)| *d == decision) {
+            match counts.iter_mut().find(|(c, _)| c == choice) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((choice.clone(), 1)),
+            }
+        }
+        counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        counts
+    }
+
+    /// Close the vote on a decision, returning the winning choice if anyone voted
+    pub fn take_winner(&mut self, decision: Decision) -> Option<String> {
+        let winner = self.tally(decision).into_iter().next().map(|(choice, _)| choice);
+        self.votes.retain(|(d, _, _)| *d != decision);
+        winner
+    }
+
+    /// Take the oldest good-luck event waiting
+    pub fn take_boon(&mut self) -> Option<Boon> {
+        self.boons.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands_are_read_and_checked() {
+        assert_eq!(
+            parse("VOTE pace grueling alice"),
+            Ok(Command::Vote { decision: Decision::Pace, choice: "grueling".to_string(), voter: Some("alice".to_string()) })
+        );
+        assert_eq!(parse("event fruit"), Ok(Command::Boon(Boon::WildFruit)));
+        assert!(parse("vote pace sprint").is_err());
+        assert!(parse("event stampede").is_err());
+        assert_eq!(crossing_vote("float"), Some(CrossingMethod::CaulkAndFloat));
+        assert_eq!(pace_vote("steady"), Some(Pace::Steady));
+    }
+
+    #[test]
+    fn each_voter_counts_once_and_the_most_votes_win() {
+        let mut queue = ControlQueue::default();
+        for line in ["vote river ford ann", "vote river ferry bob", "vote river ferry ann", "vote river ford", "vote pace steady"] {
+            queue.push(parse(line).unwrap()).unwrap();
+        }
+        assert_eq!(queue.tally(Decision::River), vec![("ferry".to_string(), 2), ("ford".to_string(), 1)]);
+        assert_eq!(queue.take_winner(Decision::River).as_deref(), Some("ferry"));
+        assert_eq!(queue.take_winner(Decision::River), None);
+        assert_eq!(queue.take_winner(Decision::Pace).as_deref(), Some("steady"));
+
+        for _ in 0..MAX_QUEUED_BOONS {
+            queue.push(Command::Boon(Boon::GoodSpirits)).unwrap();
+        }
+        assert!(queue.push(Command::Boon(Boon::FairWeather)).is_err());
+        let mut journey = Journey::default();
+        let food = journey.inventory.get_quantity(ItemType::Food);
+        Boon::WildFruit.apply(&mut journey);
+        assert!(journey.inventory.get_quantity(ItemType::Food) > food);
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::game_logic::time::TrailDate;
+
+/// File the trip report is written to at the end of a journey
+pub const TRIP_REPORT_FILE: &str = "trip_report.txt";
+
+/// What a diary entry is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EntryKind {
+    Departure,
+    Arrival,
+    Event,
+    Death,
+    RiverCrossing,
+    Note,
+}
+
+/// One dated line in the diary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiaryEntry {
+    /// When it was written
+    #[serde(flatten)]
+    pub date: TrailDate,
+    /// What the entry is about
+    pub kind: EntryKind,
+    /// What happened
+    pub text: String,
+}
+
+/// Journal kept automatically as the party travels
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Diary {
+    entries: Vec<DiaryEntry>,
+}
+
+impl Diary {
+    /// Create an empty diary
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write an entry
+    pub fn record(&mut self, date: TrailDate, kind: EntryKind, text: impl Into<String>) {
+        self.entries.push(DiaryEntry { date, kind, text: text.into() });
+    }
+
+    /// All entries, oldest first
+    pub fn entries(&self) -> &[DiaryEntry] {
+        &self.entries
+    }
+
+    /// Entries of one kind
+    pub fn entries_of(&self, kind: EntryKind) -> impl Iterator<Item = &DiaryEntry> {
+        self.entries.iter().filter(move |e| e.kind == kind)
+    }
+
+    /// Plain-text trip report, with entries grouped under their dates
+    pub fn report(&self, title: &str) -> String {
+        let mut report = format!("{}\n{}\n", title, "=".repeat(title.len()));
+        let mut last_date = None;
+        for entry in &self.entries {
+            if last_date != Some(entry.date) {
+                report.push_str(&format!("\n{}\n", entry.date));
+                last_date = Some(entry.date);
+            }
+            report.push_str(&format!("  {}\n", entry.text));
+        }
+        report
+    }
+
+    /// Write the trip report to a file
+    pub fn export(&self, title: &str, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.report(title))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(month: u8, day: u32, year: u16) -> TrailDate {
+        TrailDate::new(month, day, year).unwrap()
+    }
+
+    #[test]
+    fn report_groups_entries_by_date() {
+        let mut diary = Diary::new();
+        diary.record(date(3, 1, 1848), EntryKind::Departure, "Left Independence.");
+        diary.record(date(3, 6, 1848), EntryKind::Arrival, "Reached the Kansas River.");
+        diary.record(date(3, 6, 1848), EntryKind::RiverCrossing, "Forded the river.");
+
+        let report = diary.report("Trip report");
+        assert_eq!(
+            report,
+            "Trip report\n===========\n\nMarch 1, 1848\n  Left Independence.\n\nMarch 6, 1848\n  Reached the Kansas River.\n  Forded the river.\n"
+        );
+        assert_eq!(diary.entries_of(EntryKind::Arrival).count(), 1);
+    }
+}
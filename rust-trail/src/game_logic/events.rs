@@ -0,0 +1,463 @@
+use rand::Rng;
+use crate::game_logic::diary::EntryKind;
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::journey::Journey;
+use crate::game_logic::landmarks;
+use crate::game_logic::tables::tables;
+
+/// Food burned in a wagon fire (in pounds)
+pub const FIRE_FOOD_LOSS: std::ops::RangeInclusive<u32> = 20..=40;
+
+/// Chance a wagon fire also burns some other supply
+pub const FIRE_EXTRA_LOSS_CHANCE: f32 = 0.5;
+
+/// Share of each stolen supply a thief takes
+pub const THEFT_SHARE: std::ops::Range<f32> = 0.1..0.4;
+
+/// Most kinds of supplies a thief takes in one night
+pub const MAX_STOLEN_KINDS: usize = 2;
+
+/// Most oxen a stampede can injure
+pub const MAX_STAMPEDE_INJURIES: u32 = 2;
+
+/// Chance of each ox being injured in a stampede
+pub const STAMPEDE_INJURY_CHANCE: f32 = 0.75;
+
+/// Days lost to a navigation event
+pub const DAYS_LOST: std::ops::RangeInclusive<u32> = 1..=5;
+
+/// Factor applied to navigation events in rain, snow or fog
+pub const POOR_VISIBILITY_FACTOR: f32 = 2.0;
+
+/// Factor applied to navigation events once the party is over South Pass
+pub const PAST_SOUTH_PASS_FACTOR: f32 = 0.5;
+
+/// Factor applied to the day's miles for each broken part patched together without a spare
+pub const PATCHED_PART_FACTOR: f32 = 0.85;
+
+/// Days lost when the party refuses to go on
+pub const REFUSAL_DAYS: std::ops::RangeInclusive<u32> = 1..=2;
+
+/// Morale regained once the party has had its way and a rest
+pub const REFUSAL_MORALE_GAIN: f32 = 15.0;
+
+/// Something that happens to the party on the trail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    BuffaloStampede,
+    WagonFire,
+    Thief,
+    LostTrail,
+    WrongTrail,
+    ImpassableTrail,
+    BrokenWheel,
+    BrokenAxle,
+    BrokenTongue,
+    RefuseToGoOn,
+}
+
+impl Event {
+    /// All implemented events
+    pub const ALL: [Event; 10] = [
+        Event::BuffaloStampede,
+        Event::WagonFire,
+        Event::Thief,
+        Event::LostTrail,
+        Event::WrongTrail,
+        Event::ImpassableTrail,
+        Event::BrokenWheel,
+        Event::BrokenAxle,
+        Event::BrokenTongue,
+        Event::RefuseToGoOn,
+    ];
+
+    /// Id of the event in the tables
+    pub fn id(&self) -> &'static str {
+        match self {
+            Event::BuffaloStampede => "buffalo_stampede",
+            Event::WagonFire => "wagon_fire",
+            Event::Thief => "thief",
+            Event::LostTrail => "lost_trail",
+            Event::WrongTrail => "wrong_trail",
+            Event::ImpassableTrail => "impassable_trail",
+            Event::BrokenWheel => "broken_wheel",
+            Event::BrokenAxle => "broken_axle",
+            Event::BrokenTongue => "broken_tongue",
+            Event::RefuseToGoOn => "refuse_to_go_on",
+        }
+    }
+
+    /// Look up an event by id
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|event| event.id() == id)
+    }
+
+    /// Dialog heading
+    pub fn title(&self) -> &'static str {
+        match self {
+            Event::BuffaloStampede => "Buffalo stampede",
+            Event::WagonFire => "Fire in the wagon",
+            Event::Thief => "A thief in the night",
+            Event::LostTrail => "Lost trail",
+            Event::WrongTrail => "Wrong trail",
+            Event::ImpassableTrail => "Impassable trail",
+            Event::BrokenWheel => "Broken wagon wheel",
+            Event::BrokenAxle => "Broken wagon axle",
+            Event::BrokenTongue => "Broken wagon tongue",
+            Event::RefuseToGoOn => "The party refuses to go on",
+        }
+    }
+
+    /// Wagon part the event breaks, if any
+    pub fn part(&self) -> Option<ItemType> {
+        match self {
+            Event::BrokenWheel => Some(ItemType::SpareWheel),
+            Event::BrokenAxle => Some(ItemType::SpareAxle),
+            Event::BrokenTongue => Some(ItemType::SpareTongue),
+            _ => None,
+        }
+    }
+
+    /// Whether the event holds the party up without mileage progress
+    pub fn is_navigation(&self) -> bool {
+        matches!(self, Event::LostTrail | Event::WrongTrail | Event::ImpassableTrail)
+    }
+
+    /// Factor applied to the event's weight given where the party is
+    ///
+    /// Trails are easier to lose in rain, snow and fog, and the way is plainer
+    /// once the party is over South Pass. Only a low-spirited party refuses
+    /// to go on.
+    pub fn weight_factor(&self, journey: &Journey) -> f32 {
+        if *self == Event::RefuseToGoOn {
+            return journey.morale.refusal_factor();
+        }
+        if !self.is_navigation() {
+            return 1.0;
+        }
+        let mut factor = 1.0;
+        if journey.weather.limits_visibility() {
+            factor *= POOR_VISIBILITY_FACTOR;
+        }
+        if landmarks::find("south_pass").is_some_and(|pass| journey.player.miles_traveled >= pass.miles) {
+            factor *= PAST_SOUTH_PASS_FACTOR;
+        }
+        factor
+    }
+
+    /// Roll whether an event happens today
+    ///
+    /// Events are picked by their weight in the tables, so one with no
+    /// implementation yet simply means nothing happens.
+    pub fn roll<R: Rng>(journey: &Journey, rng: &mut R) -> Option<Self> {
+        let tables = tables();
+        if rng.gen::<f32>() >= tables.daily_event_chance {
+            return None;
+        }
+        let weights: Vec<(Option<Event>, f32)> = tables
+            .event_weights
+            .iter()
+            .map(|(id, weight)| {
+                let event = Self::from_id(id);
+                let factor = event.map_or(1.0, |e| e.weight_factor(journey));
+                (event, *weight as f32 * factor)
+            })
+            .collect();
+        let total: f32 = weights.iter().map(|(_, weight)| weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut pick = rng.gen_range(0.0..total);
+        for (event, weight) in weights {
+            if pick < weight {
+                return event;
+            }
+            pick -= weight;
+        }
+        None
+    }
+
+    /// Make the event happen to the party
+    pub fn apply<R: Rng>(&self, journey: &mut Journey, rng: &mut R) -> EventOutcome {
+        let mut outcome =
+            EventOutcome { event: *self, lost: Vec::new(), oxen_injured: 0, days_lost: 0, replaced: false };
+        match self {
+            Event::BuffaloStampede => {
+                let chance = STAMPEDE_INJURY_CHANCE * journey.player.occupation.ox_risk_factor();
+                let healthy = journey.oxen().saturating_sub(journey.injured_oxen);
+                let injured = (0..MAX_STAMPEDE_INJURIES).filter(|_| rng.gen::<f32>() < chance).count() as u32;
+                outcome.oxen_injured = injured.min(healthy);
+                journey.injured_oxen += outcome.oxen_injured;
+            }
+            Event::WagonFire => {
+                outcome.take(journey, ItemType::Food, rng.gen_range(FIRE_FOOD_LOSS));
+                if rng.gen::<f32>() < FIRE_EXTRA_LOSS_CHANCE {
+                    let others = carried(journey, &[ItemType::Food, ItemType::OxenPair]);
+                    if !others.is_empty() {
+                        let item = others[rng.gen_range(0..others.len())];
+                        outcome.take(journey, item, rng.gen_range(1..=3));
+                    }
+                }
+            }
+            Event::Thief => {
+                // Oxen are yoked to the wagon, not lying loose in it
+                let mut targets = carried(journey, &[ItemType::OxenPair]);
+                for _ in 0..rng.gen_range(1..=MAX_STOLEN_KINDS) {
+                    if targets.is_empty() {
+                        break;
+                    }
+                    let item = targets.swap_remove(rng.gen_range(0..targets.len()));
+                    let quantity = journey.inventory.get_quantity(item) as f32 * rng.gen_range(THEFT_SHARE);
+                    outcome.take(journey, item, (quantity.ceil() as u32).max(1));
+                }
+            }
+            Event::LostTrail | Event::WrongTrail | Event::ImpassableTrail => {
+                outcome.days_lost = rng.gen_range(DAYS_LOST);
+                journey.lost_days += outcome.days_lost;
+            }
+            Event::BrokenWheel | Event::BrokenAxle | Event::BrokenTongue => {
+                let part = self.part().expect("breakdowns break a part");
+                outcome.replaced = journey.inventory.remove_item(part, 1);
+                if !outcome.replaced {
+                    journey.broken_parts.push(part);
+                }
+            }
+            Event::RefuseToGoOn => {
+                outcome.days_lost = rng.gen_range(REFUSAL_DAYS);
+                journey.lost_days += outcome.days_lost;
+                journey.morale.change(REFUSAL_MORALE_GAIN);
+            }
+        }
+        journey.record(EntryKind::Event, outcome.summary());
+        outcome
+    }
+}
+
+/// Supplies the wagon carries, leaving out some kinds
+fn carried(journey: &Journey, except: &[ItemType]) -> Vec<ItemType> {
+    ItemType::ALL
+        .into_iter()
+        .filter(|item| !except.contains(item) && journey.inventory.get_quantity(*item) > 0)
+        .collect()
+}
+
+/// One wagon part in words
+pub fn part_name(part: ItemType) -> &'static str {
+    match part {
+        ItemType::SpareWheel => "wagon wheel",
+        ItemType::SpareAxle => "wagon axle",
+        ItemType::SpareTongue => "wagon tongue",
+        other => other.name(),
+    }
+}
+
+/// A number of days in words
+fn days(n: u32) -> String {
+    format!("{} day{}", n, if n == 1 { "" } else { "s" })
+}
+
+/// What an event did to the party
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventOutcome {
+    /// What happened
+    pub event: Event,
+    /// Supplies lost
+    pub lost: Vec<(ItemType, u32)>,
+    /// Oxen injured
+    pub oxen_injured: u32,
+    /// Days the party will spend without making progress
+    pub days_lost: u32,
+    /// Whether a broken part was replaced with a spare rather than patched
+    pub replaced: bool,
+}
+
+impl EventOutcome {
+    /// Remove up to a quantity of a supply, remembering what was lost
+    fn take(&mut self, journey: &mut Journey, item: ItemType, quantity: u32) {
+        let quantity = quantity.min(journey.inventory.get_quantity(item));
+        if quantity > 0 {
+            journey.inventory.remove_item(item, quantity);
+            journey.settle_injured_oxen();
+            self.lost.push((item, quantity));
+        }
+    }
+
+    /// One-line account for the diary
+    pub fn summary(&self) -> String {
+        let lost: Vec<String> = self.lost.iter().map(|(item, n)| format!("{} {}", n, item.name())).collect();
+        match self.event {
+            Event::BuffaloStampede if self.oxen_injured == 0 => "A buffalo stampede passed close by.".to_string(),
+            Event::BuffaloStampede => format!("A buffalo stampede injured {} of the oxen.", self.oxen_injured),
+            Event::WagonFire => format!("A fire in the wagon destroyed {}.", lost.join(" and ")),
+            Event::Thief if lost.is_empty() => "A thief came in the night but found nothing.".to_string(),
+            Event::Thief => format!("A thief stole {}.", lost.join(" and ")),
+            Event::LostTrail => format!("Lost the trail. Lost {}.", days(self.days_lost)),
+            Event::WrongTrail => format!("Took the wrong trail. Lost {}.", days(self.days_lost)),
+            Event::ImpassableTrail => format!("The trail was impassable. Lost {}.", days(self.days_lost)),
+            Event::BrokenWheel | Event::BrokenAxle | Event::BrokenTongue => {
+                let part = self.event.part().map_or("", part_name);
+                match self.replaced {
+                    true => format!("Broke a {} and replaced it with a spare.", part),
+                    false => format!("Broke a {} and had no spare. Patched it as best we could.", part),
+                }
+            }
+            Event::RefuseToGoOn => format!("The party refused to go on. Lost {}.", days(self.days_lost)),
+        }
+    }
+
+    /// Message for the notification dialog, with losses marked in red
+    pub fn describe(&self) -> String {
+        let mut lines = vec![match self.event {
+            Event::BuffaloStampede if self.oxen_injured == 0 => "A herd of buffalo thunders past.\nYour oxen are unharmed.".to_string(),
+            Event::BuffaloStampede => format!(
+                "A herd of buffalo stampedes through camp.\n{{red}}{}{{/}} of your oxen {} injured.",
+                self.oxen_injured,
+                if self.oxen_injured == 1 { "is" } else { "are" }
+            ),
+            Event::WagonFire => "A fire breaks out in the wagon.".to_string(),
+            Event::Thief if self.lost.is_empty() => "A thief comes during the night\nbut finds nothing to take.".to_string(),
+            Event::Thief => "A thief comes during the night.".to_string(),
+            Event::LostTrail => format!("You have lost the trail.\nYou lose {{red}}{}{{/}}.", days(self.days_lost)),
+            Event::WrongTrail => format!("You took the wrong trail.\nYou lose {{red}}{}{{/}}.", days(self.days_lost)),
+            Event::ImpassableTrail => {
+                format!("The trail ahead is impassable.\nYou lose {{red}}{}{{/}} finding a way around.", days(self.days_lost))
+            }
+            Event::BrokenWheel | Event::BrokenAxle | Event::BrokenTongue => {
+                let part = self.event.part().map_or("", part_name);
+                match self.replaced {
+                    true => format!("You have a broken {{em}}{}{{/}}.\nYou replace it with a spare.", part),
+                    false => format!(
+                        "You have a broken {{em}}{}{{/}} and no spare.\nYou patch it up, but the wagon will go\nslowly until a blacksmith can fix it.",
+                        part
+                    ),
+                }
+            }
+            Event::RefuseToGoOn => format!(
+                "Your party is tired and disheartened
+and refuses to go on. You lose {{red}}{}{{/}}
+before they agree to move again.",
+                days(self.days_lost)
+            ),
+        }];
+        for (item, quantity) in &self.lost {
+            lines.push(format!("Lost: {{red}}{} {}{{/}}", quantity, item.name()));
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use crate::game_logic::weather::Weather;
+    use crate::utils::rng::GameRng;
+
+    #[test]
+    fn fire_burns_twenty_to_forty_pounds_of_food() {
+        let mut rng = ChaCha8Rng::seed_from_u64(9);
+        for _ in 0..20 {
+            let mut journey = Journey::default();
+            let outcome = Event::WagonFire.apply(&mut journey, &mut rng);
+            let (item, pounds) = outcome.lost[0];
+            assert_eq!(item, ItemType::Food);
+            assert!(FIRE_FOOD_LOSS.contains(&pounds));
+        }
+    }
+
+    #[test]
+    fn stampede_injures_oxen_and_thief_steals() {
+        let mut rng = ChaCha8Rng::seed_from_u64(2);
+        let mut journey = Journey::default();
+        let food = journey.inventory.get_quantity(ItemType::Food);
+
+        for _ in 0..10 {
+            Event::BuffaloStampede.apply(&mut journey, &mut rng);
+        }
+        assert!(journey.injured_oxen > 0 && journey.injured_oxen <= journey.oxen());
+
+        let outcome = Event::Thief.apply(&mut journey, &mut rng);
+        assert!(!outcome.lost.is_empty());
+        assert_eq!(journey.inventory.get_quantity(ItemType::Food) < food, outcome.lost.iter().any(|(i, _)| *i == ItemType::Food));
+        assert_eq!(journey.diary.entries_of(EntryKind::Event).count(), 11);
+    }
+
+    #[test]
+    fn losing_oxen_keeps_injuries_in_bounds() {
+        let mut rng = ChaCha8Rng::seed_from_u64(5);
+        let mut journey = Journey::default();
+        let pairs = journey.inventory.get_quantity(ItemType::OxenPair);
+        journey.injured_oxen = journey.oxen() + 3;
+        Event::BuffaloStampede.apply(&mut journey, &mut rng);
+
+        for _ in 0..20 {
+            Event::Thief.apply(&mut journey, &mut rng);
+        }
+        assert_eq!(journey.inventory.get_quantity(ItemType::OxenPair), pairs);
+
+        journey.abandon(ItemType::OxenPair, pairs - 1);
+        assert_eq!(journey.injured_oxen, 2);
+    }
+
+    #[test]
+    fn lost_trails_hold_the_party_up() {
+        let mut rng = ChaCha8Rng::seed_from_u64(4);
+        let mut journey = Journey::default();
+        let outcome = Event::WrongTrail.apply(&mut journey, &mut rng);
+        assert!(DAYS_LOST.contains(&outcome.days_lost));
+
+        let miles = journey.player.miles_traveled;
+        let mut game_rng = GameRng::new(1);
+        for _ in 0..outcome.days_lost {
+            journey.travel_day(&mut game_rng);
+        }
+        assert_eq!(journey.player.miles_traveled, miles);
+        assert_eq!(journey.lost_days, 0);
+    }
+
+    #[test]
+    fn breakdowns_use_a_spare_or_slow_the_wagon() {
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let mut journey = Journey::default();
+        let spares = journey.inventory.get_quantity(ItemType::SpareAxle);
+
+        let outcome = Event::BrokenAxle.apply(&mut journey, &mut rng);
+        assert!(outcome.replaced);
+        assert_eq!(journey.inventory.get_quantity(ItemType::SpareAxle), spares - 1);
+        assert!(journey.broken_parts.is_empty());
+
+        journey.inventory.remove_item(ItemType::SpareAxle, spares - 1);
+        let outcome = Event::BrokenAxle.apply(&mut journey, &mut rng);
+        assert!(!outcome.replaced);
+        assert_eq!(journey.broken_parts, vec![ItemType::SpareAxle]);
+    }
+
+    #[test]
+    fn rain_and_fog_raise_and_south_pass_lowers_navigation_events() {
+        let mut journey = Journey::default();
+        assert_eq!(Event::LostTrail.weight_factor(&journey), 1.0);
+        journey.weather = Weather::Rain;
+        assert_eq!(Event::LostTrail.weight_factor(&journey), POOR_VISIBILITY_FACTOR);
+        assert_eq!(Event::Thief.weight_factor(&journey), 1.0);
+        journey.weather = Weather::Fog;
+        assert_eq!(Event::ImpassableTrail.weight_factor(&journey), POOR_VISIBILITY_FACTOR);
+        journey.weather = Weather::Warm;
+        journey.player.miles_traveled = landmarks::find("south_pass").unwrap().miles;
+        assert_eq!(Event::WrongTrail.weight_factor(&journey), PAST_SOUTH_PASS_FACTOR);
+    }
+
+    #[test]
+    fn only_a_disheartened_party_refuses_to_go_on() {
+        let mut journey = Journey::default();
+        assert_eq!(Event::RefuseToGoOn.weight_factor(&journey), 0.0);
+        journey.morale.change(-60.0);
+        assert!(Event::RefuseToGoOn.weight_factor(&journey) > 0.0);
+
+        let before = journey.morale.value();
+        let outcome = Event::RefuseToGoOn.apply(&mut journey, &mut ChaCha8Rng::seed_from_u64(3));
+        assert!(REFUSAL_DAYS.contains(&outcome.days_lost));
+        assert_eq!(journey.lost_days, outcome.days_lost);
+        assert!(journey.morale.value() > before);
+    }
+}
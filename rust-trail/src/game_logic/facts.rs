@@ -0,0 +1,65 @@
+// Historical notes for the classroom: short "Did you know?" facts about the
+// places and dates a journey passes, read from a JSON text asset.
+
+use serde::Deserialize;
+
+/// Text file holding the facts, loaded through the asset manager
+pub const FACTS_FILE: &str = "facts.json";
+
+/// A historical note about a place or a date
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Fact {
+    /// Landmark id the fact is about, if it's about a place
+    #[serde(default)]
+    pub landmark: Option<String>,
+    /// Month (1-12) and day the fact is about, if it's about a date
+    #[serde(default)]
+    pub date: Option<(u8, u32)>,
+    /// The fact
+    pub text: String,
+}
+
+/// Every fact the game knows
+#[derive(Debug, Clone, Default)]
+pub struct FactBook {
+    facts: Vec<Fact>,
+}
+
+impl FactBook {
+    /// Parse the fact database
+    pub fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        Ok(Self { facts: serde_json::from_str(json)? })
+    }
+
+    /// Fact about a landmark, if there is one
+    pub fn about_landmark(&self, landmark: &str) -> Option<&Fact> {
+        self.facts.iter().find(|f| f.landmark.as_deref() == Some(landmark))
+    }
+
+    /// Fact about a day of the year, if there is one
+    pub fn on_date(&self, month: u8, day: u32) -> Option<&Fact> {
+        self.facts.iter().find(|f| f.date == Some((month, day)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::landmarks;
+
+    #[test]
+    fn bundled_facts_match_places_and_dates() {
+        let book = FactBook::parse(include_str!("../../assets/text/facts.json")).unwrap();
+        assert!(book.about_landmark("fort_laramie").is_some());
+        assert!(book.on_date(7, 4).is_some());
+        assert!(book.on_date(7, 5).is_none());
+        for fact in &book.facts {
+            if let Some(id) = &fact.landmark {
+                assert!(landmarks::index_of(id).is_some(), "unknown landmark {}", id);
+            }
+            if let Some((month, day)) = fact.date {
+                assert!((1..=12).contains(&month) && (1..=31).contains(&day));
+            }
+        }
+    }
+}
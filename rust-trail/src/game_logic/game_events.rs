@@ -0,0 +1,43 @@
+use crate::game_logic::diary::EntryKind;
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::river::CrossingResult;
+
+/// Something that happened to the party, announced to the journal, scenes and audio
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameEvent {
+    /// A member of the party died
+    PartyMemberDied { name: String, drowned: bool },
+    /// The wagon got across a river, or tried to
+    RiverCrossed { river: String, result: CrossingResult },
+    /// The party reached a landmark
+    LandmarkReached { name: String },
+    /// A supply ran low
+    SuppliesLow { item: ItemType },
+}
+
+impl GameEvent {
+    /// How the event is written in the diary
+    pub fn diary_entry(&self) -> (EntryKind, String) {
+        match self {
+            GameEvent::PartyMemberDied { name, drowned: false } => (EntryKind::Death, format!("{} has died.", name)),
+            GameEvent::PartyMemberDied { name, drowned: true } => (EntryKind::Death, format!("{} has drowned.", name)),
+            GameEvent::RiverCrossed { river, result } => {
+                let text = match result {
+                    CrossingResult::Safe => format!("Crossed {} safely.", river),
+                    CrossingResult::WetSupplies => format!("Crossed {}; the supplies got wet.", river),
+                    CrossingResult::StuckInMud => format!("Got stuck in the mud crossing {}.", river),
+                    CrossingResult::Tipped => format!("The wagon tipped over crossing {}.", river),
+                };
+                (EntryKind::RiverCrossing, text)
+            }
+            GameEvent::LandmarkReached { name } => (EntryKind::Arrival, format!("Arrived at {}.", name)),
+            GameEvent::SuppliesLow { item } => {
+                let supply = match item {
+                    ItemType::Food => "food",
+                    other => other.name(),
+                };
+                (EntryKind::Note, format!("Running low on {}.", supply))
+            }
+        }
+    }
+}
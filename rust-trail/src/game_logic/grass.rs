@@ -0,0 +1,62 @@
+use crate::game_logic::landmarks::Zone;
+
+/// Share of full grass growing in each month, January first
+const MONTH_GRASS: [f32; 12] = [0.0, 0.0, 0.1, 0.4, 0.9, 1.0, 1.0, 0.8, 0.6, 0.4, 0.1, 0.0];
+
+/// Share of the oxen's full pace kept with no grass to graze on
+pub const UNGRAZED_PACE: f32 = 0.85;
+
+/// Share of the usual food foraged when nothing is growing
+pub const BARREN_FORAGE: f32 = 0.25;
+
+/// Months (1-12) a party can leave Independence in
+pub const DEPARTURE_MONTHS: [u8; 5] = [3, 4, 5, 6, 7];
+
+/// How much grass a zone grows compared to the open plains
+fn zone_factor(zone: Zone) -> f32 {
+    match zone {
+        Zone::Plains | Zone::Forest => 1.0,
+        Zone::Mountains => 0.7,
+        Zone::Desert => 0.4,
+    }
+}
+
+/// Grass for the oxen in a month (1-12) and zone, from 0 for none to 1 for plenty
+pub fn grass(month: u8, zone: Zone) -> f32 {
+    MONTH_GRASS[(month.clamp(1, 12) - 1) as usize] * zone_factor(zone)
+}
+
+/// Factor applied to the day's miles for how well the oxen have grazed
+pub fn pace_factor(grass: f32) -> f32 {
+    UNGRAZED_PACE + (1.0 - UNGRAZED_PACE) * grass
+}
+
+/// Factor applied to the food found foraging
+pub fn forage_factor(grass: f32) -> f32 {
+    BARREN_FORAGE + (1.0 - BARREN_FORAGE) * grass
+}
+
+/// How the grass looks to the party
+pub fn describe(grass: f32) -> &'static str {
+    match grass {
+        g if g < 0.1 => "none",
+        g if g < 0.4 => "scant",
+        g if g < 0.8 => "fair",
+        _ => "plentiful",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn early_spring_and_deserts_are_bare() {
+        assert_eq!(grass(3, Zone::Plains), 0.1);
+        assert!(grass(6, Zone::Plains) > grass(6, Zone::Desert));
+        assert_eq!(describe(grass(12, Zone::Plains)), "none");
+        assert_eq!(describe(grass(6, Zone::Plains)), "plentiful");
+        assert!(pace_factor(0.0) < pace_factor(1.0));
+        assert_eq!(forage_factor(1.0), 1.0);
+    }
+}
@@ -0,0 +1,152 @@
+// Graves left by earlier parties. Everyone who dies on the trail is buried
+// where they fell, and later journeys pass their graves along the way.
+
+use serde::{Deserialize, Serialize};
+use crate::game_logic::player::PartyMember;
+use crate::utils::storage::Storage;
+use crate::utils::checksum;
+use crate::utils::versioned::{self, Migration};
+
+/// File graves are kept in between journeys
+pub const GRAVES_FILE: &str = "graves.json";
+
+/// Changes to the format graves are kept in, one per version
+///
+/// Version 2 added a checksum; files from before then are taken on trust.
+const MIGRATIONS: &[Migration] = &[versioned::unversioned, checksum::trust];
+
+/// Most graves kept; the oldest are forgotten first
+pub const MAX_GRAVES: usize = 30;
+
+/// A grave by the trail
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Grave {
+    /// Who is buried here
+    pub name: String,
+    /// What they died of
+    pub cause: String,
+    /// Distance from Independence (in miles)
+    pub miles: f32,
+    /// Words on the headstone
+    pub epitaph: String,
+}
+
+impl Grave {
+    /// Bury a member who has just died
+    pub fn for_member(member: &PartyMember, miles: f32, drowned: bool) -> Self {
+        let cause = if drowned {
+            "drowning".to_string()
+        } else {
+            member.diseases.last().map_or("exhaustion", |d| d.name()).to_string()
+        };
+        let epitaph = format!("Here lies {}, taken by {}.", member.name, cause);
+        Self { name: member.name.clone(), cause, miles, epitaph }
+    }
+}
+
+/// Every grave left along the trail
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Graveyard {
+    graves: Vec<Grave>,
+    /// Whether the graves were ever edited outside the game
+    #[serde(default)]
+    tampered: bool,
+}
+
+impl Graveyard {
+    /// Load the graves, or start with none if there is nothing stored yet
+    pub fn load(storage: &dyn Storage, name: &str) -> Result<Self, String> {
+        match storage.read(name)? {
+            Some(json) => {
+                let (mut graveyard, intact): (Self, bool) = versioned::from_signed_json(&json, MIGRATIONS)
+                    .map_err(|e| format!("Failed to read graves: {} ({})", e, name))?;
+                graveyard.tampered |= !intact;
+                Ok(graveyard)
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Write the graves to storage
+    pub fn save(&self, storage: &mut dyn Storage, name: &str) -> Result<(), String> {
+        let json = versioned::to_signed_json(self, MIGRATIONS).map_err(|e| format!("Failed to write graves: {}", e))?;
+        storage.write(name, &json)
+    }
+
+    /// Dig a new grave, forgetting the oldest if there are too many
+    pub fn add(&mut self, grave: Grave) {
+        self.graves.push(grave);
+        if self.graves.len() > MAX_GRAVES {
+            self.graves.remove(0);
+        }
+    }
+
+    /// Change the words on the newest grave of someone, returning false if
+    /// there is no grave for them
+    pub fn set_epitaph(&mut self, name: &str, epitaph: &str) -> bool {
+        match self.graves.iter_mut().rev().find(|g| g.name == name) {
+            Some(grave) => {
+                grave.epitaph = epitaph.to_string();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Graves between two distances, the start left out, in trail order
+    pub fn passed(&self, from: f32, to: f32) -> Vec<&Grave> {
+        let mut graves: Vec<&Grave> = self.graves.iter().filter(|g| g.miles > from && g.miles <= to).collect();
+        graves.sort_by(|a, b| a.miles.total_cmp(&b.miles));
+        graves
+    }
+
+    /// Number of graves
+    pub fn len(&self) -> usize {
+        self.graves.len()
+    }
+
+    /// Whether there are no graves
+    pub fn is_empty(&self) -> bool {
+        self.graves.is_empty()
+    }
+
+    /// Whether the graves were ever edited outside the game
+    pub fn tampered(&self) -> bool {
+        self.tampered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::player::Disease;
+    use crate::utils::storage::MemoryStorage;
+
+    #[test]
+    fn graves_are_passed_once() {
+        let mut member = PartyMember::new("Mary", 30, false);
+        member.contract_disease(Disease::Cholera);
+        let mut graveyard = Graveyard::default();
+        graveyard.add(Grave::for_member(&member, 120.0, false));
+        assert_eq!(graveyard.passed(100.0, 110.0).len(), 0);
+        assert_eq!(graveyard.passed(110.0, 120.0)[0].epitaph, "Here lies Mary, taken by cholera.");
+        assert_eq!(graveyard.passed(120.0, 140.0).len(), 0);
+
+        for miles in 0..MAX_GRAVES {
+            graveyard.add(Grave::for_member(&member, miles as f32, true));
+        }
+        assert_eq!(graveyard.len(), MAX_GRAVES);
+        assert_eq!(graveyard.passed(110.0, 130.0).len(), 0);
+    }
+
+    #[test]
+    fn graves_outlast_the_journey() {
+        let mut storage = MemoryStorage::default();
+        assert!(Graveyard::load(&storage, GRAVES_FILE).unwrap().is_empty());
+
+        let mut graveyard = Graveyard::default();
+        graveyard.add(Grave::for_member(&PartyMember::new("Mary", 30, false), 120.0, true));
+        graveyard.save(&mut storage, GRAVES_FILE).unwrap();
+        assert_eq!(Graveyard::load(&storage, GRAVES_FILE), Ok(graveyard));
+    }
+}
@@ -0,0 +1,210 @@
+// Guidebook text format:
+//
+//   # id Chapter title
+//   ## id Entry title
+//   Paragraph text, with [[id]] or [[id|link text]] linking to a section.
+//
+// Blank lines separate paragraphs.
+
+/// Text file holding the guidebook, loaded through the asset manager
+pub const GUIDEBOOK_FILE: &str = "guidebook.txt";
+
+/// One chapter or entry of the guidebook
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    /// Id used by links
+    pub id: String,
+    /// Heading
+    pub title: String,
+    /// 1 for chapters, 2 for entries within a chapter
+    pub level: u8,
+    /// Paragraphs, still containing link markup
+    pub paragraphs: Vec<String>,
+}
+
+/// A run of text on one line, optionally linking to a section
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    /// Text shown
+    pub text: String,
+    /// Section id the text links to
+    pub link: Option<String>,
+}
+
+/// The "Learn About the Trail" reference book
+#[derive(Debug, Clone, Default)]
+pub struct Guidebook {
+    sections: Vec<Section>,
+}
+
+impl Guidebook {
+    /// Parse guidebook text
+    pub fn parse(text: &str) -> Self {
+        let mut sections: Vec<Section> = Vec::new();
+        let mut paragraph = String::new();
+
+        for line in text.lines().map(str::trim) {
+            let heading = if let Some(rest) = line.strip_prefix("## ") {
+                Some((2, rest))
+            } else {
+                line.strip_prefix("# ").map(|rest| (1, rest))
+            };
+
+            if heading.is_some() || line.is_empty() {
+                if let (Some(section), false) = (sections.last_mut(), paragraph.is_empty()) {
+                    section.paragraphs.push(std::mem::take(&mut paragraph));
+                }
+                paragraph.clear();
+            }
+
+            if let Some((level, rest)) = heading {
+                let (id, title) = rest.split_once(' ').unwrap_or((rest, rest));
+                sections.push(Section {
+                    id: id.to_string(),
+                    title: title.trim().to_string(),
+                    level,
+                    paragraphs: Vec::new(),
+                });
+            } else if !line.is_empty() {
+                if !paragraph.is_empty() {
+                    paragraph.push(' ');
+                }
+                paragraph.push_str(line);
+            }
+        }
+        if let (Some(section), false) = (sections.last_mut(), paragraph.is_empty()) {
+            section.paragraphs.push(paragraph);
+        }
+
+        Self { sections }
+    }
+
+    /// All sections in reading order, for the table of contents
+    pub fn sections(&self) -> &[Section] {
+        &self.sections
+    }
+
+    /// Index of the section with an id
+    pub fn find(&self, id: &str) -> Option<usize> {
+        self.sections.iter().position(|s| s.id == id)
+    }
+
+    /// Title of the section with an id
+    pub fn title(&self, id: &str) -> Option<&str> {
+        self.find(id).map(|i| self.sections[i].title.as_str())
+    }
+
+    /// Split a paragraph into plain text and link segments
+    pub fn segments(&self, paragraph: &str) -> Vec<Segment> {
+        let mut segments = Vec::new();
+        let mut rest = paragraph;
+        while let Some(start) = rest.find("[[") {
+            let Some(end) = rest[start..].find("]]").map(|e| start + e) else {
+                break;
+            };
+            if start > 0 {
+                segments.push(Segment { text: rest[..start].to_string(), link: None });
+            }
+            let inner = &rest[start + 2..end];
+            let (id, text) = match inner.split_once('|') {
+                Some((id, text)) => (id, text.to_string()),
+                None => (inner, self.title(inner).unwrap_or(inner).to_string()),
+            };
+            segments.push(Segment { text, link: Some(id.to_string()) });
+            rest = &rest[end + 2..];
+        }
+        if !rest.is_empty() {
+            segments.push(Segment { text: rest.to_string(), link: None });
+        }
+        segments
+    }
+
+    /// Word-wrap a paragraph into lines of segments at most `max_width` wide, as measured
+    pub fn wrap(&self, paragraph: &str, max_width: f32, measure: impl Fn(&str) -> f32) -> Vec<Vec<Segment>> {
+        let mut lines = Vec::new();
+        let mut line: Vec<Segment> = Vec::new();
+        let mut width = 0.0;
+        // Whether the previous segment ended between words
+        let mut after_space = false;
+
+        for segment in self.segments(paragraph) {
+            let starts_with_space = segment.text.starts_with(char::is_whitespace);
+            for (i, word) in segment.text.split_whitespace().enumerate() {
+                // Punctuation straight after a link stays attached to it
+                let spaced = i > 0 || starts_with_space || after_space;
+                let text = if width > 0.0 && spaced { format!(" {}", word) } else { word.to_string() };
+                let text = if width > 0.0 && width + measure(&text) > max_width {
+                    lines.push(std::mem::take(&mut line));
+                    width = 0.0;
+                    word.to_string()
+                } else {
+                    text
+                };
+                width += measure(&text);
+                match line.last_mut() {
+                    Some(last) if last.link == segment.link => last.text.push_str(&text),
+                    _ => line.push(Segment { text, link: segment.link.clone() }),
+                }
+            }
+            after_space = segment.text.ends_with(char::is_whitespace);
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOOK: &str = "# rivers River crossings\nFord with care.\n\nSee [[kansas]].\n\n# landmarks Landmarks\n## kansas Kansas River\nDeep in [[rivers|spring]].\n";
+
+    #[test]
+    fn parses_sections_and_paragraphs() {
+        let book = Guidebook::parse(BOOK);
+        let ids: Vec<_> = book.sections().iter().map(|s| (s.id.as_str(), s.level)).collect();
+        assert_eq!(ids, [("rivers", 1), ("landmarks", 1), ("kansas", 2)]);
+        assert_eq!(book.sections()[0].paragraphs, ["Ford with care.", "See [[kansas]]."]);
+        assert_eq!(book.find("kansas"), Some(2));
+    }
+
+    #[test]
+    fn links_use_section_titles_by_default() {
+        let book = Guidebook::parse(BOOK);
+        let segments = book.segments("See [[kansas]] in [[rivers|spring]].");
+        let texts: Vec<_> = segments.iter().map(|s| (s.text.as_str(), s.link.as_deref())).collect();
+        assert_eq!(
+            texts,
+            [("See ", None), ("Kansas River", Some("kansas")), (" in ", None), ("spring", Some("rivers")), (".", None)]
+        );
+    }
+
+    #[test]
+    fn wrapping_keeps_links_and_width() {
+        let book = Guidebook::parse(BOOK);
+        let measure = |text: &str| text.len() as f32;
+        let lines = book.wrap("Read about the [[kansas]] before you cross it.", 20.0, measure);
+        for line in &lines {
+            assert!(line.iter().map(|s| s.text.len()).sum::<usize>() <= 20);
+        }
+        assert!(lines.iter().flatten().any(|s| s.link.as_deref() == Some("kansas")));
+
+        let lines = book.wrap("See [[kansas]].", 40.0, measure);
+        let text: String = lines[0].iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(text, "See Kansas River.");
+    }
+
+    #[test]
+    fn bundled_guidebook_links_resolve() {
+        let book = Guidebook::parse(include_str!("../../assets/text/guidebook.txt"));
+        for section in book.sections() {
+            for paragraph in &section.paragraphs {
+                for link in book.segments(paragraph).iter().filter_map(|s| s.link.as_ref()) {
+                    assert!(book.find(link).is_some(), "broken link {} in {}", link, section.id);
+                }
+            }
+        }
+    }
+}
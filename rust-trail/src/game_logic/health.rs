@@ -0,0 +1,145 @@
+use rand::Rng;
+use crate::game_logic::clothing::{EXPOSURE_ILLNESS_FACTOR, EXPOSURE_PENALTY};
+use crate::game_logic::player::{Disease, HealthStatus, Pace, PartyMember, Rations};
+use crate::game_logic::weather::Weather;
+
+/// Points of ill health at which fair, poor, very poor and deceased begin
+pub const THRESHOLDS: [f32; 4] = [35.0, 70.0, 105.0, 140.0];
+
+/// Share of ill health shaken off each day
+pub const DAILY_RECOVERY: f32 = 0.1;
+
+/// Extra share of ill health shaken off on a day of rest
+pub const REST_RECOVERY: f32 = 0.1;
+
+/// Penalty for a day without enough food
+pub const STARVATION_PENALTY: f32 = 10.0;
+
+/// Daily chance of falling ill for each point of ill health
+pub const ILLNESS_CHANCE_PER_POINT: f32 = 0.001;
+
+/// Illnesses a worn-down member can come down with
+const ILLNESSES: [Disease; 6] = [
+    Disease::Cholera,
+    Disease::Dysentery,
+    Disease::Measles,
+    Disease::Typhoid,
+    Disease::Fever,
+    Disease::Exhaustion,
+];
+
+/// Status for a number of points of ill health
+pub fn status_for(points: f32) -> HealthStatus {
+    match THRESHOLDS.iter().position(|&threshold| points < threshold) {
+        Some(0) => HealthStatus::Good,
+        Some(1) => HealthStatus::Fair,
+        Some(2) => HealthStatus::Poor,
+        Some(_) => HealthStatus::VeryPoor,
+        None => HealthStatus::Deceased,
+    }
+}
+
+/// Fewest points of ill health for a status
+pub fn lower_bound(status: HealthStatus) -> f32 {
+    match status.severity() {
+        0 => 0.0,
+        n => THRESHOLDS[n as usize - 1],
+    }
+}
+
+/// What the party faced on one day
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conditions {
+    /// Pace kept, or resting for a day in camp
+    pub pace: Pace,
+    /// Rations eaten
+    pub rations: Rations,
+    /// The day's weather
+    pub weather: Weather,
+    /// Whether the party went hungry
+    pub starving: bool,
+    /// Whether the party lacked warm clothing when it needed it
+    pub short_of_clothing: bool,
+}
+
+impl Conditions {
+    /// Points of ill health the day adds to every member
+    pub fn penalty(&self) -> f32 {
+        let food = if self.starving { STARVATION_PENALTY } else { self.rations.health_penalty() };
+        let exposure = if self.short_of_clothing { EXPOSURE_PENALTY } else { 0.0 };
+        self.pace.health_penalty() + food + self.weather.health_penalty(self.short_of_clothing) + exposure
+    }
+
+    /// Factor applied to the chance of falling ill
+    pub fn illness_factor(&self) -> f32 {
+        if self.short_of_clothing {
+            EXPOSURE_ILLNESS_FACTOR
+        } else {
+            1.0
+        }
+    }
+
+    /// Share of ill health shaken off over the day
+    pub fn recovery(&self) -> f32 {
+        if self.pace == Pace::Resting {
+            DAILY_RECOVERY + REST_RECOVERY
+        } else {
+            DAILY_RECOVERY
+        }
+    }
+}
+
+/// Wear one day's conditions on a member, returning whether it killed them
+///
+/// Each day's penalty varies a little from member to member, and the worse a
+/// member's health the more likely they are to fall ill.
+pub fn wear<R: Rng>(member: &mut PartyMember, conditions: &Conditions, rng: &mut R) -> bool {
+    if !member.is_alive() {
+        return false;
+    }
+    if rng.gen::<f32>() < member.general_health * ILLNESS_CHANCE_PER_POINT * conditions.illness_factor() {
+        member.contract_disease(ILLNESSES[rng.gen_range(0..ILLNESSES.len())]);
+    }
+    let illness: f32 = member.diseases.iter().map(|d| d.health_penalty()).sum();
+    let penalty = (conditions.penalty() + illness) * rng.gen_range(0.5..1.5);
+    member.set_general_health(member.general_health * (1.0 - conditions.recovery()) + penalty);
+    !member.is_alive()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::player::Disease;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn conditions(pace: Pace, rations: Rations) -> Conditions {
+        Conditions { pace, rations, weather: Weather::Warm, starving: false, short_of_clothing: false }
+    }
+
+    #[test]
+    fn thresholds_set_the_status() {
+        assert_eq!(status_for(0.0), HealthStatus::Good);
+        assert_eq!(status_for(34.9), HealthStatus::Good);
+        assert_eq!(status_for(35.0), HealthStatus::Fair);
+        assert_eq!(status_for(104.0), HealthStatus::Poor);
+        assert_eq!(status_for(139.0), HealthStatus::VeryPoor);
+        assert_eq!(status_for(140.0), HealthStatus::Deceased);
+    }
+
+    #[test]
+    fn hard_travel_wears_the_party_down() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        let mut easy = PartyMember::new("Easy", 25, false);
+        let mut hard = PartyMember::new("Hard", 25, false);
+        hard.contract_disease(Disease::Dysentery);
+
+        for _ in 0..30 {
+            wear(&mut easy, &conditions(Pace::Steady, Rations::Filling), &mut rng);
+            wear(&mut hard, &conditions(Pace::Grueling, Rations::BareBones), &mut rng);
+        }
+
+        assert_eq!(easy.health, HealthStatus::Good);
+        assert!(hard.health.severity() >= HealthStatus::Poor.severity());
+    }
+}
@@ -0,0 +1,359 @@
+// Hunting grounds: the terrain the party hunts in and the game found there
+
+use std::collections::BTreeMap;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::game_logic::diary::EntryKind;
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::journey::Journey;
+use crate::game_logic::landmarks::Zone;
+use crate::game_logic::tables::tables;
+use crate::game_logic::weather::Weather;
+
+/// Miles past a river crossing that the party still hunts along its banks
+pub const RIVER_BOTTOM_MILES: f32 = 30.0;
+
+/// Bullets fired with each shot
+pub const BULLETS_PER_SHOT: u32 = 1;
+
+/// Seconds the hunter takes to reload between shots
+pub const RELOAD_SECONDS: f32 = 0.8;
+
+/// Speed of a bullet in flight (in pixels per second)
+pub const BULLET_SPEED: f32 = 900.0;
+
+/// Most meat the party can carry back to the wagon from one hunt (in pounds)
+pub const CARRY_LIMIT: u32 = 100;
+
+/// Share of the fresh meat dried over the fire each day, after which it keeps
+pub const CURE_RATE: f32 = 0.3;
+
+/// Share of the fresh meat that rots in a day of the given weather
+pub fn spoilage(weather: Weather) -> f32 {
+    match weather {
+        Weather::VeryHot => 0.3,
+        Weather::Hot => 0.15,
+        _ => 0.0,
+    }
+}
+
+/// Kind of country the party hunts in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Terrain {
+    /// Open grassland
+    Prairie,
+    /// Wooded banks along a river
+    RiverBottom,
+    /// Rocky slopes and high meadows
+    Mountain,
+    /// Dry sagebrush country
+    Desert,
+}
+
+impl Terrain {
+    /// All terrains
+    pub const ALL: [Terrain; 4] = [Terrain::Prairie, Terrain::RiverBottom, Terrain::Mountain, Terrain::Desert];
+
+    /// Display name of the terrain
+    pub fn name(&self) -> &'static str {
+        match self {
+            Terrain::Prairie => "prairie",
+            Terrain::RiverBottom => "river bottom",
+            Terrain::Mountain => "mountains",
+            Terrain::Desert => "desert",
+        }
+    }
+
+    /// Terrain around the party's position on the trail
+    ///
+    /// The party hunts along the river bottom for a while after each crossing,
+    /// and the forested Columbia country is all river bottom.
+    pub fn for_journey(journey: &Journey) -> Self {
+        let landmark = journey.last_landmark();
+        if landmark.is_river() && journey.player.miles_traveled < landmark.miles + RIVER_BOTTOM_MILES {
+            return Terrain::RiverBottom;
+        }
+        match landmark.zone {
+            Zone::Plains => Terrain::Prairie,
+            Zone::Mountains => Terrain::Mountain,
+            Zone::Desert => Terrain::Desert,
+            Zone::Forest => Terrain::RiverBottom,
+        }
+    }
+
+    /// Game found in the terrain
+    pub fn ground(&self) -> &'static HuntingGround {
+        &tables().hunting_grounds[self]
+    }
+}
+
+/// Animals that can be hunted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Animal {
+    Buffalo,
+    Elk,
+    Bear,
+    Deer,
+    Rabbit,
+    Squirrel,
+    Duck,
+}
+
+impl Animal {
+    /// Display name of the animal
+    pub fn name(&self) -> &'static str {
+        match self {
+            Animal::Buffalo => "buffalo",
+            Animal::Elk => "elk",
+            Animal::Bear => "bear",
+            Animal::Deer => "deer",
+            Animal::Rabbit => "rabbit",
+            Animal::Squirrel => "squirrel",
+            Animal::Duck => "duck",
+        }
+    }
+
+    /// Meat the animal yields (in pounds)
+    pub fn meat(&self) -> u32 {
+        match self {
+            Animal::Buffalo => 600,
+            Animal::Elk => 400,
+            Animal::Bear => 300,
+            Animal::Deer => 100,
+            Animal::Rabbit => 4,
+            Animal::Squirrel => 2,
+            Animal::Duck => 2,
+        }
+    }
+
+    /// Running speed (in pixels per second)
+    pub fn speed(&self) -> f32 {
+        match self {
+            Animal::Buffalo => 60.0,
+            Animal::Elk => 90.0,
+            Animal::Bear => 50.0,
+            Animal::Deer => 120.0,
+            Animal::Rabbit => 150.0,
+            Animal::Squirrel => 110.0,
+            Animal::Duck => 140.0,
+        }
+    }
+
+    /// Width and height of the animal on screen (in pixels)
+    pub fn size(&self) -> (f32, f32) {
+        match self {
+            Animal::Buffalo => (60.0, 36.0),
+            Animal::Elk => (50.0, 40.0),
+            Animal::Bear => (44.0, 30.0),
+            Animal::Deer => (40.0, 32.0),
+            Animal::Rabbit => (14.0, 10.0),
+            Animal::Squirrel => (12.0, 9.0),
+            Animal::Duck => (16.0, 10.0),
+        }
+    }
+
+    /// Whether the animal crosses the sky rather than the ground
+    pub fn flies(&self) -> bool {
+        matches!(self, Animal::Duck)
+    }
+}
+
+/// How much game a terrain holds and which animals make it up
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HuntingGround {
+    /// Animals that come into view each minute, on average
+    pub animals_per_minute: f32,
+    /// Relative weight of each animal
+    pub animals: BTreeMap<Animal, u32>,
+}
+
+impl HuntingGround {
+    /// Roll whether an animal comes into view during a short span of time
+    pub fn roll_spawn<R: Rng>(&self, rng: &mut R, dt: f32) -> Option<Animal> {
+        if rng.gen::<f32>() >= self.animals_per_minute / 60.0 * dt {
+            return None;
+        }
+        self.pick_animal(rng)
+    }
+
+    /// Pick an animal by its weight
+    pub fn pick_animal<R: Rng>(&self, rng: &mut R) -> Option<Animal> {
+        let total: u32 = self.animals.values().sum();
+        if total == 0 {
+            return None;
+        }
+        let mut pick = rng.gen_range(0..total);
+        for (animal, weight) in &self.animals {
+            if pick < *weight {
+                return Some(*animal);
+            }
+            pick -= weight;
+        }
+        None
+    }
+}
+
+/// Shots fired and animals hit, for one hunt or the whole trip
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Marksmanship {
+    /// Shots fired
+    pub shots: u32,
+    /// Shots that hit an animal
+    pub hits: u32,
+}
+
+impl Marksmanship {
+    /// Count a shot, and whether it hit
+    pub fn record_shot(&mut self, hit: bool) {
+        self.shots += 1;
+        if hit {
+            self.hits += 1;
+        }
+    }
+
+    /// Add another tally to this one
+    pub fn add(&mut self, other: Marksmanship) {
+        self.shots += other.shots;
+        self.hits += other.hits;
+    }
+
+    /// Share of shots that hit, from 0.0 to 1.0, or 0.0 before any were fired
+    pub fn accuracy(&self) -> f32 {
+        match self.shots {
+            0 => 0.0,
+            shots => self.hits as f32 / shots as f32,
+        }
+    }
+}
+
+/// Meat from a hunt: what was shot, what could be carried and what was left behind
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Haul {
+    /// Pounds of meat on the animals shot
+    pub shot: u32,
+    /// Pounds carried back to the wagon
+    pub carried: u32,
+    /// Pounds that fit in the wagon
+    pub kept: u32,
+    /// Pounds of each animal left to waste, in the order they were shot
+    pub wasted: Vec<(Animal, u32)>,
+}
+
+impl Haul {
+    /// Carry meat back from the animals in the order they were shot, up to the carry limit
+    pub fn from_kills(kills: &[Animal]) -> Self {
+        let mut carried = 0;
+        let mut wasted: Vec<(Animal, u32)> = Vec::new();
+        for animal in kills {
+            let taken = animal.meat().min(CARRY_LIMIT - carried);
+            carried += taken;
+            let left = animal.meat() - taken;
+            if left == 0 {
+                continue;
+            }
+            match wasted.iter_mut().find(|(a, _)| a == animal) {
+                Some((_, pounds)) => *pounds += left,
+                None => wasted.push((*animal, left)),
+            }
+        }
+        let shot = kills.iter().map(Animal::meat).sum();
+        Self { shot, carried, kept: carried, wasted }
+    }
+
+    /// Message telling the player how the hunt went
+    pub fn describe(&self) -> String {
+        if self.shot == 0 {
+            return "You were unable to shoot any food.".to_string();
+        }
+        let mut text = format!("From this hunt you brought back {} pounds of meat.", self.kept);
+        if !self.wasted.is_empty() {
+            text += &format!("\nYou could only carry {} pounds back to the wagon.\nLeft to waste:", CARRY_LIMIT);
+            for (animal, pounds) in &self.wasted {
+                text += &format!("\n  {} pounds of {}", pounds, animal.name());
+            }
+        }
+        if self.kept < self.carried {
+            text += &format!("\nThere was only room for {} pounds in the wagon.", self.kept);
+        }
+        text
+    }
+}
+
+/// Load the meat from a hunt into the wagon as fresh food
+pub fn bring_back(journey: &mut Journey, terrain: Terrain, kills: &[Animal]) -> Haul {
+    let mut haul = Haul::from_kills(kills);
+    haul.kept = haul.carried.min(journey.inventory.room_for(ItemType::Food));
+    journey.inventory.add_item(ItemType::Food, haul.kept);
+    journey.fresh_meat += haul.kept;
+    journey.stats.animals_shot += kills.len() as u32;
+    journey.stats.meat_wasted += haul.shot - haul.kept;
+    let text = match haul.kept {
+        0 => format!("Went hunting in the {} but brought back no meat.", terrain.name()),
+        kept => format!("Went hunting in the {} and brought back {} pounds of meat.", terrain.name(), kept),
+    };
+    journey.record(EntryKind::Note, text);
+    haul
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn terrain_follows_the_trail() {
+        let mut journey = Journey::default();
+        assert_eq!(Terrain::for_journey(&journey), Terrain::Prairie);
+
+        let river = tables().landmark("kansas_river").unwrap();
+        journey.landmark = tables().landmarks.iter().position(|l| l.id == river.id).unwrap();
+        journey.player.miles_traveled = river.miles + 5.0;
+        assert_eq!(Terrain::for_journey(&journey), Terrain::RiverBottom);
+        journey.player.miles_traveled = river.miles + RIVER_BOTTOM_MILES;
+        assert_eq!(Terrain::for_journey(&journey), Terrain::Prairie);
+    }
+
+    #[test]
+    fn game_is_picked_from_the_terrain() {
+        let mut rng = ChaCha8Rng::seed_from_u64(3);
+        for terrain in Terrain::ALL {
+            let ground = terrain.ground();
+            for _ in 0..50 {
+                let animal = ground.pick_animal(&mut rng).unwrap();
+                assert!(ground.animals.contains_key(&animal), "{:?} in {:?}", animal, terrain);
+            }
+        }
+        assert!(Terrain::Desert.ground().animals_per_minute < Terrain::Prairie.ground().animals_per_minute);
+        assert!(!Terrain::Desert.ground().animals.contains_key(&Animal::Buffalo));
+    }
+
+    #[test]
+    fn only_a_hundred_pounds_are_carried_back() {
+        let haul = Haul::from_kills(&[Animal::Rabbit, Animal::Buffalo, Animal::Deer]);
+        assert_eq!(haul.shot, 704);
+        assert_eq!(haul.carried, CARRY_LIMIT);
+        assert_eq!(haul.wasted, vec![(Animal::Buffalo, 504), (Animal::Deer, 100)]);
+        assert!(haul.describe().contains("504 pounds of buffalo"));
+
+        let mut journey = Journey::default();
+        let food = journey.inventory.get_quantity(ItemType::Food);
+        bring_back(&mut journey, Terrain::Prairie, &[Animal::Deer, Animal::Squirrel]);
+        assert_eq!(journey.inventory.get_quantity(ItemType::Food), food + CARRY_LIMIT);
+        assert_eq!(journey.fresh_meat, CARRY_LIMIT);
+    }
+
+    #[test]
+    fn marksmanship_adds_up() {
+        let mut hunt = Marksmanship::default();
+        assert_eq!(hunt.accuracy(), 0.0);
+        hunt.record_shot(true);
+        hunt.record_shot(false);
+        let mut trip = Marksmanship { shots: 2, hits: 0 };
+        trip.add(hunt);
+        assert_eq!(trip, Marksmanship { shots: 4, hits: 1 });
+        assert_eq!(trip.accuracy(), 0.25);
+    }
+}
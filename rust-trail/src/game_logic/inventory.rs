@@ -1,5 +1,10 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
+use crate::game_logic::money::Money;
+use crate::game_logic::tables::tables;
+
+/// Yoke of oxen needed to haul a full wagon; fewer can only pull part of a load
+pub const FULL_TEAM_PAIRS: u32 = 2;
 
 /// Represents the different types of items in the game
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -14,6 +19,39 @@ pub enum ItemType {
     MedicalSupply,  // Medicine for treating illnesses
 }
 
+impl ItemType {
+    /// All item types
+    pub const ALL: [ItemType; 8] = [
+        ItemType::Food,
+        ItemType::Clothing,
+        ItemType::Ammunition,
+        ItemType::OxenPair,
+        ItemType::SpareWheel,
+        ItemType::SpareAxle,
+        ItemType::SpareTongue,
+        ItemType::MedicalSupply,
+    ];
+
+    /// Name shown on supply lists, in the units the quantity counts
+    pub fn name(&self) -> &'static str {
+        match self {
+            ItemType::Food => "pounds of food",
+            ItemType::Clothing => "sets of clothing",
+            ItemType::Ammunition => "bullets",
+            ItemType::OxenPair => "yoke of oxen",
+            ItemType::SpareWheel => "wagon wheels",
+            ItemType::SpareAxle => "wagon axles",
+            ItemType::SpareTongue => "wagon tongues",
+            ItemType::MedicalSupply => "medicine kits",
+        }
+    }
+
+    /// Whether the item is loaded into the wagon rather than pulling it
+    pub fn is_carried(&self) -> bool {
+        *self != ItemType::OxenPair
+    }
+}
+
 /// A single type of item with quantity and properties
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Item {
@@ -23,13 +61,13 @@ pub struct Item {
     pub quantity: u32,
     /// Weight per unit in pounds
     pub weight_per_unit: f32,
-    /// Cost per unit in dollars
-    pub cost_per_unit: u32,
+    /// Cost per unit
+    pub cost_per_unit: Money,
 }
 
 impl Item {
     /// Create a new item
-    pub fn new(item_type: ItemType, quantity: u32, weight_per_unit: f32, cost_per_unit: u32) -> Self {
+    pub fn new(item_type: ItemType, quantity: u32, weight_per_unit: f32, cost_per_unit: Money) -> Self {
         Self {
             item_type,
             quantity,
@@ -44,8 +82,8 @@ impl Item {
     }
 
     /// Get the total cost of this item
-    pub fn total_cost(&self) -> u32 {
-        self.quantity * self.cost_per_unit
+    pub fn total_cost(&self) -> Money {
+        self.cost_per_unit * self.quantity
     }
 }
 
@@ -73,17 +111,9 @@ impl Inventory {
             // Item already exists, update quantity
             item.quantity += quantity;
         } else {
-            // Create new item with default properties
-            let item = match item_type {
-                ItemType::Food => Item::new(item_type, quantity, 1.0, 2),
-                ItemType::Clothing => Item::new(item_type, quantity, 2.0, 10),
-                ItemType::Ammunition => Item::new(item_type, quantity, 0.1, 2),
-                ItemType::OxenPair => Item::new(item_type, quantity, 500.0, 40),
-                ItemType::SpareWheel => Item::new(item_type, quantity, 15.0, 10),
-                ItemType::SpareAxle => Item::new(item_type, quantity, 10.0, 8),
-                ItemType::SpareTongue => Item::new(item_type, quantity, 8.0, 6),
-                ItemType::MedicalSupply => Item::new(item_type, quantity, 0.5, 15),
-            };
+            // Create new item with the properties from the game tables
+            let stats = tables().item(item_type);
+            let item = Item::new(item_type, quantity, stats.weight, stats.price);
             self.items.insert(item_type, item);
         }
     }
@@ -111,29 +141,60 @@ impl Inventory {
         self.items.get(&item_type).map_or(0, |item| item.quantity)
     }
 
-    /// Calculate the total weight of all inventory items
+    /// Calculate the total weight loaded into the wagon
     pub fn total_weight(&self) -> f32 {
-        self.items.values().map(|item| item.total_weight()).sum()
+        self.items.values().filter(|item| item.item_type.is_carried()).map(|item| item.total_weight()).sum()
+    }
+
+    /// Weight the oxen can pull, which drops when there are too few of them
+    pub fn capacity(&self) -> f32 {
+        let pairs = self.get_quantity(ItemType::OxenPair).clamp(1, FULL_TEAM_PAIRS);
+        self.max_capacity * pairs as f32 / FULL_TEAM_PAIRS as f32
+    }
+
+    /// Weight of one unit of an item in the wagon
+    fn unit_weight(&self, item_type: ItemType) -> f32 {
+        if !item_type.is_carried() {
+            return 0.0;
+        }
+        match self.items.get(&item_type) {
+            Some(item) => item.weight_per_unit,
+            None => tables().item(item_type).weight,
+        }
     }
 
     /// Check if adding an item would exceed capacity
     pub fn can_add(&self, item_type: ItemType, quantity: u32) -> bool {
-        let weight_per_unit = match self.items.get(&item_type) {
-            Some(item) => item.weight_per_unit,
-            None => match item_type {
-                ItemType::Food => 1.0,
-                ItemType::Clothing => 2.0,
-                ItemType::Ammunition => 0.1,
-                ItemType::OxenPair => 500.0,
-                ItemType::SpareWheel => 15.0,
-                ItemType::SpareAxle => 10.0,
-                ItemType::SpareTongue => 8.0,
-                ItemType::MedicalSupply => 0.5,
-            },
-        };
-
-        let additional_weight = weight_per_unit * quantity as f32;
-        self.total_weight() + additional_weight <= self.max_capacity
+        let additional_weight = self.unit_weight(item_type) * quantity as f32;
+        self.total_weight() + additional_weight <= self.capacity()
+    }
+
+    /// Most units of an item that still fit in the wagon
+    pub fn room_for(&self, item_type: ItemType) -> u32 {
+        let weight = self.unit_weight(item_type);
+        if weight <= 0.0 {
+            return u32::MAX;
+        }
+        ((self.capacity() - self.total_weight()).max(0.0) / weight).floor() as u32
+    }
+
+    /// Whether the wagon holds more than the oxen can pull
+    pub fn is_overloaded(&self) -> bool {
+        self.total_weight() > self.capacity()
+    }
+
+    /// Pounds that have to be left behind before the oxen can pull the wagon
+    pub fn overload(&self) -> f32 {
+        (self.total_weight() - self.capacity()).max(0.0)
+    }
+
+    /// Fewest units of an item that bring the wagon under capacity, or all of it if that isn't enough
+    pub fn units_to_abandon(&self, item_type: ItemType) -> u32 {
+        let weight = self.unit_weight(item_type);
+        if weight <= 0.0 {
+            return 0;
+        }
+        ((self.overload() / weight).ceil() as u32).min(self.get_quantity(item_type))
     }
 
     /// Get a list of all items
@@ -163,7 +224,30 @@ impl Inventory {
     /// Get the capacity information
     pub fn capacity_info(&self) -> (f32, f32, f32) {
         let current_weight = self.total_weight();
-        let percent_full = (current_weight / self.max_capacity) * 100.0;
-        (current_weight, self.max_capacity, percent_full)
+        let capacity = self.capacity();
+        let percent_full = (current_weight / capacity) * 100.0;
+        (current_weight, capacity, percent_full)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn losing_oxen_shrinks_the_load() {
+        let mut inventory = Inventory::new(2000.0);
+        inventory.add_item(ItemType::OxenPair, 3);
+        inventory.add_item(ItemType::Food, 1600);
+        assert_eq!(inventory.total_weight(), 1600.0);
+        assert!(!inventory.is_overloaded());
+        assert_eq!(inventory.room_for(ItemType::Food), 400);
+        assert!(!inventory.can_add(ItemType::Food, 401));
+
+        inventory.remove_item(ItemType::OxenPair, 2);
+        assert!(inventory.is_overloaded());
+        assert_eq!(inventory.overload(), 600.0);
+        assert_eq!(inventory.units_to_abandon(ItemType::Food), 600);
+        assert_eq!(inventory.room_for(ItemType::Clothing), 0);
+    }
+}
@@ -0,0 +1,804 @@
+use rand::Rng;
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use crate::game_logic::clothing::{self, WARM_SETS_PER_PERSON, WEAR_CHANCE};
+use crate::game_logic::diary::{Diary, EntryKind};
+use crate::game_logic::events::{Event, EventOutcome, PATCHED_PART_FACTOR};
+use crate::game_logic::game_events::GameEvent;
+use crate::game_logic::grass;
+use crate::game_logic::health::{self, Conditions};
+use crate::game_logic::hunting::{self, Marksmanship, CURE_RATE};
+use crate::game_logic::inventory::{Inventory, ItemType};
+use crate::game_logic::player::{Disease, HealthStatus, Pace, PlayerState};
+use crate::game_logic::rest::{self, TaskOutcome, WorkAssignments};
+use crate::game_logic::river::{self, CrossingMethod, CrossingResult, River, FERRY_COST, GUIDE_CLOTHING, RAIN_DECAY};
+use crate::game_logic::landmarks::Landmark;
+use crate::game_logic::morale::{self, Morale};
+use crate::game_logic::stats::Stats;
+use crate::game_logic::snow::{self, PASS_APPROACH, SNOWED_IN_FOOD_FACTOR};
+use crate::game_logic::tables::tables;
+use crate::game_logic::time::TrailDate;
+use crate::game_logic::weather::Weather;
+use crate::utils::rng::{GameRng, RngStream};
+
+/// Most days the party can rest at once
+pub const MAX_REST_DAYS: u32 = 9;
+
+/// Chance of each kind of supply being swept away when the wagon tips over
+pub const TIPPED_LOSS_CHANCE: f32 = 0.5;
+
+/// Chance of each member drowning when the wagon tips over
+pub const DROWNING_CHANCE: f32 = 0.1;
+
+/// Factor applied to a disease's recovery chance when treated with medicine
+pub const MEDICINE_RECOVERY_FACTOR: f32 = 3.0;
+
+/// Points of ill health relieved by a dose of medicine
+pub const MEDICINE_RELIEF: f32 = 15.0;
+
+/// Days of food left at which the party is warned it is running low
+pub const LOW_FOOD_DAYS: u32 = 7;
+
+/// Daily chance of each injured ox recovering
+pub const OX_RECOVERY_CHANCE: f32 = 0.1;
+
+/// Supplies bought at Independence when none are chosen
+pub const DEFAULT_SUPPLIES: [(ItemType, u32); 8] = [
+    (ItemType::OxenPair, 3),
+    (ItemType::Food, 1600),
+    (ItemType::Clothing, 10),
+    (ItemType::Ammunition, 200),
+    (ItemType::SpareWheel, 1),
+    (ItemType::SpareAxle, 1),
+    (ItemType::SpareTongue, 1),
+    (ItemType::MedicalSupply, 2),
+];
+
+/// What happened on one day on the trail
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DayReport {
+    /// Miles covered
+    pub miles: f32,
+    /// Landmark reached at the end of the day, as an index into the landmark table
+    pub arrived_at: Option<usize>,
+    /// Whether the party went hungry
+    pub starving: bool,
+    /// Names of members who died
+    pub deaths: Vec<String>,
+    /// Sets of clothing worn out
+    pub clothing_worn: u32,
+    /// Event that happened during the day
+    pub event: Option<EventOutcome>,
+    /// Days a blizzard snowed the party in for
+    pub blizzard: Option<u32>,
+}
+
+/// What happened on one day of rest
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RestReport {
+    /// Whether the party went hungry
+    pub starving: bool,
+    /// Members who shook off a disease
+    pub recovered: Vec<(String, Disease)>,
+    /// Names of members who died
+    pub deaths: Vec<String>,
+    /// What each member's assigned work produced
+    pub work: Vec<TaskOutcome>,
+}
+
+/// What happened crossing a river
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossingReport {
+    /// How the crossing went
+    pub result: CrossingResult,
+    /// Supplies swept away
+    pub lost: Vec<(ItemType, u32)>,
+    /// Names of members who drowned
+    pub drowned: Vec<String>,
+}
+
+/// The party's journey: who is travelling, what they carry, and how far they've come
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Journey {
+    /// Party, money, date, pace and rations
+    pub player: PlayerState,
+    /// Wagon contents
+    pub inventory: Inventory,
+    /// Days spent on the trail
+    pub days: u32,
+    /// Index of the last landmark reached
+    pub landmark: usize,
+    /// Today's weather
+    pub weather: Weather,
+    /// Recent rain and snow, which swells the rivers
+    #[serde(default)]
+    pub rainfall: f32,
+    /// Oxen hurt and pulling at less than full strength
+    #[serde(default)]
+    pub injured_oxen: u32,
+    /// Days still to be spent finding the trail again
+    #[serde(default)]
+    pub lost_days: u32,
+    /// Days still to be spent snowed in by a blizzard
+    #[serde(default)]
+    pub snowed_in_days: u32,
+    /// The party's spirits
+    #[serde(default)]
+    pub morale: Morale,
+    /// Wagon parts broken with no spare, patched together until a blacksmith fixes them
+    #[serde(default)]
+    pub broken_parts: Vec<ItemType>,
+    /// Tasks each member keeps at on days spent in camp
+    #[serde(default)]
+    pub work: WorkAssignments,
+    /// Trades attempted today
+    pub trade_attempts: u32,
+    /// Units of each item bought at each fort's store, keyed by landmark id
+    #[serde(default)]
+    pub store_sales: HashMap<String, HashMap<ItemType, u32>>,
+    /// Shots fired and hits made on every hunt of the trip
+    #[serde(default)]
+    pub marksmanship: Marksmanship,
+    /// Pounds of the food that are fresh meat, not yet dried, which rots in the heat
+    #[serde(default)]
+    pub fresh_meat: u32,
+    /// How each river along the way was crossed
+    #[serde(default)]
+    pub crossings: Vec<CrossingMethod>,
+    /// Running statistics for the scoring screen
+    #[serde(default)]
+    pub stats: Stats,
+    /// Whether a cheat code was used, which keeps the journey off the records
+    #[serde(default)]
+    pub cheated: bool,
+    /// Student travelling, when the journey is played in classroom mode
+    #[serde(default)]
+    pub student: Option<String>,
+    /// Journal of the trip
+    pub diary: Diary,
+    /// Events published since they were last collected
+    #[serde(skip)]
+    outbox: Vec<GameEvent>,
+}
+
+impl Journey {
+    /// Start a journey at Independence with the given party and supplies
+    pub fn new(leader: &str, party: &[&str], supplies: &[(ItemType, u32)]) -> Self {
+        let mut player = PlayerState::new();
+        player.setup_party(leader, party);
+        let work = WorkAssignments::new(player.party.len());
+
+        let mut inventory = Inventory::new(tables().wagon_capacity);
+        for &(item, quantity) in supplies {
+            inventory.add_item(item, quantity);
+        }
+
+        let mut journey = Self {
+            player,
+            inventory,
+            days: 0,
+            landmark: 0,
+            weather: Weather::default(),
+            rainfall: 0.0,
+            injured_oxen: 0,
+            lost_days: 0,
+            snowed_in_days: 0,
+            morale: Morale::default(),
+            broken_parts: Vec::new(),
+            work,
+            trade_attempts: 0,
+            store_sales: HashMap::new(),
+            marksmanship: Marksmanship::default(),
+            fresh_meat: 0,
+            crossings: Vec::new(),
+            stats: Stats::default(),
+            cheated: false,
+            student: None,
+            diary: Diary::new(),
+            outbox: Vec::new(),
+        };
+        journey.record_departure();
+        journey
+    }
+
+    /// Write the diary entry for leaving Independence
+    fn record_departure(&mut self) {
+        let departure = format!("Left {} with a party of {}.", self.player.location, self.player.party.len());
+        self.record(EntryKind::Departure, departure);
+    }
+
+    /// Today's date as (month, day, year)
+    pub fn date(&self) -> TrailDate {
+        self.player.date
+    }
+
+    /// Write a diary entry for today
+    pub fn record(&mut self, kind: EntryKind, text: impl Into<String>) {
+        self.diary.record(self.date(), kind, text);
+    }
+
+    /// Announce something that happened, writing it in the diary
+    pub fn publish(&mut self, event: GameEvent) {
+        // Losses weigh on the party and each landmark lifts its spirits
+        match &event {
+            GameEvent::PartyMemberDied { .. } => self.morale.change(-morale::DEATH_LOSS),
+            GameEvent::LandmarkReached { .. } => self.morale.change(morale::LANDMARK_GAIN),
+            _ => {}
+        }
+        let (kind, text) = event.diary_entry();
+        self.record(kind, text);
+        self.outbox.push(event);
+    }
+
+    /// Take the events published since the last call, oldest first
+    pub fn take_events(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.outbox)
+    }
+
+    /// Announce the deaths in a day's report
+    fn record_deaths(&mut self, deaths: &[String]) {
+        for name in deaths {
+            self.publish(GameEvent::PartyMemberDied { name: name.clone(), drowned: false });
+        }
+    }
+
+    /// The last landmark reached
+    pub fn last_landmark(&self) -> &'static Landmark {
+        &tables().landmarks[self.landmark]
+    }
+
+    /// The next landmark ahead, if the party hasn't reached the end
+    pub fn next_landmark(&self) -> Option<&'static Landmark> {
+        tables().landmarks.get(self.landmark + 1)
+    }
+
+    /// Miles left to the next landmark
+    pub fn miles_to_next(&self) -> f32 {
+        self.next_landmark().map_or(0.0, |next| (next.miles - self.player.miles_traveled).max(0.0))
+    }
+
+    /// Whether the wagon is stopped at the last landmark it reached
+    pub fn at_landmark(&self) -> bool {
+        self.player.miles_traveled >= self.last_landmark().miles
+            && self.player.miles_traveled < self.last_landmark().miles + 1.0
+    }
+
+    /// Whether the wagon is stopped at a fort with a store
+    pub fn at_fort(&self) -> bool {
+        self.at_landmark() && self.last_landmark().is_fort()
+    }
+
+    /// Today's conditions at the river the wagon is stopped at, if any
+    pub fn river(&self) -> Option<River> {
+        if !self.at_landmark() {
+            return None;
+        }
+        let baseline = self.last_landmark().river.as_ref()?;
+        Some(River::conditions(baseline, self.player.date.month(), self.rainfall))
+    }
+
+    /// Grass growing along this stretch of the trail, from 0 for none to 1 for plenty
+    pub fn grass(&self) -> f32 {
+        grass::grass(self.player.date.month(), self.last_landmark().zone)
+    }
+
+    /// Leave Independence on the first of a month instead, before the party has set out
+    pub fn set_departure(&mut self, month: u8) {
+        let year = self.player.date.year();
+        if self.days > 0 || self.player.set_date(month, 1, year).is_err() {
+            return;
+        }
+        self.diary = Diary::new();
+        self.record_departure();
+    }
+
+    /// Snow (in inches) on the pass the wagon is climbing, or none away from a pass
+    pub fn snowpack(&self) -> f32 {
+        let Some(pass) = self.next_landmark().and_then(|next| next.pass.as_ref()) else {
+            return 0.0;
+        };
+        if self.miles_to_next() > PASS_APPROACH {
+            return 0.0;
+        }
+        pass.snowpack(self.player.date)
+    }
+
+    /// Whether the pass ahead is closed and the party is trapped for the winter
+    pub fn snowbound_for_winter(&self) -> bool {
+        snow::is_closed(self.snowpack())
+    }
+
+    /// Whether snow keeps the wagon from moving
+    pub fn snowed_in(&self) -> bool {
+        self.snowed_in_days > 0 || self.snowbound_for_winter()
+    }
+
+    /// Number of oxen pulling the wagon
+    pub fn oxen(&self) -> u32 {
+        self.inventory.get_quantity(ItemType::OxenPair) * 2
+    }
+
+    /// Share of full pulling strength left after injuries, at least half
+    pub fn ox_strength(&self) -> f32 {
+        match self.oxen() {
+            0 => 0.0,
+            oxen => (1.0 - self.injured_oxen.min(oxen) as f32 / oxen as f32).max(0.5),
+        }
+    }
+
+    /// Roll each injured ox healing
+    fn heal_oxen(&mut self, rng: &mut GameRng) {
+        let rng = rng.stream(RngStream::Health);
+        let healed = (0..self.injured_oxen).filter(|_| rng.gen::<f32>() < OX_RECOVERY_CHANCE).count() as u32;
+        self.injured_oxen -= healed;
+        self.settle_injured_oxen();
+    }
+
+    /// Keep the count of injured oxen within the oxen the party still has,
+    /// after some are lost, stolen or traded away
+    pub fn settle_injured_oxen(&mut self) {
+        self.injured_oxen = self.injured_oxen.min(self.oxen());
+    }
+
+    /// Whether the party has reached the end of the trail
+    pub fn has_arrived(&self) -> bool {
+        self.next_landmark().is_none()
+    }
+
+    /// Whether every member of the party has died
+    pub fn party_died(&self) -> bool {
+        self.player.living_party_members() == 0
+    }
+
+    /// Health of the party as a whole, judged by its average member
+    pub fn party_health(&self) -> HealthStatus {
+        let living: Vec<_> = self.player.party.iter().filter(|m| m.is_alive()).collect();
+        if living.is_empty() {
+            return HealthStatus::Deceased;
+        }
+        let average = living.iter().map(|m| m.general_health).sum::<f32>() / living.len() as f32;
+        health::status_for(average.min(health::THRESHOLDS[3] - 1.0))
+    }
+
+    /// Sets of clothing for each living member
+    pub fn clothing_per_person(&self) -> f32 {
+        clothing::sets_per_person(self.inventory.get_quantity(ItemType::Clothing), self.player.living_party_members())
+    }
+
+    /// Whether the month, weather or mountains call for warm clothing
+    pub fn needs_warm_clothing(&self) -> bool {
+        clothing::needs_warm_clothing(self.player.date.month(), self.weather, self.last_landmark().zone)
+    }
+
+    /// Whether the party needs warm clothing and doesn't have enough
+    pub fn short_of_clothing(&self) -> bool {
+        self.needs_warm_clothing() && self.clothing_per_person() < WARM_SETS_PER_PERSON
+    }
+
+    /// Roll each travelling member wearing out a set of clothing, returning the sets lost
+    fn wear_clothing(&mut self, rng: &mut GameRng) -> u32 {
+        let rng = rng.stream(RngStream::Health);
+        let worn = (0..self.player.living_party_members()).filter(|_| rng.gen::<f32>() < WEAR_CHANCE).count() as u32;
+        let worn = worn.min(self.inventory.get_quantity(ItemType::Clothing));
+        self.inventory.remove_item(ItemType::Clothing, worn);
+        worn
+    }
+
+    /// What the party faces today, travelling or resting
+    pub fn conditions(&self, starving: bool, resting: bool) -> Conditions {
+        Conditions {
+            pace: if resting { Pace::Resting } else { self.player.pace },
+            rations: self.player.rations,
+            weather: self.weather,
+            starving,
+            short_of_clothing: self.short_of_clothing(),
+        }
+    }
+
+    /// Eat one day's food at the current rations, returning whether the party went hungry
+    pub fn eat(&mut self) -> bool {
+        let living = self.player.living_party_members() as f32;
+        let cold = if self.snowed_in() { SNOWED_IN_FOOD_FACTOR } else { 1.0 };
+        let wanted = (tables().food_per_person_per_day * self.player.rations.food_factor() * cold * living).ceil() as u32;
+        let food = self.inventory.get_quantity(ItemType::Food);
+        self.inventory.remove_item(ItemType::Food, wanted.min(food));
+        // Fresh meat is eaten before it can spoil
+        self.fresh_meat = self.fresh_meat.saturating_sub(wanted);
+
+        let low = wanted * LOW_FOOD_DAYS;
+        if food >= low && food - wanted.min(food) < low {
+            self.publish(GameEvent::SuppliesLow { item: ItemType::Food });
+        }
+        let starving = food < wanted;
+        if starving {
+            self.morale.change(-morale::HUNGER_LOSS);
+        }
+        starving
+    }
+
+    /// Wear the day's conditions on each living member, returning the names of anyone who died
+    pub fn update_health(&mut self, rng: &mut GameRng, conditions: &Conditions) -> Vec<String> {
+        let rng = rng.stream(RngStream::Health);
+        let mut deaths = Vec::new();
+        for member in self.player.party.iter_mut() {
+            if health::wear(member, conditions, rng) {
+                deaths.push(member.name.clone());
+            }
+        }
+        deaths
+    }
+
+    /// Move the calendar on a day and roll the new day's weather
+    pub fn advance_day(&mut self, rng: &mut GameRng) {
+        self.days += 1;
+        self.player.advance_date(1);
+        self.weather = Weather::roll(self.player.date.month(), rng.stream(RngStream::Weather));
+        self.rainfall = self.rainfall * RAIN_DECAY + river::precipitation(self.weather);
+        self.trade_attempts = 0;
+        self.morale.settle();
+        self.spoil_meat();
+    }
+
+    /// Rot some of the fresh meat if the day is hot, and dry some more of what's left
+    fn spoil_meat(&mut self) {
+        let fresh = self.fresh_meat.min(self.inventory.get_quantity(ItemType::Food));
+        let rotted = (fresh as f32 * hunting::spoilage(self.weather)).ceil() as u32;
+        if rotted > 0 {
+            self.inventory.remove_item(ItemType::Food, rotted);
+            self.record(EntryKind::Note, format!("{} pounds of fresh meat spoiled in the heat.", rotted));
+            self.stats.meat_wasted += rotted;
+        }
+        self.fresh_meat = ((fresh - rotted) as f32 * (1.0 - CURE_RATE)) as u32;
+    }
+
+    /// Roll each resting member's recovery, returning the diseases they shook off
+    pub fn rest_recovery(&mut self, rng: &mut GameRng) -> Vec<(String, Disease)> {
+        let rng = rng.stream(RngStream::Health);
+        let mut recovered = Vec::new();
+        for member in self.player.party.iter_mut().filter(|m| m.is_alive()) {
+            for disease in member.diseases.clone() {
+                if rng.gen::<f32>() < disease.recovery_chance() {
+                    member.recover_from_disease(disease);
+                    member.improve_health();
+                    recovered.push((member.name.clone(), disease));
+                }
+            }
+        }
+        self.stats.illnesses_survived += recovered.len() as u32;
+        recovered
+    }
+
+    /// Treat a sick member with a medical kit, returning the diseases it cured
+    ///
+    /// Returns None, using nothing, if there is no medicine or the member isn't sick.
+    pub fn use_medicine(&mut self, member: usize, rng: &mut GameRng) -> Option<Vec<Disease>> {
+        if !self.player.party.get(member).is_some_and(|m| m.is_sick()) || !self.inventory.use_medical_supply() {
+            return None;
+        }
+
+        let rng = rng.stream(RngStream::Health);
+        let patient = &mut self.player.party[member];
+        let mut cured = Vec::new();
+        for disease in patient.diseases.clone() {
+            if rng.gen::<f32>() < disease.recovery_chance() * MEDICINE_RECOVERY_FACTOR {
+                patient.recover_from_disease(disease);
+                cured.push(disease);
+            }
+        }
+        patient.set_general_health(patient.general_health - MEDICINE_RELIEF);
+        self.stats.illnesses_survived += cured.len() as u32;
+
+        let name = patient.name.clone();
+        self.record(EntryKind::Note, format!("Gave medicine to {}.", name));
+        Some(cured)
+    }
+
+    /// Spend one day in camp, with each member doing their assigned work
+    pub fn rest_day(&mut self, rng: &mut GameRng, assignments: &WorkAssignments) -> RestReport {
+        self.advance_day(rng);
+
+        let grass = self.grass();
+        let stream = rng.stream(RngStream::Rest);
+        let work = rest::work_day(&mut self.player, &mut self.inventory, assignments, grass, self.morale, stream);
+        self.morale.change(morale::REST_GAIN);
+        self.stats.days_rested += 1;
+        let starving = self.eat();
+
+        // A hungry camp shakes off no diseases
+        let recovered = if starving { Vec::new() } else { self.rest_recovery(rng) };
+        let deaths = self.update_health(rng, &self.conditions(starving, true));
+
+        self.record_deaths(&deaths);
+        RestReport { starving, recovered, deaths, work }
+    }
+
+    /// Spend a day in camp, each member at the task they were last given
+    pub fn camp_day(&mut self, rng: &mut GameRng) -> RestReport {
+        let work = self.work.clone();
+        self.rest_day(rng, &work)
+    }
+
+    /// Leave supplies by the trail to lighten the wagon
+    pub fn abandon(&mut self, item: ItemType, quantity: u32) {
+        let quantity = quantity.min(self.inventory.get_quantity(item));
+        if quantity > 0 {
+            self.inventory.remove_item(item, quantity);
+            self.settle_injured_oxen();
+            self.record(EntryKind::Note, format!("Left behind {} {} to lighten the wagon.", quantity, item.name()));
+        }
+    }
+
+    /// Whether the party can pay for a crossing method
+    pub fn can_pay_for(&self, method: CrossingMethod) -> bool {
+        match method {
+            CrossingMethod::Ferry => self.player.money >= FERRY_COST,
+            CrossingMethod::Guide => self.inventory.get_quantity(ItemType::Clothing) >= GUIDE_CLOTHING,
+            _ => true,
+        }
+    }
+
+    /// Take the wagon across the river it is stopped at, paying for the ferry or guide
+    pub fn cross_river(&mut self, river: &River, method: CrossingMethod, rng: &mut GameRng) -> CrossingReport {
+        match method {
+            CrossingMethod::Ferry => {
+                self.stats.money_spent += FERRY_COST.min(self.player.money);
+                self.player.money = self.player.money.saturating_sub(FERRY_COST);
+            }
+            CrossingMethod::Guide => {
+                self.inventory.remove_item(ItemType::Clothing, GUIDE_CLOTHING);
+            }
+            _ => {}
+        }
+
+        let stream = rng.stream(RngStream::River);
+        let result = river.cross(method, stream);
+        let mut report = CrossingReport { result, lost: Vec::new(), drowned: Vec::new() };
+        self.crossings.push(method);
+
+        if result == CrossingResult::Tipped {
+            for item in ItemType::ALL {
+                let quantity = self.inventory.get_quantity(item);
+                if quantity > 0 && stream.gen::<f32>() < TIPPED_LOSS_CHANCE {
+                    let lost = ((quantity as f32 * stream.gen_range(0.2..0.6)).ceil() as u32).min(quantity);
+                    self.inventory.remove_item(item, lost);
+                    report.lost.push((item, lost));
+                }
+            }
+            self.settle_injured_oxen();
+            for member in self.player.party.iter_mut().filter(|m| m.is_alive()) {
+                if stream.gen::<f32>() < DROWNING_CHANCE {
+                    member.health = HealthStatus::Deceased;
+                    report.drowned.push(member.name.clone());
+                }
+            }
+        }
+
+        let river = self.last_landmark().name.clone();
+        self.publish(GameEvent::RiverCrossed { river, result });
+        for name in &report.drowned {
+            self.publish(GameEvent::PartyMemberDied { name: name.clone(), drowned: true });
+        }
+
+        // Drying out supplies or digging out the wagon takes a day
+        if matches!(result, CrossingResult::WetSupplies | CrossingResult::StuckInMud) {
+            self.advance_day(rng);
+            self.eat();
+        }
+        report
+    }
+
+    /// Travel for one day at the current pace, stopping at the next landmark
+    pub fn travel_day(&mut self, rng: &mut GameRng) -> DayReport {
+        self.advance_day(rng);
+
+        let mut report = DayReport::default();
+
+        // No progress without oxen to pull the wagon, while finding the trail again or while snowed in
+        let delayed = self.lost_days > 0;
+        self.lost_days = self.lost_days.saturating_sub(1);
+        let stuck = self.snowed_in();
+        if !delayed && !stuck && self.inventory.get_quantity(ItemType::OxenPair) > 0 {
+            let oxen = self.ox_strength() * grass::pace_factor(self.grass());
+            let patched = PATCHED_PART_FACTOR.powi(self.broken_parts.len() as i32);
+            let spirits = self.morale.speed_factor();
+            let planned = tables().miles_per_day * self.player.pace.miles_factor() * oxen * patched * spirits;
+            report.miles = planned.min(self.miles_to_next());
+            self.player.miles_traveled += report.miles;
+        }
+        self.stats.daily_miles.push(report.miles);
+
+        if let Some(next) = self.next_landmark() {
+            if report.miles > 0.0 && self.player.miles_traveled >= next.miles {
+                self.landmark += 1;
+                self.player.location = next.name.clone();
+                report.arrived_at = Some(self.landmark);
+                self.publish(GameEvent::LandmarkReached { name: next.name.clone() });
+            }
+        }
+
+        if !delayed && !stuck {
+            let stream = rng.stream(RngStream::Events);
+            report.event = Event::roll(self, stream).map(|event| event.apply(self, stream));
+        }
+        self.heal_oxen(rng);
+        report.clothing_worn = self.wear_clothing(rng);
+        report.starving = self.eat();
+        self.snowed_in_days = self.snowed_in_days.saturating_sub(1);
+        if !stuck {
+            report.blizzard = snow::blizzard(self.snowpack(), self.weather, rng.stream(RngStream::Weather));
+            if let Some(days) = report.blizzard {
+                self.snowed_in_days = days;
+                self.record(EntryKind::Event, format!("A blizzard snowed us in for {} days.", days));
+            }
+        }
+        report.deaths = self.update_health(rng, &self.conditions(report.starving, false));
+        self.record_deaths(&report.deaths);
+        report
+    }
+}
+
+impl Default for Journey {
+    fn default() -> Self {
+        Self::new("Leader", &["Mary", "Tom", "Sally", "Joe"], &DEFAULT_SUPPLIES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::landmarks;
+    use crate::game_logic::player::{Pace, Rations};
+    use crate::game_logic::rest::RestTask;
+
+    #[test]
+    fn pace_and_rations_change_the_day() {
+        let mut steady = Journey::default();
+        let mut grueling = Journey::default();
+        grueling.player.pace = Pace::Grueling;
+        grueling.player.rations = Rations::BareBones;
+
+        let steady_day = steady.travel_day(&mut GameRng::new(3));
+        let grueling_day = grueling.travel_day(&mut GameRng::new(3));
+
+        assert!(grueling_day.miles > steady_day.miles);
+        assert!(grueling.inventory.get_quantity(ItemType::Food) > steady.inventory.get_quantity(ItemType::Food));
+    }
+
+    #[test]
+    fn diary_records_departure_and_arrivals() {
+        let mut journey = Journey::default();
+        let mut rng = GameRng::new(4);
+        while journey.landmark == 0 {
+            journey.travel_day(&mut rng);
+        }
+
+        let kinds: Vec<_> = journey.diary.entries().iter().map(|e| e.kind).collect();
+        assert_eq!(kinds.first(), Some(&EntryKind::Departure));
+        assert_eq!(journey.diary.entries_of(EntryKind::Arrival).count(), 1);
+    }
+
+    #[test]
+    fn running_low_on_food_is_published_once() {
+        let mut journey = Journey::default();
+        let food = journey.inventory.get_quantity(ItemType::Food);
+        journey.eat();
+        let daily = food - journey.inventory.get_quantity(ItemType::Food);
+        journey.inventory.remove_item(ItemType::Food, journey.inventory.get_quantity(ItemType::Food));
+        journey.inventory.add_item(ItemType::Food, daily * (LOW_FOOD_DAYS + 1));
+
+        for _ in 0..3 {
+            journey.eat();
+        }
+
+        let low = GameEvent::SuppliesLow { item: ItemType::Food };
+        assert_eq!(journey.take_events(), vec![low]);
+        assert!(journey.take_events().is_empty());
+    }
+
+    #[test]
+    fn fresh_meat_rots_in_the_heat() {
+        let mut journey = Journey::default();
+        let food = journey.inventory.get_quantity(ItemType::Food);
+        journey.fresh_meat = 100;
+
+        journey.weather = Weather::Cool;
+        journey.spoil_meat();
+        assert_eq!(journey.inventory.get_quantity(ItemType::Food), food);
+        assert_eq!(journey.fresh_meat, 70);
+
+        journey.weather = Weather::VeryHot;
+        journey.spoil_meat();
+        assert_eq!(journey.inventory.get_quantity(ItemType::Food), food - 21);
+        assert!(journey.fresh_meat < 49);
+    }
+
+    #[test]
+    fn resting_advances_the_calendar_and_eats() {
+        let mut journey = Journey::default();
+        let mut rng = GameRng::new(1);
+        let food = journey.inventory.get_quantity(ItemType::Food);
+        let miles = journey.player.miles_traveled;
+
+        journey.rest_day(&mut rng, &WorkAssignments::new(journey.player.party.len()));
+
+        assert_eq!(journey.days, 1);
+        assert_eq!((journey.player.date.month(), journey.player.date.day()), (3, 2));
+        assert!(journey.inventory.get_quantity(ItemType::Food) < food);
+        assert_eq!(journey.player.miles_traveled, miles);
+    }
+
+    #[test]
+    fn camp_days_keep_the_party_at_its_tasks() {
+        let mut journey = Journey::default();
+        let mut rng = GameRng::new(3);
+        let bullets = journey.inventory.get_quantity(ItemType::Ammunition);
+        journey.work.assign(0, RestTask::Hunt);
+
+        let report = journey.camp_day(&mut rng);
+
+        assert_eq!(report.work[0].task, RestTask::Hunt);
+        assert_eq!(journey.inventory.get_quantity(ItemType::Ammunition), bullets - rest::HUNT_AMMUNITION_PER_DAY);
+    }
+
+    #[test]
+    fn rivers_swell_after_rain() {
+        let mut journey = Journey::default();
+        let mut rng = GameRng::new(5);
+        while journey.landmark == 0 {
+            journey.travel_day(&mut rng);
+        }
+        journey.rainfall = 0.0;
+        let dry = journey.river().expect("stopped at the Kansas River");
+        journey.rainfall = 3.0;
+        let wet = journey.river().unwrap();
+        assert!(wet.depth > dry.depth);
+
+        journey.cross_river(&wet, CrossingMethod::Ford, &mut rng);
+        assert_eq!(journey.diary.entries_of(EntryKind::RiverCrossing).count(), 1);
+    }
+
+    #[test]
+    fn late_parties_are_trapped_by_snow() {
+        let mut journey = Journey {
+            landmark: landmarks::index_of("fort_walla_walla").unwrap() - 2,
+            ..Default::default()
+        };
+        journey.player.miles_traveled = landmarks::find("blue_mountains").unwrap().miles - 40.0;
+        journey.player.set_date(8, 20, 1848).unwrap();
+        assert_eq!(journey.snowpack(), 0.0);
+        assert!(!journey.snowed_in());
+
+        journey.player.set_date(12, 1, 1848).unwrap();
+        assert!(journey.snowbound_for_winter());
+        let miles = journey.player.miles_traveled;
+        journey.travel_day(&mut GameRng::new(2));
+        assert_eq!(journey.player.miles_traveled, miles);
+    }
+
+    #[test]
+    fn medicine_is_only_used_on_the_sick() {
+        let mut journey = Journey::default();
+        let mut rng = GameRng::new(6);
+        let kits = journey.inventory.get_quantity(ItemType::MedicalSupply);
+
+        assert_eq!(journey.use_medicine(0, &mut rng), None);
+        journey.player.party[0].contract_disease(Disease::Fever);
+        assert!(journey.use_medicine(0, &mut rng).is_some());
+        assert_eq!(journey.inventory.get_quantity(ItemType::MedicalSupply), kits - 1);
+        assert!(journey.player.party[0].general_health < health::THRESHOLDS[0]);
+    }
+
+    #[test]
+    fn rest_cures_diseases_eventually() {
+        let mut journey = Journey::default();
+        let mut rng = GameRng::new(2);
+        journey.player.party[0].contract_disease(Disease::Exhaustion);
+
+        let assignments = WorkAssignments::new(journey.player.party.len());
+        for _ in 0..MAX_REST_DAYS {
+            journey.rest_day(&mut rng, &assignments);
+        }
+
+        assert!(journey.player.party[0].diseases.is_empty());
+    }
+}
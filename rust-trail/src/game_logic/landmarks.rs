@@ -0,0 +1,117 @@
+use serde::{Serialize, Deserialize};
+use crate::game_logic::blacksmith::BlacksmithBaseline;
+use crate::game_logic::river::RiverBaseline;
+use crate::game_logic::snow::PassBaseline;
+use crate::game_logic::store::StoreBaseline;
+use crate::game_logic::tables::tables;
+
+/// What kind of place a landmark is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LandmarkKind {
+    /// A town where the trip begins
+    Town,
+    /// A fort or trading post with a store
+    Fort,
+    /// A river that has to be crossed
+    River,
+    /// A natural landmark such as a rock or pass
+    Natural,
+    /// The end of the trail
+    Destination,
+}
+
+/// Kind of country the trail crosses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Zone {
+    #[default]
+    Plains,
+    Mountains,
+    Desert,
+    Forest,
+}
+
+/// A landmark along the trail
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Landmark {
+    /// Stable identifier
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// Distance from Independence (in miles)
+    pub miles: f32,
+    /// What kind of place it is
+    pub kind: LandmarkKind,
+    /// Image shown on arrival, or empty for none
+    #[serde(default)]
+    pub background: String,
+    /// Country the trail crosses from here to the next landmark
+    #[serde(default)]
+    pub zone: Zone,
+    /// Usual size of the river, for river crossings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub river: Option<RiverBaseline>,
+    /// When snow closes the landmark, for mountain passes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pass: Option<PassBaseline>,
+    /// What the store sells, for forts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub store: Option<StoreBaseline>,
+    /// What the blacksmith charges, for forts with one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blacksmith: Option<BlacksmithBaseline>,
+}
+
+impl Landmark {
+    /// Whether the landmark has a store
+    pub fn is_fort(&self) -> bool {
+        self.kind == LandmarkKind::Fort
+    }
+
+    /// Whether the landmark is a river crossing
+    pub fn is_river(&self) -> bool {
+        self.kind == LandmarkKind::River
+    }
+}
+
+/// Every landmark in trail order
+pub fn all() -> &'static [Landmark] {
+    &tables().landmarks
+}
+
+/// Look up a landmark by id
+pub fn find(id: &str) -> Option<&'static Landmark> {
+    tables().landmark(id)
+}
+
+/// Index of a landmark in trail order
+pub fn index_of(id: &str) -> Option<usize> {
+    all().iter().position(|landmark| landmark.id == id)
+}
+
+/// The first landmark beyond a distance from Independence
+pub fn next_after(miles: f32) -> Option<&'static Landmark> {
+    all().iter().find(|landmark| landmark.miles > miles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forts_and_rivers_are_flagged() {
+        assert!(find("fort_laramie").unwrap().is_fort());
+        assert!(find("snake_river").unwrap().is_river());
+        assert!(!find("chimney_rock").unwrap().is_fort());
+        assert_eq!(all().last().map(|l| l.kind), Some(LandmarkKind::Destination));
+    }
+
+    #[test]
+    fn next_landmark_by_distance() {
+        assert_eq!(next_after(0.0).map(|l| l.id.as_str()), Some("kansas_river"));
+        assert_eq!(next_after(600.0).map(|l| l.id.as_str()), Some("fort_laramie"));
+        assert_eq!(next_after(5000.0), None);
+        assert_eq!(index_of("fort_kearney"), Some(3));
+    }
+}
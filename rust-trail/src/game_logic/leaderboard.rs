@@ -0,0 +1,114 @@
+// The online leaderboard: what is sent to a leaderboard server when a party
+// reaches Oregon, and how the global Top Ten it sends back is read. The seed
+// and a hash of the journey's diary let the server replay the run and check
+// the score. Sending and fetching happen in the game; this only shapes the data.
+
+use std::cmp::Reverse;
+use serde::{Deserialize, Serialize};
+use crate::game_logic::journey::Journey;
+use crate::game_logic::score;
+use crate::game_logic::top_ten::{TopTenEntry, TOP_TEN_SIZE};
+use crate::utils::checksum;
+
+/// A finished journey's score, as posted to the leaderboard
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    /// Name of the party's leader
+    pub name: String,
+    /// Final score
+    pub points: u32,
+    /// Seed the journey was played from
+    pub seed: u64,
+    /// Hash of everything that happened on the way
+    pub replay_hash: String,
+    /// Days the journey took
+    pub days: u32,
+    /// Version of the game that played it
+    pub game_version: String,
+}
+
+impl Submission {
+    /// Score a journey for the leaderboard, or None if it doesn't count:
+    /// only parties that reached Oregon without cheating are sent
+    pub fn new(journey: &Journey, seed: u64) -> Option<Self> {
+        if !journey.has_arrived() || journey.cheated {
+            return None;
+        }
+        Some(Self {
+            name: journey.player.party.first().map_or("Your party", |m| m.name.as_str()).to_string(),
+            points: score::final_score(journey),
+            seed,
+            replay_hash: replay_hash(journey),
+            days: journey.days,
+            game_version: env!("CARGO_PKG_VERSION").to_string(),
+        })
+    }
+
+    /// The submission as the body of a request
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string(self).map_err(|e| format!("Failed to write score: {}", e))
+    }
+}
+
+/// Hash of a journey's diary, which records every day of the run
+pub fn replay_hash(journey: &Journey) -> String {
+    checksum::digest(&serde_json::to_string(&journey.diary).unwrap_or_default())
+}
+
+/// Address scores are posted to on a leaderboard server
+pub fn scores_url(server: &str) -> String {
+    format!("{}/scores", server.trim_end_matches('/'))
+}
+
+/// Address the global Top Ten is fetched from on a leaderboard server
+pub fn top_ten_url(server: &str) -> String {
+    format!("{}/top-ten", server.trim_end_matches('/'))
+}
+
+/// The global Top Ten as the server sends it
+#[derive(Debug, Deserialize)]
+struct TopTenResponse {
+    entries: Vec<TopTenEntry>,
+}
+
+/// Read the global Top Ten from a server's reply, highest first
+pub fn parse_top_ten(json: &str) -> Result<Vec<TopTenEntry>, String> {
+    let response: TopTenResponse =
+        serde_json::from_str(json).map_err(|e| format!("Failed to read the online Top Ten: {}", e))?;
+    let mut entries = response.entries;
+    entries.sort_by_key(|entry| Reverse(entry.points));
+    entries.truncate(TOP_TEN_SIZE);
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::diary::EntryKind;
+    use crate::game_logic::tables::tables;
+
+    #[test]
+    fn only_finished_honest_journeys_are_sent() {
+        let mut journey = Journey::default();
+        assert_eq!(Submission::new(&journey, 7), None);
+
+        journey.landmark = tables().landmarks.len() - 1;
+        let submission = Submission::new(&journey, 7).unwrap();
+        assert_eq!((submission.seed, submission.replay_hash.len()), (7, 16));
+        assert!(submission.to_json().unwrap().contains("\"replay_hash\""));
+
+        journey.record(EntryKind::Note, "Forded the river.");
+        assert_ne!(replay_hash(&journey), submission.replay_hash);
+        journey.cheated = true;
+        assert_eq!(Submission::new(&journey, 7), None);
+    }
+
+    #[test]
+    fn global_top_ten_is_read_best_first() {
+        let json = r#"{"entries": [{"name": "Ada", "points": 900}, {"name": "Ben", "points": 4000}]}"#;
+        let entries = parse_top_ten(json).unwrap();
+        assert_eq!(entries[0].name, "Ben");
+        assert!(parse_top_ten("<html>").is_err());
+        assert_eq!(top_ten_url("https://scores.test/"), "https://scores.test/top-ten");
+    }
+}
@@ -2,30 +2,52 @@
 
 // Export modules
 pub mod player;
+pub mod achievements;
+pub mod blacksmith;
+pub mod cheats;
+pub mod classroom;
+pub mod clothing;
+pub mod commands;
+pub mod control;
+pub mod diary;
+pub mod events;
+pub mod facts;
+pub mod game_events;
+pub mod grass;
+pub mod graves;
+pub mod guidebook;
+pub mod health;
+pub mod hunting;
 pub mod inventory;
+pub mod journey;
+pub mod landmarks;
+pub mod leaderboard;
+pub mod money;
+pub mod morale;
+pub mod occupation;
+pub mod profiles;
+pub mod quotes;
+pub mod rest;
+pub mod river;
+pub mod save;
+pub mod score;
+pub mod slideshow;
+pub mod simulation;
+pub mod snow;
+pub mod speedrun;
+pub mod stats;
+pub mod store;
+pub mod summary;
+pub mod top_ten;
+pub mod tables;
+pub mod time;
+pub mod trading;
+pub mod wagon_train;
+pub mod weather;
 // Submodules will be declared here as they're created
 // pub mod resources;
-// pub mod time;
-// pub mod weather;
-// pub mod health;
-// pub mod events;
-
-/// Core game mechanics constants
-pub mod constants {
-    /// Default starting money
-    pub const DEFAULT_STARTING_MONEY: u32 = 1600;
-    
-    /// Food consumption per person per day (in pounds)
-    pub const FOOD_CONSUMPTION_PER_DAY: f32 = 2.0;
-    
-    /// Miles per day at normal pace
-    pub const MILES_PER_DAY_NORMAL: f32 = 20.0;
-    
-    /// Wagon capacity in pounds
-    pub const WAGON_CAPACITY: f32 = 2000.0;
-}
 
 /// Initializes game logic components
 pub fn initialize() {
-    println!("Game logic system initialized");
+    log::info!("Game logic system initialized");
 }
@@ -0,0 +1,124 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul};
+use crate::utils::format_money;
+
+/// An amount of money, kept in whole cents so prices like $.25 are exact
+///
+/// Data files and saves write money as a number of dollars. Sums and
+/// products too large to keep stop at the largest amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(from = "f64", into = "f64")]
+pub struct Money(u32);
+
+impl Money {
+    /// No money at all
+    pub const ZERO: Money = Money(0);
+
+    /// Most whole dollars an amount can hold
+    pub const MAX_DOLLARS: u32 = u32::MAX / 100;
+
+    /// Create an amount from cents
+    pub const fn from_cents(cents: u32) -> Self {
+        Money(cents)
+    }
+
+    /// Create an amount from whole dollars
+    pub const fn from_dollars(dollars: u32) -> Self {
+        Money(dollars.saturating_mul(100))
+    }
+
+    /// The amount in cents
+    pub fn cents(&self) -> u32 {
+        self.0
+    }
+
+    /// Whole dollars in the amount, dropping any cents
+    pub fn dollars(&self) -> u32 {
+        self.0 / 100
+    }
+
+    /// The amount in dollars, for working out exchange values
+    pub fn as_f32(&self) -> f32 {
+        self.0 as f32 / 100.0
+    }
+
+    /// Subtract, or None if there isn't enough
+    pub fn checked_sub(self, other: Money) -> Option<Money> {
+        self.0.checked_sub(other.0).map(Money)
+    }
+
+    /// Subtract, stopping at zero
+    pub fn saturating_sub(self, other: Money) -> Money {
+        Money(self.0.saturating_sub(other.0))
+    }
+}
+
+impl From<f64> for Money {
+    fn from(dollars: f64) -> Self {
+        Money((dollars.max(0.0) * 100.0).round() as u32)
+    }
+}
+
+impl From<Money> for f64 {
+    fn from(money: Money) -> Self {
+        money.0 as f64 / 100.0
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money(self.0.saturating_add(other.0))
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        self.0 = self.0.saturating_add(other.0);
+    }
+}
+
+impl Mul<u32> for Money {
+    type Output = Money;
+
+    fn mul(self, quantity: u32) -> Money {
+        Money(self.0.saturating_mul(quantity))
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_money(*self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn money_keeps_exact_cents() {
+        let bullets = Money::from(0.25);
+        assert_eq!(bullets.cents(), 25);
+        assert_eq!((bullets * 3).to_string(), "$0.75");
+        assert_eq!(Money::from_cents(123_456).to_string(), "$1,234.56");
+        assert_eq!(Money::from_dollars(1600).to_string(), "$1,600.00");
+        assert_eq!(Money::from_dollars(5).checked_sub(Money::from_cents(501)), None);
+        assert_eq!(serde_json::to_string(&Money::from_cents(1050)).unwrap(), "10.5");
+        assert_eq!(serde_json::from_str::<Money>("0.1").unwrap(), Money::from_cents(10));
+    }
+
+    #[test]
+    fn large_amounts_stop_at_the_most_money_can_hold() {
+        let most = Money::from_cents(u32::MAX);
+        assert_eq!(Money::from_dollars(50_000_000), most);
+        assert_eq!(Money::from_dollars(Money::MAX_DOLLARS).dollars(), Money::MAX_DOLLARS);
+        assert_eq!(Money::from_dollars(20) * u32::MAX, most);
+        let mut total = most;
+        total += Money::from_cents(1);
+        assert_eq!(total, most);
+        assert_eq!(most + most, most);
+    }
+}
@@ -0,0 +1,128 @@
+// Party morale: how willing the party is to keep going. Hardships wear it
+// down and good days build it back up; a low-spirited party travels and
+// hunts worse, and may refuse to go on at all.
+
+use serde::{Deserialize, Serialize};
+
+/// Morale a party sets out with, and drifts back toward
+pub const STARTING_MORALE: f32 = 70.0;
+
+/// Morale lost when a member dies
+pub const DEATH_LOSS: f32 = 20.0;
+/// Morale lost on each day without enough to eat
+pub const HUNGER_LOSS: f32 = 4.0;
+/// Morale regained on each day of rest
+pub const REST_GAIN: f32 = 3.0;
+/// Morale gained on reaching a landmark
+pub const LANDMARK_GAIN: f32 = 5.0;
+/// Points a day morale drifts back toward where it started
+pub const DAILY_SETTLE: f32 = 0.5;
+
+/// How the party feels, in words
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MoraleLevel {
+    Desperate,
+    Low,
+    Good,
+    High,
+}
+
+impl MoraleLevel {
+    /// Display name of the level
+    pub fn name(&self) -> &'static str {
+        match self {
+            MoraleLevel::Desperate => "desperate",
+            MoraleLevel::Low => "low",
+            MoraleLevel::Good => "good",
+            MoraleLevel::High => "high",
+        }
+    }
+}
+
+/// The party's spirits, from 0 (ready to give up) to 100 (eager to press on)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Morale(f32);
+
+impl Default for Morale {
+    fn default() -> Self {
+        Morale(STARTING_MORALE)
+    }
+}
+
+impl Morale {
+    /// Current value
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+
+    /// Raise or lower morale, staying in range
+    pub fn change(&mut self, amount: f32) {
+        self.0 = (self.0 + amount).clamp(0.0, 100.0);
+    }
+
+    /// Drift a day's worth back toward the starting morale
+    pub fn settle(&mut self) {
+        let gap = STARTING_MORALE - self.0;
+        self.0 += gap.clamp(-DAILY_SETTLE, DAILY_SETTLE);
+    }
+
+    /// How the party feels
+    pub fn level(&self) -> MoraleLevel {
+        match self.0 {
+            m if m >= 85.0 => MoraleLevel::High,
+            m if m >= 45.0 => MoraleLevel::Good,
+            m if m >= 20.0 => MoraleLevel::Low,
+            _ => MoraleLevel::Desperate,
+        }
+    }
+
+    /// Factor applied to the miles covered each day
+    pub fn speed_factor(&self) -> f32 {
+        match self.level() {
+            MoraleLevel::High => 1.05,
+            MoraleLevel::Good => 1.0,
+            MoraleLevel::Low => 0.9,
+            MoraleLevel::Desperate => 0.8,
+        }
+    }
+
+    /// Factor applied to the food brought back from hunting
+    pub fn hunting_factor(&self) -> f32 {
+        match self.level() {
+            MoraleLevel::High => 1.1,
+            MoraleLevel::Good => 1.0,
+            MoraleLevel::Low => 0.85,
+            MoraleLevel::Desperate => 0.7,
+        }
+    }
+
+    /// Factor applied to the weight of the party refusing to go on
+    pub fn refusal_factor(&self) -> f32 {
+        match self.level() {
+            MoraleLevel::High | MoraleLevel::Good => 0.0,
+            MoraleLevel::Low => 1.0,
+            MoraleLevel::Desperate => 3.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn morale_falls_with_hardship_and_recovers() {
+        let mut morale = Morale::default();
+        assert_eq!(morale.level(), MoraleLevel::Good);
+        morale.change(-DEATH_LOSS * 3.0);
+        assert_eq!(morale.level(), MoraleLevel::Desperate);
+        assert!(morale.speed_factor() < 1.0 && morale.refusal_factor() > 0.0);
+
+        morale.change(-1000.0);
+        assert_eq!(morale.value(), 0.0);
+        for _ in 0..1000 {
+            morale.settle();
+        }
+        assert_eq!(morale.value(), STARTING_MORALE);
+    }
+}
@@ -0,0 +1,99 @@
+use serde::{Serialize, Deserialize};
+use crate::game_logic::money::Money;
+use crate::game_logic::tables::tables;
+
+/// What the party leader did before setting out, which sets the starting money and final score bonus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Occupation {
+    #[default]
+    Banker,
+    Doctor,
+    Merchant,
+    Blacksmith,
+    Carpenter,
+    Saddlemaker,
+    Farmer,
+    Teacher,
+}
+
+impl Occupation {
+    /// All occupations, richest first
+    pub const ALL: [Occupation; 8] = [
+        Occupation::Banker,
+        Occupation::Doctor,
+        Occupation::Merchant,
+        Occupation::Blacksmith,
+        Occupation::Carpenter,
+        Occupation::Saddlemaker,
+        Occupation::Farmer,
+        Occupation::Teacher,
+    ];
+
+    /// Display name of the occupation
+    pub fn name(&self) -> &'static str {
+        match self {
+            Occupation::Banker => "banker from Boston",
+            Occupation::Doctor => "doctor",
+            Occupation::Merchant => "merchant",
+            Occupation::Blacksmith => "blacksmith",
+            Occupation::Carpenter => "carpenter from Ohio",
+            Occupation::Saddlemaker => "saddlemaker",
+            Occupation::Farmer => "farmer from Illinois",
+            Occupation::Teacher => "teacher",
+        }
+    }
+
+    /// What the occupation is good for on the trail
+    pub fn perk(&self) -> &'static str {
+        match self {
+            Occupation::Banker | Occupation::Merchant => "More money to spend at the start.",
+            Occupation::Doctor => "Better at nursing the sick back to health.",
+            Occupation::Blacksmith | Occupation::Carpenter => "Better at repairing the wagon.",
+            Occupation::Farmer => "Keeps the oxen healthier.",
+            Occupation::Saddlemaker | Occupation::Teacher => "A bigger bonus to the final score.",
+        }
+    }
+
+    /// Money the party starts with
+    pub fn starting_money(&self) -> Money {
+        tables().occupation(*self).money
+    }
+
+    /// Factor applied to the final score
+    pub fn score_multiplier(&self) -> f32 {
+        tables().occupation(*self).score_multiplier
+    }
+
+    /// Factor applied to the chance of fashioning a spare part
+    pub fn repair_factor(&self) -> f32 {
+        match self {
+            Occupation::Carpenter | Occupation::Blacksmith => 2.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Factor applied to the chance of nursing a sick member back to health
+    pub fn tend_factor(&self) -> f32 {
+        match self {
+            Occupation::Doctor => 1.5,
+            _ => 1.0,
+        }
+    }
+
+    /// Factor applied to the chance of oxen being injured or dying
+    pub fn ox_risk_factor(&self) -> f32 {
+        match self {
+            Occupation::Farmer => 0.5,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Starting money and score bonus of an occupation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OccupationStats {
+    /// Money the party starts with
+    pub money: Money,
+    /// Factor applied to the final score
+    pub score_multiplier: f32,
+}
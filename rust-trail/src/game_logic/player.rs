@@ -1,4 +1,8 @@
 use serde::{Serialize, Deserialize};
+use crate::game_logic::health;
+use crate::game_logic::money::Money;
+use crate::game_logic::occupation::Occupation;
+use crate::game_logic::time::{DateError, TrailDate};
 
 /// Represents the health status of a party member
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -10,6 +14,41 @@ pub enum HealthStatus {
     Deceased,
 }
 
+impl HealthStatus {
+    /// Display name of the status
+    pub fn name(&self) -> &'static str {
+        match self {
+            HealthStatus::Good => "good",
+            HealthStatus::Fair => "fair",
+            HealthStatus::Poor => "poor",
+            HealthStatus::VeryPoor => "very poor",
+            HealthStatus::Deceased => "dead",
+        }
+    }
+
+    /// How badly off a member is, from 0 (good) to 4 (deceased)
+    pub fn severity(&self) -> u8 {
+        match self {
+            HealthStatus::Good => 0,
+            HealthStatus::Fair => 1,
+            HealthStatus::Poor => 2,
+            HealthStatus::VeryPoor => 3,
+            HealthStatus::Deceased => 4,
+        }
+    }
+
+    /// How much useful work a member in this condition can do (0.0 - 1.0)
+    pub fn work_factor(&self) -> f32 {
+        match self {
+            HealthStatus::Good => 1.0,
+            HealthStatus::Fair => 0.75,
+            HealthStatus::Poor => 0.5,
+            HealthStatus::VeryPoor => 0.25,
+            HealthStatus::Deceased => 0.0,
+        }
+    }
+}
+
 /// Represents a specific disease or ailment
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Disease {
@@ -24,13 +63,59 @@ pub enum Disease {
     SnakeBite,
 }
 
+impl Disease {
+    /// Display name of the disease
+    pub fn name(&self) -> &'static str {
+        match self {
+            Disease::Cholera => "cholera",
+            Disease::Dysentery => "dysentery",
+            Disease::Measles => "measles",
+            Disease::Typhoid => "typhoid fever",
+            Disease::Fever => "a fever",
+            Disease::BrokenLeg => "a broken leg",
+            Disease::BrokenArm => "a broken arm",
+            Disease::Exhaustion => "exhaustion",
+            Disease::SnakeBite => "a snakebite",
+        }
+    }
+
+    /// Chance of shaking off the disease on a day of rest
+    pub fn recovery_chance(&self) -> f32 {
+        match self {
+            Disease::Cholera => 0.15,
+            Disease::Dysentery => 0.2,
+            Disease::Measles => 0.25,
+            Disease::Typhoid => 0.15,
+            Disease::Fever => 0.3,
+            Disease::BrokenLeg => 0.1,
+            Disease::BrokenArm => 0.15,
+            Disease::Exhaustion => 0.6,
+            Disease::SnakeBite => 0.3,
+        }
+    }
+
+    /// Points of ill health the disease adds each day
+    pub fn health_penalty(&self) -> f32 {
+        match self {
+            Disease::Cholera | Disease::Typhoid => 6.0,
+            Disease::Dysentery => 5.0,
+            Disease::Measles | Disease::SnakeBite => 4.0,
+            Disease::Fever | Disease::BrokenLeg => 3.0,
+            Disease::BrokenArm | Disease::Exhaustion => 2.0,
+        }
+    }
+}
+
 /// Represents a single party member
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PartyMember {
     /// Name of the party member
     pub name: String,
-    /// Current health status
+    /// Current health status, set by the general health
     pub health: HealthStatus,
+    /// Points of accumulated ill health (0 is perfectly healthy)
+    #[serde(default)]
+    pub general_health: f32,
     /// Any diseases the party member has
     pub diseases: Vec<Disease>,
     /// Whether this member is the party leader
@@ -45,6 +130,7 @@ impl PartyMember {
         Self {
             name: name.to_string(),
             health: HealthStatus::Good,
+            general_health: 0.0,
             diseases: Vec::new(),
             is_leader,
             age,
@@ -70,26 +156,37 @@ impl PartyMember {
         self.diseases.retain(|&d| d != disease);
     }
     
+    /// Whether the member is alive and has a disease or poor health
+    pub fn is_sick(&self) -> bool {
+        self.is_alive() && (!self.diseases.is_empty() || self.health != HealthStatus::Good)
+    }
+
+    /// Set the general health, updating the health status; the dead stay dead
+    pub fn set_general_health(&mut self, points: f32) {
+        if !self.is_alive() {
+            return;
+        }
+        self.general_health = points.max(0.0);
+        self.health = health::status_for(self.general_health);
+    }
+
     /// Degrade health by one level
     pub fn degrade_health(&mut self) {
-        self.health = match self.health {
-            HealthStatus::Good => HealthStatus::Fair,
-            HealthStatus::Fair => HealthStatus::Poor,
-            HealthStatus::Poor => HealthStatus::VeryPoor,
-            HealthStatus::VeryPoor => HealthStatus::Deceased,
-            HealthStatus::Deceased => HealthStatus::Deceased,
-        };
+        let severity = self.health.severity() as usize;
+        if severity < health::THRESHOLDS.len() {
+            self.set_general_health(self.general_health.max(health::THRESHOLDS[severity]));
+        }
     }
     
     /// Improve health by one level
     pub fn improve_health(&mut self) {
-        self.health = match self.health {
-            HealthStatus::Good => HealthStatus::Good,
+        let better = match self.health {
             HealthStatus::Fair => HealthStatus::Good,
             HealthStatus::Poor => HealthStatus::Fair,
             HealthStatus::VeryPoor => HealthStatus::Poor,
-            HealthStatus::Deceased => HealthStatus::Deceased,
+            HealthStatus::Good | HealthStatus::Deceased => return,
         };
+        self.set_general_health(self.general_health.min(health::lower_bound(better)));
     }
 }
 
@@ -102,6 +199,50 @@ pub enum Pace {
     Resting,
 }
 
+impl Pace {
+    /// All paces in menu order
+    pub const ALL: [Pace; 4] = [Pace::Steady, Pace::Strenuous, Pace::Grueling, Pace::Resting];
+
+    /// Display name of the pace
+    pub fn name(&self) -> &'static str {
+        match self {
+            Pace::Steady => "steady",
+            Pace::Strenuous => "strenuous",
+            Pace::Grueling => "grueling",
+            Pace::Resting => "resting",
+        }
+    }
+
+    /// What the pace means for the party
+    pub fn description(&self) -> &'static str {
+        match self {
+            Pace::Steady => "You travel about 8 hours a day, taking frequent rests.",
+            Pace::Strenuous => "You travel about 12 hours a day. The party grows tired.",
+            Pace::Grueling => "You travel about 16 hours a day. Health will suffer.",
+            Pace::Resting => "The wagon stays in camp.",
+        }
+    }
+
+    /// Multiplier applied to the normal miles per day
+    pub fn miles_factor(&self) -> f32 {
+        match self {
+            Pace::Steady => 1.0,
+            Pace::Strenuous => 1.5,
+            Pace::Grueling => 2.0,
+            Pace::Resting => 0.0,
+        }
+    }
+
+    /// Points of ill health the pace adds each day
+    pub fn health_penalty(&self) -> f32 {
+        match self {
+            Pace::Steady | Pace::Resting => 0.0,
+            Pace::Strenuous => 2.0,
+            Pace::Grueling => 4.0,
+        }
+    }
+}
+
 /// Represents the food ration level
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Rations {
@@ -110,13 +251,57 @@ pub enum Rations {
     BareBones,
 }
 
+impl Rations {
+    /// All ration levels in menu order
+    pub const ALL: [Rations; 3] = [Rations::Filling, Rations::Meager, Rations::BareBones];
+
+    /// Display name of the ration level
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rations::Filling => "filling",
+            Rations::Meager => "meager",
+            Rations::BareBones => "bare bones",
+        }
+    }
+
+    /// What the ration level means for the party
+    pub fn description(&self) -> &'static str {
+        match self {
+            Rations::Filling => "Meals are large and generous.",
+            Rations::Meager => "Meals are small, but adequate.",
+            Rations::BareBones => "Meals are very small. Everyone stays hungry.",
+        }
+    }
+
+    /// Multiplier applied to the normal food eaten per person per day
+    pub fn food_factor(&self) -> f32 {
+        match self {
+            Rations::Filling => 1.5,
+            Rations::Meager => 1.0,
+            Rations::BareBones => 0.5,
+        }
+    }
+
+    /// Points of ill health the rations add each day
+    pub fn health_penalty(&self) -> f32 {
+        match self {
+            Rations::Filling => 0.0,
+            Rations::Meager => 2.0,
+            Rations::BareBones => 4.0,
+        }
+    }
+}
+
 /// Manages the player's party and game state
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerState {
     /// Party members
     pub party: Vec<PartyMember>,
-    /// Money (in dollars)
-    pub money: u32,
+    /// Money left to spend
+    pub money: Money,
+    /// What the leader did before setting out
+    #[serde(default)]
+    pub occupation: Occupation,
     /// Current pace of travel
     pub pace: Pace,
     /// Current food ration level
@@ -125,12 +310,15 @@ pub struct PlayerState {
     pub miles_traveled: f32,
     /// Current location name
     pub location: String,
-    /// Day of the journey
-    pub day: u32,
-    /// Month (1-12)
-    pub month: u8,
-    /// Year
-    pub year: u16,
+    /// Today's date
+    #[serde(flatten)]
+    pub date: TrailDate,
+}
+
+impl Default for PlayerState {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl PlayerState {
@@ -138,14 +326,13 @@ impl PlayerState {
     pub fn new() -> Self {
         Self {
             party: Vec::new(),
-            money: 1600, // Default starting money
+            money: Occupation::default().starting_money(),
+            occupation: Occupation::default(),
             pace: Pace::Steady,
             rations: Rations::Filling,
             miles_traveled: 0.0,
             location: "Independence, Missouri".to_string(),
-            day: 1,
-            month: 3, // March
-            year: 1848,
+            date: TrailDate::clamped(3, 1, 1848),
         }
     }
     
@@ -162,51 +349,138 @@ impl PlayerState {
         }
     }
     
+    /// Choose the leader's occupation, which sets the starting money
+    pub fn set_occupation(&mut self, occupation: Occupation) {
+        self.occupation = occupation;
+        self.money = occupation.starting_money();
+    }
+
     /// Get the number of living party members
     pub fn living_party_members(&self) -> usize {
         self.party.iter().filter(|m| m.is_alive()).count()
     }
     
+    /// Set the current date, rejecting days that don't exist
+    pub fn set_date(&mut self, month: u8, day: u32, year: u16) -> Result<(), DateError> {
+        self.date = TrailDate::new(month, day, year)?;
+        Ok(())
+    }
+
     /// Advance the date by the specified number of days
     pub fn advance_date(&mut self, days: u32) {
-        let mut remaining_days = days;
-        
-        while remaining_days > 0 {
-            // Days in the current month
-            let days_in_month = match self.month {
-                1 => 31,  // January
-                2 => if self.year % 4 == 0 { 29 } else { 28 },  // February
-                3 => 31,  // March
-                4 => 30,  // April
-                5 => 31,  // May
-                6 => 30,  // June
-                7 => 31,  // July
-                8 => 31,  // August
-                9 => 30,  // September
-                10 => 31, // October
-                11 => 30, // November
-                12 => 31, // December
-                _ => 30,  // Default
-            };
-            
-            // Calculate how many days to advance in the current month
-            let days_to_advance = remaining_days.min(days_in_month - self.day + 1);
-            
-            // Advance the day
-            self.day += days_to_advance;
-            remaining_days -= days_to_advance;
-            
-            // Check if we need to move to the next month
-            if self.day > days_in_month {
-                self.day = 1;
-                self.month += 1;
-                
-                // Check if we need to move to the next year
-                if self.month > 12 {
-                    self.month = 1;
-                    self.year += 1;
-                }
-            }
-        }
-    }
-}
\ No newline at end of file
+        match self.date.add_days(days) {
+            Ok(date) => self.date = date,
+            Err(e) => log::error!("Could not advance date: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_on(month: u8, day: u32, year: u16) -> PlayerState {
+        let mut state = PlayerState::new();
+        state.set_date(month, day, year).unwrap();
+        state
+    }
+
+    fn date(state: &PlayerState) -> (u8, u32, u16) {
+        (state.date.month(), state.date.day(), state.date.year())
+    }
+
+    #[test]
+    fn advancing_zero_days_keeps_date() {
+        let mut state = state_on(3, 1, 1848);
+        state.advance_date(0);
+        assert_eq!(date(&state), (3, 1, 1848));
+    }
+
+    #[test]
+    fn advancing_within_a_month() {
+        let mut state = state_on(3, 1, 1848);
+        state.advance_date(10);
+        assert_eq!(date(&state), (3, 11, 1848));
+    }
+
+    #[test]
+    fn advancing_exactly_to_month_end() {
+        let mut state = state_on(3, 1, 1848);
+        state.advance_date(30);
+        assert_eq!(date(&state), (3, 31, 1848));
+    }
+
+    #[test]
+    fn advancing_one_past_month_end_rolls_over() {
+        let mut state = state_on(3, 31, 1848);
+        state.advance_date(1);
+        assert_eq!(date(&state), (4, 1, 1848));
+    }
+
+    #[test]
+    fn advancing_from_last_day_of_short_month() {
+        let mut state = state_on(4, 30, 1848);
+        state.advance_date(1);
+        assert_eq!(date(&state), (5, 1, 1848));
+    }
+
+    #[test]
+    fn multi_month_jump() {
+        let mut state = state_on(3, 15, 1848);
+        state.advance_date(150);
+        assert_eq!(date(&state), (8, 12, 1848));
+    }
+
+    #[test]
+    fn leap_year_february() {
+        let mut state = state_on(2, 28, 1848);
+        state.advance_date(1);
+        assert_eq!(date(&state), (2, 29, 1848));
+        state.advance_date(1);
+        assert_eq!(date(&state), (3, 1, 1848));
+    }
+
+    #[test]
+    fn non_leap_year_february() {
+        let mut state = state_on(2, 28, 1849);
+        state.advance_date(1);
+        assert_eq!(date(&state), (3, 1, 1849));
+    }
+
+    #[test]
+    fn year_rollover() {
+        let mut state = state_on(12, 31, 1848);
+        state.advance_date(1);
+        assert_eq!(date(&state), (1, 1, 1849));
+    }
+
+    #[test]
+    fn advancing_day_by_day_matches_single_jump() {
+        let mut stepped = state_on(3, 1, 1848);
+        let mut jumped = state_on(3, 1, 1848);
+        for _ in 0..400 {
+            stepped.advance_date(1);
+        }
+        jumped.advance_date(400);
+        assert_eq!(date(&stepped), date(&jumped));
+    }
+
+    #[test]
+    fn set_date_rejects_invalid_dates() {
+        let mut state = PlayerState::new();
+        assert!(state.set_date(13, 1, 1848).is_err());
+        assert!(state.set_date(4, 31, 1848).is_err());
+        assert!(state.set_date(2, 29, 1849).is_err());
+        assert_eq!(date(&state), (3, 1, 1848));
+    }
+
+    #[test]
+    fn out_of_range_fields_are_clamped_before_advancing() {
+        let mut json = serde_json::to_value(PlayerState::new()).unwrap();
+        json["month"] = 4.into();
+        json["day"] = 35.into();
+        let mut state: PlayerState = serde_json::from_value(json).unwrap();
+        state.advance_date(1);
+        assert_eq!(date(&state), (5, 1, 1848));
+    }
+}
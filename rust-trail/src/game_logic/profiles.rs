@@ -0,0 +1,114 @@
+// Player profiles, for several people sharing one machine. Each profile keeps
+// its own settings, achievements, lifetime statistics and saved game; the
+// Top Ten, graves and class list are shared by everyone.
+
+use serde::{Deserialize, Serialize};
+use crate::utils::storage::{self, Storage};
+use crate::utils::versioned::{self, Migration};
+
+/// File the list of profiles is kept in
+pub const PROFILES_FILE: &str = "profiles.json";
+
+/// Changes to the format the profile list is kept in, one per version
+const MIGRATIONS: &[Migration] = &[];
+
+/// Most profiles kept
+pub const MAX_PROFILES: usize = 8;
+
+/// Longest profile name
+pub const MAX_NAME_LEN: usize = 16;
+
+/// Name a per-profile file is stored under for a profile, or as it is
+/// without one
+pub fn profile_file(profile: Option<&str>, name: &str) -> String {
+    match profile {
+        Some(profile) => format!("profile_{}_{}", storage::safe_name(profile), name),
+        None => name.to_string(),
+    }
+}
+
+/// Every profile, and the one played last
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Profiles {
+    names: Vec<String>,
+    /// Profile chosen last time, offered first next time
+    #[serde(default)]
+    last: Option<String>,
+}
+
+impl Profiles {
+    /// Load the profiles, or start with none if there is nothing stored yet
+    pub fn load(storage: &dyn Storage, name: &str) -> Result<Self, String> {
+        match storage.read(name)? {
+            Some(json) => versioned::from_json(&json, MIGRATIONS).map_err(|e| format!("Failed to read profiles: {} ({})", e, name)),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Write the profiles to storage
+    pub fn save(&self, storage: &mut dyn Storage, name: &str) -> Result<(), String> {
+        let json = versioned::to_json(self, MIGRATIONS).map_err(|e| format!("Failed to write profiles: {}", e))?;
+        storage.write(name, &json)
+    }
+
+    /// Profile names, oldest first
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Profile chosen last time, if it's still there
+    pub fn last(&self) -> Option<&str> {
+        self.last.as_deref().filter(|last| self.names.iter().any(|n| n == last))
+    }
+
+    /// Remember the profile chosen, or None for playing as a guest
+    pub fn set_last(&mut self, name: Option<&str>) {
+        self.last = name.map(str::to_string);
+    }
+
+    /// Add a profile, returning its name as kept, or None if the name is
+    /// blank, clashes with another once made safe for file names, or there
+    /// are too many profiles
+    pub fn add(&mut self, name: &str) -> Option<String> {
+        let name: String = name.trim().chars().take(MAX_NAME_LEN).collect();
+        let taken = self.names.iter().any(|n| storage::safe_name(n) == storage::safe_name(&name));
+        if name.is_empty() || taken || self.names.len() >= MAX_PROFILES {
+            return None;
+        }
+        self.names.push(name.clone());
+        Some(name)
+    }
+
+    /// Take a profile off the list; what it saved is left in storage
+    pub fn remove(&mut self, index: usize) {
+        if index < self.names.len() {
+            self.names.remove(index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::storage::MemoryStorage;
+
+    #[test]
+    fn profiles_keep_their_own_files() {
+        let mut profiles = Profiles::default();
+        assert_eq!(profiles.add(" Ada "), Some("Ada".to_string()));
+        assert_eq!(profiles.add("ada"), None);
+        assert_eq!(profiles.add("Ben"), Some("Ben".to_string()));
+        profiles.set_last(Some("Ben"));
+        assert_eq!(profiles.last(), Some("Ben"));
+
+        let mut storage = MemoryStorage::default();
+        profiles.save(&mut storage, PROFILES_FILE).unwrap();
+        let mut loaded = Profiles::load(&storage, PROFILES_FILE).unwrap();
+        assert_eq!(loaded, profiles);
+        loaded.remove(1);
+        assert_eq!(loaded.last(), None);
+
+        assert_eq!(profile_file(Some("Mary Ann"), "save.json"), "profile_mary_ann_save.json");
+        assert_eq!(profile_file(None, "save.json"), "save.json");
+    }
+}
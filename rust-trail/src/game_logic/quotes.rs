@@ -0,0 +1,116 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::Deserialize;
+
+/// Text file holding the quotes, loaded through the asset manager
+pub const QUOTES_FILE: &str = "quotes.json";
+
+/// Something a person on the trail has to say
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Quote {
+    /// Who is speaking
+    pub speaker: String,
+    /// Portrait number, matching the TALK images
+    #[serde(default = "default_portrait")]
+    pub portrait: u8,
+    /// Landmark id where the quote is heard, or anywhere if missing
+    #[serde(default)]
+    pub landmark: Option<String>,
+    /// Months (1-12) the quote is heard in, or any month if empty
+    #[serde(default)]
+    pub months: Vec<u8>,
+    /// What they say
+    pub text: String,
+}
+
+/// Portrait used when a quote doesn't name one
+fn default_portrait() -> u8 {
+    1
+}
+
+impl Quote {
+    /// Whether the quote is heard in the given month
+    fn fits_month(&self, month: u8) -> bool {
+        self.months.is_empty() || self.months.contains(&month)
+    }
+}
+
+/// All the quotes people on the trail can say
+#[derive(Debug, Clone, Default)]
+pub struct QuoteBook {
+    quotes: Vec<Quote>,
+}
+
+impl QuoteBook {
+    /// Parse the quote database
+    pub fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        Ok(Self { quotes: serde_json::from_str(json)? })
+    }
+
+    /// Number of quotes
+    pub fn len(&self) -> usize {
+        self.quotes.len()
+    }
+
+    /// Whether there are no quotes
+    pub fn is_empty(&self) -> bool {
+        self.quotes.is_empty()
+    }
+
+    /// Quotes that fit a landmark and month, preferring ones about the landmark
+    pub fn candidates(&self, landmark: &str, month: u8) -> Vec<&Quote> {
+        let local: Vec<&Quote> = self
+            .quotes
+            .iter()
+            .filter(|q| q.landmark.as_deref() == Some(landmark) && q.fits_month(month))
+            .collect();
+        if !local.is_empty() {
+            return local;
+        }
+        self.quotes
+            .iter()
+            .filter(|q| q.landmark.is_none() && q.fits_month(month))
+            .collect()
+    }
+
+    /// Pick someone to talk to at a landmark in a month
+    pub fn pick<R: Rng>(&self, landmark: &str, month: u8, rng: &mut R) -> Option<&Quote> {
+        self.candidates(landmark, month).choose(rng).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUOTES: &str = r#"[
+        {"speaker": "A", "landmark": "fort_hall", "text": "at the fort"},
+        {"speaker": "B", "landmark": "fort_hall", "months": [12], "text": "at the fort in winter"},
+        {"speaker": "C", "months": [6], "text": "anywhere in summer"},
+        {"speaker": "D", "text": "anywhere"}
+    ]"#;
+
+    fn speakers(book: &QuoteBook, landmark: &str, month: u8) -> Vec<String> {
+        book.candidates(landmark, month).iter().map(|q| q.speaker.clone()).collect()
+    }
+
+    #[test]
+    fn landmark_quotes_come_first() {
+        let book = QuoteBook::parse(QUOTES).unwrap();
+        assert_eq!(speakers(&book, "fort_hall", 6), ["A"]);
+        assert_eq!(speakers(&book, "fort_hall", 12), ["A", "B"]);
+    }
+
+    #[test]
+    fn general_quotes_fill_in_by_month() {
+        let book = QuoteBook::parse(QUOTES).unwrap();
+        assert_eq!(speakers(&book, "south_pass", 6), ["C", "D"]);
+        assert_eq!(speakers(&book, "south_pass", 1), ["D"]);
+    }
+
+    #[test]
+    fn bundled_quotes_parse() {
+        let book = QuoteBook::parse(include_str!("../../assets/text/quotes.json")).unwrap();
+        assert!(!book.is_empty());
+    }
+}
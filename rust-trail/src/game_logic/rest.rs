@@ -0,0 +1,367 @@
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+use crate::game_logic::grass;
+use crate::game_logic::inventory::{Inventory, ItemType};
+use crate::game_logic::morale::Morale;
+use crate::game_logic::player::{HealthStatus, PlayerState};
+
+/// Bullets used by a member who spends the day hunting
+pub const HUNT_AMMUNITION_PER_DAY: u32 = 10;
+
+/// Most food a healthy member can bring back from a day of hunting (in pounds)
+pub const HUNT_MAX_FOOD: u32 = 60;
+
+/// Most food a healthy member can gather from a day of foraging (in pounds)
+pub const FORAGE_MAX_FOOD: u32 = 15;
+
+/// Chance a healthy member fashions a usable spare part in a day of repairs
+pub const REPAIR_SUCCESS_CHANCE: f32 = 0.3;
+
+/// Chance a healthy caretaker helps a sick member recover in a day
+pub const TEND_SUCCESS_CHANCE: f32 = 0.5;
+
+/// A task a party member can be given during a rest day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RestTask {
+    Rest,       // Recover strength in camp
+    Hunt,       // Hunt for game near camp
+    Forage,     // Gather berries, roots, and greens
+    Repair,     // Work on the wagon and fashion spare parts
+    TendSick,   // Look after the sickest party member
+}
+
+impl RestTask {
+    /// All tasks in menu order
+    pub const ALL: [RestTask; 5] = [
+        RestTask::Rest,
+        RestTask::Hunt,
+        RestTask::Forage,
+        RestTask::Repair,
+        RestTask::TendSick,
+    ];
+
+    /// Display name of the task
+    pub fn name(&self) -> &'static str {
+        match self {
+            RestTask::Rest => "rest",
+            RestTask::Hunt => "hunt",
+            RestTask::Forage => "forage",
+            RestTask::Repair => "repair the wagon",
+            RestTask::TendSick => "tend the sick",
+        }
+    }
+}
+
+/// Tasks assigned to each party member, indexed the same as PlayerState::party
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkAssignments {
+    tasks: Vec<RestTask>,
+}
+
+impl WorkAssignments {
+    /// Create assignments with every member resting
+    pub fn new(party_size: usize) -> Self {
+        Self {
+            tasks: vec![RestTask::Rest; party_size],
+        }
+    }
+
+    /// Assign a task to the member at the given party index
+    pub fn assign(&mut self, member: usize, task: RestTask) {
+        if member >= self.tasks.len() {
+            self.tasks.resize(member + 1, RestTask::Rest);
+        }
+        self.tasks[member] = task;
+    }
+
+    /// Get the task for the member at the given party index
+    pub fn task_for(&self, member: usize) -> RestTask {
+        self.tasks.get(member).copied().unwrap_or(RestTask::Rest)
+    }
+
+    /// Check whether the member spends the day resting
+    pub fn is_resting(&self, member: usize) -> bool {
+        self.task_for(member) == RestTask::Rest
+    }
+}
+
+/// What came of one member's day of work
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskResult {
+    Rested,
+    Food(u32),
+    Part(ItemType),
+    NothingFound,
+    NoAmmunition,
+    Treated(String),
+    NoPatients,
+    WagonFull,
+}
+
+/// A member's task result for one day
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskOutcome {
+    /// Name of the member who did the work
+    pub member: String,
+    /// The task they were assigned
+    pub task: RestTask,
+    /// What it produced
+    pub result: TaskResult,
+}
+
+impl TaskOutcome {
+    /// Describe the outcome for the rest summary
+    pub fn describe(&self) -> String {
+        match &self.result {
+            TaskResult::Rested => format!("{} rested.", self.member),
+            TaskResult::Food(pounds) => format!(
+                "{} went to {} and brought back {} pounds of food.",
+                self.member, self.task.name(), pounds
+            ),
+            TaskResult::Part(item) => format!("{} fashioned a {}.", self.member, part_name(*item)),
+            TaskResult::NothingFound => format!(
+                "{} tried to {} but had no luck.",
+                self.member, self.task.name()
+            ),
+            TaskResult::NoAmmunition => format!("{} had no bullets to hunt with.", self.member),
+            TaskResult::Treated(patient) => format!("{} nursed {} back to better health.", self.member, patient),
+            TaskResult::NoPatients => format!("{} found no one who needed tending.", self.member),
+            TaskResult::WagonFull => format!("{} found food but the wagon had no room for it.", self.member),
+        }
+    }
+}
+
+/// Get the display name of a spare wagon part
+fn part_name(item: ItemType) -> &'static str {
+    match item {
+        ItemType::SpareWheel => "spare wagon wheel",
+        ItemType::SpareAxle => "spare wagon axle",
+        ItemType::SpareTongue => "spare wagon tongue",
+        _ => "spare part",
+    }
+}
+
+/// Carry out one day of assigned work for every living member
+pub fn work_day<R: Rng>(
+    player: &mut PlayerState,
+    inventory: &mut Inventory,
+    assignments: &WorkAssignments,
+    grass: f32,
+    morale: Morale,
+    rng: &mut R,
+) -> Vec<TaskOutcome> {
+    let mut outcomes = Vec::new();
+
+    for index in 0..player.party.len() {
+        let member = &player.party[index];
+        if !member.is_alive() {
+            continue;
+        }
+
+        let task = assignments.task_for(index);
+        let factor = member.health.work_factor();
+        let name = member.name.clone();
+
+        let result = match task {
+            RestTask::Rest => TaskResult::Rested,
+            RestTask::Hunt => {
+                if inventory.use_ammunition(HUNT_AMMUNITION_PER_DAY) {
+                    let pounds = (rng.gen_range(0..=HUNT_MAX_FOOD) as f32 * factor * morale.hunting_factor()) as u32;
+                    gather_food(inventory, pounds)
+                } else {
+                    TaskResult::NoAmmunition
+                }
+            }
+            RestTask::Forage => {
+                let pounds = (rng.gen_range(0..=FORAGE_MAX_FOOD) as f32 * factor * grass::forage_factor(grass)) as u32;
+                gather_food(inventory, pounds)
+            }
+            RestTask::Repair => {
+                if rng.gen::<f32>() < REPAIR_SUCCESS_CHANCE * factor * player.occupation.repair_factor() {
+                    let part = match rng.gen_range(0..3) {
+                        0 => ItemType::SpareWheel,
+                        1 => ItemType::SpareAxle,
+                        _ => ItemType::SpareTongue,
+                    };
+                    if inventory.can_add(part, 1) {
+                        inventory.add_item(part, 1);
+                        TaskResult::Part(part)
+                    } else {
+                        TaskResult::NothingFound
+                    }
+                } else {
+                    TaskResult::NothingFound
+                }
+            }
+            RestTask::TendSick => match sickest_member(player, index) {
+                Some(patient) => {
+                    if rng.gen::<f32>() < TEND_SUCCESS_CHANCE * factor * player.occupation.tend_factor() {
+                        let patient = &mut player.party[patient];
+                        patient.improve_health();
+                        if let Some(&disease) = patient.diseases.first() {
+                            patient.recover_from_disease(disease);
+                        }
+                        TaskResult::Treated(patient.name.clone())
+                    } else {
+                        TaskResult::NothingFound
+                    }
+                }
+                None => TaskResult::NoPatients,
+            },
+        };
+
+        outcomes.push(TaskOutcome { member: name, task, result });
+    }
+
+    outcomes
+}
+
+/// Add gathered food to the wagon, reporting a failed search if nothing was found
+fn gather_food(inventory: &mut Inventory, pounds: u32) -> TaskResult {
+    if pounds == 0 {
+        return TaskResult::NothingFound;
+    }
+    let pounds = pounds.min(inventory.room_for(ItemType::Food));
+    if pounds == 0 {
+        return TaskResult::WagonFull;
+    }
+    inventory.add_item(ItemType::Food, pounds);
+    TaskResult::Food(pounds)
+}
+
+/// Find the living member (other than the caretaker) in the worst health
+fn sickest_member(player: &PlayerState, caretaker: usize) -> Option<usize> {
+    player
+        .party
+        .iter()
+        .enumerate()
+        .filter(|(i, m)| *i != caretaker && m.is_alive())
+        .filter(|(_, m)| m.health != HealthStatus::Good || !m.diseases.is_empty())
+        .max_by_key(|(_, m)| (m.health.severity(), m.diseases.len()))
+        .map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use crate::game_logic::player::Disease;
+
+    /// A party of two in good health and a wagon with bullets to spare
+    fn camp() -> (PlayerState, Inventory) {
+        let mut player = PlayerState::new();
+        player.setup_party("Ezra", &["Ruth"]);
+        let mut inventory = Inventory::new(2000.0);
+        inventory.add_item(ItemType::Ammunition, 100);
+        (player, inventory)
+    }
+
+    /// Result of the first member's day at a task
+    fn first_result(player: &mut PlayerState, inventory: &mut Inventory, task: RestTask, seed: u64) -> TaskResult {
+        let mut assignments = WorkAssignments::new(player.party.len());
+        assignments.assign(0, task);
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        work_day(player, inventory, &assignments, 1.0, Morale::default(), &mut rng).remove(0).result
+    }
+
+    /// Pounds of food a member in some health brings back over many days
+    fn food_over_days(task: RestTask, health: HealthStatus) -> u32 {
+        (0..40)
+            .map(|seed| {
+                let (mut player, mut inventory) = camp();
+                player.party[0].health = health;
+                match first_result(&mut player, &mut inventory, task, seed) {
+                    TaskResult::Food(pounds) => pounds,
+                    _ => 0,
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn resting_members_do_nothing_else() {
+        let (mut player, mut inventory) = camp();
+        let food = inventory.get_quantity(ItemType::Food);
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let outcomes = work_day(&mut player, &mut inventory, &WorkAssignments::new(2), 1.0, Morale::default(), &mut rng);
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(|o| o.task == RestTask::Rest && o.result == TaskResult::Rested));
+        assert_eq!(inventory.get_quantity(ItemType::Food), food);
+        assert_eq!(inventory.get_quantity(ItemType::Ammunition), 100);
+    }
+
+    #[test]
+    fn hunting_uses_bullets_and_brings_back_food() {
+        let (mut player, mut inventory) = camp();
+        let result = first_result(&mut player, &mut inventory, RestTask::Hunt, 3);
+        assert_eq!(inventory.get_quantity(ItemType::Ammunition), 100 - HUNT_AMMUNITION_PER_DAY);
+        match result {
+            TaskResult::Food(pounds) => assert_eq!(inventory.get_quantity(ItemType::Food), pounds),
+            other => assert_eq!(other, TaskResult::NothingFound),
+        }
+
+        assert!(inventory.use_ammunition(inventory.get_quantity(ItemType::Ammunition)));
+        assert_eq!(first_result(&mut player, &mut inventory, RestTask::Hunt, 3), TaskResult::NoAmmunition);
+    }
+
+    #[test]
+    fn foraging_gathers_food_without_bullets() {
+        assert!(food_over_days(RestTask::Forage, HealthStatus::Good) > 0);
+        let (mut player, mut inventory) = camp();
+        first_result(&mut player, &mut inventory, RestTask::Forage, 5);
+        assert_eq!(inventory.get_quantity(ItemType::Ammunition), 100);
+    }
+
+    #[test]
+    fn repairs_sometimes_make_spare_parts() {
+        let parts: Vec<TaskResult> = (0..20)
+            .map(|seed| {
+                let (mut player, mut inventory) = camp();
+                first_result(&mut player, &mut inventory, RestTask::Repair, seed)
+            })
+            .collect();
+        assert!(parts.iter().any(|r| matches!(r, TaskResult::Part(_))));
+        assert!(parts.iter().all(|r| matches!(r, TaskResult::Part(_) | TaskResult::NothingFound)));
+    }
+
+    #[test]
+    fn tending_helps_the_sickest_member() {
+        let (mut player, mut inventory) = camp();
+        assert_eq!(first_result(&mut player, &mut inventory, RestTask::TendSick, 1), TaskResult::NoPatients);
+
+        let treated = (0..20).find_map(|seed| {
+            let (mut player, mut inventory) = camp();
+            player.party[1].contract_disease(Disease::Fever);
+            player.party[1].health = HealthStatus::Poor;
+            match first_result(&mut player, &mut inventory, RestTask::TendSick, seed) {
+                TaskResult::Treated(name) => Some((name, player.party[1].clone())),
+                _ => None,
+            }
+        });
+        let (name, patient) = treated.expect("a patient was treated");
+        assert_eq!(name, "Ruth");
+        assert_eq!(patient.health, HealthStatus::Fair);
+        assert!(patient.diseases.is_empty());
+    }
+
+    #[test]
+    fn weaker_members_get_less_done() {
+        for task in [RestTask::Hunt, RestTask::Forage] {
+            let good = food_over_days(task, HealthStatus::Good);
+            let poor = food_over_days(task, HealthStatus::Poor);
+            assert!(poor <= good / 2 + 40, "{:?}: {} against {}", task, poor, good);
+            assert!(poor < good);
+        }
+
+        let (mut player, mut inventory) = camp();
+        player.party[0].health = HealthStatus::Deceased;
+        let mut assignments = WorkAssignments::new(2);
+        assignments.assign(0, RestTask::Hunt);
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        let outcomes = work_day(&mut player, &mut inventory, &assignments, 1.0, Morale::default(), &mut rng);
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].member, "Ruth");
+        assert_eq!(inventory.get_quantity(ItemType::Ammunition), 100);
+    }
+}
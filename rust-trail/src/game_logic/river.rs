@@ -0,0 +1,237 @@
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+use crate::game_logic::money::Money;
+use crate::game_logic::weather::Weather;
+
+/// Extra depth from snowmelt in each month, as a share of the river's usual depth
+const SNOWMELT: [f32; 12] = [0.0, 0.0, 0.1, 0.3, 0.5, 0.6, 0.3, 0.1, 0.0, 0.0, 0.0, 0.0];
+
+/// Extra depth for each unit of recent precipitation, as a share of the usual depth
+const RAIN_DEPTH: f32 = 0.25;
+
+/// Extra swiftness for each unit of recent precipitation
+const RAIN_SWIFTNESS: f32 = 0.1;
+
+/// Share of recent precipitation still in the rivers the next day
+pub const RAIN_DECAY: f32 = 0.7;
+
+/// Deepest water a wagon can ford without getting its supplies wet (in feet)
+pub const SAFE_FORD_DEPTH: f32 = 2.5;
+
+/// Deepest water a wagon can ford without risk of tipping over (in feet)
+pub const MAX_FORD_DEPTH: f32 = 3.0;
+
+/// Shallowest water a caulked wagon will float in (in feet)
+pub const MIN_FLOAT_DEPTH: f32 = 1.5;
+
+/// Price of a ferry crossing (in dollars)
+pub const FERRY_COST: Money = Money::from_dollars(5);
+
+/// Most days the party may have to wait for the ferry
+pub const MAX_FERRY_WAIT: u32 = 5;
+
+/// Sets of clothing a guide asks for
+pub const GUIDE_CLOTHING: u32 = 3;
+
+/// Chance of a ferry accident
+const FERRY_RISK: f32 = 0.02;
+
+/// Chance of tipping over with a guide, before the current is counted
+const GUIDE_RISK: f32 = 0.03;
+
+/// Amount a day's weather adds to the recent precipitation
+pub fn precipitation(weather: Weather) -> f32 {
+    match weather {
+        Weather::Rain => 1.0,
+        Weather::Snow => 0.5,
+        _ => 0.0,
+    }
+}
+
+/// A river's usual size in a dry month, from the tables
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RiverBaseline {
+    /// Width (in feet)
+    pub width: f32,
+    /// Depth in the middle (in feet)
+    pub depth: f32,
+    /// How fast the water runs, 0 (still) to 1 (torrent)
+    pub swiftness: f32,
+    /// Whether a ferry runs here
+    #[serde(default)]
+    pub ferry: bool,
+    /// Whether a guide offers to take wagons across
+    #[serde(default)]
+    pub guide: bool,
+}
+
+/// Ways of getting the wagon across a river
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrossingMethod {
+    Ford,
+    CaulkAndFloat,
+    Ferry,
+    Guide,
+}
+
+impl CrossingMethod {
+    /// All crossing methods
+    pub const ALL: [CrossingMethod; 4] = [
+        CrossingMethod::Ford,
+        CrossingMethod::CaulkAndFloat,
+        CrossingMethod::Ferry,
+        CrossingMethod::Guide,
+    ];
+
+    /// Menu label
+    pub fn label(&self) -> &'static str {
+        match self {
+            CrossingMethod::Ford => "Attempt to ford the river",
+            CrossingMethod::CaulkAndFloat => "Caulk the wagon and float it across",
+            CrossingMethod::Ferry => "Take a ferry across",
+            CrossingMethod::Guide => "Hire a Shoshoni guide",
+        }
+    }
+}
+
+/// How a crossing went
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossingResult {
+    /// Across without trouble
+    Safe,
+    /// Across, but the supplies got wet and a day is lost drying them
+    WetSupplies,
+    /// The water was too shallow to float and the wagon got stuck in the mud
+    StuckInMud,
+    /// The wagon tipped over and supplies were lost
+    Tipped,
+}
+
+/// A river as it is today
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct River {
+    /// Width (in feet)
+    pub width: f32,
+    /// Depth in the middle (in feet)
+    pub depth: f32,
+    /// How fast the water runs, 0 (still) to 1 (torrent)
+    pub swiftness: f32,
+    /// Whether a ferry runs here
+    pub ferry: bool,
+    /// Whether a guide offers to take wagons across
+    pub guide: bool,
+}
+
+impl River {
+    /// Today's conditions, from the river's baseline, the month and recent precipitation
+    pub fn conditions(baseline: &RiverBaseline, month: u8, rainfall: f32) -> Self {
+        let snowmelt = SNOWMELT[(month.clamp(1, 12) - 1) as usize];
+        let swell = snowmelt + RAIN_DEPTH * rainfall;
+        Self {
+            width: baseline.width * (1.0 + swell * 0.5),
+            depth: baseline.depth * (1.0 + swell),
+            swiftness: (baseline.swiftness + snowmelt * 0.5 + RAIN_SWIFTNESS * rainfall).min(1.0),
+            ferry: baseline.ferry,
+            guide: baseline.guide,
+        }
+    }
+
+    /// Crossing methods offered at this river
+    pub fn methods(&self) -> Vec<CrossingMethod> {
+        CrossingMethod::ALL
+            .into_iter()
+            .filter(|method| match method {
+                CrossingMethod::Ferry => self.ferry,
+                CrossingMethod::Guide => self.guide,
+                _ => true,
+            })
+            .collect()
+    }
+
+    /// Roll how many days the party must wait for the ferry
+    pub fn ferry_wait<R: Rng>(rng: &mut R) -> u32 {
+        rng.gen_range(1..=MAX_FERRY_WAIT)
+    }
+
+    /// Word for how fast the water is running
+    pub fn swiftness_name(&self) -> &'static str {
+        match self.swiftness {
+            s if s < 0.3 => "slow",
+            s if s < 0.6 => "moderate",
+            s if s < 0.8 => "swift",
+            _ => "dangerously swift",
+        }
+    }
+
+    /// Chance of the wagon tipping over with a crossing method
+    pub fn risk(&self, method: CrossingMethod) -> f32 {
+        match method {
+            CrossingMethod::Ford if self.depth <= MAX_FORD_DEPTH => 0.0,
+            CrossingMethod::Ford => (0.5 + (self.depth - MAX_FORD_DEPTH) * 0.15 + self.swiftness * 0.3).min(0.95),
+            CrossingMethod::CaulkAndFloat if self.depth < MIN_FLOAT_DEPTH => 0.0,
+            CrossingMethod::CaulkAndFloat => (0.05 + self.swiftness * 0.35).min(0.95),
+            CrossingMethod::Ferry => FERRY_RISK,
+            CrossingMethod::Guide => GUIDE_RISK + self.swiftness * 0.05,
+        }
+    }
+
+    /// Roll how a crossing goes
+    pub fn cross<R: Rng>(&self, method: CrossingMethod, rng: &mut R) -> CrossingResult {
+        if rng.gen::<f32>() < self.risk(method) {
+            return CrossingResult::Tipped;
+        }
+        match method {
+            CrossingMethod::Ford if self.depth > SAFE_FORD_DEPTH => CrossingResult::WetSupplies,
+            CrossingMethod::CaulkAndFloat if self.depth < MIN_FLOAT_DEPTH => CrossingResult::StuckInMud,
+            _ => CrossingResult::Safe,
+        }
+    }
+
+    /// Description of the river for the crossing screen
+    pub fn describe(&self) -> String {
+        format!(
+            "The river is {:.0} feet across and {:.1} feet deep in the middle. The current is {}.",
+            self.width,
+            self.depth,
+            self.swiftness_name()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    const KANSAS: RiverBaseline = RiverBaseline { width: 620.0, depth: 2.4, swiftness: 0.3, ferry: true, guide: false };
+
+    #[test]
+    fn rain_and_snowmelt_raise_the_river() {
+        let dry = River::conditions(&KANSAS, 9, 0.0);
+        let spring = River::conditions(&KANSAS, 5, 0.0);
+        let stormy = River::conditions(&KANSAS, 9, 3.0);
+
+        assert_eq!(dry.depth, KANSAS.depth);
+        assert!(spring.depth > dry.depth && spring.swiftness > dry.swiftness);
+        assert!(stormy.depth > dry.depth && stormy.width > dry.width);
+        assert!(stormy.risk(CrossingMethod::Ford) > dry.risk(CrossingMethod::Ford));
+    }
+
+    #[test]
+    fn shallow_rivers_are_safe_to_ford_but_not_float() {
+        let river = River { width: 200.0, depth: 1.0, swiftness: 0.2, ferry: false, guide: false };
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        assert_eq!(river.cross(CrossingMethod::Ford, &mut rng), CrossingResult::Safe);
+        assert_eq!(river.cross(CrossingMethod::CaulkAndFloat, &mut rng), CrossingResult::StuckInMud);
+    }
+
+    #[test]
+    fn ferries_and_guides_are_safer_than_floating() {
+        let river = River::conditions(&KANSAS, 5, 2.0);
+        assert_eq!(river.methods(), vec![CrossingMethod::Ford, CrossingMethod::CaulkAndFloat, CrossingMethod::Ferry]);
+        assert!(river.risk(CrossingMethod::Ferry) < river.risk(CrossingMethod::Guide));
+        assert!(river.risk(CrossingMethod::Guide) < river.risk(CrossingMethod::CaulkAndFloat));
+        assert!(river.risk(CrossingMethod::CaulkAndFloat) < river.risk(CrossingMethod::Ford));
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Serialize, Deserialize};
+use crate::game_logic::journey::Journey;
+use crate::utils::storage::Storage;
+use crate::utils::versioned::{self, Migration};
+
+/// Name the journey in progress is saved under
+pub const SAVE_FILE: &str = "savegame.json";
+
+/// Changes to the save format, one per version
+const MIGRATIONS: &[Migration] = &[versioned::unversioned];
+
+/// A journey written to disk, with the seed it was started from
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SaveGame {
+    /// Seed of the journey's random numbers
+    pub seed: u64,
+    /// The journey in progress
+    pub journey: Journey,
+}
+
+/// Borrowed copy of a save, so saving doesn't need to own the journey
+#[derive(Serialize)]
+struct SaveGameRef<'a> {
+    seed: u64,
+    journey: &'a Journey,
+}
+
+/// Write a journey as JSON
+pub fn to_json(journey: &Journey, seed: u64) -> Result<String, String> {
+    versioned::to_json(&SaveGameRef { seed, journey }, MIGRATIONS)
+        .map_err(|e| format!("Failed to write saved game: {}", e))
+}
+
+/// Read a journey written by `to_json`
+pub fn from_json(json: &str) -> Result<SaveGame, String> {
+    versioned::from_json(json, MIGRATIONS).map_err(|e| format!("Failed to read saved game: {}", e))
+}
+
+/// Version saves are written at
+pub fn current_version() -> u64 {
+    versioned::current_version(MIGRATIONS)
+}
+
+/// Version a save was written at, without reading the rest of it
+pub fn version_of(json: &str) -> Result<u64, String> {
+    let value = serde_json::from_str(json).map_err(|e| format!("Failed to read saved game: {}", e))?;
+    versioned::version_of(&value).map_err(|e| format!("Failed to read saved game: {}", e))
+}
+
+/// Save a journey to storage
+pub fn save(journey: &Journey, seed: u64, storage: &mut dyn Storage, name: &str) -> Result<(), String> {
+    let json = to_json(journey, seed)?;
+    storage.write(name, &json)
+}
+
+/// Load a journey from storage, or None if no game has been saved
+pub fn load(storage: &dyn Storage, name: &str) -> Result<Option<SaveGame>, String> {
+    let Some(json) = storage.read(name)? else {
+        return Ok(None);
+    };
+    from_json(&json).map(Some).map_err(|e| format!("{} ({})", e, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::inventory::ItemType;
+    use crate::utils::rng::GameRng;
+
+    #[test]
+    fn saved_journeys_load_where_they_left_off() {
+        let mut journey = Journey::default();
+        let mut rng = GameRng::new(7);
+        for _ in 0..10 {
+            journey.travel_day(&mut rng);
+        }
+
+        let saved = from_json(&to_json(&journey, 7).unwrap()).unwrap();
+        assert_eq!(saved.seed, 7);
+        assert_eq!(saved.journey.days, journey.days);
+        assert_eq!(saved.journey.date(), journey.date());
+        assert_eq!(saved.journey.player.miles_traveled, journey.player.miles_traveled);
+        assert_eq!(
+            saved.journey.inventory.get_quantity(ItemType::Food),
+            journey.inventory.get_quantity(ItemType::Food)
+        );
+        assert_eq!(saved.journey.diary.entries().len(), journey.diary.entries().len());
+    }
+
+    #[test]
+    fn saves_from_before_versioning_still_load() {
+        let journey = Journey::default();
+        let mut old: serde_json::Value = serde_json::from_str(&to_json(&journey, 7).unwrap()).unwrap();
+        old.as_object_mut().unwrap().remove("version");
+        assert_eq!(from_json(&old.to_string()).unwrap().seed, 7);
+
+        assert_eq!(version_of(&old.to_string()), Ok(0));
+        old["version"] = 99.into();
+        assert_eq!(version_of(&old.to_string()), Ok(99));
+        assert!(from_json(&old.to_string()).unwrap_err().contains("newer version"));
+    }
+}
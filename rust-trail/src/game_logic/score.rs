@@ -0,0 +1,59 @@
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::journey::Journey;
+use crate::game_logic::player::HealthStatus;
+
+/// Points for arriving with the wagon
+pub const WAGON_POINTS: u32 = 50;
+
+/// Points for each survivor in the given health
+fn survivor_points(health: HealthStatus) -> u32 {
+    match health {
+        HealthStatus::Good => 500,
+        HealthStatus::Fair => 400,
+        HealthStatus::Poor => 300,
+        HealthStatus::VeryPoor => 200,
+        HealthStatus::Deceased => 0,
+    }
+}
+
+/// Points for the supplies left in the wagon
+fn supply_points(journey: &Journey) -> u32 {
+    let quantity = |item| journey.inventory.get_quantity(item);
+    let spare_parts = quantity(ItemType::SpareWheel) + quantity(ItemType::SpareAxle) + quantity(ItemType::SpareTongue);
+    quantity(ItemType::OxenPair) * 2 * 4
+        + spare_parts * 2
+        + quantity(ItemType::Clothing) * 2
+        + quantity(ItemType::Ammunition) / 50
+        + quantity(ItemType::Food) / 25
+        + journey.player.money.dollars() / 5
+}
+
+/// Points earned before the occupation bonus
+pub fn base_score(journey: &Journey) -> u32 {
+    let survivors: u32 = journey.player.party.iter().map(|m| survivor_points(m.health)).sum();
+    survivors + WAGON_POINTS + supply_points(journey)
+}
+
+/// Final score for a party that reached Oregon, with the occupation bonus applied
+pub fn final_score(journey: &Journey) -> u32 {
+    (base_score(journey) as f32 * journey.player.occupation.score_multiplier()).round() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::money::Money;
+    use crate::game_logic::occupation::Occupation;
+
+    #[test]
+    fn occupation_multiplies_the_score() {
+        let mut journey = Journey::new("Leader", &["Mary"], &[(ItemType::Food, 100)]);
+        journey.player.money = Money::from_dollars(100);
+        let base = base_score(&journey);
+        assert_eq!(base, 500 * 2 + WAGON_POINTS + 100 / 25 + 100 / 5);
+        assert_eq!(final_score(&journey), base);
+
+        journey.player.occupation = Occupation::Farmer;
+        assert_eq!(final_score(&journey), base * 3);
+    }
+}
@@ -0,0 +1,334 @@
+use serde::{Serialize, Deserialize};
+use crate::game_logic::blacksmith;
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::journey::{Journey, DEFAULT_SUPPLIES, MAX_REST_DAYS};
+use crate::game_logic::player::{HealthStatus, Pace, Rations};
+use crate::game_logic::rest::{self, RestTask, WorkAssignments};
+use crate::game_logic::river::{CrossingMethod, River};
+use crate::utils::rng::{GameRng, RngStream};
+
+/// Names given to the simulated party, leader first
+const PARTY_NAMES: [&str; 5] = ["Leader", "Mary", "Tom", "Sally", "Joe"];
+
+/// How a simulated party travels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Policy {
+    /// Pace kept every day of the journey
+    pub pace: Pace,
+    /// Rations eaten every day of the journey
+    pub rations: Rations,
+    /// Month (1-12) the party leaves Independence
+    pub departure_month: u8,
+    /// How rivers are crossed; where it isn't offered or can't be paid for
+    /// the wagon is caulked and floated instead
+    #[serde(default = "default_crossing")]
+    pub crossing: CrossingMethod,
+    /// Pounds of food below which the party stops a day to hunt (0 never hunts)
+    #[serde(default)]
+    pub hunt_below: u32,
+    /// Health at or below which a member makes the party stop a day to rest
+    #[serde(default)]
+    pub rest_at: Option<HealthStatus>,
+}
+
+fn default_crossing() -> CrossingMethod {
+    CrossingMethod::Ferry
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self {
+            pace: Pace::Steady,
+            rations: Rations::Filling,
+            departure_month: 4,
+            crossing: default_crossing(),
+            hunt_below: 100,
+            rest_at: Some(HealthStatus::Poor),
+        }
+    }
+}
+
+impl Policy {
+    /// Whether the party should stop to rest today; a hungry camp doesn't mend
+    fn wants_rest(&self, journey: &Journey) -> bool {
+        journey.inventory.get_quantity(ItemType::Food) > 0
+            && self.rest_at.is_some_and(|threshold| {
+                journey.player.party.iter().any(|m| m.is_alive() && m.health.severity() >= threshold.severity())
+            })
+    }
+
+    /// Whether the party should stop to hunt today
+    fn wants_hunt(&self, journey: &Journey) -> bool {
+        journey.inventory.get_quantity(ItemType::Food) < self.hunt_below
+            && journey.inventory.get_quantity(ItemType::Ammunition) >= rest::HUNT_AMMUNITION_PER_DAY
+    }
+
+    /// Crossing method used at a river
+    fn crossing_for(&self, river: &River, journey: &Journey) -> CrossingMethod {
+        if river.methods().contains(&self.crossing) && journey.can_pay_for(self.crossing) {
+            self.crossing
+        } else {
+            CrossingMethod::CaulkAndFloat
+        }
+    }
+}
+
+/// Everything needed to run a journey without a window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationConfig {
+    /// Seed for every random outcome
+    pub seed: u64,
+    /// Travel policy
+    pub policy: Policy,
+    /// Number of party members, leader included (1-5)
+    pub party_size: usize,
+    /// Supplies loaded at Independence
+    pub supplies: Vec<(ItemType, u32)>,
+    /// Days before the journey is abandoned
+    pub max_days: u32,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            policy: Policy::default(),
+            party_size: PARTY_NAMES.len(),
+            supplies: DEFAULT_SUPPLIES.to_vec(),
+            max_days: 365,
+        }
+    }
+}
+
+/// How a journey ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Outcome {
+    /// Reached the Willamette Valley
+    Arrived,
+    /// Every member of the party died
+    PartyDied,
+    /// Trapped by snow in a mountain pass for the winter
+    SnowedIn,
+    /// Ran out of days before reaching Oregon
+    TimedOut,
+}
+
+/// Summary of a finished journey
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationResult {
+    /// Seed the journey was run with
+    pub seed: u64,
+    /// How the journey ended
+    pub outcome: Outcome,
+    /// Days spent on the trail
+    pub days: u32,
+    /// Miles covered
+    pub miles: f32,
+    /// Living party members at the end
+    pub survivors: usize,
+    /// Pounds of food left at the end
+    pub food_left: u32,
+}
+
+/// A journey played out by game logic alone, with no window or scenes
+pub struct Simulation {
+    /// Party, supplies and progress
+    pub journey: Journey,
+    rng: GameRng,
+    policy: Policy,
+    /// Days in a row the party has stopped to rest
+    rested: u32,
+    max_days: u32,
+}
+
+impl Simulation {
+    /// Set up a journey at Independence
+    pub fn new(config: &SimulationConfig) -> Self {
+        let size = config.party_size.clamp(1, PARTY_NAMES.len());
+        let mut journey = Journey::new(PARTY_NAMES[0], &PARTY_NAMES[1..size], &config.supplies);
+        journey.player.pace = config.policy.pace;
+        journey.player.rations = config.policy.rations;
+        journey.set_departure(config.policy.departure_month);
+
+        Self {
+            journey,
+            rng: GameRng::new(config.seed),
+            policy: config.policy,
+            rested: 0,
+            max_days: config.max_days,
+        }
+    }
+
+    /// Days simulated so far
+    pub fn days(&self) -> u32 {
+        self.journey.days
+    }
+
+    /// Play out one day, returning the outcome if the journey ended
+    pub fn step(&mut self) -> Option<Outcome> {
+        // Hunger comes first; a party that doesn't mend after the longest
+        // rest moves on regardless
+        if self.policy.wants_hunt(&self.journey) {
+            self.hunt();
+        } else if self.rested < MAX_REST_DAYS && self.policy.wants_rest(&self.journey) {
+            let party_size = self.journey.player.party.len();
+            self.journey.rest_day(&mut self.rng, &WorkAssignments::new(party_size));
+            self.rested += 1;
+        } else {
+            self.rested = 0;
+            if self.journey.travel_day(&mut self.rng).arrived_at.is_some() {
+                self.cross_river();
+            }
+        }
+        // Patched parts are mended at the first fort that can afford it
+        if blacksmith::quote(&self.journey).is_some() {
+            let _ = blacksmith::repair(&mut self.journey, &mut self.rng);
+        }
+        self.journey.take_events();
+        self.outcome()
+    }
+
+    /// How the journey has ended, if it has
+    fn outcome(&self) -> Option<Outcome> {
+        if self.journey.party_died() {
+            Some(Outcome::PartyDied)
+        } else if self.journey.snowbound_for_winter() {
+            Some(Outcome::SnowedIn)
+        } else if self.journey.has_arrived() {
+            Some(Outcome::Arrived)
+        } else if self.journey.days >= self.max_days {
+            Some(Outcome::TimedOut)
+        } else {
+            None
+        }
+    }
+
+    /// Spend a day hunting, the first living member taking the gun
+    fn hunt(&mut self) {
+        let party = &self.journey.player.party;
+        let mut work = WorkAssignments::new(party.len());
+        if let Some(hunter) = party.iter().position(|m| m.is_alive()) {
+            work.assign(hunter, RestTask::Hunt);
+        }
+        self.journey.rest_day(&mut self.rng, &work);
+    }
+
+    /// Cross the river the wagon has just reached, if it has
+    fn cross_river(&mut self) {
+        let Some(river) = self.journey.river() else {
+            return;
+        };
+        let method = self.policy.crossing_for(&river, &self.journey);
+        if method == CrossingMethod::Ferry {
+            for _ in 0..River::ferry_wait(self.rng.stream(RngStream::River)) {
+                self.journey.camp_day(&mut self.rng);
+            }
+        }
+        // The water may have risen or fallen while waiting
+        let river = self.journey.river().unwrap_or(river);
+        self.journey.cross_river(&river, method, &mut self.rng);
+    }
+
+    /// Play the journey to the end
+    pub fn run(mut self) -> SimulationResult {
+        let outcome = loop {
+            if let Some(outcome) = self.step() {
+                break outcome;
+            }
+        };
+
+        SimulationResult {
+            seed: self.rng.seed(),
+            outcome,
+            days: self.journey.days,
+            miles: self.journey.player.miles_traveled,
+            survivors: self.journey.player.living_party_members(),
+            food_left: self.journey.inventory.get_quantity(ItemType::Food),
+        }
+    }
+}
+
+/// Run a journey from a config
+pub fn simulate(config: &SimulationConfig) -> SimulationResult {
+    Simulation::new(config).run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(seed: u64, pace: Pace, rations: Rations) -> SimulationConfig {
+        SimulationConfig { seed, policy: Policy { pace, rations, ..Default::default() }, ..Default::default() }
+    }
+
+    #[test]
+    fn same_seed_same_journey() {
+        let a = simulate(&config(1848, Pace::Strenuous, Rations::Meager));
+        let b = simulate(&config(1848, Pace::Strenuous, Rations::Meager));
+        assert_eq!(a.outcome, b.outcome);
+        assert_eq!(a.days, b.days);
+        assert_eq!(a.survivors, b.survivors);
+        assert_eq!(a.food_left, b.food_left);
+    }
+
+    #[test]
+    fn resting_never_arrives() {
+        let result = simulate(&config(1, Pace::Resting, Rations::Filling));
+        assert_ne!(result.outcome, Outcome::Arrived);
+        assert_eq!(result.miles, 0.0);
+    }
+
+    #[test]
+    fn no_oxen_no_progress() {
+        let mut config = config(2, Pace::Grueling, Rations::Filling);
+        config.supplies.retain(|(item, _)| *item != ItemType::OxenPair);
+        config.max_days = 30;
+        let result = simulate(&config);
+        assert_eq!(result.miles, 0.0);
+    }
+
+    #[test]
+    fn rivers_are_crossed_as_the_policy_says() {
+        for crossing in [CrossingMethod::Ford, CrossingMethod::Ferry] {
+            let mut config = config(3, Pace::Steady, Rations::Filling);
+            config.policy.crossing = crossing;
+            let mut simulation = Simulation::new(&config);
+            while simulation.step().is_none() {}
+            let crossings = &simulation.journey.crossings;
+            assert!(!crossings.is_empty());
+            assert!(crossings.iter().all(|&m| m == crossing || m == CrossingMethod::CaulkAndFloat));
+            assert!(crossings.contains(&crossing));
+        }
+    }
+
+    #[test]
+    fn hungry_parties_hunt_and_sick_ones_rest() {
+        let mut hungry = config(4, Pace::Steady, Rations::Filling);
+        hungry.policy.hunt_below = 10_000;
+        let mut simulation = Simulation::new(&hungry);
+        let bullets = simulation.journey.inventory.get_quantity(ItemType::Ammunition);
+        simulation.step();
+        assert_eq!(simulation.journey.player.miles_traveled, 0.0);
+        assert_eq!(simulation.journey.inventory.get_quantity(ItemType::Ammunition), bullets - rest::HUNT_AMMUNITION_PER_DAY);
+
+        let mut simulation = Simulation::new(&config(4, Pace::Steady, Rations::Filling));
+        simulation.journey.player.party[1].health = HealthStatus::VeryPoor;
+        simulation.step();
+        assert_eq!(simulation.journey.stats.days_rested, 1);
+        for _ in 0..MAX_REST_DAYS {
+            simulation.step();
+        }
+        assert!(simulation.journey.player.miles_traveled > 0.0);
+    }
+
+    #[test]
+    fn journeys_end_within_max_days() {
+        for seed in 0..50 {
+            let result = simulate(&config(seed, Pace::Steady, Rations::Filling));
+            assert!(result.days <= 365);
+            if result.outcome == Outcome::Arrived {
+                assert!(result.survivors > 0);
+            }
+        }
+    }
+}
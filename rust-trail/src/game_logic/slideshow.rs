@@ -0,0 +1,76 @@
+// Slideshows: pictures shown one after another with a few lines of text under
+// each, read from a JSON text asset so they can be rewritten without touching
+// the code. The opening sequence plays one on a timer; the introduction is
+// paged through by hand.
+
+use serde::Deserialize;
+
+/// Text file holding the opening sequence, loaded through the asset manager
+pub const OPENING_FILE: &str = "opening.json";
+
+/// Text file holding the introduction's pages
+pub const INTRODUCTION_FILE: &str = "introduction.json";
+
+/// Seconds a slide stays up when it doesn't say
+const DEFAULT_SECONDS: f32 = 5.0;
+
+/// One picture and what is said over it
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Slide {
+    /// Image shown, by asset name
+    pub image: String,
+    /// Heading shown over the slide, if any
+    #[serde(default)]
+    pub title: String,
+    /// Text shown under the image, if any
+    #[serde(default)]
+    pub text: String,
+    /// Seconds the slide stays up when played on a timer
+    #[serde(default = "default_seconds")]
+    pub seconds: f32,
+}
+
+/// Time a slide stays up when it doesn't say
+fn default_seconds() -> f32 {
+    DEFAULT_SECONDS
+}
+
+/// Slides shown in order, with music to play under them
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Slideshow {
+    /// Music track played from the first slide, by asset name
+    #[serde(default)]
+    pub music: Option<String>,
+    /// The slides, in order
+    pub slides: Vec<Slide>,
+}
+
+impl Slideshow {
+    /// Parse a slideshow
+    pub fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slides_fill_in_defaults() {
+        let show = Slideshow::parse(r#"{ "slides": [
+            { "image": "MECC.png", "seconds": 2 },
+            { "image": "MAP.png", "text": "Westward" }
+        ] }"#)
+        .unwrap();
+        assert_eq!(show.music, None);
+        assert_eq!(show.slides[0].text, "");
+        assert_eq!(show.slides[1].seconds, DEFAULT_SECONDS);
+
+        let opening = Slideshow::parse(include_str!("../../assets/text/opening.json")).unwrap();
+        assert!(opening.slides.iter().all(|slide| slide.seconds > 0.0));
+        let introduction = Slideshow::parse(include_str!("../../assets/text/introduction.json")).unwrap();
+        assert!(introduction.slides.iter().all(|slide| !slide.title.is_empty() && !slide.text.is_empty()));
+    }
+}
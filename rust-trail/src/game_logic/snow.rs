@@ -0,0 +1,90 @@
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+use crate::game_logic::time::TrailDate;
+use crate::game_logic::weather::Weather;
+
+/// Miles of climbing before a pass where snow can stop the wagon
+pub const PASS_APPROACH: f32 = 80.0;
+
+/// Snowpack (in inches) deep enough for a blizzard to snow the party in
+pub const BLIZZARD_DEPTH: f32 = 6.0;
+
+/// Snowpack (in inches) that closes a pass for the winter
+pub const CLOSED_DEPTH: f32 = 36.0;
+
+/// Chance a day of rain or snow on a snowbound pass turns into a blizzard
+pub const BLIZZARD_CHANCE: f32 = 0.5;
+
+/// Days a blizzard keeps the party snowed in
+pub const SNOWED_IN_DAYS: std::ops::RangeInclusive<u32> = 2..=6;
+
+/// Factor applied to the food eaten each day while snowed in
+pub const SNOWED_IN_FOOD_FACTOR: f32 = 1.5;
+
+/// When snow starts to build up on a mountain pass
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PassBaseline {
+    /// Day of the year (1-365) of the first lasting snow
+    pub first_snow: u32,
+    /// Snow added each day after that, and melted each day in spring (in inches)
+    pub snowfall: f32,
+    /// Day of the year (1-365) by which the spring thaw has cleared the pass
+    pub thaw: u32,
+}
+
+impl PassBaseline {
+    /// Snow on the pass (in inches) on a date
+    ///
+    /// Snow builds up from the first snow to the end of the year and melts
+    /// away in the spring until the thaw.
+    pub fn snowpack(&self, date: TrailDate) -> f32 {
+        let day_of_year = date.day_of_year();
+        let days = if day_of_year < self.thaw {
+            self.thaw - day_of_year
+        } else {
+            day_of_year.saturating_sub(self.first_snow)
+        };
+        days as f32 * self.snowfall
+    }
+}
+
+/// Whether a pass with this much snow is closed for the winter
+pub fn is_closed(snowpack: f32) -> bool {
+    snowpack >= CLOSED_DEPTH
+}
+
+/// Roll whether the day's weather snows the party in, returning the days it lasts
+///
+/// Up on a pass that already holds snow, rain falls as snow too.
+pub fn blizzard<R: Rng>(snowpack: f32, weather: Weather, rng: &mut R) -> Option<u32> {
+    if !matches!(weather, Weather::Rain | Weather::Snow) || snowpack < BLIZZARD_DEPTH || rng.gen::<f32>() >= BLIZZARD_CHANCE {
+        return None;
+    }
+    Some(rng.gen_range(SNOWED_IN_DAYS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn date(month: u8, day: u32, year: u16) -> TrailDate {
+        TrailDate::new(month, day, year).unwrap()
+    }
+
+    #[test]
+    fn snow_builds_up_through_the_autumn() {
+        let pass = PassBaseline { first_snow: 274, snowfall: 0.5, thaw: 120 };
+        assert_eq!(pass.snowpack(date(8, 15, 1848)), 0.0);
+        assert_eq!(pass.snowpack(date(10, 10, 1848)), 5.0);
+        assert!(is_closed(pass.snowpack(date(12, 31, 1848))));
+        assert!(is_closed(pass.snowpack(date(1, 15, 1849))));
+        assert_eq!(pass.snowpack(date(4, 20, 1849)), 5.0);
+
+        let mut rng = ChaCha8Rng::seed_from_u64(1);
+        assert!((0..50).all(|_| blizzard(0.0, Weather::Snow, &mut rng).is_none()));
+        assert!((0..50).all(|_| blizzard(20.0, Weather::Cold, &mut rng).is_none()));
+        assert!((0..50).any(|_| blizzard(20.0, Weather::Snow, &mut rng).is_some()));
+    }
+}
@@ -0,0 +1,103 @@
+// Speedrun timing: real time from the start of a journey, with a split each
+// time the party reaches a landmark.
+
+use crate::game_logic::time::TrailDate;
+
+/// Time at which a landmark was reached
+#[derive(Debug, Clone, PartialEq)]
+pub struct Split {
+    /// Landmark reached
+    pub landmark: String,
+    /// Real time since the journey started (in seconds)
+    pub time: f32,
+}
+
+/// Real-time clock for a run, stopped once the journey is over
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SpeedrunTimer {
+    /// Real time since the journey started (in seconds)
+    elapsed: f32,
+    /// Landmarks reached, in order
+    splits: Vec<Split>,
+    /// In-game date the journey ended on, once it has
+    finished_on: Option<TrailDate>,
+}
+
+impl SpeedrunTimer {
+    /// Create a stopped timer at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clear the timer for a new run
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Count real time, unless the run is over
+    pub fn tick(&mut self, dt: f32) {
+        if self.finished_on.is_none() {
+            self.elapsed += dt;
+        }
+    }
+
+    /// Record reaching a landmark
+    pub fn split(&mut self, landmark: &str) {
+        if self.finished_on.is_none() {
+            self.splits.push(Split { landmark: landmark.to_string(), time: self.elapsed });
+        }
+    }
+
+    /// Stop the clock on the date the journey ended
+    pub fn finish(&mut self, date: TrailDate) {
+        self.finished_on.get_or_insert(date);
+    }
+
+    /// Real time since the journey started (in seconds)
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// Landmarks reached, in order
+    pub fn splits(&self) -> &[Split] {
+        &self.splits
+    }
+
+    /// In-game date the journey ended on, if it has
+    pub fn finished_on(&self) -> Option<TrailDate> {
+        self.finished_on
+    }
+}
+
+/// Time in seconds as m:ss.cc, or h:mm:ss.cc past the hour
+pub fn format_time(seconds: f32) -> String {
+    let centis = (seconds.max(0.0) * 100.0) as u64;
+    let (hours, minutes, secs, centis) = (centis / 360_000, centis / 6000 % 60, centis / 100 % 60, centis % 100);
+    match hours {
+        0 => format!("{}:{:02}.{:02}", minutes, secs, centis),
+        _ => format!("{}:{:02}:{:02}.{:02}", hours, minutes, secs, centis),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timer_splits_and_stops_at_the_finish() {
+        let mut timer = SpeedrunTimer::new();
+        timer.tick(61.5);
+        timer.split("Kansas River crossing");
+        timer.tick(10.0);
+        timer.finish(TrailDate::clamped(9, 20, 1848));
+        timer.tick(10.0);
+        timer.split("Oregon City");
+
+        assert_eq!(timer.elapsed(), 71.5);
+        assert_eq!(timer.splits().len(), 1);
+        assert_eq!(format_time(timer.splits()[0].time), "1:01.50");
+        assert_eq!(format_time(3723.0), "1:02:03.00");
+        timer.reset();
+        assert_eq!(timer, SpeedrunTimer::new());
+    }
+}
@@ -0,0 +1,203 @@
+// Statistics kept over a journey, and totals kept over every journey played.
+// The totals are kept on disk so they carry over from one game to the next.
+
+use serde::{Deserialize, Serialize};
+use crate::game_logic::journey::Journey;
+use crate::game_logic::money::Money;
+use crate::game_logic::score;
+use crate::utils::storage::Storage;
+use crate::utils::checksum;
+use crate::utils::versioned::{self, Migration};
+
+/// File lifetime statistics are kept in between games
+pub const STATS_FILE: &str = "stats.json";
+
+/// Changes to the format lifetime statistics are kept in, one per version
+///
+/// Version 2 added a checksum; files from before then are taken on trust.
+const MIGRATIONS: &[Migration] = &[versioned::unversioned, checksum::trust];
+
+/// What happened over one journey
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Stats {
+    /// Miles covered on each day of travel, in order
+    pub daily_miles: Vec<f32>,
+    /// Animals shot while hunting
+    pub animals_shot: u32,
+    /// Pounds of meat left behind on hunts or spoiled in the wagon
+    pub meat_wasted: u32,
+    /// Days spent resting in camp
+    pub days_rested: u32,
+    /// Money spent at stores, blacksmiths and ferries
+    pub money_spent: Money,
+    /// Diseases members recovered from
+    pub illnesses_survived: u32,
+}
+
+impl Stats {
+    /// Miles covered over the whole journey
+    pub fn miles(&self) -> f32 {
+        self.daily_miles.iter().sum()
+    }
+
+    /// Average miles a day over the days the wagon moved
+    pub fn average_miles(&self) -> f32 {
+        let moving: Vec<f32> = self.daily_miles.iter().copied().filter(|&m| m > 0.0).collect();
+        match moving.len() {
+            0 => 0.0,
+            days => moving.iter().sum::<f32>() / days as f32,
+        }
+    }
+
+    /// Most miles covered in one day
+    pub fn best_day(&self) -> f32 {
+        self.daily_miles.iter().copied().fold(0.0, f32::max)
+    }
+
+    /// The statistics as lines of text
+    pub fn lines(&self) -> Vec<String> {
+        vec![
+            format!("Miles a day: {:.1} on average, {:.0} at best", self.average_miles(), self.best_day()),
+            format!("Days rested: {}", self.days_rested),
+            format!("Animals shot: {}", self.animals_shot),
+            format!("Meat wasted: {} pounds", self.meat_wasted),
+            format!("Money spent: {}", self.money_spent),
+            format!("Illnesses survived: {}", self.illnesses_survived),
+        ]
+    }
+}
+
+/// Totals over every journey played
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifetimeStats {
+    /// Journeys finished, however they ended
+    pub journeys: u32,
+    /// Journeys that reached Oregon
+    pub arrivals: u32,
+    /// Highest final score
+    pub best_score: u32,
+    /// Days spent on the trail
+    pub days: u32,
+    /// Miles travelled
+    pub miles: f32,
+    /// Members lost
+    pub deaths: u32,
+    /// Animals shot while hunting
+    pub animals_shot: u32,
+    /// Pounds of meat wasted
+    pub meat_wasted: u32,
+    /// Days spent resting in camp
+    pub days_rested: u32,
+    /// Money spent
+    pub money_spent: Money,
+    /// Diseases members recovered from
+    pub illnesses_survived: u32,
+    /// Whether the totals were ever edited outside the game
+    pub tampered: bool,
+}
+
+impl LifetimeStats {
+    /// Load the totals, or start from nothing if there is nothing stored yet
+    pub fn load(storage: &dyn Storage, name: &str) -> Result<Self, String> {
+        match storage.read(name)? {
+            Some(json) => {
+                let (mut lifetime, intact): (Self, bool) = versioned::from_signed_json(&json, MIGRATIONS)
+                    .map_err(|e| format!("Failed to read statistics: {} ({})", e, name))?;
+                lifetime.tampered |= !intact;
+                Ok(lifetime)
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Write the totals to storage
+    pub fn save(&self, storage: &mut dyn Storage, name: &str) -> Result<(), String> {
+        let json = versioned::to_signed_json(self, MIGRATIONS).map_err(|e| format!("Failed to write statistics: {}", e))?;
+        storage.write(name, &json)
+    }
+
+    /// Add a finished journey to the totals
+    pub fn add(&mut self, journey: &Journey) {
+        let stats = &journey.stats;
+        self.journeys += 1;
+        if journey.has_arrived() {
+            self.arrivals += 1;
+            // A cheated score doesn't count as a best
+            if !journey.cheated {
+                self.best_score = self.best_score.max(score::final_score(journey));
+            }
+        }
+        self.days += journey.days;
+        self.miles += stats.miles();
+        self.deaths += journey.player.party.iter().filter(|m| !m.is_alive()).count() as u32;
+        self.animals_shot += stats.animals_shot;
+        self.meat_wasted += stats.meat_wasted;
+        self.days_rested += stats.days_rested;
+        self.money_spent += stats.money_spent;
+        self.illnesses_survived += stats.illnesses_survived;
+    }
+
+    /// The totals as lines of text
+    pub fn lines(&self) -> Vec<String> {
+        vec![
+            format!("Journeys: {}, {} reaching Oregon", self.journeys, self.arrivals),
+            match self.tampered {
+                true => format!("Best score: {} (records edited outside the game)", self.best_score),
+                false => format!("Best score: {}", self.best_score),
+            },
+            format!("Days on the trail: {}", self.days),
+            format!("Miles travelled: {:.0}", self.miles),
+            format!("Party members lost: {}", self.deaths),
+            format!("Days rested: {}", self.days_rested),
+            format!("Animals shot: {}", self.animals_shot),
+            format!("Meat wasted: {} pounds", self.meat_wasted),
+            format!("Money spent: {}", self.money_spent),
+            format!("Illnesses survived: {}", self.illnesses_survived),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::GameRng;
+    use crate::utils::storage::MemoryStorage;
+
+    #[test]
+    fn journeys_add_up_to_lifetime_totals() {
+        let mut journey = Journey::default();
+        let mut rng = GameRng::new(4);
+        for _ in 0..5 {
+            journey.travel_day(&mut rng);
+        }
+        assert_eq!(journey.stats.daily_miles.len(), 5);
+        assert!((journey.stats.miles() - journey.player.miles_traveled).abs() < 0.01);
+        assert!(journey.stats.best_day() >= journey.stats.average_miles());
+
+        let mut lifetime = LifetimeStats::default();
+        lifetime.add(&journey);
+        lifetime.add(&journey);
+        assert_eq!(lifetime.journeys, 2);
+        assert_eq!(lifetime.arrivals, 0);
+        assert_eq!(lifetime.days, journey.days * 2);
+    }
+
+    #[test]
+    fn edited_totals_stay_flagged() {
+        let mut storage = MemoryStorage::default();
+        let lifetime = LifetimeStats { journeys: 3, best_score: 1200, miles: 1837.3, ..LifetimeStats::default() };
+        lifetime.save(&mut storage, STATS_FILE).unwrap();
+        assert_eq!(LifetimeStats::load(&storage, STATS_FILE), Ok(lifetime));
+
+        let json = storage.read(STATS_FILE).unwrap().unwrap();
+        storage.write(STATS_FILE, &json.replace("1200", "60000")).unwrap();
+        let edited = LifetimeStats::load(&storage, STATS_FILE).unwrap();
+        assert!(edited.tampered);
+
+        // Saving again doesn't launder the edit
+        edited.save(&mut storage, STATS_FILE).unwrap();
+        assert!(LifetimeStats::load(&storage, STATS_FILE).unwrap().tampered);
+    }
+}
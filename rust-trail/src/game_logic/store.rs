@@ -0,0 +1,107 @@
+// Fort stores: prices that climb the farther west the fort is, and stock
+// that runs out as the party buys it
+
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::journey::Journey;
+use crate::game_logic::money::Money;
+use crate::game_logic::tables::tables;
+
+/// How much dearer goods are at the end of the trail than at Independence
+pub const WEST_MARKUP: f32 = 1.5;
+
+/// What a fort's store has on its shelves, from the tables
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoreBaseline {
+    /// Units of each item the store can sell; items not listed aren't sold
+    pub stock: HashMap<ItemType, u32>,
+}
+
+/// How many times the Independence price goods cost this far along the trail
+pub fn price_factor(miles: f32) -> f32 {
+    1.0 + WEST_MARKUP * (miles / tables().trail_length()).clamp(0.0, 1.0)
+}
+
+/// Price of one unit of an item at a store this far along the trail
+pub fn price(item: ItemType, miles: f32) -> Money {
+    let dollars = tables().item(item).price.as_f32() * price_factor(miles);
+    Money::from(dollars as f64)
+}
+
+/// Units of an item left in the store at the fort the party is stopped at
+pub fn in_stock(journey: &Journey, item: ItemType) -> u32 {
+    if !journey.at_fort() {
+        return 0;
+    }
+    let landmark = journey.last_landmark();
+    let Some(store) = &landmark.store else {
+        return 0;
+    };
+    let stocked = store.stock.get(&item).copied().unwrap_or(0);
+    let sold = journey.store_sales.get(&landmark.id).and_then(|sales| sales.get(&item)).copied().unwrap_or(0);
+    stocked.saturating_sub(sold)
+}
+
+/// Buy from the fort's store, returning what was paid
+pub fn buy(journey: &mut Journey, item: ItemType, quantity: u32) -> Result<Money, String> {
+    let stock = in_stock(journey, item);
+    if stock == 0 {
+        return Err(format!("The store is out of {}.", item.name()));
+    }
+    if quantity > stock {
+        return Err(format!("The store only has {} {} left.", stock, item.name()));
+    }
+    let cost = price(item, journey.player.miles_traveled) * quantity;
+    let Some(money) = journey.player.money.checked_sub(cost) else {
+        return Err("You don't have enough money.".to_string());
+    };
+    if !journey.inventory.can_add(item, quantity) {
+        return Err("Your wagon is too full to take that.".to_string());
+    }
+
+    journey.player.money = money;
+    journey.stats.money_spent += cost;
+    journey.inventory.add_item(item, quantity);
+    let fort = journey.last_landmark().id.clone();
+    *journey.store_sales.entry(fort).or_default().entry(item).or_insert(0) += quantity;
+    Ok(cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::landmarks;
+
+    /// A journey stopped at a landmark
+    fn stopped_at(id: &str) -> Journey {
+        let mut journey = Journey::default();
+        journey.landmark = landmarks::index_of(id).unwrap();
+        journey.player.miles_traveled = journey.last_landmark().miles;
+        journey
+    }
+
+    #[test]
+    fn prices_rise_to_the_west() {
+        let kearney = landmarks::find("fort_kearney").unwrap().miles;
+        let walla_walla = landmarks::find("fort_walla_walla").unwrap().miles;
+        assert_eq!(price(ItemType::SpareAxle, 0.0), tables().item(ItemType::SpareAxle).price);
+        assert!(price(ItemType::SpareAxle, walla_walla) > price(ItemType::SpareAxle, kearney));
+    }
+
+    #[test]
+    fn stores_sell_out() {
+        let mut journey = stopped_at("fort_kearney");
+        journey.player.money = Money::from_dollars(10_000);
+        let stock = in_stock(&journey, ItemType::SpareWheel);
+        assert!(stock > 0);
+        assert!(buy(&mut journey, ItemType::SpareWheel, stock + 1).is_err());
+        assert!(buy(&mut journey, ItemType::SpareWheel, stock).is_ok());
+        assert_eq!(in_stock(&journey, ItemType::SpareWheel), 0);
+        assert!(buy(&mut journey, ItemType::SpareWheel, 1).is_err());
+
+        // Each fort keeps its own stock
+        assert!(in_stock(&stopped_at("fort_laramie"), ItemType::SpareWheel) > 0);
+        assert_eq!(in_stock(&stopped_at("chimney_rock"), ItemType::Food), 0);
+    }
+}
@@ -0,0 +1,101 @@
+// Shareable summary of a finished journey: how the party fared, the final
+// score, the route taken and the notable events along the way.
+
+use std::fmt::Write;
+use crate::game_logic::diary::EntryKind;
+use crate::game_logic::journey::Journey;
+use crate::game_logic::score;
+use crate::game_logic::tables::tables;
+
+/// File the journey summary is written to
+pub const SUMMARY_FILE: &str = "journey_summary.txt";
+
+/// File a picture of the scoring screen is saved to
+pub const SUMMARY_IMAGE_FILE: &str = "journey_summary.png";
+
+/// Diary entries worth listing among the notable events
+const NOTABLE: [EntryKind; 3] = [EntryKind::Event, EntryKind::Death, EntryKind::RiverCrossing];
+
+/// How the journey ended, in a few words
+fn ending(journey: &Journey) -> &'static str {
+    if journey.has_arrived() {
+        "Reached Oregon"
+    } else if journey.party_died() {
+        "Everyone in the party died"
+    } else if journey.snowbound_for_winter() {
+        "Trapped in the mountains by winter snow"
+    } else {
+        "Still on the trail"
+    }
+}
+
+/// Plain-text summary of the journey
+pub fn summary(journey: &Journey) -> String {
+    let player = &journey.player;
+    let leader = player.party.first().map_or("Unknown", |m| m.name.as_str());
+    let title = format!("The Oregon Trail - the journey of {}", leader);
+    let mut text = format!("{}\n{}\n\n", title, "=".repeat(title.len()));
+
+    let _ = writeln!(text, "Leader: {}, a {}", leader, player.occupation.name());
+    let _ = writeln!(text, "Outcome: {} after {} days, on {}", ending(journey), journey.days, journey.date());
+    if journey.has_arrived() {
+        let _ = writeln!(text, "Final score: {}", score::final_score(journey));
+    }
+
+    text.push_str("\nThe party\n");
+    let entries = journey.diary.entries();
+    for member in &player.party {
+        let fate = match member.is_alive() {
+            true => format!("survived, in {} health", member.health.name()),
+            false => entries
+                .iter()
+                .find(|e| e.kind == EntryKind::Death && e.text.starts_with(&member.name))
+                .map_or("died".to_string(), |e| format!("{} ({})", e.text.trim_end_matches('.'), e.date)),
+        };
+        let _ = writeln!(text, "  {}: {}", member.name, fate);
+    }
+
+    text.push_str("\nThe route\n");
+    let dates = journey.diary.entries_of(EntryKind::Departure).chain(journey.diary.entries_of(EntryKind::Arrival));
+    let route = &tables().landmarks[..=journey.landmark];
+    for (landmark, entry) in route.iter().zip(dates) {
+        let _ = writeln!(text, "  {}  {}", entry.date, landmark.name);
+    }
+
+    text.push_str("\nNotable events\n");
+    for entry in entries.iter().filter(|e| NOTABLE.contains(&e.kind)) {
+        let _ = writeln!(text, "  {}  {}", entry.date, entry.text);
+    }
+    text
+}
+
+/// Write the journey summary to a file
+pub fn export(journey: &Journey, path: &str) -> Result<(), String> {
+    std::fs::write(path, summary(journey)).map_err(|e| format!("Failed to write '{}': {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::game_events::GameEvent;
+    use crate::game_logic::player::HealthStatus;
+    use crate::utils::rng::GameRng;
+
+    #[test]
+    fn summary_covers_fate_route_and_events() {
+        let mut journey = Journey::default();
+        let mut rng = GameRng::new(8);
+        while journey.landmark == 0 {
+            journey.travel_day(&mut rng);
+        }
+        journey.player.party[1].health = HealthStatus::Deceased;
+        journey.publish(GameEvent::PartyMemberDied { name: "Mary".to_string(), drowned: false });
+
+        let text = summary(&journey);
+        assert!(text.contains("Outcome: Still on the trail"));
+        assert!(text.contains(&format!("Mary has died ({})", journey.date())));
+        assert!(text.contains(&tables().landmarks[0].name));
+        assert!(text.contains(&tables().landmarks[1].name));
+        assert!(!text.contains("Final score"));
+    }
+}
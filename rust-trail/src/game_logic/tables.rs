@@ -0,0 +1,164 @@
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+use crate::game_logic::hunting::{HuntingGround, Terrain};
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::landmarks::Landmark;
+use crate::game_logic::money::Money;
+use crate::game_logic::occupation::{Occupation, OccupationStats};
+
+/// Default location of the tables file, relative to the working directory
+pub const TABLES_FILE: &str = "assets/data/tables.json";
+
+/// Built-in copy of the tables, used when no file is loaded
+const DEFAULT_TABLES: &str = include_str!("../../assets/data/tables.json");
+
+/// Weight and store price of an item
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ItemStats {
+    /// Weight of one unit (in pounds)
+    pub weight: f32,
+    /// Price of one unit at Independence
+    pub price: Money,
+}
+
+/// Balancing values and tables loaded from data files
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameTables {
+    /// Starting money and score bonus of every occupation
+    pub occupations: HashMap<Occupation, OccupationStats>,
+    /// Food eaten per person per day at meager rations (in pounds)
+    pub food_per_person_per_day: f32,
+    /// Miles covered per day at a steady pace
+    pub miles_per_day: f32,
+    /// Most the wagon can carry (in pounds)
+    pub wagon_capacity: f32,
+    /// Weight and price of every item
+    pub items: HashMap<ItemType, ItemStats>,
+    /// Chance of an event happening on any day of travel
+    pub daily_event_chance: f32,
+    /// Relative weight of each event, keyed by event id
+    pub event_weights: BTreeMap<String, u32>,
+    /// Landmarks in trail order
+    pub landmarks: Vec<Landmark>,
+    /// Game found in each hunting terrain
+    pub hunting_grounds: HashMap<Terrain, HuntingGround>,
+}
+
+impl GameTables {
+    /// Parse tables from JSON, checking they are complete
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let tables: GameTables = serde_json::from_str(json)
+            .map_err(|e| format!("Failed to parse game tables: {}", e))?;
+        tables.validate()?;
+        Ok(tables)
+    }
+
+    /// Load tables from a JSON file
+    pub fn load(path: &str) -> Result<Self, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read '{}': {}", path, e))?;
+        Self::from_json(&json).map_err(|e| format!("{} ({})", e, path))
+    }
+
+    /// Check every item and occupation has stats, landmarks are in trail order, rivers have a size,
+    /// forts have a store and every terrain has game
+    fn validate(&self) -> Result<(), String> {
+        if let Some(item) = ItemType::ALL.iter().find(|item| !self.items.contains_key(item)) {
+            return Err(format!("Game tables are missing stats for {:?}", item));
+        }
+        if let Some(occupation) = Occupation::ALL.iter().find(|o| !self.occupations.contains_key(o)) {
+            return Err(format!("Game tables are missing stats for {:?}", occupation));
+        }
+        if self.landmarks.is_empty() {
+            return Err("Game tables have no landmarks".to_string());
+        }
+        if let Some(pair) = self.landmarks.windows(2).find(|pair| pair[1].miles <= pair[0].miles) {
+            return Err(format!("Landmark '{}' isn't further along the trail than '{}'", pair[1].id, pair[0].id));
+        }
+        if let Some(landmark) = self.landmarks.iter().find(|l| l.is_river() && l.river.is_none()) {
+            return Err(format!("River '{}' has no width, depth or swiftness", landmark.id));
+        }
+        if let Some(landmark) = self.landmarks.iter().find(|l| l.is_fort() && l.store.is_none()) {
+            return Err(format!("Fort '{}' has no store", landmark.id));
+        }
+        if let Some(terrain) = Terrain::ALL.iter().find(|t| !self.hunting_grounds.contains_key(t)) {
+            return Err(format!("Game tables have no hunting ground for {:?}", terrain));
+        }
+        Ok(())
+    }
+
+    /// Weight and price of an item
+    pub fn item(&self, item_type: ItemType) -> ItemStats {
+        self.items[&item_type]
+    }
+
+    /// Starting money and score bonus of an occupation
+    pub fn occupation(&self, occupation: Occupation) -> OccupationStats {
+        self.occupations[&occupation]
+    }
+
+    /// Length of the trail (in miles)
+    pub fn trail_length(&self) -> f32 {
+        self.landmarks.last().map_or(0.0, |landmark| landmark.miles)
+    }
+
+    /// Look up a landmark by id
+    pub fn landmark(&self, id: &str) -> Option<&Landmark> {
+        self.landmarks.iter().find(|landmark| landmark.id == id)
+    }
+}
+
+static TABLES: OnceLock<GameTables> = OnceLock::new();
+
+/// Load the tables used for the rest of the run
+///
+/// Must be called before anything reads the tables; falls back to the
+/// built-in copy if the file can't be loaded.
+pub fn init(path: &str) -> Result<(), String> {
+    let result = GameTables::load(path);
+    let tables = match &result {
+        Ok(tables) => tables.clone(),
+        Err(_) => builtin(),
+    };
+    TABLES.set(tables).map_err(|_| "Game tables were already loaded".to_string())?;
+    result.map(|_| ())
+}
+
+/// The active game tables
+pub fn tables() -> &'static GameTables {
+    TABLES.get_or_init(builtin)
+}
+
+/// Parse the built-in tables
+fn builtin() -> GameTables {
+    GameTables::from_json(DEFAULT_TABLES).expect("built-in game tables are valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_tables_are_valid() {
+        let tables = builtin();
+        assert_eq!(tables.trail_length(), 2040.0);
+        assert_eq!(tables.landmark("independence").map(|l| l.miles), Some(0.0));
+    }
+
+    #[test]
+    fn missing_items_are_rejected() {
+        let mut tables = builtin();
+        tables.items.remove(&ItemType::Food);
+        let json = serde_json::to_string(&tables).unwrap();
+        assert!(GameTables::from_json(&json).is_err());
+    }
+
+    #[test]
+    fn landmarks_at_the_same_mile_are_rejected() {
+        let mut tables = builtin();
+        tables.landmarks[2].miles = tables.landmarks[1].miles;
+        let json = serde_json::to_string(&tables).unwrap();
+        assert!(GameTables::from_json(&json).is_err());
+    }
+}
@@ -0,0 +1,371 @@
+use serde::{Serialize, Deserialize};
+use std::fmt;
+
+/// Month names, indexed from 0 (January)
+pub const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+/// Reasons a month/day/year combination can be rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateError {
+    /// Year 0, which the calendar doesn't have
+    InvalidYear(u16),
+    /// Month outside 1-12
+    InvalidMonth(u8),
+    /// Day outside the month's range
+    InvalidDay { month: u8, day: u32, year: u16 },
+    /// Year too large to represent after advancing
+    YearOverflow,
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateError::InvalidYear(year) => write!(f, "invalid year {}", year),
+            DateError::InvalidMonth(month) => write!(f, "invalid month {}", month),
+            DateError::InvalidDay { month, day, year } => {
+                write!(f, "invalid day {} for month {} of {}", day, month, year)
+            }
+            DateError::YearOverflow => write!(f, "year out of range"),
+        }
+    }
+}
+
+impl std::error::Error for DateError {}
+
+/// Check if a year is a leap year in the Gregorian calendar
+pub fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+/// Get the number of days in a month (1-12), or None for an invalid month
+pub fn days_in_month(month: u8, year: u16) -> Option<u32> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 => Some(if is_leap_year(year) { 29 } else { 28 }),
+        _ => None,
+    }
+}
+
+/// Check that a month/day/year combination exists
+pub fn validate_date(month: u8, day: u32, year: u16) -> Result<(), DateError> {
+    if year == 0 {
+        return Err(DateError::InvalidYear(year));
+    }
+    let days = days_in_month(month, year).ok_or(DateError::InvalidMonth(month))?;
+    if day == 0 || day > days {
+        return Err(DateError::InvalidDay { month, day, year });
+    }
+    Ok(())
+}
+
+/// Advance a valid date by a number of days, rolling over months and years
+pub fn add_days(month: u8, day: u32, year: u16, days: u32) -> Result<(u8, u32, u16), DateError> {
+    validate_date(month, day, year)?;
+
+    let (mut month, mut day, mut year) = (month, day, year);
+    let mut remaining = days;
+
+    while remaining > 0 {
+        let month_length = days_in_month(month, year).unwrap_or(30);
+        let left_in_month = month_length - day;
+
+        if remaining <= left_in_month {
+            day += remaining;
+            break;
+        }
+
+        // Jump to the first of next month
+        remaining -= left_in_month + 1;
+        day = 1;
+        if month == 12 {
+            month = 1;
+            year = year.checked_add(1).ok_or(DateError::YearOverflow)?;
+        } else {
+            month += 1;
+        }
+    }
+
+    Ok((month, day, year))
+}
+
+/// Count the days from January 1st of year 1 to a valid date
+pub fn day_number(month: u8, day: u32, year: u16) -> Result<u32, DateError> {
+    validate_date(month, day, year)?;
+
+    let prior_years = year as u32 - 1;
+    let mut days = prior_years * 365 + prior_years / 4 - prior_years / 100 + prior_years / 400;
+    for m in 1..month {
+        days += days_in_month(m, year).unwrap_or(0);
+    }
+    Ok(days + day - 1)
+}
+
+/// Day of the week
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Days of the week in order, starting on Monday
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Display name of the day
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "Monday",
+            Weekday::Tuesday => "Tuesday",
+            Weekday::Wednesday => "Wednesday",
+            Weekday::Thursday => "Thursday",
+            Weekday::Friday => "Friday",
+            Weekday::Saturday => "Saturday",
+            Weekday::Sunday => "Sunday",
+        }
+    }
+}
+
+/// Season of the year
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    Winter,
+    Spring,
+    Summer,
+    Autumn,
+}
+
+impl Season {
+    /// Display name of the season
+    pub fn name(&self) -> &'static str {
+        match self {
+            Season::Winter => "winter",
+            Season::Spring => "spring",
+            Season::Summer => "summer",
+            Season::Autumn => "autumn",
+        }
+    }
+}
+
+/// Date fields as written in saves, which may be out of range
+#[derive(Deserialize)]
+struct DateFields {
+    month: u8,
+    day: u32,
+    year: u16,
+}
+
+impl From<DateFields> for TrailDate {
+    fn from(fields: DateFields) -> Self {
+        TrailDate::clamped(fields.month, fields.day, fields.year)
+    }
+}
+
+/// A calendar date on the trail, always a day that exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(from = "DateFields")]
+pub struct TrailDate {
+    year: u16,
+    month: u8,
+    day: u32,
+}
+
+impl TrailDate {
+    /// Create a date, rejecting days that don't exist
+    pub fn new(month: u8, day: u32, year: u16) -> Result<Self, DateError> {
+        validate_date(month, day, year)?;
+        Ok(Self { year, month, day })
+    }
+
+    /// Create a date, clamping an out-of-range year, month or day to the nearest valid one
+    pub fn clamped(month: u8, day: u32, year: u16) -> Self {
+        let year = year.max(1);
+        let month = month.clamp(1, 12);
+        let day = day.clamp(1, days_in_month(month, year).unwrap_or(31));
+        Self { year, month, day }
+    }
+
+    /// Month (1-12)
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Day of the month
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Year
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Name of the month
+    pub fn month_name(&self) -> &'static str {
+        MONTH_NAMES[self.month as usize - 1]
+    }
+
+    /// The date a number of days later
+    pub fn add_days(&self, days: u32) -> Result<Self, DateError> {
+        let (month, day, year) = add_days(self.month, self.day, self.year, days)?;
+        Ok(Self { year, month, day })
+    }
+
+    /// Days since January 1st of year 1
+    pub fn day_number(&self) -> u32 {
+        day_number(self.month, self.day, self.year).unwrap_or(0)
+    }
+
+    /// Day of the year, from 1 for January 1st
+    pub fn day_of_year(&self) -> u32 {
+        self.day_number() - day_number(1, 1, self.year).unwrap_or(0) + 1
+    }
+
+    /// Day of the week
+    pub fn weekday(&self) -> Weekday {
+        // January 1st of year 1 was a Monday
+        Weekday::ALL[(self.day_number() % 7) as usize]
+    }
+
+    /// Season the date falls in
+    pub fn season(&self) -> Season {
+        match self.month {
+            3..=5 => Season::Spring,
+            6..=8 => Season::Summer,
+            9..=11 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+}
+
+impl fmt::Display for TrailDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}, {}", self.month_name(), self.day, self.year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn leap_years_follow_gregorian_rules() {
+        assert!(is_leap_year(1848));
+        assert!(!is_leap_year(1849));
+        assert!(!is_leap_year(1900));
+        assert!(is_leap_year(2000));
+    }
+
+    #[test]
+    fn february_length_depends_on_year() {
+        assert_eq!(days_in_month(2, 1848), Some(29));
+        assert_eq!(days_in_month(2, 1847), Some(28));
+    }
+
+    #[test]
+    fn invalid_months_are_rejected() {
+        assert_eq!(days_in_month(0, 1848), None);
+        assert_eq!(days_in_month(13, 1848), None);
+        assert_eq!(validate_date(13, 1, 1848), Err(DateError::InvalidMonth(13)));
+    }
+
+    #[test]
+    fn invalid_days_are_rejected() {
+        assert!(validate_date(4, 31, 1848).is_err());
+        assert!(validate_date(2, 30, 1848).is_err());
+        assert!(validate_date(2, 29, 1847).is_err());
+        assert!(validate_date(3, 0, 1848).is_err());
+        assert!(validate_date(2, 29, 1848).is_ok());
+    }
+
+    #[test]
+    fn day_numbers_are_consecutive_across_year_end() {
+        let dec_31 = day_number(12, 31, 1848).unwrap();
+        let jan_1 = day_number(1, 1, 1849).unwrap();
+        assert_eq!(jan_1, dec_31 + 1);
+    }
+
+    #[test]
+    fn trail_dates_cross_month_and_leap_year_boundaries() {
+        let date = TrailDate::new(2, 28, 1848).unwrap();
+        assert_eq!(date.add_days(1).unwrap(), TrailDate::new(2, 29, 1848).unwrap());
+        assert_eq!(date.add_days(2).unwrap(), TrailDate::new(3, 1, 1848).unwrap());
+        assert_eq!(TrailDate::new(2, 28, 1849).unwrap().add_days(1).unwrap().to_string(), "March 1, 1849");
+        assert_eq!(TrailDate::new(12, 31, 1848).unwrap().add_days(1).unwrap().day_of_year(), 1);
+        assert_eq!(TrailDate::new(12, 31, 1848).unwrap().day_of_year(), 366);
+        assert!(TrailDate::new(2, 29, 1849).is_err());
+        assert_eq!(TrailDate::clamped(4, 35, 1848), TrailDate::new(4, 30, 1848).unwrap());
+    }
+
+    #[test]
+    fn year_zero_is_refused_and_clamped_in_saves() {
+        assert_eq!(validate_date(3, 1, 0), Err(DateError::InvalidYear(0)));
+        assert!(day_number(3, 1, 0).is_err());
+
+        let saved: TrailDate = serde_json::from_str(r#"{"year": 0, "month": 3, "day": 1}"#).unwrap();
+        assert_eq!(saved, TrailDate::new(3, 1, 1).unwrap());
+        assert_eq!(saved.day_of_year(), 60);
+        assert_eq!(saved.weekday(), Weekday::Thursday);
+    }
+
+    #[test]
+    fn trail_dates_know_their_weekday_and_season() {
+        let departure = TrailDate::new(3, 1, 1848).unwrap();
+        assert_eq!(departure.weekday(), Weekday::Wednesday);
+        assert_eq!(departure.add_days(4).unwrap().weekday(), Weekday::Sunday);
+        assert_eq!(departure.season(), Season::Spring);
+        assert_eq!(TrailDate::new(12, 1, 1848).unwrap().season(), Season::Winter);
+        assert!(departure < departure.add_days(1).unwrap());
+    }
+
+    proptest! {
+        #[test]
+        fn add_days_always_produces_valid_dates(
+            month in 1u8..=12, day in 1u32..=31, year in 1800u16..2000, days in 0u32..2000
+        ) {
+            prop_assume!(validate_date(month, day, year).is_ok());
+            let (m, d, y) = add_days(month, day, year, days).unwrap();
+            prop_assert!(validate_date(m, d, y).is_ok());
+        }
+
+        #[test]
+        fn add_days_moves_day_number_by_exact_amount(
+            month in 1u8..=12, day in 1u32..=31, year in 1800u16..2000, days in 0u32..2000
+        ) {
+            prop_assume!(validate_date(month, day, year).is_ok());
+            let (m, d, y) = add_days(month, day, year, days).unwrap();
+            prop_assert_eq!(
+                day_number(m, d, y).unwrap(),
+                day_number(month, day, year).unwrap() + days
+            );
+        }
+
+        #[test]
+        fn add_days_is_additive(
+            month in 1u8..=12, day in 1u32..=31, year in 1800u16..2000,
+            a in 0u32..500, b in 0u32..500
+        ) {
+            prop_assume!(validate_date(month, day, year).is_ok());
+            let (m1, d1, y1) = add_days(month, day, year, a).unwrap();
+            let stepped = add_days(m1, d1, y1, b).unwrap();
+            prop_assert_eq!(stepped, add_days(month, day, year, a + b).unwrap());
+        }
+    }
+}
@@ -0,0 +1,135 @@
+// The Oregon Top Ten: the best scores of parties that reached Oregon, kept
+// between games. It starts out filled with the original game's names, which
+// players push off the list as they do better.
+
+use serde::{Deserialize, Serialize};
+use crate::utils::storage::Storage;
+use crate::utils::checksum;
+use crate::utils::versioned::{self, Migration};
+
+/// File the Top Ten is kept in between games
+pub const TOP_TEN_FILE: &str = "topten.json";
+
+/// Changes to the format the Top Ten is kept in, one per version
+///
+/// It has had a checksum from the start.
+const MIGRATIONS: &[Migration] = &[checksum::trust];
+
+/// Entries on the list
+pub const TOP_TEN_SIZE: usize = 10;
+
+/// Names and scores the list starts out with, as in the original game
+const ORIGINAL: [(&str, u32); TOP_TEN_SIZE] = [
+    ("Stephen Meek", 7650),
+    ("Celinda Hines", 5694),
+    ("Andrew Sublette", 4138),
+    ("David Hastings", 3221),
+    ("Ezra Meeker", 2588),
+    ("Willian Vaughn", 1944),
+    ("Mary Bartlett", 1651),
+    ("William Wiggins", 1004),
+    ("Charles Hopper", 860),
+    ("Elijah White", 636),
+];
+
+/// Rating given for a score
+pub fn rating(points: u32) -> &'static str {
+    match points {
+        7000.. => "Trail guide",
+        3000.. => "Adventurer",
+        _ => "Greenhorn",
+    }
+}
+
+/// One party's place on the list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopTenEntry {
+    /// Name of the party's leader
+    pub name: String,
+    /// Final score
+    pub points: u32,
+}
+
+/// The ten best scores, highest first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopTen {
+    entries: Vec<TopTenEntry>,
+    /// Whether the list was ever edited outside the game
+    #[serde(default)]
+    tampered: bool,
+}
+
+impl Default for TopTen {
+    fn default() -> Self {
+        let entries = ORIGINAL.iter().map(|&(name, points)| TopTenEntry { name: name.to_string(), points }).collect();
+        Self { entries, tampered: false }
+    }
+}
+
+impl TopTen {
+    /// Load the list, or start with the original one if there is nothing stored yet
+    pub fn load(storage: &dyn Storage, name: &str) -> Result<Self, String> {
+        match storage.read(name)? {
+            Some(json) => {
+                let (mut top_ten, intact): (Self, bool) = versioned::from_signed_json(&json, MIGRATIONS)
+                    .map_err(|e| format!("Failed to read the Top Ten: {} ({})", e, name))?;
+                top_ten.tampered |= !intact;
+                Ok(top_ten)
+            }
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Write the list to storage
+    pub fn save(&self, storage: &mut dyn Storage, name: &str) -> Result<(), String> {
+        let json = versioned::to_signed_json(self, MIGRATIONS).map_err(|e| format!("Failed to write the Top Ten: {}", e))?;
+        storage.write(name, &json)
+    }
+
+    /// The entries, highest score first
+    pub fn entries(&self) -> &[TopTenEntry] {
+        &self.entries
+    }
+
+    /// Whether the list was ever edited outside the game
+    pub fn tampered(&self) -> bool {
+        self.tampered
+    }
+
+    /// Put a score on the list if it's good enough, returning its place
+    ///
+    /// A score equal to one already on the list goes below it.
+    pub fn add(&mut self, name: &str, points: u32) -> Option<usize> {
+        let place = self.entries.iter().position(|e| points > e.points).unwrap_or(self.entries.len());
+        if place >= TOP_TEN_SIZE {
+            return None;
+        }
+        self.entries.insert(place, TopTenEntry { name: name.to_string(), points });
+        self.entries.truncate(TOP_TEN_SIZE);
+        Some(place)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::storage::MemoryStorage;
+
+    #[test]
+    fn good_scores_push_others_off_the_list() {
+        let mut top_ten = TopTen::default();
+        assert_eq!(top_ten.add("Nobody", 636), None);
+        assert_eq!(top_ten.add("Mary", 5000), Some(2));
+        assert_eq!(top_ten.entries().len(), TOP_TEN_SIZE);
+        assert_eq!(top_ten.entries()[2].name, "Mary");
+        assert_eq!(top_ten.entries().last().unwrap().name, "Charles Hopper");
+        assert_eq!(rating(top_ten.entries()[0].points), "Trail guide");
+
+        let mut storage = MemoryStorage::default();
+        top_ten.save(&mut storage, TOP_TEN_FILE).unwrap();
+        assert_eq!(TopTen::load(&storage, TOP_TEN_FILE), Ok(top_ten));
+        let json = storage.read(TOP_TEN_FILE).unwrap().unwrap();
+        storage.write(TOP_TEN_FILE, &json.replace("5000", "9999")).unwrap();
+        assert!(TopTen::load(&storage, TOP_TEN_FILE).unwrap().tampered());
+    }
+}
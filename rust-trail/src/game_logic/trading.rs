@@ -0,0 +1,180 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use crate::game_logic::inventory::{Inventory, ItemType};
+use crate::game_logic::journey::Journey;
+use crate::game_logic::tables::tables;
+
+/// Most trades the party can attempt in one day
+pub const MAX_TRADES_PER_DAY: u32 = 3;
+
+/// Chance an emigrant offers something the party is short of
+pub const SHORTAGE_OFFER_CHANCE: f32 = 0.7;
+
+/// How much more an emigrant asks for at the far end of the trail, where goods are scarce
+pub const WEST_MARKUP: f32 = 0.75;
+
+/// Extra an emigrant asks for when they can see the party needs what they offer
+pub const SHORTAGE_MARKUP: f32 = 0.25;
+
+/// Days of food below which the party is short
+const FOOD_DAYS_WANTED: f32 = 10.0;
+
+/// A trade proposed by a fellow emigrant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TradeOffer {
+    /// What the emigrant gives the party
+    pub offered: ItemType,
+    /// How many of it
+    pub offered_quantity: u32,
+    /// What the emigrant wants from the party
+    pub wanted: ItemType,
+    /// How many of it
+    pub wanted_quantity: u32,
+}
+
+impl TradeOffer {
+    /// Describe the offer the way the emigrant puts it
+    pub fn describe(&self) -> String {
+        format!(
+            "I'd give you {} {} for {} {}.",
+            self.offered_quantity,
+            self.offered.name(),
+            self.wanted_quantity,
+            self.wanted.name()
+        )
+    }
+
+    /// Whether the party has what the emigrant wants
+    pub fn can_accept(&self, inventory: &Inventory) -> bool {
+        inventory.get_quantity(self.wanted) >= self.wanted_quantity
+    }
+
+    /// Whether what the emigrant offers fits in the wagon once the party has paid
+    pub fn fits(&self, inventory: &Inventory) -> bool {
+        let weight = |item: ItemType, quantity: u32| match item.is_carried() {
+            true => tables().item(item).weight * quantity as f32,
+            false => 0.0,
+        };
+        let load = inventory.total_weight() - weight(self.wanted, self.wanted_quantity)
+            + weight(self.offered, self.offered_quantity);
+        load <= inventory.capacity()
+    }
+
+    /// Make the trade, returning false if the party can't pay
+    pub fn accept(&self, inventory: &mut Inventory) -> bool {
+        if !inventory.remove_item(self.wanted, self.wanted_quantity) {
+            return false;
+        }
+        inventory.add_item(self.offered, self.offered_quantity);
+        true
+    }
+}
+
+/// Items the party is running short of
+pub fn shortages(journey: &Journey) -> Vec<ItemType> {
+    let inventory = &journey.inventory;
+    let living = journey.player.living_party_members() as u32;
+    let food_wanted = (tables().food_per_person_per_day * living as f32 * FOOD_DAYS_WANTED) as u32;
+
+    ItemType::ALL
+        .iter()
+        .copied()
+        .filter(|item| {
+            let have = inventory.get_quantity(*item);
+            match item {
+                ItemType::Food => have < food_wanted,
+                ItemType::Clothing => have < living * 2,
+                ItemType::Ammunition => have < 50,
+                ItemType::OxenPair => have < 2,
+                _ => have == 0,
+            }
+        })
+        .collect()
+}
+
+/// Size of the bundle an emigrant offers of an item
+fn bundle<R: Rng>(item: ItemType, rng: &mut R) -> u32 {
+    match item {
+        ItemType::Food => rng.gen_range(5..=15) * 10,
+        ItemType::Clothing => rng.gen_range(1..=4),
+        ItemType::Ammunition => rng.gen_range(5..=20) * 10,
+        _ => 1,
+    }
+}
+
+/// Generate an offer from a fellow emigrant, or None if nobody wants what the party has
+pub fn generate_offer<R: Rng>(journey: &Journey, rng: &mut R) -> Option<TradeOffer> {
+    let short = shortages(journey);
+    let offered = if !short.is_empty() && rng.gen::<f32>() < SHORTAGE_OFFER_CHANCE {
+        *short.choose(rng)?
+    } else {
+        *ItemType::ALL.choose(rng)?
+    };
+    let offered_quantity = bundle(offered, rng);
+
+    // Goods get dearer the further west the party is
+    let progress = (journey.player.miles_traveled / tables().trail_length()).clamp(0.0, 1.0);
+    let mut markup = 1.0 + progress * WEST_MARKUP;
+    if short.contains(&offered) {
+        markup += SHORTAGE_MARKUP;
+    }
+    let value = tables().item(offered).price.as_f32() * offered_quantity as f32 * markup;
+
+    // Ask for something the party has enough of to pay with
+    let inventory = &journey.inventory;
+    let payments: Vec<TradeOffer> = ItemType::ALL
+        .iter()
+        .copied()
+        .filter(|item| *item != offered && !short.contains(item))
+        .map(|wanted| TradeOffer {
+            offered,
+            offered_quantity,
+            wanted,
+            wanted_quantity: (value / tables().item(wanted).price.as_f32()).ceil().max(1.0) as u32,
+        })
+        .filter(|offer| offer.can_accept(inventory))
+        .collect();
+
+    payments.choose(rng).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rng::{GameRng, RngStream};
+
+    #[test]
+    fn offers_are_affordable() {
+        let journey = Journey::default();
+        let mut rng = GameRng::new(9);
+        for _ in 0..200 {
+            let offer = generate_offer(&journey, rng.stream(RngStream::Trading)).unwrap();
+            assert_ne!(offer.offered, offer.wanted);
+            assert!(offer.can_accept(&journey.inventory));
+        }
+    }
+
+    #[test]
+    fn accepting_swaps_items() {
+        let mut journey = Journey::default();
+        let offer = TradeOffer {
+            offered: ItemType::Clothing,
+            offered_quantity: 2,
+            wanted: ItemType::Food,
+            wanted_quantity: 50,
+        };
+        let food = journey.inventory.get_quantity(ItemType::Food);
+        let clothing = journey.inventory.get_quantity(ItemType::Clothing);
+
+        assert!(offer.accept(&mut journey.inventory));
+        assert_eq!(journey.inventory.get_quantity(ItemType::Food), food - 50);
+        assert_eq!(journey.inventory.get_quantity(ItemType::Clothing), clothing + 2);
+    }
+
+    #[test]
+    fn nothing_to_trade_means_no_offer() {
+        let journey = Journey::new("Leader", &[], &[]);
+        let mut rng = GameRng::new(9);
+        assert_eq!(generate_offer(&journey, rng.stream(RngStream::Trading)), None);
+    }
+}
@@ -0,0 +1,198 @@
+// Hot-seat wagon trains: two to four players each lead a wagon down the same
+// trail, taking turns a day at a time at one machine. Wagons stopped at the
+// same landmark can trade supplies, and the train is scored wagon by wagon
+// and as a whole.
+
+use std::mem;
+use crate::game_logic::journey::{Journey, DEFAULT_SUPPLIES};
+use crate::game_logic::score;
+use crate::game_logic::trading::TradeOffer;
+
+/// Fewest wagons in a train
+pub const MIN_WAGONS: usize = 2;
+
+/// Most wagons in a train
+pub const MAX_WAGONS: usize = 4;
+
+/// Other members of each wagon's party
+const PARTY: [&str; 4] = ["Mary", "Tom", "Sally", "Joe"];
+
+/// One player's wagon
+#[derive(Debug)]
+pub struct Wagon {
+    /// Player leading the wagon
+    pub player: String,
+    /// The wagon's journey; while its player has the turn, the game holds it
+    /// and this is a stand-in
+    pub journey: Journey,
+    /// Whether the wagon's journey is over
+    pub finished: bool,
+}
+
+/// Wagons travelling together, and whose turn it is
+#[derive(Debug)]
+pub struct WagonTrain {
+    wagons: Vec<Wagon>,
+    /// Index of the wagon whose player has the turn
+    current: usize,
+}
+
+impl WagonTrain {
+    /// Form a train with a wagon for each player, each led by that player
+    pub fn new(players: &[&str]) -> Result<Self, String> {
+        if !(MIN_WAGONS..=MAX_WAGONS).contains(&players.len()) {
+            return Err(format!("A wagon train needs {} to {} players", MIN_WAGONS, MAX_WAGONS));
+        }
+        let wagons = players
+            .iter()
+            .map(|&player| Wagon { player: player.to_string(), journey: Journey::new(player, &PARTY, &DEFAULT_SUPPLIES), finished: false })
+            .collect();
+        Ok(Self { wagons, current: 0 })
+    }
+
+    /// The wagons, in turn order
+    pub fn wagons(&self) -> &[Wagon] {
+        &self.wagons
+    }
+
+    /// Index of the wagon whose player has the turn
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Player whose turn it is
+    pub fn player(&self) -> &str {
+        &self.wagons[self.current].player
+    }
+
+    /// Hand the first wagon's journey to the game to play
+    pub fn start(&mut self, active: &mut Journey) {
+        self.current = 0;
+        mem::swap(active, &mut self.wagons[0].journey);
+    }
+
+    /// Next wagon still on the trail, counting on from the current one and
+    /// coming back round to it, or None once every journey is over
+    pub fn next_on_trail(&self) -> Option<usize> {
+        let count = self.wagons.len();
+        (1..=count).map(|step| (self.current + step) % count).find(|&i| !self.wagons[i].finished)
+    }
+
+    /// Pass the turn: the active journey goes back to its wagon and the next
+    /// wagon's journey is taken out to play
+    pub fn pass_turn(&mut self, active: &mut Journey, next: usize) {
+        mem::swap(active, &mut self.wagons[self.current].journey);
+        mem::swap(active, &mut self.wagons[next].journey);
+        self.current = next;
+    }
+
+    /// Mark the current wagon's journey as over
+    pub fn finish_current(&mut self) {
+        self.wagons[self.current].finished = true;
+    }
+
+    /// Put the active journey back in its wagon once the train's journey is
+    /// over, so every wagon can be scored
+    pub fn put_back(&mut self, active: &mut Journey) {
+        mem::swap(active, &mut self.wagons[self.current].journey);
+    }
+
+    /// Wagons stopped at the same landmark as the active journey, which it can trade with
+    pub fn partners(&self, active: &Journey) -> Vec<usize> {
+        if !active.at_landmark() {
+            return Vec::new();
+        }
+        (0..self.wagons.len())
+            .filter(|&i| i != self.current && !self.wagons[i].finished)
+            .filter(|&i| self.wagons[i].journey.landmark == active.landmark && self.wagons[i].journey.at_landmark())
+            .collect()
+    }
+
+    /// Trade between the active journey and a partner wagon; see [`trade`]
+    pub fn trade_with(&mut self, active: &mut Journey, partner: usize, offer: &TradeOffer) -> Result<(), String> {
+        trade(active, &mut self.wagons[partner].journey, offer)
+    }
+
+    /// Final score of each wagon, in turn order; the journeys must all be back in their wagons
+    pub fn scores(&self) -> Vec<u32> {
+        self.wagons.iter().map(|wagon| score::final_score(&wagon.journey)).collect()
+    }
+
+    /// Score of the whole train
+    pub fn combined_score(&self) -> u32 {
+        self.scores().iter().sum()
+    }
+}
+
+/// Trade between two wagons: the first gives what the offer wants and gets
+/// what it offers from the second
+pub fn trade(mine: &mut Journey, theirs: &mut Journey, offer: &TradeOffer) -> Result<(), String> {
+    let reverse = TradeOffer {
+        offered: offer.wanted,
+        offered_quantity: offer.wanted_quantity,
+        wanted: offer.offered,
+        wanted_quantity: offer.offered_quantity,
+    };
+    if !offer.can_accept(&mine.inventory) {
+        return Err(format!("You don't have {} {}.", offer.wanted_quantity, offer.wanted.name()));
+    }
+    if !reverse.can_accept(&theirs.inventory) {
+        return Err(format!("They don't have {} {}.", offer.offered_quantity, offer.offered.name()));
+    }
+    if !offer.fits(&mine.inventory) || !reverse.fits(&theirs.inventory) {
+        return Err("That won't fit in the wagon.".to_string());
+    }
+    offer.accept(&mut mine.inventory);
+    reverse.accept(&mut theirs.inventory);
+    mine.settle_injured_oxen();
+    theirs.settle_injured_oxen();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_logic::inventory::ItemType;
+
+    #[test]
+    fn players_take_turns_until_every_journey_is_over() {
+        assert!(WagonTrain::new(&["Ada"]).is_err());
+        let mut train = WagonTrain::new(&["Ada", "Ben", "Cy"]).unwrap();
+        let mut active = Journey::default();
+        train.start(&mut active);
+        assert_eq!(active.player.party[0].name, "Ada");
+
+        active.days = 5;
+        train.pass_turn(&mut active, 1);
+        assert_eq!((train.player(), active.player.party[0].name.as_str()), ("Ben", "Ben"));
+        train.finish_current();
+        train.pass_turn(&mut active, train.next_on_trail().unwrap());
+        assert_eq!(train.player(), "Cy");
+        // Ben's journey is over, so the turn comes back round to Ada
+        assert_eq!(train.next_on_trail(), Some(0));
+        train.pass_turn(&mut active, 0);
+        assert_eq!(active.days, 5);
+
+        train.finish_current();
+        train.pass_turn(&mut active, 2);
+        train.finish_current();
+        assert_eq!(train.next_on_trail(), None);
+        train.put_back(&mut active);
+        assert_eq!(train.wagons()[0].journey.days, 5);
+        assert_eq!(train.combined_score(), train.scores().iter().sum::<u32>());
+    }
+
+    #[test]
+    fn wagons_trade_what_they_have() {
+        let mut mine = Journey::default();
+        let mut theirs = Journey::default();
+        let food = mine.inventory.get_quantity(ItemType::Food);
+        let offer = TradeOffer { offered: ItemType::SpareWheel, offered_quantity: 1, wanted: ItemType::Food, wanted_quantity: 50 };
+        trade(&mut mine, &mut theirs, &offer).unwrap();
+        assert_eq!(mine.inventory.get_quantity(ItemType::Food), food - 50);
+        assert_eq!(theirs.inventory.get_quantity(ItemType::Food), food + 50);
+
+        let greedy = TradeOffer { offered_quantity: 99, ..offer };
+        assert!(trade(&mut mine, &mut theirs, &greedy).is_err());
+    }
+}
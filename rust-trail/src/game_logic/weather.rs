@@ -0,0 +1,122 @@
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+/// Typical temperature band for each month, 0 (very cold) to 5 (very hot)
+const MONTH_TEMPERATURE: [i32; 12] = [0, 1, 2, 2, 3, 4, 5, 4, 3, 2, 1, 0];
+
+/// Daily chance of rain or snow
+const PRECIPITATION_CHANCE: f32 = 0.15;
+
+/// Chance of fog on a dry day that's neither hot nor cold
+const FOG_CHANCE: f32 = 0.08;
+
+/// The day's weather on the trail
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Weather {
+    VeryHot,
+    Hot,
+    #[default]
+    Warm,
+    Cool,
+    Cold,
+    VeryCold,
+    Rain,
+    Snow,
+    Fog,
+}
+
+impl Weather {
+    /// All kinds of weather
+    pub const ALL: [Weather; 9] = [
+        Weather::VeryHot,
+        Weather::Hot,
+        Weather::Warm,
+        Weather::Cool,
+        Weather::Cold,
+        Weather::VeryCold,
+        Weather::Rain,
+        Weather::Snow,
+        Weather::Fog,
+    ];
+
+    /// Display name of the weather
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weather::VeryHot => "very hot",
+            Weather::Hot => "hot",
+            Weather::Warm => "warm",
+            Weather::Cool => "cool",
+            Weather::Cold => "cold",
+            Weather::VeryCold => "very cold",
+            Weather::Rain => "rain",
+            Weather::Snow => "snow",
+            Weather::Fog => "fog",
+        }
+    }
+
+    /// Points of ill health the weather adds each day, more for a party short of clothing
+    pub fn health_penalty(&self, short_of_clothing: bool) -> f32 {
+        let (base, unclothed) = match self {
+            Weather::VeryHot => (2.0, 0.0),
+            Weather::Hot | Weather::Rain => (1.0, 0.0),
+            Weather::Cold => (1.0, 2.0),
+            Weather::VeryCold | Weather::Snow => (2.0, 4.0),
+            _ => (0.0, 0.0),
+        };
+        if short_of_clothing {
+            base + unclothed
+        } else {
+            base
+        }
+    }
+
+    /// Whether the weather is cold enough that the party needs warm clothing
+    pub fn is_cold(&self) -> bool {
+        matches!(self, Weather::Cold | Weather::VeryCold | Weather::Snow)
+    }
+
+    /// Whether the weather makes the trail hard to see
+    pub fn limits_visibility(&self) -> bool {
+        matches!(self, Weather::Rain | Weather::Snow | Weather::Fog)
+    }
+
+    /// Roll the weather for a day in the given month (1-12)
+    pub fn roll<R: Rng>(month: u8, rng: &mut R) -> Self {
+        let base = MONTH_TEMPERATURE[(month.clamp(1, 12) - 1) as usize];
+        let temperature = (base + rng.gen_range(-1..=1)).clamp(0, 5);
+
+        if rng.gen::<f32>() < PRECIPITATION_CHANCE {
+            return if temperature <= 1 { Weather::Snow } else { Weather::Rain };
+        }
+        if (2..=3).contains(&temperature) && rng.gen::<f32>() < FOG_CHANCE {
+            return Weather::Fog;
+        }
+
+        match temperature {
+            0 => Weather::VeryCold,
+            1 => Weather::Cold,
+            2 => Weather::Cool,
+            3 => Weather::Warm,
+            4 => Weather::Hot,
+            _ => Weather::VeryHot,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn seasons_bound_the_weather() {
+        let mut rng = ChaCha8Rng::seed_from_u64(7);
+        for _ in 0..500 {
+            assert!(!matches!(Weather::roll(1, &mut rng), Weather::VeryHot | Weather::Hot | Weather::Rain));
+            assert!(!matches!(Weather::roll(7, &mut rng), Weather::VeryCold | Weather::Cold | Weather::Snow));
+        }
+        assert!((0..500).any(|_| Weather::roll(4, &mut rng) == Weather::Fog));
+        assert!((0..500).all(|_| Weather::roll(1, &mut rng) != Weather::Fog));
+    }
+}
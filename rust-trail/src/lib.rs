@@ -0,0 +1,5 @@
+// Game rules and utilities shared by the game and its command-line tools.
+// Nothing in here depends on macroquad, so it runs without a window.
+
+pub mod game_logic;
+pub mod utils;
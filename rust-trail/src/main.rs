@@ -1,24 +1,82 @@
 use macroquad::prelude::*;
 
+mod debug;
 mod game;
 mod engine;
 mod scenes;
-mod game_logic;
-mod utils;
+mod settings;
+
+use rust_trail::{game_logic, utils};
+
+/// Longest step the game is advanced by in one frame (in seconds)
+///
+/// Browsers stop drawing hidden tabs, and the first frame back would
+/// otherwise cover the whole time away.
+const MAX_FRAME_TIME: f32 = 0.1;
 
 #[macroquad::main("Oregon Trail Deluxe")]
 async fn main() {
+    // Print the original content parity checklist and exit
+    if std::env::args().any(|arg| arg == "--parity") {
+        println!("{}", debug::parity::report());
+        return;
+    }
+    
+    // Log to the console and game.log; the level is adjusted once settings load
+    if let Err(e) = engine::logging::init(log::LevelFilter::Info) {
+        eprintln!("{}", e);
+    }
+
+    // Load balancing tables, falling back to the built-in copy (always, on the web)
+    if !cfg!(target_arch = "wasm32") {
+        if let Err(e) = game_logic::tables::init(game_logic::tables::TABLES_FILE) {
+            log::warn!("{}", e);
+        }
+    }
+
+    // Seed the journey from `--seed <value>` so runs can be replayed
+    let args: Vec<String> = std::env::args().collect();
+    let rng = match args.iter().position(|arg| arg == "--seed").and_then(|i| args.get(i + 1)) {
+        Some(seed) => utils::rng::GameRng::from_user_seed(seed),
+        None => utils::rng::GameRng::from_entropy(),
+    };
+    log::info!("Journey seed: {}", rng.seed());
+
     // Initialize game
-    let mut game = game::Game::new();
+    let mut game = game::Game::new(rng);
+
+    // Stop on the first missing or broken asset instead of drawing placeholders
+    if args.iter().any(|arg| arg == "--strict-assets") {
+        game.set_asset_error_policy(|_| engine::asset_loader::ErrorAction::Fatal);
+    }
     
-    // Load game assets
+    // Look for assets in the player's replacements, then mods, then what
+    // ships with the game (loose, then bundled), then the original archive.
+    // `--assets <dir>` names a different folder for the replacements
+    if !cfg!(target_arch = "wasm32") {
+        use engine::asset_loader::{AssetRoot, BUNDLED_DIR, BUNDLE_FILE, MODS_DIR, ORIGINAL_ARCHIVE, OVERRIDE_DIR};
+        let overrides = args
+            .iter()
+            .position(|arg| arg == "--assets")
+            .and_then(|i| args.get(i + 1))
+            .map_or(OVERRIDE_DIR, |dir| dir.as_str());
+        game.set_asset_roots(vec![
+            AssetRoot::Dir(overrides.to_string()),
+            AssetRoot::Dir(MODS_DIR.to_string()),
+            AssetRoot::Dir(BUNDLED_DIR.to_string()),
+            AssetRoot::Pak(BUNDLE_FILE.to_string()),
+            AssetRoot::Gxl(ORIGINAL_ARCHIVE.to_string()),
+        ]);
+    }
+
+    // Load settings and queue the asset preload
     game.load_assets().await;
     
     // Main game loop
     loop {
         // Update game state based on delta time
-        let delta_time = get_frame_time();
-        game.update(delta_time);
+        let delta_time = get_frame_time().min(MAX_FRAME_TIME);
+        game.update(delta_time).await;
         
         // Render current frame
         clear_background(BLACK);
@@ -0,0 +1,121 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::spinner::Spinner;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::inventory::ItemType;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Height of one supply row
+const ROW_HEIGHT: f32 = 24.0;
+
+/// Screen for leaving supplies behind when the oxen can no longer pull the load
+pub struct AbandonScene {
+    /// Supplies that can be left behind
+    items: Vec<ItemType>,
+    /// Supply rows
+    list: ListBox,
+    /// How many of the selected supply to leave
+    amount: Spinner,
+    /// Row the amount was last set for
+    shown: Option<usize>,
+}
+
+impl AbandonScene {
+    /// Create the screen for the wagon's current load
+    pub fn new() -> Self {
+        let rows = ItemType::ALL.iter().filter(|item| item.is_carried()).count();
+        let list = ListBox::new(Rect::new(80.0, 140.0, screen_width() - 160.0, ROW_HEIGHT * rows as f32), Vec::new());
+        let mut amount = Spinner::new(Rect::new(80.0, screen_height() - 140.0, 160.0, 28.0), 0, 0, 0);
+        amount.set_focused(true);
+        Self { items: Vec::new(), list, amount, shown: None }
+    }
+
+    /// Refresh the rows from the wagon
+    fn refresh(&mut self, ctx: &GameContext) {
+        let inventory = &ctx.journey.inventory;
+        self.items = ItemType::ALL
+            .into_iter()
+            .filter(|item| item.is_carried() && inventory.get_quantity(*item) > 0)
+            .collect();
+        self.list.set_items(
+            self.items
+                .iter()
+                .map(|item| format!("{:<20} {}", item.name(), inventory.get_quantity(*item)))
+                .collect(),
+        );
+        if self.list.selected().is_none_or(|i| i >= self.items.len()) {
+            self.list.select(0);
+        }
+        self.shown = None;
+    }
+
+    /// Offer just enough of the selected supply to bring the wagon under capacity
+    fn suggest(&mut self, ctx: &GameContext) {
+        let selected = self.list.selected();
+        if selected == self.shown {
+            return;
+        }
+        self.shown = selected;
+        if let Some(item) = selected.and_then(|i| self.items.get(i)) {
+            let inventory = &ctx.journey.inventory;
+            self.amount.set_range(0, inventory.get_quantity(*item) as i32);
+            self.amount.set_value(inventory.units_to_abandon(*item).max(1) as i32);
+        }
+    }
+}
+
+impl Scene for AbandonScene {
+    fn state(&self) -> GameState {
+        GameState::Travel
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.refresh(ctx);
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if !ctx.journey.inventory.is_overloaded() {
+            return SceneTransition::Pop;
+        }
+
+        self.amount.update();
+        // A click on a new row only selects it, so the amount shown is always the one left
+        let activated = self.list.update().filter(|&i| Some(i) == self.shown);
+        if let Some(item) = activated.and_then(|i| self.items.get(i).copied()) {
+            ctx.journey.abandon(item, self.amount.value() as u32);
+            self.refresh(ctx);
+        }
+        self.suggest(ctx);
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text("Your wagon is too heavy", panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+        let inventory = &ctx.journey.inventory;
+        let warning = format!(
+            "Your oxen can pull {:.0} pounds. You must leave {:.0} pounds behind.",
+            inventory.capacity(),
+            inventory.overload().ceil()
+        );
+        draw_text(&warning, panel.x + 20.0, panel.y + 70.0, style::FONT_SIZE, style::theme().focus);
+
+        self.list.draw();
+        draw_text("Amount to leave:", 80.0, screen_height() - 150.0, style::FONT_SIZE, style::theme().text);
+        self.amount.draw();
+
+        draw_text(
+            "UP/DOWN to choose, LEFT/RIGHT to change the amount, ENTER to leave it behind",
+            panel.x + 20.0,
+            panel.y + panel.h - 24.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+}
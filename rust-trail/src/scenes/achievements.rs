@@ -0,0 +1,67 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::achievements::{Achievement, Achievements, ACHIEVEMENTS_FILE};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Height of one achievement's entry
+const ROW_HEIGHT: f32 = 56.0;
+
+/// Gallery of every achievement, showing which have been unlocked
+pub struct AchievementsScene {
+    /// Achievements unlocked so far, read when the scene is entered
+    achievements: Achievements,
+}
+
+impl AchievementsScene {
+    /// Create the gallery
+    pub fn new() -> Self {
+        Self { achievements: Achievements::default() }
+    }
+}
+
+impl Scene for AchievementsScene {
+    fn state(&self) -> GameState {
+        GameState::MainMenu
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.achievements = Achievements::load(ctx.storage.as_ref(), &ctx.profile_file(ACHIEVEMENTS_FILE)).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            Achievements::default()
+        });
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Cancel) || input::is_action_pressed(Action::Confirm) {
+            return SceneTransition::Pop;
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text("Achievements", panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+        let count = format!("{} of {} unlocked", self.achievements.len(), Achievement::ALL.len());
+        draw_text(&count, panel.x + 20.0, panel.y + 66.0, style::FONT_SIZE, style::theme().text_disabled);
+
+        for (i, &achievement) in Achievement::ALL.iter().enumerate() {
+            let y = panel.y + 110.0 + i as f32 * ROW_HEIGHT;
+            let (mark, color) = match self.achievements.is_unlocked(achievement) {
+                true => ("*", style::theme().focus),
+                false => ("-", style::theme().text_disabled),
+            };
+            draw_text(mark, panel.x + 20.0, y, 24.0, color);
+            draw_text(achievement.name(), panel.x + 44.0, y, 24.0, color);
+            draw_text(achievement.description(), panel.x + 44.0, y + 22.0, style::FONT_SIZE, style::theme().text_disabled);
+        }
+
+        draw_text("Esc to go back", panel.x + 20.0, panel.y + panel.h - 20.0, style::FONT_SIZE, style::theme().text_disabled);
+    }
+}
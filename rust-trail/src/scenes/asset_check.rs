@@ -0,0 +1,114 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::integrity::IntegrityReport;
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::button::{Button, ButtonGroup};
+use crate::engine::ui::focus::FocusAxis;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::scenes::opening::OpeningScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Width of each button
+const BUTTON_WIDTH: f32 = 140.0;
+/// Height of each button
+const BUTTON_HEIGHT: f32 = 32.0;
+
+/// Buttons under the list of files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckButton {
+    Continue,
+    Quit,
+}
+
+/// Shown before the opening when installed assets are missing or differ
+/// from the ones the game shipped with, listing each file
+pub struct AssetCheckScene {
+    /// Number of missing and damaged files
+    summary: String,
+    /// The files, each marked missing or damaged
+    list: ListBox,
+    /// Continue anyway, or quit to fix the files
+    buttons: ButtonGroup<CheckButton>,
+}
+
+impl AssetCheckScene {
+    /// Create the screen for a report with problems in it
+    pub fn new(report: &IntegrityReport) -> Self {
+        let files = report
+            .missing
+            .iter()
+            .map(|file| format!("Missing: {}", file))
+            .chain(report.corrupt.iter().map(|file| format!("Damaged: {}", file)))
+            .collect();
+        let summary = format!("{} missing, {} damaged", report.missing.len(), report.corrupt.len());
+
+        let buttons = [(CheckButton::Continue, "Continue"), (CheckButton::Quit, "Quit")]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (action, label))| Button::text(action, Self::button_rect(i), label))
+            .collect();
+
+        Self {
+            summary,
+            list: ListBox::new(Self::list_rect(), files),
+            buttons: ButtonGroup::new(buttons, FocusAxis::Horizontal),
+        }
+    }
+
+    /// Panel the screen is drawn on
+    fn panel() -> Rect {
+        Rect::new(40.0, 30.0, screen_width() - 80.0, screen_height() - 60.0)
+    }
+
+    /// Area of the list of files
+    fn list_rect() -> Rect {
+        let panel = Self::panel();
+        Rect::new(panel.x + 20.0, panel.y + 110.0, panel.w - 40.0, panel.h - 180.0)
+    }
+
+    /// Where a button sits along the bottom of the panel
+    fn button_rect(index: usize) -> Rect {
+        let panel = Self::panel();
+        let x = panel.x + panel.w - 20.0 - (2 - index) as f32 * (BUTTON_WIDTH + 10.0) + 10.0;
+        Rect::new(x, panel.y + panel.h - 20.0 - BUTTON_HEIGHT, BUTTON_WIDTH, BUTTON_HEIGHT)
+    }
+}
+
+impl Scene for AssetCheckScene {
+    fn state(&self) -> GameState {
+        GameState::TitleScreen
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        self.list.update();
+        match self.buttons.update(dt) {
+            Some(CheckButton::Continue) => SceneTransition::Replace(Box::new(OpeningScene::new())),
+            Some(CheckButton::Quit) => SceneTransition::Quit,
+            None if input::is_action_pressed(Action::Cancel) => SceneTransition::Quit,
+            None => SceneTransition::None,
+        }
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let theme = style::theme();
+        let panel = Self::panel();
+        draw_panel(panel);
+        draw_text("Some game files aren't right", panel.x + 20.0, panel.y + 36.0, 30.0, theme.text);
+        draw_text(&self.summary, panel.x + 20.0, panel.y + 64.0, style::FONT_SIZE, theme.danger);
+        draw_text(
+            "Convert them again from the original game, or continue with placeholders.",
+            panel.x + 20.0,
+            panel.y + 90.0,
+            style::FONT_SIZE,
+            theme.text_disabled,
+        );
+
+        self.list.draw();
+        self.buttons.draw();
+    }
+}
@@ -0,0 +1,76 @@
+use crate::engine::ui::dialog::Dialog;
+use crate::game::{GameContext, GameState};
+use crate::game_logic::blacksmith::{self, Quote};
+use crate::game_logic::events::part_name;
+use crate::scenes::message::MessageScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Title shown on blacksmith dialogs
+const TITLE: &str = "Visit the blacksmith";
+
+/// The fort blacksmith's offer to mend broken wagon parts
+pub struct BlacksmithScene {
+    /// Price and wait for the job, if anything needs mending
+    quote: Option<Quote>,
+    /// Offer or refusal dialog, built once the scene is entered
+    dialog: Option<Dialog>,
+}
+
+impl BlacksmithScene {
+    /// Create the blacksmith scene
+    pub fn new() -> Self {
+        Self { quote: None, dialog: None }
+    }
+}
+
+impl Scene for BlacksmithScene {
+    fn state(&self) -> GameState {
+        GameState::Travel
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        let journey = &ctx.journey;
+        self.quote = blacksmith::quote(journey);
+        self.dialog = Some(match self.quote {
+            Some(quote) => {
+                let parts: Vec<&str> = journey.broken_parts.iter().copied().map(part_name).collect();
+                let message = format!(
+                    "The blacksmith can mend your broken {}\nfor {}. The work will take {} day{}.\nWill you have it done?",
+                    parts.join(" and "),
+                    quote.cost,
+                    quote.days,
+                    if quote.days == 1 { "" } else { "s" }
+                );
+                Dialog::new(TITLE, &message, &["Yes", "No"]).with_cancel(1)
+            }
+            None => Dialog::message(TITLE, "Your wagon is in good repair."),
+        });
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        let Some(choice) = self.dialog.as_mut().and_then(|d| d.update(dt)) else {
+            return SceneTransition::None;
+        };
+
+        match (choice, self.quote) {
+            (0, Some(_)) => {
+                let message = match blacksmith::repair(&mut ctx.journey, &mut ctx.rng) {
+                    Ok(_) => "Your wagon is as good as new.".to_string(),
+                    Err(e) => e,
+                };
+                SceneTransition::Replace(Box::new(MessageScene::new(GameState::Travel, TITLE, &message)))
+            }
+            _ => SceneTransition::Pop,
+        }
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        if let Some(dialog) = &self.dialog {
+            dialog.draw();
+        }
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,185 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::dialog::Dialog;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::text_input::{TextInput, TextInputEvent};
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::classroom::{Classroom, CLASSROOM_FILE, MAX_NAME_LEN};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Heading of the screen and its dialogs
+const TITLE: &str = "Classroom Mode";
+/// Height of one list row
+const ROW_HEIGHT: f32 = 24.0;
+/// Rows of the list shown at once
+const VISIBLE_ROWS: usize = 12;
+
+/// Entries on the classroom screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    Add,
+    Student(usize),
+    Epitaphs,
+    Back,
+}
+
+/// "Classroom mode": the teacher's class list, whose students take turns on
+/// the trail, and whether students may write on tombstones
+pub struct ClassroomScene {
+    /// The class list, read when the scene is entered
+    class: Classroom,
+    /// Students and settings
+    list: ListBox,
+    /// Name being typed for a new student, while one is being added
+    adding: Option<TextInput>,
+    /// Dialog showing, with the student to take off the list if it's answered Yes
+    dialog: Option<(Dialog, Option<usize>)>,
+}
+
+impl ClassroomScene {
+    /// Create the classroom screen
+    pub fn new() -> Self {
+        Self { class: Classroom::default(), list: ListBox::new(Self::list_rect(), Vec::new()), adding: None, dialog: None }
+    }
+
+    /// Area of the list
+    fn list_rect() -> Rect {
+        Rect::new(screen_width() / 2.0 - 200.0, 110.0, 400.0, VISIBLE_ROWS as f32 * ROW_HEIGHT)
+    }
+
+    /// Entries in the order shown
+    fn entries(&self) -> Vec<Entry> {
+        let students = (0..self.class.students().len()).map(Entry::Student);
+        std::iter::once(Entry::Add).chain(students).chain([Entry::Epitaphs, Entry::Back]).collect()
+    }
+
+    /// Show the entries as they now stand
+    fn refresh(&mut self) {
+        let up = self.class.student_up();
+        let labels = self
+            .entries()
+            .into_iter()
+            .map(|entry| match entry {
+                Entry::Add => "Add a student...".to_string(),
+                Entry::Student(i) => {
+                    let name = &self.class.students()[i];
+                    if up == Some(name.as_str()) { format!("  {} (next to play)", name) } else { format!("  {}", name) }
+                }
+                Entry::Epitaphs => {
+                    format!("Tombstone messages: {}", if self.class.epitaphs_allowed() { "Written by players" } else { "Plain" })
+                }
+                Entry::Back => "Return to management options".to_string(),
+            })
+            .collect();
+        self.list.set_labels(labels);
+    }
+
+    /// Write the class list, logging any failure
+    fn save(&self, ctx: &mut GameContext) {
+        if let Err(e) = self.class.save(ctx.storage.as_mut(), CLASSROOM_FILE) {
+            log::error!("{}", e);
+        }
+    }
+
+    /// Handle typing a new student's name
+    fn update_adding(&mut self, ctx: &mut GameContext, dt: f32) {
+        let Some(field) = &mut self.adding else {
+            return;
+        };
+        if field.update(dt) == Some(TextInputEvent::Submitted) {
+            let name = field.text().to_string();
+            self.adding = None;
+            if self.class.add(&name) {
+                self.save(ctx);
+                self.refresh();
+            } else if !name.trim().is_empty() {
+                self.dialog = Some((Dialog::message(TITLE, "That student is already on the list,\nor the list is full."), None));
+            }
+        } else if input::is_action_pressed(Action::Cancel) {
+            self.adding = None;
+        }
+    }
+}
+
+impl Scene for ClassroomScene {
+    fn state(&self) -> GameState {
+        GameState::MainMenu
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.class = Classroom::load(ctx.storage.as_ref(), CLASSROOM_FILE).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            Classroom::default()
+        });
+        self.refresh();
+        self.list.select(0);
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        if let Some((dialog, remove)) = &mut self.dialog {
+            let Some(choice) = dialog.update(dt) else {
+                return SceneTransition::None;
+            };
+            if let Some(index) = remove.filter(|_| choice == 0) {
+                self.class.remove(index);
+                self.save(ctx);
+                self.refresh();
+            }
+            self.dialog = None;
+            return SceneTransition::None;
+        }
+        if self.adding.is_some() {
+            self.update_adding(ctx, dt);
+            return SceneTransition::None;
+        }
+
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Pop;
+        }
+        let Some(index) = self.list.update() else {
+            return SceneTransition::None;
+        };
+        match self.entries()[index] {
+            Entry::Add => {
+                let list = Self::list_rect();
+                let mut field = TextInput::new(Rect::new(list.x, list.y + list.h + 12.0, list.w, 30.0), MAX_NAME_LEN);
+                field.set_focused(true);
+                self.adding = Some(field);
+            }
+            Entry::Student(i) => {
+                let question = format!("Take {} off the class list?", self.class.students()[i]);
+                self.dialog = Some((Dialog::confirm(TITLE, &question), Some(i)));
+            }
+            Entry::Epitaphs => {
+                self.class.set_epitaphs_allowed(!self.class.epitaphs_allowed());
+                self.save(ctx);
+                self.refresh();
+            }
+            Entry::Back => return SceneTransition::Pop,
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text(TITLE, panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+        let about = "Students on the list take turns, and each journey is written to a report.";
+        draw_text(about, panel.x + 20.0, panel.y + 60.0, 16.0, style::theme().text_disabled);
+        self.list.draw();
+        if let Some(field) = &self.adding {
+            field.draw();
+        }
+        let hint = if self.adding.is_some() { "Type a name, ENTER to add, ESC to cancel" } else { "ENTER to choose, ESC to go back" };
+        draw_text(hint, panel.x + 20.0, panel.y + panel.h - 20.0, style::FONT_SIZE, style::theme().text_disabled);
+
+        if let Some((dialog, _)) = &self.dialog {
+            dialog.draw();
+        }
+    }
+}
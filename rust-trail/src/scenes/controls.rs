@@ -0,0 +1,127 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::style;
+use crate::game::{GameContext, GameState};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Screen for viewing and rebinding the controls for each action
+pub struct ControlsScene {
+    /// One row per action
+    list: ListBox,
+    /// Action waiting for a new binding
+    capturing: Option<Action>,
+    /// Status line shown under the list
+    message: String,
+}
+
+impl ControlsScene {
+    /// Create the controls scene
+    pub fn new() -> Self {
+        let mut scene = Self {
+            list: ListBox::new(Self::list_rect(), Vec::new()),
+            capturing: None,
+            message: String::new(),
+        };
+        scene.refresh();
+        scene.list.select(0);
+        scene
+    }
+
+    /// Area of the action list for the current screen size
+    fn list_rect() -> Rect {
+        Rect::new(40.0, 90.0, screen_width() - 80.0, screen_height() - 190.0)
+    }
+
+    /// Rebuild the list labels from the current bindings
+    fn refresh(&mut self) {
+        let labels = Action::ALL
+            .iter()
+            .map(|action| {
+                let bound: Vec<String> = input::bindings(*action).iter().map(|b| b.label()).collect();
+                let bound = if bound.is_empty() { "(unbound)".to_string() } else { bound.join(", ") };
+                format!("{:<12} {}", action.name(), bound)
+            })
+            .collect();
+        self.list.set_labels(labels);
+    }
+
+    /// Save bindings and report the result
+    fn save(&mut self, ctx: &mut GameContext) {
+        if let Err(e) = input::save_bindings(ctx.storage.as_mut()) {
+            log::error!("{}", e);
+        }
+    }
+}
+
+impl Scene for ControlsScene {
+    fn state(&self) -> GameState {
+        GameState::Options
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        self.list.set_rect(Self::list_rect());
+
+        // Waiting for the player to press the new input
+        if let Some(action) = self.capturing {
+            if is_key_pressed(KeyCode::Escape) {
+                self.capturing = None;
+                self.message = "Cancelled.".to_string();
+            } else if let Some(binding) = input::last_pressed_binding() {
+                input::rebind(action, binding);
+                self.capturing = None;
+                self.message = format!("{} is now bound to {}.", action.name(), binding.label());
+                self.refresh();
+            }
+            return SceneTransition::None;
+        }
+
+        // Escape always leaves, so unbinding Cancel can't trap the player here
+        if input::is_action_pressed(Action::Cancel) || is_key_pressed(KeyCode::Escape) {
+            self.save(ctx);
+            return SceneTransition::Pop;
+        }
+
+        if let Some(index) = self.list.update() {
+            let action = Action::ALL[index];
+            self.capturing = Some(action);
+            self.message = format!("Press a key or button for {} (Esc to cancel)", action.name());
+        }
+
+        if let Some(index) = self.list.selected() {
+            if is_key_pressed(KeyCode::Backspace) || is_key_pressed(KeyCode::Delete) {
+                let action = Action::ALL[index];
+                input::clear_bindings(action);
+                self.message = format!("Cleared bindings for {}.", action.name());
+                self.refresh();
+            }
+        }
+
+        if is_key_pressed(KeyCode::R) {
+            input::reset_bindings();
+            self.message = "Controls reset to defaults.".to_string();
+            self.refresh();
+        }
+
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+        draw_text("Controls", 40.0, 50.0, 30.0, style::theme().text);
+
+        self.list.draw();
+
+        let bottom = screen_height();
+        draw_text(&self.message, 40.0, bottom - 75.0, style::FONT_SIZE, style::theme().warning);
+        draw_text(
+            "Enter: rebind   Backspace: clear   R: reset defaults   Esc: back",
+            40.0,
+            bottom - 40.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+}
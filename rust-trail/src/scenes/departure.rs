@@ -0,0 +1,83 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::grass::DEPARTURE_MONTHS;
+use crate::game_logic::time::MONTH_NAMES;
+use crate::scenes::main_menu::MainMenuScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::travel::TravelScene;
+
+/// Height of one list row
+const ROW_HEIGHT: f32 = 24.0;
+
+/// Advice given with the choice of month
+const ADVICE: [&str; 3] = [
+    "Leave too early and there will be no grass for the oxen",
+    "and the nights will be cold. Leave too late and you may",
+    "be caught by snow in the mountains.",
+];
+
+/// Choosing the month to leave Independence
+pub struct DepartureScene {
+    /// Month choices
+    list: ListBox,
+}
+
+impl DepartureScene {
+    /// Create the departure month screen
+    pub fn new() -> Self {
+        let labels = DEPARTURE_MONTHS
+            .iter()
+            .map(|&month| MONTH_NAMES[month as usize - 1].to_string())
+            .collect();
+        let rect = Rect::new(60.0, 110.0, screen_width() - 120.0, ROW_HEIGHT * DEPARTURE_MONTHS.len() as f32);
+        let mut list = ListBox::new(rect, labels);
+        list.select(1);
+        Self { list }
+    }
+}
+
+impl Scene for DepartureScene {
+    fn state(&self) -> GameState {
+        GameState::Setup
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Replace(Box::new(MainMenuScene::new()));
+        }
+
+        if let Some(index) = self.list.update() {
+            ctx.journey.set_departure(DEPARTURE_MONTHS[index]);
+            return SceneTransition::Replace(Box::new(TravelScene::new()));
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(20.0, 20.0, screen_width() - 40.0, screen_height() - 40.0);
+        draw_panel(panel);
+        draw_text("It is 1848. Your jumping off place for Oregon", 40.0, 56.0, style::FONT_SIZE + 4.0, style::theme().text);
+        draw_text("is Independence, Missouri. When will you leave?", 40.0, 84.0, style::FONT_SIZE, style::theme().text);
+        self.list.draw();
+
+        let y = 110.0 + ROW_HEIGHT * DEPARTURE_MONTHS.len() as f32 + 40.0;
+        for (i, line) in ADVICE.iter().enumerate() {
+            draw_text(line, 60.0, y + i as f32 * 26.0, style::FONT_SIZE, style::theme().focus);
+        }
+
+        draw_text(
+            "ENTER to choose, ESC to go back",
+            40.0,
+            screen_height() - 36.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::utils::wrap_text;
+
+/// Characters per line of diary text
+const LINE_CHARS: usize = 52;
+/// Height of one line of text
+const LINE_HEIGHT: f32 = 22.0;
+
+/// The party's diary, newest entries at the bottom
+pub struct DiaryScene {
+    /// Lines to show, with whether each is a date heading
+    lines: Vec<(String, bool)>,
+    /// First visible line
+    scroll: usize,
+}
+
+impl DiaryScene {
+    /// Create the diary scene; entries are read when it's entered
+    pub fn new() -> Self {
+        Self { lines: Vec::new(), scroll: 0 }
+    }
+
+    /// Area for the diary text
+    fn text_rect() -> Rect {
+        Rect::new(40.0, 80.0, screen_width() - 80.0, screen_height() - 140.0)
+    }
+
+    /// Number of lines that fit on screen
+    fn visible_lines() -> usize {
+        (Self::text_rect().h / LINE_HEIGHT) as usize
+    }
+
+    /// Furthest the diary can scroll
+    fn max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(Self::visible_lines())
+    }
+}
+
+impl Scene for DiaryScene {
+    fn state(&self) -> GameState {
+        GameState::Travel
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        let mut last_date = String::new();
+        for entry in ctx.journey.diary.entries() {
+            let date = entry.date.to_string();
+            if date != last_date {
+                self.lines.push((date.clone(), true));
+                last_date = date;
+            }
+            for line in wrap_text(&entry.text, LINE_CHARS) {
+                self.lines.push((format!("  {}", line), false));
+            }
+        }
+        self.scroll = self.max_scroll();
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Cancel) || input::is_action_pressed(Action::Confirm) {
+            return SceneTransition::Pop;
+        }
+
+        let page = Self::visible_lines();
+        if input::is_action_pressed(Action::MenuDown) {
+            self.scroll += 1;
+        }
+        if input::is_action_pressed(Action::MenuUp) {
+            self.scroll = self.scroll.saturating_sub(1);
+        }
+        if is_key_pressed(KeyCode::PageDown) {
+            self.scroll += page;
+        }
+        if is_key_pressed(KeyCode::PageUp) {
+            self.scroll = self.scroll.saturating_sub(page);
+        }
+        self.scroll = self.scroll.min(self.max_scroll());
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(20.0, 20.0, screen_width() - 40.0, screen_height() - 40.0);
+        draw_panel(panel);
+        draw_text("Diary", 40.0, 56.0, 30.0, style::theme().text);
+
+        let area = Self::text_rect();
+        for (i, (line, heading)) in self.lines.iter().skip(self.scroll).take(Self::visible_lines()).enumerate() {
+            let color = if *heading { style::theme().focus } else { style::theme().text };
+            draw_text(line, area.x, area.y + i as f32 * LINE_HEIGHT + LINE_HEIGHT * 0.75, style::FONT_SIZE, color);
+        }
+
+        draw_text(
+            "UP/DOWN to scroll, ENTER to close",
+            40.0,
+            screen_height() - 36.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::ui::text_input::{TextInput, TextInputEvent};
+use crate::engine::ui::{draw_panel, draw_text_centered, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::graves::{Graveyard, GRAVES_FILE};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::score::ScoreScene;
+
+/// Longest epitaph
+const MAX_EPITAPH: usize = 40;
+
+/// Writing the words on the leader's tombstone once the whole party has died,
+/// for later parties to read as they pass
+pub struct EpitaphScene {
+    /// Who the tombstone is for
+    name: String,
+    /// How the journey ended, passed on to the scoring screen
+    message: String,
+    /// The words being written
+    input: TextInput,
+}
+
+impl EpitaphScene {
+    /// Create the tombstone for the leader, going on to the score with the message
+    pub fn new(name: &str, message: &str) -> Self {
+        let rect = Rect::new(screen_width() / 2.0 - 180.0, screen_height() / 2.0 + 10.0, 360.0, 30.0);
+        let mut input = TextInput::new(rect, MAX_EPITAPH);
+        input.set_focused(true);
+        Self { name: name.to_string(), message: message.to_string(), input }
+    }
+
+    /// Carve the words written onto the grave; with none the usual words stay
+    fn carve(&self, ctx: &mut GameContext) {
+        let words = self.input.text().trim();
+        if words.is_empty() {
+            return;
+        }
+        let mut graveyard = Graveyard::load(ctx.storage.as_ref(), GRAVES_FILE).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            Graveyard::default()
+        });
+        if !graveyard.set_epitaph(&self.name, words) {
+            return;
+        }
+        if let Err(e) = graveyard.save(ctx.storage.as_mut(), GRAVES_FILE) {
+            log::error!("{}", e);
+        }
+    }
+}
+
+impl Scene for EpitaphScene {
+    fn state(&self) -> GameState {
+        GameState::GameOver
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        let submitted = self.input.update(dt) == Some(TextInputEvent::Submitted);
+        if submitted {
+            self.carve(ctx);
+        }
+        if submitted || input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Replace(Box::new(ScoreScene::new(&self.message)));
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let stone = Rect::new(screen_width() / 2.0 - 200.0, screen_height() / 2.0 - 120.0, 400.0, 200.0);
+        draw_panel(stone);
+        let heading = Rect::new(stone.x, stone.y + 20.0, stone.w, 30.0);
+        draw_text_centered(&format!("Here lies {}", self.name), heading, 28.0, style::theme().text);
+        let prompt = Rect::new(stone.x, stone.y + 70.0, stone.w, 30.0);
+        draw_text_centered("What words would you like written on the tombstone?", prompt, style::FONT_SIZE, style::theme().text);
+        self.input.draw();
+
+        let hint = Rect::new(0.0, stone.y + stone.h + 20.0, screen_width(), 24.0);
+        draw_text_centered("ENTER to carve the words, ESC to keep the usual words", hint, 16.0, style::theme().text_disabled);
+    }
+}
@@ -0,0 +1,298 @@
+use macroquad::prelude::*;
+use crate::engine::display::{mouse_position, screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, mouse_in_rect, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::guidebook::{Guidebook, Segment, GUIDEBOOK_FILE};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::title_screen::TitleScreen;
+
+/// Height of one line of text
+const LINE_HEIGHT: f32 = 22.0;
+/// The open page of the guidebook
+struct Page {
+    /// Section being read
+    section: usize,
+    /// Wrapped lines; an empty line separates paragraphs
+    lines: Vec<Vec<Segment>>,
+    /// First visible line
+    scroll: usize,
+    /// Position (line, segment) of every link on the page
+    links: Vec<(usize, usize)>,
+    /// Link selected with the keyboard
+    selected: Option<usize>,
+}
+
+/// "Learn About the Trail": a browsable guidebook with a table of contents and links
+pub struct GuideScene {
+    /// Parsed guidebook
+    book: Guidebook,
+    /// Table of contents
+    contents: ListBox,
+    /// Page being read, or None while browsing the contents
+    page: Option<Page>,
+    /// Sections visited before the current one, for going back
+    history: Vec<usize>,
+}
+
+impl GuideScene {
+    /// Create the guidebook scene; the text is read when it's entered
+    pub fn new() -> Self {
+        Self {
+            book: Guidebook::default(),
+            contents: ListBox::new(Self::text_rect(), Vec::new()),
+            page: None,
+            history: Vec::new(),
+        }
+    }
+
+    /// Area for the contents and page text
+    fn text_rect() -> Rect {
+        Rect::new(40.0, 80.0, screen_width() - 80.0, screen_height() - 140.0)
+    }
+
+    /// Number of text lines that fit on screen
+    fn visible_lines() -> usize {
+        (Self::text_rect().h / LINE_HEIGHT) as usize
+    }
+
+    /// Open a section, remembering the current one for going back
+    fn open(&mut self, section: usize) {
+        if let Some(page) = &self.page {
+            self.history.push(page.section);
+        }
+        self.show(section);
+    }
+
+    /// Lay out a section's text and show it
+    fn show(&mut self, section: usize) {
+        let width = Self::text_rect().w;
+        let measure = |text: &str| measure_text(text, None, style::FONT_SIZE as u16, 1.0).width;
+        let mut lines = Vec::new();
+        for paragraph in &self.book.sections()[section].paragraphs {
+            if !lines.is_empty() {
+                lines.push(Vec::new());
+            }
+            lines.extend(self.book.wrap(paragraph, width, measure));
+        }
+
+        // Entries in a chapter are listed after its introduction
+        let level = self.book.sections()[section].level;
+        if level == 1 {
+            let entries: Vec<String> = self.book.sections()[section + 1..]
+                .iter()
+                .take_while(|s| s.level > 1)
+                .map(|s| format!("[[{}]]", s.id))
+                .collect();
+            for entry in entries {
+                lines.push(Vec::new());
+                lines.extend(self.book.wrap(&entry, width, measure));
+            }
+        }
+
+        let links = lines
+            .iter()
+            .enumerate()
+            .flat_map(|(l, line): (usize, &Vec<Segment>)| {
+                line.iter().enumerate().filter(|(_, s)| s.link.is_some()).map(move |(s, _)| (l, s))
+            })
+            .collect();
+
+        self.contents.select(section);
+        self.page = Some(Page { section, lines, scroll: 0, links, selected: None });
+    }
+
+    /// Go back to the previous section, or to the contents
+    fn back(&mut self) {
+        match self.history.pop() {
+            Some(section) => self.show(section),
+            None => self.page = None,
+        }
+    }
+
+    /// Screen rectangle of each visible link, with its index in the page's link list
+    fn link_rects(page: &Page) -> Vec<(Rect, usize)> {
+        let area = Self::text_rect();
+        let mut rects = Vec::new();
+        for (i, &(line, segment)) in page.links.iter().enumerate() {
+            if line < page.scroll || line >= page.scroll + Self::visible_lines() {
+                continue;
+            }
+            let before: String = page.lines[line][..segment].iter().map(|s| s.text.as_str()).collect();
+            let text = &page.lines[line][segment].text;
+            let x = area.x + measure_text(&before, None, style::FONT_SIZE as u16, 1.0).width;
+            let width = measure_text(text, None, style::FONT_SIZE as u16, 1.0).width;
+            let y = area.y + (line - page.scroll) as f32 * LINE_HEIGHT;
+            rects.push((Rect::new(x, y, width, LINE_HEIGHT), i));
+        }
+        rects
+    }
+
+    /// Handle input while reading a page, returning a section to open
+    fn update_page(page: &mut Page) -> Option<String> {
+        let visible = Self::visible_lines();
+        let max_scroll = page.lines.len().saturating_sub(visible);
+
+        if input::is_action_pressed(Action::MenuDown) {
+            page.scroll = (page.scroll + 1).min(max_scroll);
+        }
+        if input::is_action_pressed(Action::MenuUp) {
+            page.scroll = page.scroll.saturating_sub(1);
+        }
+        if is_key_pressed(KeyCode::PageDown) {
+            page.scroll = (page.scroll + visible).min(max_scroll);
+        }
+        if is_key_pressed(KeyCode::PageUp) {
+            page.scroll = page.scroll.saturating_sub(visible);
+        }
+        let (_, wheel) = mouse_wheel();
+        if wheel < 0.0 {
+            page.scroll = (page.scroll + 1).min(max_scroll);
+        } else if wheel > 0.0 {
+            page.scroll = page.scroll.saturating_sub(1);
+        }
+
+        // Tab and Left/Right move between links, scrolling them into view
+        if !page.links.is_empty() {
+            let count = page.links.len();
+            let step = if is_key_pressed(KeyCode::Tab) || input::is_action_pressed(Action::MenuRight) {
+                Some(1)
+            } else if input::is_action_pressed(Action::MenuLeft) {
+                Some(count - 1)
+            } else {
+                None
+            };
+            if let Some(step) = step {
+                let next = page.selected.map_or(if step == 1 { 0 } else { count - 1 }, |i| (i + step) % count);
+                page.selected = Some(next);
+                let line = page.links[next].0;
+                if line < page.scroll {
+                    page.scroll = line;
+                } else if line >= page.scroll + visible {
+                    page.scroll = (line + 1 - visible).min(max_scroll);
+                }
+            }
+        }
+
+        let link_at = |i: usize| {
+            let (line, segment) = page.links[i];
+            page.lines[line][segment].link.clone()
+        };
+
+        if input::is_action_pressed(Action::Confirm) {
+            if let Some(i) = page.selected {
+                return link_at(i);
+            }
+        }
+        if is_mouse_button_pressed(MouseButton::Left) {
+            let (mx, my) = mouse_position();
+            if let Some((_, i)) = Self::link_rects(page).into_iter().find(|(r, _)| r.contains(vec2(mx, my))) {
+                return link_at(i);
+            }
+        }
+        None
+    }
+}
+
+impl Scene for GuideScene {
+    fn state(&self) -> GameState {
+        GameState::Introduction
+    }
+
+    fn asset_group(&self) -> Option<&'static str> {
+        Some("guide")
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.book = Guidebook::parse(ctx.assets.get_text(GUIDEBOOK_FILE).unwrap_or_default());
+        let labels = self
+            .book
+            .sections()
+            .iter()
+            .map(|s| if s.level > 1 { format!("    {}", s.title) } else { s.title.clone() })
+            .collect();
+        self.contents.set_items(labels);
+        self.contents.select(0);
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if self.page.is_none() {
+            if input::is_action_pressed(Action::Cancel) {
+                return SceneTransition::Replace(Box::new(TitleScreen::new()));
+            }
+            if let Some(section) = self.contents.update() {
+                self.open(section);
+            }
+            return SceneTransition::None;
+        }
+
+        if input::is_action_pressed(Action::Cancel) || is_key_pressed(KeyCode::Backspace) {
+            self.back();
+            return SceneTransition::None;
+        }
+
+        let target = self.page.as_mut().and_then(Self::update_page);
+        if let Some(section) = target.and_then(|id| self.book.find(&id)) {
+            self.open(section);
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(20.0, 20.0, screen_width() - 40.0, screen_height() - 40.0);
+        draw_panel(panel);
+
+        let Some(page) = &self.page else {
+            draw_text("Learn About the Trail", 40.0, 56.0, 30.0, style::theme().text);
+            if self.book.sections().is_empty() {
+                draw_text("The guidebook could not be found.", 40.0, 100.0, style::FONT_SIZE, style::theme().text);
+            }
+            self.contents.draw();
+            draw_text(
+                "ENTER to read, ESC to close",
+                40.0,
+                screen_height() - 36.0,
+                style::FONT_SIZE,
+                style::theme().text_disabled,
+            );
+            return;
+        };
+
+        draw_text(&self.book.sections()[page.section].title, 40.0, 56.0, 30.0, style::theme().text);
+
+        let area = Self::text_rect();
+        for (i, line) in page.lines.iter().enumerate().skip(page.scroll).take(Self::visible_lines()) {
+            let mut x = area.x;
+            let y = area.y + (i - page.scroll) as f32 * LINE_HEIGHT + LINE_HEIGHT * 0.75;
+            for segment in line {
+                let color = if segment.link.is_some() { style::theme().info } else { style::theme().text };
+                draw_text(&segment.text, x, y, style::FONT_SIZE, color);
+                x += measure_text(&segment.text, None, style::FONT_SIZE as u16, 1.0).width;
+            }
+        }
+
+        // Underline links, highlighting the selected or hovered one
+        for (rect, i) in Self::link_rects(page) {
+            let active = page.selected == Some(i) || mouse_in_rect(rect);
+            let color = if active { style::theme().focus } else { style::theme().info };
+            draw_line(rect.x, rect.y + LINE_HEIGHT - 2.0, rect.x + rect.w, rect.y + LINE_HEIGHT - 2.0, 1.0, color);
+        }
+
+        if page.lines.len() > Self::visible_lines() {
+            let shown = format!("{}-{} of {}", page.scroll + 1, (page.scroll + Self::visible_lines()).min(page.lines.len()), page.lines.len());
+            draw_text(&shown, screen_width() - 160.0, 56.0, style::FONT_SIZE, style::theme().text_disabled);
+        }
+
+        draw_text(
+            "UP/DOWN scroll, TAB select link, ENTER follow, ESC back",
+            40.0,
+            screen_height() - 36.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::player::PartyMember;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Height of one party row
+const ROW_HEIGHT: f32 = 24.0;
+
+/// One line describing a member's health
+fn describe(member: &PartyMember) -> String {
+    let diseases: Vec<&str> = member.diseases.iter().map(|d| d.name()).collect();
+    if diseases.is_empty() {
+        format!("{:<12} {}", member.name, member.health.name())
+    } else {
+        format!("{:<12} {} ({})", member.name, member.health.name(), diseases.join(", "))
+    }
+}
+
+/// "Party health" screen, where medicine can be given to the sick
+pub struct HealthScene {
+    /// Party members
+    list: ListBox,
+    /// What happened the last time medicine was given
+    feedback: Option<String>,
+}
+
+impl HealthScene {
+    /// Create the party health screen
+    pub fn new() -> Self {
+        let mut list = ListBox::new(Rect::new(80.0, 120.0, screen_width() - 160.0, ROW_HEIGHT * 5.0), Vec::new());
+        list.select(0);
+        Self { list, feedback: None }
+    }
+
+    /// Refresh the rows from the party
+    fn refresh(&mut self, ctx: &GameContext) {
+        let party = &ctx.journey.player.party;
+        self.list.set_items(party.iter().map(describe).collect());
+        for (i, member) in party.iter().enumerate() {
+            self.list.set_enabled(i, member.is_alive());
+        }
+    }
+
+    /// Give medicine to a member and describe what happened
+    fn treat(ctx: &mut GameContext, member: usize) -> String {
+        let name = ctx.journey.player.party[member].name.clone();
+        if !ctx.journey.player.party[member].is_sick() {
+            return format!("{} doesn't need any medicine.", name);
+        }
+        match ctx.journey.use_medicine(member, &mut ctx.rng) {
+            None => "You have no medicine left.".to_string(),
+            Some(cured) if cured.is_empty() => format!("{} takes the medicine and feels a little better.", name),
+            Some(cured) => {
+                let names: Vec<&str> = cured.iter().map(|d| d.name()).collect();
+                format!("The medicine cures {} of {}.", name, names.join(" and "))
+            }
+        }
+    }
+}
+
+impl Scene for HealthScene {
+    fn state(&self) -> GameState {
+        GameState::Travel
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.refresh(ctx);
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Pop;
+        }
+        if let Some(member) = self.list.update() {
+            self.feedback = Some(Self::treat(ctx, member));
+            self.refresh(ctx);
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text("Party Health", panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+        let kits = ctx.journey.inventory.get_quantity(ItemType::MedicalSupply);
+        draw_text(&format!("Medicine: {} kits", kits), panel.x + 20.0, panel.y + 66.0, style::FONT_SIZE, style::theme().text);
+
+        self.list.draw();
+
+        if let Some(feedback) = &self.feedback {
+            draw_text(feedback, panel.x + 20.0, panel.y + panel.h - 60.0, style::FONT_SIZE, style::theme().focus);
+        }
+        draw_text(
+            "ENTER to give medicine, ESC to close",
+            panel.x + 20.0,
+            panel.y + panel.h - 24.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+}
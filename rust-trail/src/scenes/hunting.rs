@@ -0,0 +1,384 @@
+use macroquad::prelude::*;
+use ::rand::Rng;
+use crate::engine::cursor::Cursor;
+use crate::engine::display::{mouse_position, screen_height, screen_width};
+use crate::engine::entities::EntityPool;
+use crate::engine::input::{self, Action};
+use crate::engine::parallax::{self, Layer, Silhouette};
+use crate::engine::renderer::{draw_text, draw_texture_ex};
+use crate::engine::ui::{draw_panel, draw_text_button, style, ClickRegion};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::hunting::{self, Animal, Marksmanship, Terrain, BULLETS_PER_SHOT, BULLET_SPEED, RELOAD_SECONDS};
+use crate::game_logic::inventory::ItemType;
+use crate::scenes::message::MessageScene;
+use crate::scenes::pause::PauseScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::utils::rng::RngStream;
+
+/// Title shown on hunting messages
+const TITLE: &str = "Hunting";
+
+/// Real seconds a hunt lasts
+const HUNT_SECONDS: f32 = 60.0;
+
+/// Height of the status bar at the bottom of the screen
+const STATUS_HEIGHT: f32 = 50.0;
+
+/// Pixels per second the crosshair moves at full aim
+const CROSSHAIR_SPEED: f32 = 320.0;
+/// Height of the hunter standing at the bottom of the field
+const HUNTER_HEIGHT: f32 = 40.0;
+/// Width of the on-screen stop button shown on touchscreens
+const STOP_WIDTH: f32 = 80.0;
+
+/// Build a backdrop layer
+const fn layer(silhouette: Silhouette, height: f32, period: f32, color: Color) -> Layer {
+    Layer { silhouette, height, period, rate: 1.0, color }
+}
+
+/// River bottom: cottonwoods along the water's edge
+const RIVER_BOTTOM: [Layer; 2] = [
+    layer(Silhouette::Trees, 60.0, 22.0, Color::new(0.15, 0.35, 0.2, 1.0)),
+    layer(Silhouette::Flat, 0.0, 1.0, Color::new(0.4, 0.55, 0.3, 1.0)),
+];
+
+/// Desert: buttes over sagebrush flats
+const DESERT: [Layer; 2] = [
+    layer(Silhouette::Mesas, 60.0, 300.0, Color::new(0.7, 0.4, 0.3, 1.0)),
+    layer(Silhouette::Hills, 10.0, 120.0, Color::new(0.8, 0.7, 0.45, 1.0)),
+];
+
+/// Prairie: grass rolling out to the horizon
+const PRAIRIE: [Layer; 2] = [
+    layer(Silhouette::Hills, 20.0, 260.0, Color::new(0.35, 0.55, 0.25, 1.0)),
+    layer(Silhouette::Hills, 6.0, 90.0, Color::new(0.55, 0.6, 0.3, 1.0)),
+];
+
+/// Mountains: peaks behind a high meadow
+const MOUNTAIN: [Layer; 2] = [
+    layer(Silhouette::Peaks, 120.0, 200.0, Color::new(0.45, 0.45, 0.55, 1.0)),
+    layer(Silhouette::Hills, 12.0, 100.0, Color::new(0.4, 0.5, 0.3, 1.0)),
+];
+
+/// Picture drawn behind a terrain, with sky color and layers to use when it isn't loaded
+fn backdrop(terrain: Terrain) -> (Option<&'static str>, Color, &'static [Layer]) {
+    match terrain {
+        Terrain::Prairie => (Some("PLAIN.png"), Color::new(0.55, 0.75, 0.9, 1.0), &PRAIRIE),
+        Terrain::Mountain => (Some("MOUNTAIN.png"), Color::new(0.6, 0.75, 0.9, 1.0), &MOUNTAIN),
+        Terrain::RiverBottom => (None, Color::new(0.55, 0.7, 0.85, 1.0), &RIVER_BOTTOM),
+        Terrain::Desert => (None, Color::new(0.85, 0.75, 0.6, 1.0), &DESERT),
+    }
+}
+
+/// Color an animal is drawn in
+fn animal_color(animal: Animal) -> Color {
+    match animal {
+        Animal::Buffalo => Color::new(0.3, 0.2, 0.12, 1.0),
+        Animal::Elk => Color::new(0.55, 0.35, 0.2, 1.0),
+        Animal::Bear => Color::new(0.15, 0.1, 0.08, 1.0),
+        Animal::Deer => Color::new(0.65, 0.45, 0.25, 1.0),
+        Animal::Rabbit => Color::new(0.6, 0.55, 0.45, 1.0),
+        Animal::Squirrel => Color::new(0.5, 0.35, 0.25, 1.0),
+        Animal::Duck => Color::new(0.3, 0.4, 0.3, 1.0),
+    }
+}
+
+/// An animal in view
+struct Quarry {
+    animal: Animal,
+    /// Top-left corner
+    position: Vec2,
+    /// Pixels per second, negative when running left
+    velocity: f32,
+    /// Whether the animal has been shot
+    dead: bool,
+}
+
+impl Quarry {
+    /// Area the animal covers
+    fn rect(&self) -> Rect {
+        let (w, h) = self.animal.size();
+        Rect::new(self.position.x, self.position.y, w, h)
+    }
+}
+
+/// A bullet in flight
+struct Bullet {
+    /// Where the bullet is now
+    position: Vec2,
+    /// Point it was aimed at, where it strikes
+    target: Vec2,
+}
+
+/// The hunting field, where the party spends a day shooting game for food
+pub struct HuntingScene {
+    /// Country being hunted
+    terrain: Terrain,
+    /// Animals in view
+    animals: EntityPool<Quarry>,
+    /// Bullets in flight
+    bullets: EntityPool<Bullet>,
+    /// Where a shot will land
+    crosshair: Vec2,
+    /// Mouse position last frame, so the crosshair only follows the mouse when it moves
+    mouse: Vec2,
+    /// Seconds left before the hunt ends
+    time_left: f32,
+    /// Seconds until the hunter can fire again
+    reload: f32,
+    /// Shots fired and hits made on this hunt
+    marksmanship: Marksmanship,
+    /// Animals shot so far, in order
+    kills: Vec<Animal>,
+    /// Whether the party set out with no bullets
+    no_bullets: bool,
+    /// Button ending the hunt, shown on touchscreens
+    stop: ClickRegion,
+}
+
+impl HuntingScene {
+    /// Create the hunting scene
+    pub fn new() -> Self {
+        Self {
+            terrain: Terrain::Prairie,
+            animals: EntityPool::with_capacity(16),
+            bullets: EntityPool::with_capacity(4),
+            crosshair: Vec2::ZERO,
+            mouse: Vec2::ZERO,
+            time_left: HUNT_SECONDS,
+            reload: 0.0,
+            marksmanship: Marksmanship::default(),
+            kills: Vec::new(),
+            no_bullets: false,
+            stop: ClickRegion::new(Self::stop_rect()),
+        }
+    }
+
+    /// Where the stop button sits, at the right of the status bar
+    fn stop_rect() -> Rect {
+        let bar_top = screen_height() - STATUS_HEIGHT;
+        Rect::new(screen_width() - STOP_WIDTH - 10.0, bar_top + 8.0, STOP_WIDTH, STATUS_HEIGHT - 16.0)
+    }
+
+    /// Area animals run across
+    fn field() -> Rect {
+        Rect::new(0.0, 0.0, screen_width(), screen_height() - STATUS_HEIGHT)
+    }
+
+    /// Point at the hunter's shoulder that bullets are fired from
+    fn muzzle() -> Vec2 {
+        let field = Self::field();
+        vec2(field.w / 2.0, field.h - HUNTER_HEIGHT)
+    }
+
+    /// Height of the horizon, above which only birds fly
+    fn horizon() -> f32 {
+        Self::field().h * 0.45
+    }
+
+    /// Bring an animal into view at one side of the field
+    fn spawn(&mut self, animal: Animal, ctx: &mut GameContext) {
+        let field = Self::field();
+        let (w, h) = animal.size();
+        let rng = ctx.rng.stream(RngStream::Hunting);
+        let from_left = rng.gen_bool(0.5);
+        let y = if animal.flies() {
+            rng.gen_range(20.0..Self::horizon() - h)
+        } else {
+            rng.gen_range(Self::horizon()..field.h - h)
+        };
+        let x = if from_left { -w } else { field.w };
+        let velocity = if from_left { animal.speed() } else { -animal.speed() };
+        self.animals.spawn(Quarry { animal, position: vec2(x, y), velocity, dead: false });
+    }
+
+    /// Fire at the crosshair if the rifle is loaded
+    fn shoot(&mut self, ctx: &mut GameContext) {
+        if self.reload > 0.0 || !ctx.journey.inventory.use_ammunition(BULLETS_PER_SHOT) {
+            return;
+        }
+        // A downhearted hunter is slower to reload
+        self.reload = RELOAD_SECONDS / ctx.journey.morale.hunting_factor();
+        self.bullets.spawn(Bullet { position: Self::muzzle(), target: self.crosshair });
+    }
+
+    /// Move bullets toward their targets, killing the first living animal where each one strikes
+    fn move_bullets(&mut self, dt: f32) {
+        let step = BULLET_SPEED * dt;
+        let mut strikes = Vec::new();
+        self.bullets.retain(|bullet| {
+            let to_target = bullet.target - bullet.position;
+            if to_target.length() <= step {
+                strikes.push(bullet.target);
+                false
+            } else {
+                bullet.position += to_target.normalize() * step;
+                true
+            }
+        });
+
+        for point in strikes {
+            let hit = match self.animals.values_mut().find(|q| !q.dead && q.rect().contains(point)) {
+                Some(quarry) => {
+                    quarry.dead = true;
+                    self.kills.push(quarry.animal);
+                    true
+                }
+                None => false,
+            };
+            self.marksmanship.record_shot(hit);
+        }
+    }
+
+    /// End the hunt, passing the day and loading the meat
+    fn finish(&mut self, ctx: &mut GameContext) -> SceneTransition {
+        // Bullets still in the air when the hunt ends are misses
+        for _ in 0..self.bullets.len() {
+            self.marksmanship.record_shot(false);
+        }
+        ctx.journey.marksmanship.add(self.marksmanship);
+
+        ctx.journey.camp_day(&mut ctx.rng);
+        let haul = hunting::bring_back(&mut ctx.journey, self.terrain, &self.kills);
+        SceneTransition::Replace(Box::new(MessageScene::new(GameState::Hunting, TITLE, &haul.describe())))
+    }
+}
+
+impl Scene for HuntingScene {
+    fn state(&self) -> GameState {
+        GameState::Hunting
+    }
+
+    fn asset_group(&self) -> Option<&'static str> {
+        Some("hunting")
+    }
+
+    fn cursor(&self) -> Cursor {
+        Cursor::Crosshair
+    }
+
+    // The crosshair can be moved with keys or a stick as well as the mouse
+    fn cursor_position(&self) -> Option<Vec2> {
+        Some(self.crosshair)
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.terrain = Terrain::for_journey(&ctx.journey);
+        self.no_bullets = ctx.journey.inventory.get_quantity(ItemType::Ammunition) < BULLETS_PER_SHOT;
+        let field = Self::field();
+        self.crosshair = vec2(field.w / 2.0, field.h / 2.0);
+        self.mouse = mouse_position().into();
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        if self.no_bullets {
+            return SceneTransition::Replace(Box::new(MessageScene::new(
+                GameState::Hunting,
+                TITLE,
+                "You have no bullets to hunt with.",
+            )));
+        }
+
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Push(Box::new(PauseScene::new()));
+        }
+
+        self.time_left -= dt;
+        let out_of_bullets =
+            ctx.journey.inventory.get_quantity(ItemType::Ammunition) < BULLETS_PER_SHOT && self.bullets.is_empty();
+        let stopped = input::is_touch() && self.stop.update();
+        if input::is_action_pressed(Action::Confirm) || stopped || self.time_left <= 0.0 || out_of_bullets {
+            return self.finish(ctx);
+        }
+
+        // Aim with the keys or either stick, or follow the mouse when it moves or a
+        // finger dragged across the field
+        let field = Self::field();
+        let mouse: Vec2 = mouse_position().into();
+        if mouse != self.mouse {
+            self.crosshair = mouse;
+            self.mouse = mouse;
+        }
+        if let Some(touch) = input::touch_held().filter(|&t| field.contains(t)) {
+            self.crosshair = touch;
+        }
+        self.crosshair += input::aim_vector() * CROSSHAIR_SPEED * dt;
+        self.crosshair = self.crosshair.clamp(vec2(0.0, 0.0), vec2(field.w, field.h));
+
+        if let Some(animal) = self.terrain.ground().roll_spawn(ctx.rng.stream(RngStream::Hunting), dt) {
+            self.spawn(animal, ctx);
+        }
+
+        for quarry in self.animals.values_mut().filter(|q| !q.dead) {
+            quarry.position.x += quarry.velocity * dt;
+        }
+        self.animals.retain(|q| q.position.x > -q.rect().w && q.position.x < field.w);
+
+        self.move_bullets(dt);
+        self.reload = (self.reload - dt).max(0.0);
+        // On a touchscreen the shot goes off when the finger is lifted
+        let fired = input::touch_lifted().is_some_and(|t| field.contains(t));
+        if input::is_action_pressed(Action::Shoot) || fired {
+            self.shoot(ctx);
+        }
+
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        let field = Self::field();
+        let (picture, sky, layers) = backdrop(self.terrain);
+        match picture.and_then(|name| ctx.assets.get_texture(name)) {
+            Some(texture) => draw_texture_ex(
+                texture,
+                field.x,
+                field.y,
+                WHITE,
+                DrawTextureParams {
+                    dest_size: Some(vec2(field.w, field.h)),
+                    ..Default::default()
+                },
+            ),
+            None => {
+                clear_background(sky);
+                parallax::draw(layers, 0.0, Self::horizon(), field.h);
+            }
+        }
+
+        for quarry in self.animals.values() {
+            let rect = quarry.rect();
+            let color = if quarry.dead { Color::new(0.5, 0.1, 0.1, 1.0) } else { animal_color(quarry.animal) };
+            draw_rectangle(rect.x, rect.y, rect.w, rect.h, color);
+        }
+
+        for bullet in self.bullets.values() {
+            draw_circle(bullet.position.x, bullet.position.y, 2.0, BLACK);
+        }
+
+        // The hunter, raising the rifle once it's loaded
+        let muzzle = Self::muzzle();
+        draw_rectangle(muzzle.x - 6.0, muzzle.y, 12.0, HUNTER_HEIGHT, Color::new(0.3, 0.25, 0.4, 1.0));
+        draw_circle(muzzle.x, muzzle.y - 6.0, 6.0, Color::new(0.85, 0.65, 0.5, 1.0));
+        if self.reload <= 0.0 {
+            let barrel = muzzle + (self.crosshair - muzzle).normalize_or_zero() * 24.0;
+            draw_line(muzzle.x, muzzle.y, barrel.x, barrel.y, 3.0, Color::new(0.25, 0.15, 0.1, 1.0));
+        }
+
+        let bar = Rect::new(0.0, field.h, screen_width(), STATUS_HEIGHT);
+        draw_panel(bar);
+        let status = format!(
+            "Hunting the {}    Bullets: {}    Hits: {}/{}    Meat: {} lbs    Time: {:.0}s{}",
+            self.terrain.name(),
+            ctx.journey.inventory.get_quantity(ItemType::Ammunition),
+            self.marksmanship.hits,
+            self.marksmanship.shots,
+            self.kills.iter().map(Animal::meat).sum::<u32>(),
+            self.time_left.max(0.0),
+            if input::is_touch() { "" } else { "    Enter to stop" }
+        );
+        draw_text(&status, bar.x + 20.0, bar.y + 32.0, style::FONT_SIZE, style::theme().text);
+        if input::is_touch() {
+            draw_text_button(&self.stop, "Stop", true);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::cell::Cell;
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::{draw_text, draw_texture_ex};
+use crate::engine::ui::text_box::TextBox;
+use crate::engine::ui::{draw_panel, draw_text_button, draw_text_centered, scaled, style, ClickRegion};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::slideshow::{Slideshow, INTRODUCTION_FILE};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::title_screen::TitleScreen;
+
+/// Height of one line of page text
+const LINE_HEIGHT: f32 = 21.0;
+/// Height of the picture area
+const IMAGE_HEIGHT: f32 = 170.0;
+/// Largest a picture is scaled up
+const MAX_SCALE: f32 = 2.0;
+
+thread_local! {
+    /// Page the introduction was left on, so coming back picks up there
+    static PAGE: Cell<usize> = const { Cell::new(0) };
+}
+
+/// "Introduction": a few pages on the history of the trail, each with a
+/// picture, paged through with the arrow keys or the buttons
+pub struct IntroductionScene {
+    /// The pages
+    show: Slideshow,
+    /// Picture of each page, where it loaded
+    images: Vec<Option<Texture2D>>,
+    /// Page showing
+    page: usize,
+    /// Text of the page showing
+    text: TextBox,
+    /// How quickly page text is revealed, from the settings
+    text_speed: Option<f32>,
+    /// Button back a page
+    previous: ClickRegion,
+    /// Button on a page, or out after the last
+    next: ClickRegion,
+}
+
+impl IntroductionScene {
+    /// Create the introduction; the pages are read when it's entered
+    pub fn new() -> Self {
+        let y = screen_height() - 62.0;
+        Self {
+            show: Slideshow::default(),
+            images: Vec::new(),
+            page: 0,
+            text: TextBox::new(Rect::default()),
+            text_speed: None,
+            previous: ClickRegion::new(Rect::new(40.0, y, 120.0, 30.0)),
+            next: ClickRegion::new(Rect::new(screen_width() - 160.0, y, 120.0, 30.0)),
+        }
+    }
+
+    /// Whether the last page is showing
+    fn on_last_page(&self) -> bool {
+        self.page + 1 >= self.show.slides.len()
+    }
+
+    /// Turn to a page, remembering it for next time
+    fn turn_to(&mut self, page: usize) {
+        self.page = page;
+        PAGE.with(|p| p.set(page));
+        self.show_text();
+    }
+
+    /// Lay out the text of the page showing, under its picture
+    fn show_text(&mut self) {
+        let top = 70.0 + IMAGE_HEIGHT + 10.0;
+        let area = Rect::new(40.0, top, screen_width() - 80.0, screen_height() - 70.0 - top);
+        self.text = TextBox::new(area).with_font(scaled(style::FONT_SIZE), scaled(LINE_HEIGHT)).with_speed(self.text_speed);
+        if let Some(slide) = self.show.slides.get(self.page) {
+            self.text.set_text(&slide.text);
+        }
+    }
+}
+
+impl Scene for IntroductionScene {
+    fn state(&self) -> GameState {
+        GameState::Introduction
+    }
+
+    fn asset_group(&self) -> Option<&'static str> {
+        Some("introduction")
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.show = match ctx.assets.get_text(INTRODUCTION_FILE).map(Slideshow::parse) {
+            Some(Ok(show)) => show,
+            Some(Err(e)) => {
+                log::error!("Failed to read {}: {}", INTRODUCTION_FILE, e);
+                Slideshow::default()
+            }
+            None => Slideshow::default(),
+        };
+        self.images = self.show.slides.iter().map(|slide| ctx.assets.get_texture(&slide.image)).collect();
+        let remembered = PAGE.with(Cell::get);
+        self.page = if remembered < self.show.slides.len() { remembered } else { 0 };
+        self.text_speed = ctx.settings.text_speed.chars_per_second();
+        self.show_text();
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        self.text.update(dt);
+        let back = self.previous.update() && self.page > 0;
+        let forward = self.next.update();
+
+        if input::is_action_pressed(Action::Cancel) || self.show.slides.is_empty() {
+            return SceneTransition::Replace(Box::new(TitleScreen::new()));
+        }
+        if back || input::is_action_pressed(Action::MenuLeft) || is_key_pressed(KeyCode::PageUp) {
+            self.turn_to(self.page.saturating_sub(1));
+        } else if forward || input::is_action_pressed(Action::MenuRight) || input::is_action_pressed(Action::Confirm) {
+            if self.text.is_revealing() {
+                self.text.finish();
+            } else if self.on_last_page() {
+                // Finished, so the next visit starts from the beginning
+                self.turn_to(0);
+                return SceneTransition::Replace(Box::new(TitleScreen::new()));
+            } else {
+                self.turn_to(self.page + 1);
+            }
+        } else if is_key_pressed(KeyCode::PageDown) && !self.on_last_page() {
+            self.turn_to(self.page + 1);
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(20.0, 20.0, screen_width() - 40.0, screen_height() - 40.0);
+        draw_panel(panel);
+
+        let Some(slide) = self.show.slides.get(self.page) else {
+            draw_text("The introduction could not be found.", 40.0, 100.0, style::FONT_SIZE, style::theme().text);
+            return;
+        };
+
+        draw_text(&slide.title, 40.0, 56.0, 30.0, style::theme().text);
+        let count = format!("Page {} of {}", self.page + 1, self.show.slides.len());
+        draw_text(&count, screen_width() - 150.0, 56.0, style::FONT_SIZE, style::theme().text_disabled);
+
+        let area = Rect::new(40.0, 70.0, screen_width() - 80.0, IMAGE_HEIGHT);
+        if let Some(texture) = self.images[self.page] {
+            let scale = (area.w / texture.width()).min(area.h / texture.height()).min(MAX_SCALE);
+            let size = vec2(texture.width(), texture.height()) * scale;
+            let x = area.x + (area.w - size.x) / 2.0;
+            let y = area.y + (area.h - size.y) / 2.0;
+            draw_texture_ex(texture, x, y, WHITE, DrawTextureParams { dest_size: Some(size), ..Default::default() });
+        }
+
+        self.text.draw();
+
+        draw_text_button(&self.previous, "< Previous", self.page > 0);
+        draw_text_button(&self.next, if self.on_last_page() { "Done" } else { "Next >" }, true);
+        let hint = Rect::new(160.0, self.next.rect.y, screen_width() - 320.0, self.next.rect.h);
+        draw_text_centered("LEFT/RIGHT turn the page, ESC close", hint, 16.0, style::theme().text_disabled);
+    }
+}
@@ -0,0 +1,149 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::narration;
+use crate::engine::palette::{Palette, PaletteFade, PaletteShader};
+use crate::engine::renderer::{draw_text, draw_texture_ex};
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::facts::{FactBook, FACTS_FILE};
+use crate::game_logic::landmarks::Landmark;
+use crate::scenes::message::MessageScene;
+use crate::scenes::river_crossing::RiverCrossingScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::travel_menu::TravelMenuScene;
+
+/// Height of the caption panel under the picture
+const CAPTION_HEIGHT: f32 = 90.0;
+/// Seconds the picture takes to come up out of black
+const FADE_IN: f32 = 0.6;
+
+/// Picture of a landmark the party has just reached
+pub struct LandmarkScene {
+    /// Landmark being shown
+    landmark: &'static Landmark,
+    /// Date of arrival, written out
+    date: String,
+    /// Historical note about the landmark, until it has been shown
+    fact: Option<String>,
+    /// Hour of the day the party arrived, which lights the picture
+    hour: Option<f32>,
+    /// The picture's own palette, if it has one
+    palette: Option<Palette>,
+    /// Draws the picture through its palette, where the shader could be built
+    shader: Option<PaletteShader>,
+    /// The picture coming up out of black
+    fade: PaletteFade,
+}
+
+impl LandmarkScene {
+    /// Create the arrival scene for a landmark
+    pub fn new(landmark: &'static Landmark) -> Self {
+        Self {
+            landmark,
+            date: String::new(),
+            fact: None,
+            hour: None,
+            palette: None,
+            shader: None,
+            fade: PaletteFade::fade_in(FADE_IN),
+        }
+    }
+
+    /// Light the picture for the hour of the day the party arrived
+    pub fn at_hour(mut self, hour: f32) -> Self {
+        self.hour = Some(hour);
+        self
+    }
+
+    /// Bring the picture's palette up to date with the fade and the hour
+    fn light_picture(&mut self) {
+        let (Some(shader), Some(palette)) = (self.shader.as_mut(), &self.palette) else {
+            return;
+        };
+        let lit = self.hour.map_or_else(|| palette.clone(), |hour| palette.at_hour(hour));
+        shader.set_palettes(palette, &lit.faded(self.fade.brightness()));
+    }
+}
+
+/// Read the historical notes, or none if they can't be read
+pub fn load_facts(ctx: &GameContext) -> FactBook {
+    match ctx.assets.get_text(FACTS_FILE).map(FactBook::parse) {
+        Some(Ok(book)) => book,
+        Some(Err(e)) => {
+            log::error!("Failed to parse {}: {}", FACTS_FILE, e);
+            FactBook::default()
+        }
+        None => FactBook::default(),
+    }
+}
+
+impl Scene for LandmarkScene {
+    fn state(&self) -> GameState {
+        GameState::Landmark
+    }
+
+    fn asset_group(&self) -> Option<&'static str> {
+        // River pictures live with the crossing assets
+        Some(if self.landmark.is_river() { "river" } else { "landmarks" })
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        let player = &ctx.journey.player;
+        self.date = player.date.to_string();
+        narration::say(&format!("{}. {}. Press Enter to continue.", self.landmark.name, self.date));
+        if ctx.settings.facts {
+            self.fact = load_facts(ctx).about_landmark(&self.landmark.id).map(|fact| fact.text.clone());
+        }
+
+        // Pictures without a palette are shown as they are
+        self.palette = ctx.assets.get_palette(&self.landmark.background).cloned();
+        self.shader = self.palette.as_ref().and_then(|_| PaletteShader::new());
+        self.fade = PaletteFade::fade_in(FADE_IN);
+        self.light_picture();
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        if !self.fade.is_finished() {
+            self.fade.update(dt);
+            self.light_picture();
+        }
+
+        if input::is_action_pressed(Action::Confirm) || input::is_action_pressed(Action::Cancel) {
+            // Share a note about the place before moving on, if there is one
+            if let Some(fact) = self.fact.take() {
+                return SceneTransition::Push(Box::new(MessageScene::new(GameState::Landmark, "Did you know?", &fact)));
+            }
+            if self.landmark.is_river() {
+                return SceneTransition::Replace(Box::new(RiverCrossingScene::new()));
+            }
+            let heading = format!("You have reached {}", self.landmark.name);
+            return SceneTransition::Replace(Box::new(TravelMenuScene::new(&heading)));
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let picture = Rect::new(20.0, 20.0, screen_width() - 40.0, screen_height() - CAPTION_HEIGHT - 40.0);
+        let params = DrawTextureParams { dest_size: Some(vec2(picture.w, picture.h)), ..Default::default() };
+        match (ctx.assets.get_texture(&self.landmark.background), &self.shader) {
+            (Some(texture), Some(shader)) => shader.draw_texture(texture, picture.x, picture.y, WHITE, params),
+            (Some(texture), None) => draw_texture_ex(texture, picture.x, picture.y, WHITE, params),
+            (None, _) => draw_rectangle_lines(picture.x, picture.y, picture.w, picture.h, 2.0, style::theme().border),
+        }
+
+        let caption = Rect::new(20.0, screen_height() - CAPTION_HEIGHT - 10.0, screen_width() - 40.0, CAPTION_HEIGHT);
+        draw_panel(caption);
+        draw_text(&self.landmark.name, caption.x + 20.0, caption.y + 32.0, style::FONT_SIZE + 6.0, style::theme().text);
+        draw_text(&self.date, caption.x + 20.0, caption.y + 58.0, style::FONT_SIZE, style::theme().text);
+        draw_text(
+            "Press ENTER to continue",
+            caption.x + caption.w - 230.0,
+            caption.y + 58.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+}
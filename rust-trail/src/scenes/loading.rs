@@ -0,0 +1,44 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::style;
+
+/// Text file of trail tips, one per line, shown while scenes load
+pub const HINTS_FILE: &str = "hints.txt";
+
+/// The nth tip from the hints file, wrapping around
+pub fn hint(text: &str, n: usize) -> Option<&str> {
+    let hints: Vec<&str> = text.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+    if hints.is_empty() {
+        None
+    } else {
+        Some(hints[n % hints.len()])
+    }
+}
+
+/// Draw a loading message with a progress bar and an optional tip beneath it
+pub fn draw_loading_screen(progress: f32, hint: Option<&str>) {
+    clear_background(BLACK);
+
+    let text = "Loading resources...";
+    let font_size = 30.0;
+    let text_size = measure_text(text, None, font_size as u16, 1.0);
+    draw_text(
+        text,
+        screen_width() / 2.0 - text_size.width / 2.0,
+        screen_height() / 2.0 - 20.0,
+        font_size,
+        WHITE,
+    );
+
+    let bar = Rect::new(screen_width() / 2.0 - 150.0, screen_height() / 2.0, 300.0, 20.0);
+    draw_rectangle(bar.x, bar.y, bar.w * progress.clamp(0.0, 1.0), bar.h, style::theme().highlight);
+    draw_rectangle_lines(bar.x, bar.y, bar.w, bar.h, 2.0, style::theme().border);
+
+    if let Some(hint) = hint {
+        let tip = format!("Tip: {}", hint);
+        let size = measure_text(&tip, None, style::FONT_SIZE as u16, 1.0);
+        let x = (screen_width() / 2.0 - size.width / 2.0).max(20.0);
+        draw_text(&tip, x, bar.y + bar.h + 50.0, style::FONT_SIZE, style::theme().text_disabled);
+    }
+}
@@ -0,0 +1,201 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::classroom::{Classroom, CLASSROOM_FILE};
+use crate::game_logic::save::{self, SAVE_FILE};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::achievements::AchievementsScene;
+use crate::scenes::statistics::StatisticsScene;
+use crate::scenes::guide::GuideScene;
+use crate::scenes::message::MessageScene;
+use crate::scenes::occupation::OccupationScene;
+use crate::scenes::profiles::ProfileScene;
+use crate::scenes::management::ManagementScene;
+use crate::scenes::title_screen::TitleScreen;
+use crate::scenes::wagon_train::WagonTrainScene;
+use crate::scenes::top_ten::TopTenScene;
+use crate::scenes::travel::TravelScene;
+use crate::settings::Settings;
+use crate::utils::rng::GameRng;
+
+/// Entries on the main menu, in the order shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    Travel,
+    Learn,
+    TopTen,
+    Sound,
+    Management,
+    End,
+}
+
+impl Entry {
+    /// Every entry, in the order shown
+    const ALL: [Entry; 6] = [Entry::Travel, Entry::Learn, Entry::TopTen, Entry::Sound, Entry::Management, Entry::End];
+
+    /// Text shown for the entry, numbered as in the original game
+    fn label(self, number: usize, settings: &Settings) -> String {
+        let text = match self {
+            Entry::Travel => "Travel the trail",
+            Entry::Learn => "Learn about the trail",
+            Entry::TopTen => "See the Oregon Top Ten",
+            Entry::Sound if settings.sound => "Turn sound off",
+            Entry::Sound => "Turn sound on",
+            Entry::Management => "Choose Management Options",
+            Entry::End => "End",
+        };
+        format!("{}. {}", number, text)
+    }
+}
+
+/// Number keys choosing the entries directly
+const NUMBER_KEYS: [KeyCode; 6] = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4, KeyCode::Key5, KeyCode::Key6];
+
+/// Main menu shown after leaving the title screen: the original game's
+/// "You may:" list, chosen with the arrows and Enter, a number key or the mouse
+pub struct MainMenuScene {
+    /// The entries
+    list: ListBox,
+    /// Student whose turn it is, in classroom mode
+    student_up: Option<String>,
+}
+
+impl MainMenuScene {
+    /// Create the main menu scene
+    pub fn new() -> Self {
+        let mut list = ListBox::new(Self::list_rect(), vec![String::new(); Entry::ALL.len()]);
+        list.select(0);
+        Self { list, student_up: None }
+    }
+
+    /// Area of the entry list
+    fn list_rect() -> Rect {
+        Rect::new(screen_width() / 2.0 - 150.0, 150.0, 300.0, Entry::ALL.len() as f32 * 24.0)
+    }
+
+    /// Show the entries, with the sound entry saying what it will do
+    fn refresh(&mut self, settings: &Settings) {
+        self.list.set_labels(Entry::ALL.iter().enumerate().map(|(i, e)| e.label(i + 1, settings)).collect());
+    }
+
+    /// Do what an entry says
+    fn choose(&mut self, entry: Entry, ctx: &mut GameContext) -> SceneTransition {
+        match entry {
+            Entry::Travel => SceneTransition::Replace(Box::new(OccupationScene::new())),
+            Entry::Learn => SceneTransition::Replace(Box::new(GuideScene::new())),
+            Entry::TopTen => SceneTransition::Push(Box::new(TopTenScene::new())),
+            Entry::Sound => {
+                // The game applies the change as soon as the settings differ
+                ctx.settings.sound = !ctx.settings.sound;
+                ctx.save_settings();
+                self.refresh(&ctx.settings);
+                SceneTransition::None
+            }
+            Entry::Management => SceneTransition::Push(Box::new(ManagementScene::new())),
+            Entry::End => SceneTransition::Quit,
+        }
+    }
+
+    /// Pick up the saved journey where it was left off
+    fn load(ctx: &mut GameContext) -> SceneTransition {
+        match save::load(ctx.storage.as_ref(), &ctx.profile_file(SAVE_FILE)) {
+            Ok(Some(saved)) => {
+                ctx.journey = saved.journey;
+                ctx.wagon_train = None;
+                ctx.rng = GameRng::new(saved.seed);
+                SceneTransition::Replace(Box::new(TravelScene::new()))
+            }
+            Ok(None) => {
+                let message = MessageScene::new(GameState::MainMenu, "Load saved game", "There is no saved game to load.");
+                SceneTransition::Push(Box::new(message))
+            }
+            Err(e) => {
+                log::error!("{}", e);
+                let text = "Your saved game could not be read. It may be\ndamaged, or from a newer version of the game.";
+                SceneTransition::Push(Box::new(MessageScene::new(GameState::MainMenu, "Load saved game", text)))
+            }
+        }
+    }
+}
+
+impl Scene for MainMenuScene {
+    fn state(&self) -> GameState {
+        GameState::MainMenu
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.refresh(&ctx.settings);
+        let class = Classroom::load(ctx.storage.as_ref(), CLASSROOM_FILE).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            Classroom::default()
+        });
+        self.student_up = class.student_up().map(str::to_string);
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if let Some(index) = NUMBER_KEYS.iter().position(|&key| is_key_pressed(key)) {
+            self.list.select(index);
+            return self.choose(Entry::ALL[index], ctx);
+        }
+        if let Some(index) = self.list.update() {
+            return self.choose(Entry::ALL[index], ctx);
+        }
+
+        // Extras beyond the original menu
+        if is_key_pressed(KeyCode::L) {
+            return Self::load(ctx);
+        }
+        if is_key_pressed(KeyCode::A) {
+            return SceneTransition::Push(Box::new(AchievementsScene::new()));
+        }
+        if is_key_pressed(KeyCode::S) {
+            return SceneTransition::Push(Box::new(StatisticsScene::new()));
+        }
+        if is_key_pressed(KeyCode::P) {
+            return SceneTransition::Replace(Box::new(ProfileScene::new()));
+        }
+        if is_key_pressed(KeyCode::W) {
+            return SceneTransition::Replace(Box::new(WagonTrainScene::new()));
+        }
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Replace(Box::new(TitleScreen::new()));
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text("The Oregon Trail", panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+        let player = format!("Playing as {}", ctx.profile.as_deref().unwrap_or("a guest"));
+        let width = measure_text(&player, None, style::FONT_SIZE as u16, 1.0).width;
+        draw_text(&player, panel.x + panel.w - width - 20.0, panel.y + 36.0, style::FONT_SIZE, style::theme().text_disabled);
+        draw_text("You may:", Self::list_rect().x, 130.0, style::FONT_SIZE, style::theme().text);
+        self.list.draw();
+        if let Some(student) = &self.student_up {
+            let turn = format!("Classroom mode: it's {}'s turn", student);
+            draw_text(&turn, panel.x + 20.0, panel.y + 62.0, style::FONT_SIZE, style::theme().focus);
+        }
+
+        draw_text(
+            "L load game, A achievements, S statistics, P change player, W wagon train",
+            panel.x + 20.0,
+            panel.y + panel.h - 44.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+        draw_text(
+            "What is your choice? ESC returns to the title screen",
+            panel.x + 20.0,
+            panel.y + panel.h - 20.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+}
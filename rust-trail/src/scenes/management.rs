@@ -0,0 +1,156 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::dialog::Dialog;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::graves::{Graveyard, GRAVES_FILE};
+use crate::game_logic::top_ten::{TopTen, TOP_TEN_FILE};
+use crate::scenes::classroom::ClassroomScene;
+use crate::scenes::options::OptionsScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::top_ten::TopTenScene;
+
+/// Heading of the screen and its dialogs
+const TITLE: &str = "Management Options";
+
+/// Entries on the management options screen, in the order shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    SeeTopTen,
+    EraseTopTen,
+    EraseGraves,
+    Defaults,
+    Classroom,
+    Back,
+}
+
+impl Entry {
+    /// Every entry, in the order shown
+    const ALL: [Entry; 6] =
+        [Entry::SeeTopTen, Entry::EraseTopTen, Entry::EraseGraves, Entry::Defaults, Entry::Classroom, Entry::Back];
+
+    /// Text shown for the entry, numbered as in the original game
+    fn label(self, number: usize) -> String {
+        let text = match self {
+            Entry::SeeTopTen => "See the current Top Ten list",
+            Entry::EraseTopTen => "Erase the current Top Ten list",
+            Entry::EraseGraves => "Erase the tombstone messages",
+            Entry::Defaults => "Change game defaults",
+            Entry::Classroom => "Set up classroom mode",
+            Entry::Back => "Return to the main menu",
+        };
+        format!("{}. {}", number, text)
+    }
+}
+
+/// Records that can be erased
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Erase {
+    TopTen,
+    Graves,
+}
+
+impl Erase {
+    /// Question asked before erasing
+    fn question(self) -> &'static str {
+        match self {
+            Erase::TopTen => "Erase the current Top Ten list?\nThe original names and scores will be put back.",
+            Erase::Graves => "Erase the tombstone messages?\nLater parties will find no graves along the trail.",
+        }
+    }
+
+    /// Write the fresh records over the old ones, returning what to tell the player
+    fn erase(self, ctx: &mut GameContext) -> String {
+        let (saved, done) = match self {
+            Erase::TopTen => (TopTen::default().save(ctx.storage.as_mut(), TOP_TEN_FILE), "The Top Ten list has been erased."),
+            Erase::Graves => (Graveyard::default().save(ctx.storage.as_mut(), GRAVES_FILE), "The tombstone messages have been erased."),
+        };
+        match saved {
+            Ok(()) => done.to_string(),
+            Err(e) => {
+                log::error!("{}", e);
+                "The records could not be erased.".to_string()
+            }
+        }
+    }
+}
+
+/// "Choose Management Options": looking after the records the game keeps
+/// between journeys, and the settings new games start with
+pub struct ManagementScene {
+    /// The entries
+    list: ListBox,
+    /// Dialog showing, with what to erase if it's answered Yes
+    dialog: Option<(Dialog, Option<Erase>)>,
+}
+
+impl ManagementScene {
+    /// Create the management options screen
+    pub fn new() -> Self {
+        let labels = Entry::ALL.iter().enumerate().map(|(i, e)| e.label(i + 1)).collect();
+        let mut list = ListBox::new(Self::list_rect(), labels);
+        list.select(0);
+        Self { list, dialog: None }
+    }
+
+    /// Area of the entry list
+    fn list_rect() -> Rect {
+        Rect::new(screen_width() / 2.0 - 160.0, 150.0, 320.0, Entry::ALL.len() as f32 * 24.0)
+    }
+
+    /// Ask before erasing a record
+    fn ask(&mut self, erase: Erase) {
+        self.dialog = Some((Dialog::confirm(TITLE, erase.question()), Some(erase)));
+    }
+}
+
+impl Scene for ManagementScene {
+    fn state(&self) -> GameState {
+        GameState::MainMenu
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        if let Some((dialog, erase)) = &mut self.dialog {
+            let Some(choice) = dialog.update(dt) else {
+                return SceneTransition::None;
+            };
+            // Yes erases and says so; anything else just closes the dialog
+            self.dialog = erase.filter(|_| choice == 0).map(|erase| (Dialog::message(TITLE, &erase.erase(ctx)), None));
+            return SceneTransition::None;
+        }
+
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Pop;
+        }
+        let Some(index) = self.list.update() else {
+            return SceneTransition::None;
+        };
+        match Entry::ALL[index] {
+            Entry::SeeTopTen => return SceneTransition::Push(Box::new(TopTenScene::new())),
+            Entry::EraseTopTen => self.ask(Erase::TopTen),
+            Entry::EraseGraves => self.ask(Erase::Graves),
+            Entry::Defaults => return SceneTransition::Push(Box::new(OptionsScene::over("Return to management options"))),
+            Entry::Classroom => return SceneTransition::Push(Box::new(ClassroomScene::new())),
+            Entry::Back => return SceneTransition::Pop,
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text(TITLE, panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+        draw_text("You may:", Self::list_rect().x, 130.0, style::FONT_SIZE, style::theme().text);
+        self.list.draw();
+        draw_text("Esc to go back", panel.x + 20.0, panel.y + panel.h - 20.0, style::FONT_SIZE, style::theme().text_disabled);
+
+        if let Some((dialog, _)) = &self.dialog {
+            dialog.draw();
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::engine::ui::dialog::Dialog;
+use crate::game::{GameContext, GameState};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Overlay that shows a message box and returns to the scene beneath when closed
+pub struct MessageScene {
+    /// State reported while the message is up
+    state: GameState,
+    /// The message box
+    dialog: Dialog,
+}
+
+impl MessageScene {
+    /// Create a message overlay for a scene in the given state
+    pub fn new(state: GameState, title: &str, message: &str) -> Self {
+        Self {
+            state,
+            dialog: Dialog::message(title, message),
+        }
+    }
+}
+
+impl Scene for MessageScene {
+    fn state(&self) -> GameState {
+        self.state
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.dialog.set_text_speed(ctx.settings.text_speed.chars_per_second());
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        match self.dialog.update(dt) {
+            Some(_) => SceneTransition::Pop,
+            None => SceneTransition::None,
+        }
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        self.dialog.draw();
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
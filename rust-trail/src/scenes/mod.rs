@@ -1,20 +1,47 @@
 // Scenes module - handles different game screens and their transitions
 
 // Export scene modules
+pub mod scene_manager;
 pub mod title_screen;
-pub mod button;
+pub mod main_menu;
+pub mod options;
+pub mod abandon;
+pub mod achievements;
+pub mod asset_check;
+pub mod blacksmith;
+pub mod classroom;
+pub mod controls;
+pub mod departure;
+pub mod diary;
+pub mod epitaph;
+pub mod guide;
+pub mod health;
+pub mod hunting;
+pub mod introduction;
+pub mod landmarks;
+pub mod loading;
+pub mod management;
+pub mod message;
+pub mod occupation;
+pub mod opening;
+pub mod pace_rations;
+pub mod pause;
+pub mod profiles;
+pub mod rest;
+pub mod river_crossing;
+pub mod score;
+pub mod statistics;
+pub mod store;
+pub mod supplies;
+pub mod talk;
+pub mod top_ten;
+pub mod trading;
+pub mod train_score;
+pub mod travel;
+pub mod travel_menu;
+pub mod wagon_trade;
+pub mod wagon_train;
 
 // Submodules will be declared here as they're created
-// pub mod main_menu;
 // pub mod setup;
-// pub mod travel;
-// pub mod hunting;
-// pub mod river_crossing;
-// pub mod landmarks;
-// pub mod trading;
 // pub mod events;
-
-/// Initializes scene components
-pub fn initialize() {
-    println!("Scenes system initialized");
-}
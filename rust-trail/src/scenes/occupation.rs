@@ -0,0 +1,98 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::classroom::{Classroom, CLASSROOM_FILE};
+use crate::game_logic::journey::Journey;
+use crate::game_logic::occupation::Occupation;
+use crate::scenes::main_menu::MainMenuScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::departure::DepartureScene;
+
+/// Height of one list row
+const ROW_HEIGHT: f32 = 24.0;
+
+/// "Many kinds of people made the trip to Oregon": choosing the leader's occupation
+pub struct OccupationScene {
+    /// Occupation choices
+    list: ListBox,
+}
+
+impl OccupationScene {
+    /// Create the occupation screen
+    pub fn new() -> Self {
+        let labels = Occupation::ALL
+            .iter()
+            .map(|o| format!("Be a {}", o.name()))
+            .collect();
+        let mut list = ListBox::new(Self::list_rect(), labels);
+        list.select(0);
+        Self { list }
+    }
+
+    /// Student whose turn it is, in classroom mode
+    fn student_up(ctx: &GameContext) -> Option<String> {
+        let class = Classroom::load(ctx.storage.as_ref(), CLASSROOM_FILE).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            Classroom::default()
+        });
+        class.student_up().map(str::to_string)
+    }
+
+    /// Area of the choice list
+    fn list_rect() -> Rect {
+        Rect::new(60.0, 110.0, screen_width() - 120.0, ROW_HEIGHT * Occupation::ALL.len() as f32)
+    }
+}
+
+impl Scene for OccupationScene {
+    fn state(&self) -> GameState {
+        GameState::Setup
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Replace(Box::new(MainMenuScene::new()));
+        }
+
+        // Start a journey with the default party until party setup exists
+        if let Some(index) = self.list.update() {
+            ctx.journey = Journey::default();
+            ctx.wagon_train = None;
+            ctx.journey.player.set_occupation(Occupation::ALL[index]);
+            ctx.journey.student = Self::student_up(ctx);
+            return SceneTransition::Replace(Box::new(DepartureScene::new()));
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(20.0, 20.0, screen_width() - 40.0, screen_height() - 40.0);
+        draw_panel(panel);
+        draw_text("Many kinds of people made the trip to Oregon.", 40.0, 56.0, style::FONT_SIZE + 4.0, style::theme().text);
+        draw_text("You may:", 40.0, 92.0, style::FONT_SIZE, style::theme().text);
+        self.list.draw();
+
+        if let Some(occupation) = self.list.selected().and_then(|i| Occupation::ALL.get(i)) {
+            let y = Self::list_rect().y + Self::list_rect().h + 40.0;
+            let money = format!("Starting money: {}", occupation.starting_money());
+            let bonus = format!("Final score bonus: x{}", occupation.score_multiplier());
+            draw_text(&money, 60.0, y, style::FONT_SIZE, style::theme().text);
+            draw_text(&bonus, 60.0, y + 26.0, style::FONT_SIZE, style::theme().text);
+            draw_text(occupation.perk(), 60.0, y + 52.0, style::FONT_SIZE, style::theme().focus);
+        }
+
+        draw_text(
+            "ENTER to choose, ESC to go back",
+            40.0,
+            screen_height() - 36.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use macroquad::prelude::*;
+use crate::engine::audio;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::palette::{Palette, PaletteShader};
+use crate::engine::renderer::{draw_text, draw_texture_ex};
+use crate::engine::ui::{draw_panel, draw_text_centered, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::slideshow::{Slideshow, OPENING_FILE};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::title_screen::TitleScreen;
+use crate::utils::wrap_text;
+
+/// Seconds each slide takes to fade in, and again to fade out
+const FADE: f32 = 0.5;
+/// Largest an image is scaled up
+const MAX_SCALE: f32 = 2.0;
+/// Characters per line of narration
+const LINE_CHARS: usize = 60;
+/// Height of one line of narration
+const LINE_HEIGHT: f32 = 24.0;
+/// Height of the narration panel
+const TEXT_HEIGHT: f32 = 120.0;
+
+/// The opening sequence: publisher logo, splash and a few narrated pictures,
+/// played on a timer before the title screen. Any key moves on a slide,
+/// ESC skips the lot.
+pub struct OpeningScene {
+    /// Slides to play
+    show: Slideshow,
+    /// Images of the slides, in order, where they loaded
+    images: Vec<Option<Texture2D>>,
+    /// Palettes of the slides' images, where they have one
+    palettes: Vec<Option<Palette>>,
+    /// Fades images through their palettes, where the shader could be built
+    shader: Option<PaletteShader>,
+    /// Slide showing
+    slide: usize,
+    /// Seconds the current slide has been up
+    time: f32,
+}
+
+impl OpeningScene {
+    /// Create the opening sequence; the slides are read when it's entered
+    pub fn new() -> Self {
+        Self { show: Slideshow::default(), images: Vec::new(), palettes: Vec::new(), shader: None, slide: 0, time: 0.0 }
+    }
+
+    /// Move on to the next slide, or to the title screen after the last
+    fn advance(&mut self) -> SceneTransition {
+        self.slide += 1;
+        self.time = 0.0;
+        if self.slide >= self.show.slides.len() {
+            return SceneTransition::Replace(Box::new(TitleScreen::new()));
+        }
+        SceneTransition::None
+    }
+
+    /// How visible the current slide is, fading in and out at its ends
+    fn opacity(&self) -> f32 {
+        let seconds = self.show.slides[self.slide].seconds;
+        (self.time / FADE).min((seconds - self.time) / FADE).clamp(0.0, 1.0)
+    }
+
+    /// Fade the current slide's palette along with the slide
+    fn light_slide(&mut self) {
+        let Some(Some(palette)) = self.palettes.get(self.slide) else {
+            return;
+        };
+        let faded = palette.faded(self.opacity());
+        if let Some(shader) = self.shader.as_mut() {
+            shader.set_palettes(palette, &faded);
+        }
+    }
+
+    /// Whether the current slide's image fades through its palette rather than its alpha
+    fn fades_by_palette(&self) -> bool {
+        self.shader.is_some() && self.palettes.get(self.slide).is_some_and(Option::is_some)
+    }
+}
+
+impl Scene for OpeningScene {
+    fn state(&self) -> GameState {
+        GameState::TitleScreen
+    }
+
+    fn asset_group(&self) -> Option<&'static str> {
+        Some("opening")
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.show = match ctx.assets.get_text(OPENING_FILE).map(Slideshow::parse) {
+            Some(Ok(show)) => show,
+            Some(Err(e)) => {
+                log::error!("Failed to read {}: {}", OPENING_FILE, e);
+                Slideshow::default()
+            }
+            None => Slideshow::default(),
+        };
+        self.images = self.show.slides.iter().map(|slide| ctx.assets.get_texture(&slide.image)).collect();
+        self.palettes = self.show.slides.iter().map(|slide| ctx.assets.get_palette(&slide.image).cloned()).collect();
+        if self.shader.is_none() && self.palettes.iter().any(Option::is_some) {
+            self.shader = PaletteShader::new();
+        }
+        self.slide = 0;
+        self.time = 0.0;
+        self.light_slide();
+
+        if let Some(track) = self.show.music.as_deref().and_then(|name| ctx.assets.get_music(name)) {
+            audio::play_music(track);
+        }
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        if self.slide >= self.show.slides.len() || input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Replace(Box::new(TitleScreen::new()));
+        }
+        let transition = if input::is_action_pressed(Action::Confirm) || is_mouse_button_pressed(MouseButton::Left) {
+            self.advance()
+        } else {
+            self.time += dt;
+            if self.time >= self.show.slides[self.slide].seconds {
+                self.advance()
+            } else {
+                SceneTransition::None
+            }
+        };
+        self.light_slide();
+        transition
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+        let Some(slide) = self.show.slides.get(self.slide) else {
+            return;
+        };
+        let fade = Color::new(1.0, 1.0, 1.0, self.opacity());
+
+        // Narrated slides keep the bottom of the screen for their text
+        let narrated = !slide.text.is_empty();
+        let area = if narrated {
+            Rect::new(20.0, 20.0, screen_width() - 40.0, screen_height() - TEXT_HEIGHT - 60.0)
+        } else {
+            Rect::new(0.0, 0.0, screen_width(), screen_height())
+        };
+
+        if let Some(texture) = self.images[self.slide] {
+            let scale = (area.w / texture.width()).min(area.h / texture.height()).min(MAX_SCALE);
+            let size = vec2(texture.width(), texture.height()) * scale;
+            let x = area.x + (area.w - size.x) / 2.0;
+            let y = area.y + (area.h - size.y) / 2.0;
+            let params = DrawTextureParams { dest_size: Some(size), ..Default::default() };
+            match &self.shader {
+                // Faded like the original, by darkening the palette
+                Some(shader) if self.fades_by_palette() => shader.draw_texture(texture, x, y, WHITE, params),
+                _ => draw_texture_ex(texture, x, y, fade, params),
+            }
+        } else if !narrated {
+            draw_text_centered(&slide.image, area, 30.0, Color::new(1.0, 1.0, 1.0, fade.a));
+        }
+
+        if narrated {
+            let panel = Rect::new(20.0, screen_height() - TEXT_HEIGHT - 30.0, screen_width() - 40.0, TEXT_HEIGHT);
+            draw_panel(panel);
+            let color = Color::new(style::theme().text.r, style::theme().text.g, style::theme().text.b, fade.a);
+            for (i, line) in wrap_text(&slide.text, LINE_CHARS).iter().enumerate() {
+                draw_text(line, panel.x + 20.0, panel.y + 32.0 + i as f32 * LINE_HEIGHT, style::FONT_SIZE, color);
+            }
+        }
+
+        draw_text(
+            "ENTER next, ESC skip",
+            screen_width() - 190.0,
+            screen_height() - 8.0,
+            16.0,
+            style::theme().text_disabled,
+        );
+    }
+}
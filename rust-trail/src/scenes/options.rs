@@ -0,0 +1,264 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width, ScalingMode, WindowMode};
+use crate::engine::input::{self, Action};
+use crate::engine::narration::Narration;
+use crate::engine::renderer::draw_text;
+use crate::engine::transition::TransitionEffect;
+use crate::engine::ui;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::style::{self, Palette};
+use crate::game::{GameContext, GameState};
+use crate::scenes::controls::ControlsScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::title_screen::TitleScreen;
+use crate::settings::{Difficulty, LogLevel, Settings, TextSpeed};
+
+/// Most entries shown at once; the rest scroll into view
+const VISIBLE_ROWS: usize = 14;
+/// How much one step changes a volume setting
+const VOLUME_STEP: i32 = 10;
+/// How much one step changes the text size (in percent)
+const TEXT_SIZE_STEP: i32 = 25;
+/// Width of the option list at normal text size
+const LIST_WIDTH: f32 = 320.0;
+/// Height of one option row at normal text size
+const ROW_HEIGHT: f32 = 24.0;
+
+/// Entries on the options screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    MusicVolume,
+    SfxVolume,
+    WindowMode,
+    Scaling,
+    Crt,
+    Palette,
+    HighContrast,
+    Difficulty,
+    TextSpeed,
+    TextSize,
+    Narration,
+    Transition,
+    Speedrun,
+    Cheats,
+    Facts,
+    Leaderboard,
+    StreamControl,
+    LogLevel,
+    Controls,
+    Done,
+}
+
+impl Entry {
+    /// All entries in menu order
+    const ALL: [Entry; 20] = [
+        Entry::MusicVolume,
+        Entry::SfxVolume,
+        Entry::WindowMode,
+        Entry::Scaling,
+        Entry::Crt,
+        Entry::Palette,
+        Entry::HighContrast,
+        Entry::Difficulty,
+        Entry::TextSpeed,
+        Entry::TextSize,
+        Entry::Narration,
+        Entry::Transition,
+        Entry::Speedrun,
+        Entry::Cheats,
+        Entry::Facts,
+        Entry::Leaderboard,
+        Entry::StreamControl,
+        Entry::LogLevel,
+        Entry::Controls,
+        Entry::Done,
+    ];
+
+    /// Row label showing the entry's current value
+    fn label(&self, settings: &Settings, back: Option<&str>) -> String {
+        match self {
+            Entry::MusicVolume => format!("Music volume: {}%", settings.music_volume),
+            Entry::SfxVolume => format!("Sound volume: {}%", settings.sfx_volume),
+            Entry::WindowMode => format!("Window: {}", settings.window_mode.name()),
+            Entry::Scaling => format!("Scaling: {}", settings.scaling.name()),
+            Entry::Crt => format!("CRT effect: {}", if settings.crt { "On" } else { "Off" }),
+            Entry::Palette => format!("Colors: {}", settings.palette.name()),
+            Entry::HighContrast => format!("High contrast: {}", if settings.high_contrast { "On" } else { "Off" }),
+            Entry::Difficulty => format!("Difficulty: {}", settings.difficulty.name()),
+            Entry::TextSpeed => format!("Text speed: {}", settings.text_speed.name()),
+            Entry::TextSize => format!("Text size: {}%", settings.text_size),
+            Entry::Narration => format!("Narration: {}", settings.narration.name()),
+            Entry::Transition => format!("Screen transitions: {}", settings.transition.name()),
+            Entry::Speedrun => format!("Speedrun timer: {}", if settings.speedrun { "On" } else { "Off" }),
+            Entry::Cheats => format!("Cheat codes: {}", if settings.cheats { "On" } else { "Off" }),
+            Entry::Facts => format!("Historical notes: {}", if settings.facts { "On" } else { "Off" }),
+            Entry::Leaderboard => match (settings.online_leaderboard, settings.leaderboard().is_some()) {
+                (false, _) => "Online leaderboard: Off".to_string(),
+                (true, true) => "Online leaderboard: On".to_string(),
+                (true, false) => "Online leaderboard: On (no server set)".to_string(),
+            },
+            Entry::StreamControl if settings.stream_control => format!("Stream control: On (port {})", settings.control_port),
+            Entry::StreamControl => "Stream control: Off".to_string(),
+            Entry::LogLevel => format!("Log level: {}", settings.log_level.name()),
+            Entry::Controls => "Controls...".to_string(),
+            Entry::Done => back.unwrap_or("Return to title screen").to_string(),
+        }
+    }
+
+    /// Change the entry's setting one step in the given direction
+    fn adjust(&self, settings: &mut Settings, direction: i32) {
+        match self {
+            Entry::MusicVolume => settings.music_volume = step_volume(settings.music_volume, direction),
+            Entry::SfxVolume => settings.sfx_volume = step_volume(settings.sfx_volume, direction),
+            Entry::WindowMode => settings.window_mode = cycle(&WindowMode::ALL, settings.window_mode, direction),
+            Entry::Scaling => settings.scaling = cycle(&ScalingMode::ALL, settings.scaling, direction),
+            Entry::Difficulty => settings.difficulty = cycle(&Difficulty::ALL, settings.difficulty, direction),
+            Entry::TextSpeed => settings.text_speed = cycle(&TextSpeed::ALL, settings.text_speed, direction),
+            Entry::TextSize => settings.text_size = step_text_size(settings.text_size, direction),
+            Entry::Narration => settings.narration = cycle(&Narration::ALL, settings.narration, direction),
+            Entry::Transition => settings.transition = cycle(&TransitionEffect::ALL, settings.transition, direction),
+            Entry::Crt => settings.crt = !settings.crt,
+            Entry::Palette => settings.palette = cycle(&Palette::ALL, settings.palette, direction),
+            Entry::HighContrast => settings.high_contrast = !settings.high_contrast,
+            Entry::Speedrun => settings.speedrun = !settings.speedrun,
+            Entry::Cheats => settings.cheats = !settings.cheats,
+            Entry::Facts => settings.facts = !settings.facts,
+            Entry::Leaderboard => settings.online_leaderboard = !settings.online_leaderboard,
+            Entry::StreamControl => settings.stream_control = !settings.stream_control,
+            Entry::LogLevel => settings.log_level = cycle(&LogLevel::ALL, settings.log_level, direction),
+            Entry::Controls | Entry::Done => {}
+        }
+    }
+}
+
+/// Move a volume one step, staying within 0-100
+fn step_volume(volume: u8, direction: i32) -> u8 {
+    (volume as i32 + direction * VOLUME_STEP).clamp(0, 100) as u8
+}
+
+/// Move the text size one step, staying within 100-200%
+fn step_text_size(size: u16, direction: i32) -> u16 {
+    let (min, max) = ((ui::MIN_TEXT_SCALE * 100.0) as i32, (ui::MAX_TEXT_SCALE * 100.0) as i32);
+    (size as i32 + direction * TEXT_SIZE_STEP).clamp(min, max) as u16
+}
+
+/// Move to the next or previous value in a list, wrapping around
+fn cycle<T: Copy + PartialEq>(values: &[T], current: T, direction: i32) -> T {
+    let index = values.iter().position(|v| *v == current).unwrap_or(0) as i32;
+    let len = values.len() as i32;
+    values[(index + direction).rem_euclid(len) as usize]
+}
+
+/// Options screen for adjusting and saving settings
+pub struct OptionsScene {
+    /// Option entries
+    list: ListBox,
+    /// Label of the entry going back to the screen this was opened over,
+    /// or None if it replaced the title screen and returns there
+    back: Option<&'static str>,
+}
+
+impl OptionsScene {
+    /// Create the options scene
+    pub fn new() -> Self {
+        let mut list = ListBox::new(Self::list_rect(), vec![String::new(); Entry::ALL.len()]);
+        list.select(0);
+        Self { list, back: None }
+    }
+
+    /// Create the options scene opened from the pause menu
+    pub fn in_game() -> Self {
+        Self::over("Return to game")
+    }
+
+    /// Create the options scene opened over another screen, with the label
+    /// of the entry going back to it
+    pub fn over(back: &'static str) -> Self {
+        Self { back: Some(back), ..Self::new() }
+    }
+
+    /// Area of the option list, wider and taller for larger text but kept on screen
+    fn list_rect() -> Rect {
+        let width = (LIST_WIDTH * ui::text_scale()).min(screen_width() - 40.0);
+        let rows = ui::scaled(ROW_HEIGHT) * Entry::ALL.len().min(VISIBLE_ROWS) as f32;
+        let height = rows.min(screen_height() - 180.0);
+        Rect::new((screen_width() - width) / 2.0, 100.0, width, height)
+    }
+
+    /// Refresh the row labels from the settings
+    fn refresh(&mut self, settings: &Settings) {
+        self.list.set_labels(Entry::ALL.iter().map(|e| e.label(settings, self.back)).collect());
+    }
+
+    /// Save settings and go back to the title screen, or the screen this was opened over
+    fn leave(&self, ctx: &mut GameContext) -> SceneTransition {
+        ctx.save_settings();
+        if self.back.is_some() {
+            SceneTransition::Pop
+        } else {
+            SceneTransition::Replace(Box::new(TitleScreen::new()))
+        }
+    }
+}
+
+impl Scene for OptionsScene {
+    fn state(&self) -> GameState {
+        GameState::Options
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.refresh(&ctx.settings);
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Cancel) {
+            return self.leave(ctx);
+        }
+
+        // The list grows with the text size, which may have just changed
+        self.list.set_rect(Self::list_rect());
+        let activated = self.list.update();
+        let Some(entry) = self.list.selected().map(|i| Entry::ALL[i]) else {
+            return SceneTransition::None;
+        };
+
+        let mut direction = 0;
+        if input::is_action_pressed(Action::MenuLeft) {
+            direction = -1;
+        } else if input::is_action_pressed(Action::MenuRight) {
+            direction = 1;
+        } else if activated.is_some() {
+            match entry {
+                Entry::Controls => return SceneTransition::Push(Box::new(ControlsScene::new())),
+                Entry::Done => return self.leave(ctx),
+                _ => direction = 1,
+            }
+        }
+
+        if direction != 0 {
+            entry.adjust(&mut ctx.settings, direction);
+            self.refresh(&ctx.settings);
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let title = "Options";
+        let size = measure_text(title, None, 30, 1.0);
+        draw_text(title, screen_width() / 2.0 - size.width / 2.0, 60.0, 30.0, style::theme().text);
+
+        self.list.draw();
+
+        let hint = "Left/Right: change   Enter: select   Esc: save and return";
+        let size = measure_text(hint, None, style::FONT_SIZE as u16, 1.0);
+        draw_text(
+            hint,
+            screen_width() / 2.0 - size.width / 2.0,
+            screen_height() - 40.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::diary::EntryKind;
+use crate::game_logic::player::{Pace, Rations};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Size of the dialog panel
+const PANEL_SIZE: (f32, f32) = (440.0, 230.0);
+/// Height of one list row
+const ROW_HEIGHT: f32 = 24.0;
+
+/// Paces offered on the travel menu; resting is done with "stop to rest"
+const PACES: [Pace; 3] = [Pace::Steady, Pace::Strenuous, Pace::Grueling];
+
+/// Which travel setting the dialog changes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TravelSetting {
+    Pace,
+    Rations,
+}
+
+impl TravelSetting {
+    /// Dialog heading
+    fn title(&self) -> &'static str {
+        match self {
+            TravelSetting::Pace => "Change pace",
+            TravelSetting::Rations => "Change food rations",
+        }
+    }
+
+    /// Number of options
+    fn len(&self) -> usize {
+        match self {
+            TravelSetting::Pace => PACES.len(),
+            TravelSetting::Rations => Rations::ALL.len(),
+        }
+    }
+
+    /// Name of an option
+    fn name(&self, index: usize) -> &'static str {
+        match self {
+            TravelSetting::Pace => PACES[index].name(),
+            TravelSetting::Rations => Rations::ALL[index].name(),
+        }
+    }
+
+    /// Description of an option
+    fn description(&self, index: usize) -> &'static str {
+        match self {
+            TravelSetting::Pace => PACES[index].description(),
+            TravelSetting::Rations => Rations::ALL[index].description(),
+        }
+    }
+
+    /// Index of the party's current choice
+    fn current(&self, ctx: &GameContext) -> usize {
+        let player = &ctx.journey.player;
+        match self {
+            TravelSetting::Pace => PACES.iter().position(|p| *p == player.pace).unwrap_or(0),
+            TravelSetting::Rations => Rations::ALL.iter().position(|r| *r == player.rations).unwrap_or(0),
+        }
+    }
+
+    /// Make an option the party's choice, noting a change in the diary
+    fn apply(&self, ctx: &mut GameContext, index: usize) {
+        if index == self.current(ctx) {
+            return;
+        }
+        let player = &mut ctx.journey.player;
+        let note = match self {
+            TravelSetting::Pace => {
+                player.pace = PACES[index];
+                format!("Set the pace to {}.", self.name(index).to_lowercase())
+            }
+            TravelSetting::Rations => {
+                player.rations = Rations::ALL[index];
+                format!("Changed the food rations to {}.", self.name(index).to_lowercase())
+            }
+        };
+        ctx.journey.record(EntryKind::Note, note);
+    }
+}
+
+/// Dialog for changing the party's pace or food rations
+pub struct TravelSettingScene {
+    /// Setting being changed
+    setting: TravelSetting,
+    /// Available options
+    list: ListBox,
+    /// Name of the option in effect when the dialog opened
+    current: &'static str,
+}
+
+impl TravelSettingScene {
+    /// Create the dialog for a setting
+    pub fn new(setting: TravelSetting) -> Self {
+        let labels = (0..setting.len()).map(|i| setting.name(i).to_string()).collect();
+        Self {
+            setting,
+            list: ListBox::new(Self::list_rect(setting), labels),
+            current: "",
+        }
+    }
+
+    /// Area of the dialog panel
+    fn panel_rect() -> Rect {
+        Rect::new(
+            screen_width() / 2.0 - PANEL_SIZE.0 / 2.0,
+            screen_height() / 2.0 - PANEL_SIZE.1 / 2.0,
+            PANEL_SIZE.0,
+            PANEL_SIZE.1,
+        )
+    }
+
+    /// Area of the option list
+    fn list_rect(setting: TravelSetting) -> Rect {
+        let panel = Self::panel_rect();
+        Rect::new(panel.x + 20.0, panel.y + 70.0, panel.w - 40.0, ROW_HEIGHT * setting.len() as f32)
+    }
+}
+
+impl Scene for TravelSettingScene {
+    fn state(&self) -> GameState {
+        GameState::Travel
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        let current = self.setting.current(ctx);
+        self.current = self.setting.name(current);
+        self.list.select(current);
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Pop;
+        }
+
+        match self.list.update() {
+            Some(index) => {
+                self.setting.apply(ctx, index);
+                SceneTransition::Pop
+            }
+            None => SceneTransition::None,
+        }
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), style::theme().dim);
+
+        let panel = Self::panel_rect();
+        draw_panel(panel);
+        draw_text(self.setting.title(), panel.x + 20.0, panel.y + 30.0, style::FONT_SIZE + 4.0, style::theme().text);
+        draw_text(
+            &format!("Currently: {}", self.current),
+            panel.x + 20.0,
+            panel.y + 56.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+
+        self.list.draw();
+
+        if let Some(index) = self.list.selected() {
+            draw_text(
+                self.setting.description(index),
+                panel.x + 20.0,
+                panel.y + panel.h - 20.0,
+                style::FONT_SIZE,
+                style::theme().text,
+            );
+        }
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,128 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::save::{self, SAVE_FILE};
+use crate::scenes::message::MessageScene;
+use crate::scenes::options::OptionsScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::title_screen::TitleScreen;
+
+/// Width of the menu panel
+const PANEL_WIDTH: f32 = 300.0;
+/// Height of one menu row
+const ROW_HEIGHT: f32 = 24.0;
+
+/// Choices on the pause menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PauseChoice {
+    Resume,
+    SaveGame,
+    Options,
+    QuitToTitle,
+}
+
+impl PauseChoice {
+    /// All choices in menu order
+    const ALL: [PauseChoice; 4] = [
+        PauseChoice::Resume,
+        PauseChoice::SaveGame,
+        PauseChoice::Options,
+        PauseChoice::QuitToTitle,
+    ];
+
+    /// Menu label
+    fn label(&self) -> &'static str {
+        match self {
+            PauseChoice::Resume => "Resume",
+            PauseChoice::SaveGame => "Save game",
+            PauseChoice::Options => "Options",
+            PauseChoice::QuitToTitle => "Quit to title",
+        }
+    }
+}
+
+/// Menu over the travel scene that stops the days from passing until it's closed
+pub struct PauseScene {
+    /// Menu choices
+    list: ListBox,
+}
+
+impl PauseScene {
+    /// Create the pause menu
+    pub fn new() -> Self {
+        let labels = PauseChoice::ALL.iter().map(|c| c.label().to_string()).collect();
+        let mut list = ListBox::new(Self::list_rect(), labels);
+        list.select(0);
+        Self { list }
+    }
+
+    /// Area of the menu panel
+    fn panel_rect() -> Rect {
+        let height = ROW_HEIGHT * PauseChoice::ALL.len() as f32 + 80.0;
+        Rect::new(
+            screen_width() / 2.0 - PANEL_WIDTH / 2.0,
+            screen_height() / 2.0 - height / 2.0,
+            PANEL_WIDTH,
+            height,
+        )
+    }
+
+    /// Area of the choice list inside the panel
+    fn list_rect() -> Rect {
+        let panel = Self::panel_rect();
+        Rect::new(panel.x + 20.0, panel.y + 60.0, panel.w - 40.0, ROW_HEIGHT * PauseChoice::ALL.len() as f32)
+    }
+
+    /// Save the journey and report how it went
+    fn save(ctx: &mut GameContext) -> SceneTransition {
+        if ctx.wagon_train.is_some() {
+            return SceneTransition::Push(Box::new(MessageScene::new(GameState::Paused, "Save game", "A wagon train can't be saved.")));
+        }
+        let file = ctx.profile_file(SAVE_FILE);
+        let text = match save::save(&ctx.journey, ctx.rng.seed(), ctx.storage.as_mut(), &file) {
+            Ok(()) => "Your journey has been saved.".to_string(),
+            Err(e) => {
+                log::error!("{}", e);
+                "The game could not be saved.".to_string()
+            }
+        };
+        SceneTransition::Push(Box::new(MessageScene::new(GameState::Paused, "Save game", &text)))
+    }
+}
+
+impl Scene for PauseScene {
+    fn state(&self) -> GameState {
+        GameState::Paused
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Pop;
+        }
+
+        match self.list.update().map(|i| PauseChoice::ALL[i]) {
+            Some(PauseChoice::Resume) => SceneTransition::Pop,
+            Some(PauseChoice::SaveGame) => Self::save(ctx),
+            Some(PauseChoice::Options) => SceneTransition::Push(Box::new(OptionsScene::in_game())),
+            Some(PauseChoice::QuitToTitle) => SceneTransition::Reset(Box::new(TitleScreen::new())),
+            None => SceneTransition::None,
+        }
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), style::theme().dim);
+
+        let panel = Self::panel_rect();
+        draw_panel(panel);
+        draw_text("Paused", panel.x + 20.0, panel.y + 34.0, style::FONT_SIZE + 4.0, style::theme().text);
+        self.list.draw();
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,193 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::dialog::Dialog;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::text_input::{TextInput, TextInputEvent};
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::profiles::{Profiles, MAX_NAME_LEN, PROFILES_FILE};
+use crate::scenes::main_menu::MainMenuScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::title_screen::TitleScreen;
+
+/// Heading of the screen and its dialogs
+const TITLE: &str = "Who is playing?";
+/// Height of one list row
+const ROW_HEIGHT: f32 = 24.0;
+
+/// Entries on the profile picker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entry {
+    Profile(usize),
+    New,
+    Guest,
+}
+
+/// Choosing a profile before the main menu, each with its own settings,
+/// achievements, statistics and saved game
+pub struct ProfileScene {
+    /// The profiles, read when the scene is entered
+    profiles: Profiles,
+    /// Profiles and the other choices
+    list: ListBox,
+    /// Name being typed for a new profile, while one is being made
+    naming: Option<TextInput>,
+    /// Dialog showing, with the profile to remove if it's answered Yes
+    dialog: Option<(Dialog, Option<usize>)>,
+}
+
+impl ProfileScene {
+    /// Create the profile picker
+    pub fn new() -> Self {
+        Self { profiles: Profiles::default(), list: ListBox::new(Self::list_rect(), Vec::new()), naming: None, dialog: None }
+    }
+
+    /// Area of the list
+    fn list_rect() -> Rect {
+        Rect::new(screen_width() / 2.0 - 160.0, 120.0, 320.0, 10.0 * ROW_HEIGHT)
+    }
+
+    /// Entries in the order shown
+    fn entries(&self) -> Vec<Entry> {
+        (0..self.profiles.names().len()).map(Entry::Profile).chain([Entry::New, Entry::Guest]).collect()
+    }
+
+    /// Show the entries as they now stand
+    fn refresh(&mut self) {
+        let labels = self
+            .entries()
+            .into_iter()
+            .map(|entry| match entry {
+                Entry::Profile(i) => self.profiles.names()[i].clone(),
+                Entry::New => "New profile...".to_string(),
+                Entry::Guest => "Play as a guest".to_string(),
+            })
+            .collect();
+        self.list.set_labels(labels);
+    }
+
+    /// Write the profile list, logging any failure
+    fn save(&self, ctx: &mut GameContext) {
+        if let Err(e) = self.profiles.save(ctx.storage.as_mut(), PROFILES_FILE) {
+            log::error!("{}", e);
+        }
+    }
+
+    /// Play as a profile, or as a guest, and go on to the main menu
+    fn choose(&mut self, ctx: &mut GameContext, profile: Option<String>) -> SceneTransition {
+        self.profiles.set_last(profile.as_deref());
+        self.save(ctx);
+        ctx.switch_profile(profile);
+        SceneTransition::Replace(Box::new(MainMenuScene::new()))
+    }
+
+    /// Handle typing a new profile's name
+    fn update_naming(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        let Some(field) = &mut self.naming else {
+            return SceneTransition::None;
+        };
+        if field.update(dt) == Some(TextInputEvent::Submitted) {
+            let name = field.text().to_string();
+            self.naming = None;
+            if let Some(name) = self.profiles.add(&name) {
+                return self.choose(ctx, Some(name));
+            }
+            if !name.trim().is_empty() {
+                self.dialog = Some((Dialog::message(TITLE, "There is already a profile by that name,\nor there are too many profiles."), None));
+            }
+        } else if input::is_action_pressed(Action::Cancel) {
+            self.naming = None;
+        }
+        SceneTransition::None
+    }
+}
+
+impl Scene for ProfileScene {
+    fn state(&self) -> GameState {
+        GameState::MainMenu
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.profiles = Profiles::load(ctx.storage.as_ref(), PROFILES_FILE).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            Profiles::default()
+        });
+        self.refresh();
+        // Offer whoever played last first
+        let last = self.profiles.last().and_then(|last| self.profiles.names().iter().position(|n| n == last));
+        self.list.select(last.unwrap_or(self.profiles.names().len()));
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        if let Some((dialog, remove)) = &mut self.dialog {
+            let Some(choice) = dialog.update(dt) else {
+                return SceneTransition::None;
+            };
+            if let Some(index) = remove.filter(|_| choice == 0) {
+                self.profiles.remove(index);
+                self.save(ctx);
+                self.refresh();
+            }
+            self.dialog = None;
+            return SceneTransition::None;
+        }
+        if self.naming.is_some() {
+            return self.update_naming(ctx, dt);
+        }
+
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Replace(Box::new(TitleScreen::new()));
+        }
+        // Delete takes the selected profile off the list
+        let selected = self.list.selected().map(|i| self.entries()[i]);
+        if let Some(Entry::Profile(i)) = selected.filter(|_| is_key_pressed(KeyCode::Delete)) {
+            let question = format!("Remove the profile {}?", self.profiles.names()[i]);
+            self.dialog = Some((Dialog::confirm(TITLE, &question), Some(i)));
+            return SceneTransition::None;
+        }
+
+        let Some(index) = self.list.update() else {
+            return SceneTransition::None;
+        };
+        match self.entries()[index] {
+            Entry::Profile(i) => {
+                let name = self.profiles.names()[i].clone();
+                return self.choose(ctx, Some(name));
+            }
+            Entry::New => {
+                let list = Self::list_rect();
+                let mut field = TextInput::new(Rect::new(list.x, list.y + list.h + 12.0, list.w, 30.0), MAX_NAME_LEN);
+                field.set_focused(true);
+                self.naming = Some(field);
+            }
+            Entry::Guest => return self.choose(ctx, None),
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text(TITLE, panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+        let about = "Each profile keeps its own settings, achievements, statistics and saved game.";
+        draw_text(about, panel.x + 20.0, panel.y + 60.0, 16.0, style::theme().text_disabled);
+        self.list.draw();
+        if let Some(field) = &self.naming {
+            field.draw();
+        }
+        let hint = if self.naming.is_some() {
+            "Type a name, ENTER to make the profile, ESC to cancel"
+        } else {
+            "ENTER to choose, DELETE to remove a profile, ESC to go back"
+        };
+        draw_text(hint, panel.x + 20.0, panel.y + panel.h - 20.0, style::FONT_SIZE, style::theme().text_disabled);
+
+        if let Some((dialog, _)) = &self.dialog {
+            dialog.draw();
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::focus::{FocusAxis, FocusRing};
+use crate::engine::ui::spinner::Spinner;
+use crate::engine::ui::{draw_focus_outline, draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::diary::EntryKind;
+use crate::game_logic::journey::{Journey, MAX_REST_DAYS};
+use crate::game_logic::rest::{RestTask, TaskResult, WorkAssignments};
+use crate::scenes::message::MessageScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Width of the dialog panel
+const PANEL_WIDTH: f32 = 420.0;
+
+/// Height of the panel without any task rows
+const PANEL_BASE_HEIGHT: f32 = 200.0;
+
+/// Height of one member's task row
+const ROW_HEIGHT: f32 = 28.0;
+
+/// Number keys that pick a day count directly
+const DAY_KEYS: [KeyCode; 9] = [
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+];
+
+/// Dialog asking how many days to rest and what each member does meanwhile,
+/// which then rests the party
+pub struct RestScene {
+    /// Number of days to rest
+    days: Spinner,
+    /// Task for each party member, indexed the same as PlayerState::party
+    work: WorkAssignments,
+    /// Party indices and names of the living members, one task row each
+    members: Vec<(usize, String)>,
+    /// Focus over the day spinner (first) and the task rows
+    focus: FocusRing,
+}
+
+impl RestScene {
+    /// Create the rest dialog, starting from the tasks the party last had
+    pub fn new(journey: &Journey) -> Self {
+        let members: Vec<(usize, String)> = journey
+            .player
+            .party
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| m.is_alive())
+            .map(|(i, m)| (i, m.name.clone()))
+            .collect();
+        let mut days = Spinner::new(Self::spinner_rect(members.len()), 1, 1, MAX_REST_DAYS as i32);
+        days.set_focused(true);
+        Self {
+            days,
+            work: journey.work.clone(),
+            focus: FocusRing::new(members.len() + 1, FocusAxis::Vertical),
+            members,
+        }
+    }
+
+    /// Area of the dialog panel for a number of task rows
+    fn panel_rect(rows: usize) -> Rect {
+        let height = PANEL_BASE_HEIGHT + ROW_HEIGHT * rows as f32;
+        Rect::new(
+            screen_width() / 2.0 - PANEL_WIDTH / 2.0,
+            screen_height() / 2.0 - height / 2.0,
+            PANEL_WIDTH,
+            height,
+        )
+    }
+
+    /// Area of the day spinner
+    fn spinner_rect(rows: usize) -> Rect {
+        let panel = Self::panel_rect(rows);
+        Rect::new(panel.x + panel.w / 2.0 - 60.0, panel.y + 70.0, 120.0, 32.0)
+    }
+
+    /// Area of a member's task row
+    fn row_rect(&self, row: usize) -> Rect {
+        let panel = Self::panel_rect(self.members.len());
+        Rect::new(panel.x + 20.0, panel.y + 140.0 + ROW_HEIGHT * row as f32, panel.w - 40.0, ROW_HEIGHT - 4.0)
+    }
+
+    /// Give a member the next or previous task in the list
+    fn cycle_task(&mut self, member: usize, direction: i32) {
+        let current = RestTask::ALL.iter().position(|&t| t == self.work.task_for(member)).unwrap_or(0);
+        let next = (current as i32 + direction).rem_euclid(RestTask::ALL.len() as i32) as usize;
+        self.work.assign(member, RestTask::ALL[next]);
+    }
+
+    /// Rest for the chosen number of days and describe what happened
+    fn rest(&self, ctx: &mut GameContext) -> String {
+        let days = self.days.value() as u32;
+        ctx.journey.work = self.work.clone();
+        let mut lines = vec![format!(
+            "You rest for {} day{}.",
+            days,
+            if days == 1 { "" } else { "s" }
+        )];
+        let mut starving = false;
+
+        for _ in 0..days {
+            let report = ctx.journey.camp_day(&mut ctx.rng);
+            starving |= report.starving;
+            for outcome in report.work.iter().filter(|o| o.result != TaskResult::Rested) {
+                lines.push(outcome.describe());
+            }
+            for (name, disease) in &report.recovered {
+                lines.push(format!("{} has recovered from {}.", name, disease.name()));
+            }
+            for name in &report.deaths {
+                lines.push(format!("{} has died.", name));
+            }
+            if ctx.journey.party_died() {
+                break;
+            }
+        }
+
+        ctx.journey.record(EntryKind::Note, format!("Rested for {} day{}.", days, if days == 1 { "" } else { "s" }));
+        if starving {
+            lines.push("The party went hungry.".to_string());
+        }
+        lines.push(format!("Weather: {}", ctx.journey.weather.name()));
+        lines.join("\n")
+    }
+}
+
+impl Scene for RestScene {
+    fn state(&self) -> GameState {
+        GameState::Travel
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Pop;
+        }
+
+        self.focus.update();
+        self.days.set_focused(self.focus.is_focused(0));
+        self.days.update();
+        if let Some(row) = self.focus.focused().filter(|&row| row > 0) {
+            let member = self.members[row - 1].0;
+            if input::is_action_pressed(Action::MenuLeft) {
+                self.cycle_task(member, -1);
+            }
+            if input::is_action_pressed(Action::MenuRight) {
+                self.cycle_task(member, 1);
+            }
+        }
+        if let Some(i) = DAY_KEYS.iter().position(|key| is_key_pressed(*key)) {
+            self.days.set_value(i as i32 + 1);
+        }
+
+        if input::is_action_pressed(Action::Confirm) {
+            let summary = self.rest(ctx);
+            return SceneTransition::Replace(Box::new(MessageScene::new(GameState::Travel, "Stop to rest", &summary)));
+        }
+
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        let theme = style::theme();
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), theme.dim);
+
+        let panel = Self::panel_rect(self.members.len());
+        draw_panel(panel);
+        draw_text("Stop to rest", panel.x + 20.0, panel.y + 30.0, style::FONT_SIZE + 4.0, theme.text);
+        draw_text("How many days would you like to rest?", panel.x + 20.0, panel.y + 58.0, style::FONT_SIZE, theme.text);
+
+        self.days.draw();
+
+        draw_text("What will each of you do?", panel.x + 20.0, panel.y + 130.0, style::FONT_SIZE, theme.text);
+        for (row, (member, name)) in self.members.iter().enumerate() {
+            let rect = self.row_rect(row);
+            if self.focus.is_focused(row + 1) {
+                draw_focus_outline(rect);
+            }
+            let baseline = rect.y + rect.h - 6.0;
+            draw_text(name, rect.x + 8.0, baseline, style::FONT_SIZE, theme.text);
+            let task = format!("< {} >", self.work.task_for(*member).name());
+            draw_text(&task, rect.x + rect.w / 2.0, baseline, style::FONT_SIZE, theme.text);
+        }
+
+        draw_text(
+            "UP/DOWN to choose, LEFT/RIGHT to change",
+            panel.x + 20.0,
+            panel.y + panel.h - 40.0,
+            style::FONT_SIZE,
+            theme.text_disabled,
+        );
+        draw_text(
+            "ENTER to rest, ESC to cancel",
+            panel.x + 20.0,
+            panel.y + panel.h - 20.0,
+            style::FONT_SIZE,
+            theme.text_disabled,
+        );
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
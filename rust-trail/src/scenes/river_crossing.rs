@@ -0,0 +1,336 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::renderer::{draw_text, draw_texture_ex};
+use crate::engine::ui::dialog::Dialog;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::control::{self, Decision};
+use crate::game_logic::river::{
+    CrossingMethod, CrossingResult, River, FERRY_COST, GUIDE_CLOTHING, MAX_FORD_DEPTH, MIN_FLOAT_DEPTH, SAFE_FORD_DEPTH,
+};
+use crate::scenes::message::MessageScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::utils::rng::RngStream;
+use crate::utils::wrap_text;
+
+/// Height of one list row
+const ROW_HEIGHT: f32 = 24.0;
+/// Height of the panel under the picture
+const PANEL_HEIGHT: f32 = 250.0;
+/// Most rows the choice list can have
+const MAX_CHOICES: usize = CrossingMethod::ALL.len() + 2;
+/// Characters per line of the river description
+const LINE_CHARS: usize = 60;
+/// Seconds the stream's vote stays open after the first vote comes in
+const VOTE_SECONDS: f32 = 20.0;
+
+/// What the party can do at a river
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RiverChoice {
+    Cross(CrossingMethod),
+    Wait,
+    Information,
+}
+
+impl RiverChoice {
+    /// Menu label
+    fn label(&self) -> &'static str {
+        match self {
+            RiverChoice::Cross(method) => method.label(),
+            RiverChoice::Wait => "Wait to see if conditions improve",
+            RiverChoice::Information => "Get more information",
+        }
+    }
+}
+
+/// Advice shown for "get more information"
+fn advice() -> String {
+    format!(
+        "A wagon can ford water up to {} feet deep without harm; deeper than {} feet it \
+         may tip over. A caulked wagon floats in water at least {} feet deep, but a swift \
+         current can still overturn it. Rain and melting snow raise the rivers, so waiting \
+         a few days may help. Ferries cost {} and a Shoshoni guide at the Snake River \
+         asks for {} sets of clothing.",
+        SAFE_FORD_DEPTH, MAX_FORD_DEPTH, MIN_FLOAT_DEPTH, FERRY_COST, GUIDE_CLOTHING
+    )
+}
+
+/// Question asked before paying for a crossing
+fn offer(method: CrossingMethod, wait_days: u32) -> String {
+    match method {
+        CrossingMethod::Ferry => format!(
+            "The ferry operator will take your wagon\nacross for {}. You will have to wait\n{} day{} for your turn. Are you willing?",
+            FERRY_COST,
+            wait_days,
+            if wait_days == 1 { "" } else { "s" }
+        ),
+        _ => format!(
+            "A Shoshoni guide will help you float\nyour wagon across in exchange for\n{} sets of clothing. Are you willing?",
+            GUIDE_CLOTHING
+        ),
+    }
+}
+
+/// Why the party can't pay for a crossing
+fn cannot_pay(method: CrossingMethod) -> &'static str {
+    match method {
+        CrossingMethod::Ferry => "You don't have enough money\nto take the ferry.",
+        _ => "You don't have enough clothing\nto pay the guide.",
+    }
+}
+
+/// Screen for deciding how to get the wagon across a river
+pub struct RiverCrossingScene {
+    /// River's name
+    name: String,
+    /// Today's conditions
+    river: Option<River>,
+    /// Date and weather line
+    status: String,
+    /// What happened while waiting, if the party waited
+    note: Option<String>,
+    /// Choices offered at this river
+    choices: Vec<RiverChoice>,
+    /// Menu of choices
+    list: ListBox,
+    /// Open question or refusal, with the paid crossing and wait it confirms
+    dialog: Option<(Dialog, Option<(CrossingMethod, u32)>)>,
+    /// Seconds the stream's vote has been open
+    vote_timer: f32,
+}
+
+impl RiverCrossingScene {
+    /// Create the crossing screen for the river the wagon is stopped at
+    pub fn new() -> Self {
+        Self {
+            name: String::new(),
+            river: None,
+            status: String::new(),
+            note: None,
+            choices: Vec::new(),
+            list: ListBox::new(Self::list_rect(), Vec::new()),
+            dialog: None,
+            vote_timer: 0.0,
+        }
+    }
+
+    /// Area of the panel under the picture
+    fn panel_rect() -> Rect {
+        Rect::new(10.0, screen_height() - PANEL_HEIGHT - 10.0, screen_width() - 20.0, PANEL_HEIGHT)
+    }
+
+    /// Area of the choice list inside the panel
+    fn list_rect() -> Rect {
+        let panel = Self::panel_rect();
+        let height = ROW_HEIGHT * MAX_CHOICES as f32;
+        Rect::new(panel.x + 20.0, panel.y + panel.h - height - 14.0, panel.w - 40.0, height)
+    }
+
+    /// Read today's river conditions from the journey
+    fn refresh(&mut self, ctx: &GameContext) {
+        let journey = &ctx.journey;
+        self.name = journey.last_landmark().name.clone();
+        self.river = journey.river();
+        if let Some(river) = &self.river {
+            let choices: Vec<RiverChoice> = river
+                .methods()
+                .into_iter()
+                .map(RiverChoice::Cross)
+                .chain([RiverChoice::Wait, RiverChoice::Information])
+                .collect();
+            if choices != self.choices {
+                self.list.set_items(choices.iter().map(|c| c.label().to_string()).collect());
+                self.list.select(0);
+                self.choices = choices;
+            }
+        }
+        let player = &journey.player;
+        self.status = format!(
+            "{}  Weather: {}",
+            player.date,
+            journey.weather.name()
+        );
+    }
+
+    /// Cross the river and describe what happened
+    fn cross(&self, ctx: &mut GameContext, river: &River, method: CrossingMethod) -> String {
+        let report = ctx.journey.cross_river(river, method, &mut ctx.rng);
+        let mut lines = vec![match report.result {
+            CrossingResult::Safe => "You made it safely across.".to_string(),
+            CrossingResult::WetSupplies => "Your supplies got wet. You lose a day drying them out.".to_string(),
+            CrossingResult::StuckInMud => {
+                "The river is too shallow to float.\nYou lose a day digging the wagon out of the mud.".to_string()
+            }
+            CrossingResult::Tipped => "The wagon tipped over while crossing!".to_string(),
+        }];
+        for (item, quantity) in &report.lost {
+            lines.push(format!("Lost: {} {}", quantity, item.name()));
+        }
+        for name in &report.drowned {
+            lines.push(format!("{} has drowned.", name));
+        }
+        lines.join("\n")
+    }
+
+    /// Camp by the river for some days
+    fn wait(&mut self, ctx: &mut GameContext, days: u32) {
+        let mut note = format!("You camp near the river for {} day{}.", days, if days == 1 { "" } else { "s" });
+        for _ in 0..days {
+            let report = ctx.journey.camp_day(&mut ctx.rng);
+            for name in &report.deaths {
+                note.push_str(&format!(" {} has died.", name));
+            }
+        }
+        self.note = Some(note);
+        self.refresh(ctx);
+    }
+
+    /// Ask before paying for a ferry or guide
+    fn ask(&mut self, ctx: &mut GameContext, method: CrossingMethod) {
+        let title = method.label();
+        if !ctx.journey.can_pay_for(method) {
+            self.dialog = Some((Dialog::message(title, cannot_pay(method)), None));
+            return;
+        }
+        let wait_days = match method {
+            CrossingMethod::Ferry => River::ferry_wait(ctx.rng.stream(RngStream::River)),
+            _ => 0,
+        };
+        self.dialog = Some((Dialog::confirm(title, &offer(method, wait_days)), Some((method, wait_days))));
+    }
+
+    /// Act on a choice from the menu, or the stream's vote
+    fn choose(&mut self, ctx: &mut GameContext, river: &River, choice: RiverChoice) -> SceneTransition {
+        match choice {
+            RiverChoice::Cross(method @ (CrossingMethod::Ferry | CrossingMethod::Guide)) => {
+                self.ask(ctx, method);
+                SceneTransition::None
+            }
+            RiverChoice::Cross(method) => self.finish(ctx, river, method),
+            RiverChoice::Wait => {
+                self.wait(ctx, 1);
+                SceneTransition::None
+            }
+            RiverChoice::Information => {
+                SceneTransition::Push(Box::new(MessageScene::new(GameState::RiverCrossing, "River crossings", &advice())))
+            }
+        }
+    }
+
+    /// Cross and show what happened in place of this screen
+    fn finish(&self, ctx: &mut GameContext, river: &River, method: CrossingMethod) -> SceneTransition {
+        let summary = self.cross(ctx, river, method);
+        SceneTransition::Replace(Box::new(MessageScene::new(GameState::RiverCrossing, &self.name, &summary)))
+    }
+}
+
+impl Scene for RiverCrossingScene {
+    fn state(&self) -> GameState {
+        GameState::RiverCrossing
+    }
+
+    fn asset_group(&self) -> Option<&'static str> {
+        Some("river")
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.refresh(ctx);
+        // Votes cast before the party reached this river don't count
+        ctx.control.take_winner(Decision::River);
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        let Some(river) = self.river else {
+            return SceneTransition::Pop;
+        };
+        if ctx.journey.party_died() {
+            return SceneTransition::Pop;
+        }
+
+        if let Some((dialog, paid)) = &mut self.dialog {
+            let Some(choice) = dialog.update(dt) else {
+                return SceneTransition::None;
+            };
+            let paid = *paid;
+            self.dialog = None;
+            return match paid {
+                Some((method, wait_days)) if choice == 0 => {
+                    if wait_days > 0 {
+                        self.wait(ctx, wait_days);
+                        if ctx.journey.party_died() {
+                            return SceneTransition::Pop;
+                        }
+                    }
+                    let river = self.river.unwrap_or(river);
+                    self.finish(ctx, &river, method)
+                }
+                _ => SceneTransition::None,
+            };
+        }
+
+        // The stream's vote closes a while after the first vote, and its pick is taken
+        if !ctx.control.tally(Decision::River).is_empty() {
+            self.vote_timer += dt;
+            if self.vote_timer >= VOTE_SECONDS {
+                self.vote_timer = 0.0;
+                let method = ctx.control.take_winner(Decision::River).and_then(|choice| control::crossing_vote(&choice));
+                if let Some(choice) = method.map(RiverChoice::Cross).filter(|c| self.choices.contains(c)) {
+                    return self.choose(ctx, &river, choice);
+                }
+            }
+        }
+
+        match self.list.update().and_then(|i| self.choices.get(i).copied()) {
+            Some(choice) => self.choose(ctx, &river, choice),
+            None => SceneTransition::None,
+        }
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let picture = Rect::new(10.0, 10.0, screen_width() - 20.0, screen_height() - PANEL_HEIGHT - 30.0);
+        match ctx.assets.get_texture("RIVERX.png") {
+            Some(texture) => draw_texture_ex(
+                texture,
+                picture.x,
+                picture.y,
+                WHITE,
+                DrawTextureParams {
+                    dest_size: Some(vec2(picture.w, picture.h)),
+                    ..Default::default()
+                },
+            ),
+            None => draw_rectangle(picture.x, picture.y, picture.w, picture.h, Color::new(0.2, 0.35, 0.6, 1.0)),
+        }
+
+        let panel = Self::panel_rect();
+        draw_panel(panel);
+        draw_text(&self.name, panel.x + 20.0, panel.y + 28.0, style::FONT_SIZE + 4.0, style::theme().text);
+        draw_text(&self.status, panel.x + panel.w - 300.0, panel.y + 28.0, style::FONT_SIZE, style::theme().text_disabled);
+
+        let mut text = self.river.map(|r| r.describe()).unwrap_or_default();
+        if let Some(note) = &self.note {
+            text = format!("{} {}", note, text);
+        }
+        for (i, line) in wrap_text(&text, LINE_CHARS).iter().take(3).enumerate() {
+            draw_text(line, panel.x + 20.0, panel.y + 54.0 + i as f32 * 20.0, style::FONT_SIZE, style::theme().text);
+        }
+
+        self.list.draw();
+
+        let votes = ctx.control.tally(Decision::River);
+        if !votes.is_empty() {
+            let counts: Vec<String> = votes.iter().map(|(choice, count)| format!("{} {}", choice, count)).collect();
+            let closing = (VOTE_SECONDS - self.vote_timer).ceil();
+            let line = format!("Stream votes: {}  (closes in {}s)", counts.join(", "), closing);
+            draw_rectangle(panel.x, panel.y - 30.0, panel.w, 26.0, Color::new(0.0, 0.0, 0.0, 0.6));
+            draw_text(&line, panel.x + 20.0, panel.y - 11.0, style::FONT_SIZE, style::theme().focus);
+        }
+
+        if let Some((dialog, _)) = &self.dialog {
+            dialog.draw();
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use macroquad::prelude::Vec2;
+use crate::engine::cursor::Cursor;
+use crate::engine::input::{self, Action};
+use crate::engine::transition::{ScreenTransition, DEFAULT_DURATION};
+use crate::game::{GameContext, GameState};
+use crate::scenes::loading::{self, HINTS_FILE};
+
+/// A single game screen managed by the SceneManager
+pub trait Scene {
+    /// The game state this scene represents
+    fn state(&self) -> GameState;
+
+    /// Manifest group whose assets must be loaded before the scene is entered
+    fn asset_group(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Called once the scene's assets are loaded and it becomes active
+    fn on_enter(&mut self, _ctx: &mut GameContext) {}
+
+    /// Called when the scene is removed from the stack
+    fn on_exit(&mut self, _ctx: &mut GameContext) {}
+
+    /// Update the scene and report any transition it wants to make
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition;
+
+    /// Draw the scene
+    fn draw(&self, ctx: &GameContext);
+
+    /// Whether the scene below this one should still be drawn (dialogs, pause menus)
+    fn is_overlay(&self) -> bool {
+        false
+    }
+
+    /// Cursor drawn over the scene
+    fn cursor(&self) -> Cursor {
+        Cursor::Arrow
+    }
+
+    /// Where the cursor is drawn, for scenes that move it themselves rather
+    /// than leaving it on the mouse
+    fn cursor_position(&self) -> Option<Vec2> {
+        None
+    }
+}
+
+/// Transition requested by a scene after an update
+pub enum SceneTransition {
+    /// Stay on the current scene
+    None,
+    /// Layer a new scene on top of the current one
+    Push(Box<dyn Scene>),
+    /// Remove the current scene and return to the one below it
+    Pop,
+    /// Swap the current scene for a new one
+    Replace(Box<dyn Scene>),
+    /// Remove every scene and start again from a new one
+    Reset(Box<dyn Scene>),
+    /// Exit the game
+    Quit,
+}
+
+/// Holds the stack of active scenes and applies transitions between them
+pub struct SceneManager {
+    /// Scene stack, the last entry is the active scene
+    stack: Vec<Box<dyn Scene>>,
+    /// Transition waiting to be applied at the end of the frame
+    pending: Option<SceneTransition>,
+    /// Scene waiting for its assets before it is entered
+    loading: Option<Box<dyn Scene>>,
+    /// Tip shown on the loading screen
+    hint: Option<String>,
+    /// Loading screens shown so far, used to cycle through the tips
+    loads: usize,
+    /// Screen effect playing between two scenes
+    effect: Option<ScreenTransition>,
+    /// Transition applied once the effect has covered the screen
+    covered: Option<SceneTransition>,
+}
+
+impl SceneManager {
+    /// Create an empty scene manager
+    pub fn new() -> Self {
+        Self {
+            stack: Vec::new(),
+            pending: None,
+            loading: None,
+            hint: None,
+            loads: 0,
+            effect: None,
+            covered: None,
+        }
+    }
+
+    /// Get the game state of the active scene
+    pub fn current_state(&self) -> Option<GameState> {
+        self.stack.last().map(|scene| scene.state())
+    }
+
+    /// Queue a transition to be applied on the next call to apply_pending
+    pub fn request(&mut self, transition: SceneTransition) {
+        self.pending = Some(transition);
+    }
+
+    /// Play a screen effect, applying the transition while the screen is covered
+    pub fn request_with_effect(&mut self, transition: SceneTransition, effect: ScreenTransition) {
+        self.effect = Some(effect);
+        self.covered = Some(transition);
+    }
+
+    /// Whether a scene is waiting for its assets
+    pub fn is_loading(&self) -> bool {
+        self.loading.is_some()
+    }
+
+    /// Update the active scene, queueing any transition it requests
+    pub fn update(&mut self, ctx: &mut GameContext, dt: f32) {
+        // Scenes wait while an effect plays; Enter or Esc skips it
+        if let Some(effect) = &mut self.effect {
+            if input::is_action_pressed(Action::Confirm) || input::is_action_pressed(Action::Cancel) {
+                effect.skip();
+            }
+            effect.update(dt);
+            return;
+        }
+        if self.is_loading() {
+            return;
+        }
+        if let Some(scene) = self.stack.last_mut() {
+            let transition = scene.update(ctx, dt);
+            if !matches!(transition, SceneTransition::None) {
+                self.pending = Some(transition);
+            }
+        }
+    }
+
+    /// Apply the queued transition, loading assets for any scene being entered
+    pub async fn apply_pending(&mut self, ctx: &mut GameContext) {
+        if let Some(effect) = self.effect {
+            if effect.is_covered() {
+                if let Some(transition) = self.covered.take() {
+                    self.apply(transition, ctx).await;
+                }
+            }
+            if effect.is_finished() {
+                self.effect = None;
+            }
+        }
+
+        if self.is_loading() {
+            self.load_step(ctx).await;
+            return;
+        }
+
+        let Some(transition) = self.pending.take() else {
+            return;
+        };
+
+        // Whole-screen changes play the player's transition effect
+        if matches!(transition, SceneTransition::Replace(_) | SceneTransition::Reset(_)) && self.is_allowed(&transition) {
+            let effect = ScreenTransition::new(ctx.settings.transition, DEFAULT_DURATION);
+            if !effect.is_finished() {
+                self.request_with_effect(transition, effect);
+                return;
+            }
+        }
+        self.apply(transition, ctx).await;
+    }
+
+    /// Whether the current state may lead to the scene a transition enters
+    fn is_allowed(&self, transition: &SceneTransition) -> bool {
+        match transition {
+            SceneTransition::Push(scene) | SceneTransition::Replace(scene) | SceneTransition::Reset(scene) => {
+                self.current_state().is_none_or(|from| from.can_transition_to(scene.state()))
+            }
+            _ => true,
+        }
+    }
+
+    /// Change the scene stack, loading assets for any scene being entered
+    async fn apply(&mut self, transition: SceneTransition, ctx: &mut GameContext) {
+        let from = self.current_state();
+
+        // Refuse scenes the current state can't lead to, staying where we are
+        if !self.is_allowed(&transition) {
+            if let SceneTransition::Push(scene) | SceneTransition::Replace(scene) | SceneTransition::Reset(scene) = &transition {
+                log::warn!("Refusing transition from {:?} to {:?}", from, scene.state());
+            }
+            return;
+        }
+
+        match transition {
+            SceneTransition::None => {}
+            SceneTransition::Push(scene) => {
+                self.enter(scene, ctx).await;
+            }
+            SceneTransition::Pop => {
+                if let Some(scene) = self.stack.pop() {
+                    Self::exit(scene, ctx);
+                }
+            }
+            SceneTransition::Replace(scene) => {
+                if let Some(old) = self.stack.pop() {
+                    Self::exit(old, ctx);
+                }
+                self.enter(scene, ctx).await;
+            }
+            SceneTransition::Reset(scene) => {
+                while let Some(old) = self.stack.pop() {
+                    Self::exit(old, ctx);
+                }
+                self.enter(scene, ctx).await;
+            }
+            SceneTransition::Quit => {
+                ctx.exit_requested = true;
+            }
+        }
+
+        let to = self.current_state();
+        if from != to {
+            log::debug!("Transitioning from {:?} to {:?}", from, to);
+        }
+    }
+
+    /// Enter a scene, first showing the loading screen if its assets aren't all in
+    async fn enter(&mut self, scene: Box<dyn Scene>, ctx: &mut GameContext) {
+        if let Some(group) = scene.asset_group().filter(|group| !ctx.assets.is_group_loaded(group)) {
+            match ctx.assets.queue_group(group) {
+                Ok(()) => {
+                    let hints = ctx.assets.get_text(HINTS_FILE);
+                    self.hint = hints.and_then(|text| loading::hint(text, self.loads)).map(str::to_string);
+                    self.loads += 1;
+                    self.loading = Some(scene);
+                    return;
+                }
+                Err(e) => log::error!("{}", e),
+            }
+        }
+        self.push_entered(scene, ctx);
+    }
+
+    /// Load the next of the waiting scene's assets, entering it once they're all in
+    async fn load_step(&mut self, ctx: &mut GameContext) {
+        if let Some(Err(e)) = ctx.assets.load_next().await {
+            log::error!("{}", e);
+        }
+
+        let group = self.loading.as_ref().and_then(|scene| scene.asset_group());
+        if group.is_none_or(|group| ctx.assets.is_group_ready(group)) {
+            if let Some(scene) = self.loading.take() {
+                self.push_entered(scene, ctx);
+            }
+        }
+    }
+
+    /// Call a scene's enter hook and put it on top of the stack
+    fn push_entered(&mut self, mut scene: Box<dyn Scene>, ctx: &mut GameContext) {
+        if let Some(group) = scene.asset_group() {
+            ctx.assets.acquire_group(group);
+        }
+        scene.on_enter(ctx);
+        self.stack.push(scene);
+    }
+
+    /// Call a scene's exit hook as it leaves the stack, letting go of its assets
+    fn exit(mut scene: Box<dyn Scene>, ctx: &mut GameContext) {
+        scene.on_exit(ctx);
+        if let Some(group) = scene.asset_group() {
+            ctx.assets.release_group(group);
+        }
+    }
+
+    /// Draw the active scene, or the loading screen, with any screen effect on top
+    pub fn draw(&self, ctx: &GameContext) {
+        if self.is_loading() {
+            loading::draw_loading_screen(ctx.assets.progress(), self.hint.as_deref());
+        } else {
+            self.draw_stack(ctx);
+        }
+
+        if let Some(effect) = &self.effect {
+            effect.draw();
+        }
+    }
+
+    /// Cursor for the active scene and where it goes, or the hourglass while
+    /// a scene loads
+    pub fn cursor(&self) -> (Cursor, Option<Vec2>) {
+        match self.stack.last() {
+            _ if self.is_loading() => (Cursor::Hourglass, None),
+            Some(scene) => (scene.cursor(), scene.cursor_position()),
+            None => (Cursor::Arrow, None),
+        }
+    }
+
+    /// Draw the active scene and the scenes visible beneath it
+    fn draw_stack(&self, ctx: &GameContext) {
+        // Find the lowest scene that needs drawing
+        let mut first = self.stack.len();
+        for (i, scene) in self.stack.iter().enumerate().rev() {
+            first = i;
+            if !scene.is_overlay() {
+                break;
+            }
+        }
+
+        for scene in &self.stack[first..] {
+            scene.draw(ctx);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use macroquad::prelude::*;
+use crate::engine::display::{self, screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::graves::{Graveyard, GRAVES_FILE};
+use crate::game_logic::stats::{LifetimeStats, STATS_FILE};
+use crate::game_logic::summary::{self, SUMMARY_FILE, SUMMARY_IMAGE_FILE};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::title_screen::TitleScreen;
+
+/// Height of one line of text
+const LINE_HEIGHT: f32 = 26.0;
+
+/// End of the journey: how it ended, the final score and the trip's statistics
+pub struct ScoreScene {
+    /// How the journey ended, one line per entry
+    message: Vec<String>,
+    /// Where the summary was written, or why it couldn't be
+    notice: String,
+    /// Best score over every journey
+    best_score: u32,
+    /// Whether the score or graves files were edited outside the game
+    tampered: bool,
+}
+
+impl ScoreScene {
+    /// Create the scoring screen with the message announcing how the journey ended
+    pub fn new(message: &str) -> Self {
+        Self {
+            message: message.lines().map(str::to_string).collect(),
+            notice: String::new(),
+            best_score: 0,
+            tampered: false,
+        }
+    }
+}
+
+impl Scene for ScoreScene {
+    fn state(&self) -> GameState {
+        GameState::GameOver
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.notice = match summary::export(&ctx.journey, SUMMARY_FILE) {
+            Ok(()) => format!("Summary written to {}. P saves a picture.", SUMMARY_FILE),
+            Err(e) => e,
+        };
+
+        let lifetime = LifetimeStats::load(ctx.storage.as_ref(), &ctx.profile_file(STATS_FILE)).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            LifetimeStats::default()
+        });
+        let graveyard = Graveyard::load(ctx.storage.as_ref(), GRAVES_FILE).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            Graveyard::default()
+        });
+        self.best_score = lifetime.best_score;
+        self.tampered = lifetime.tampered || graveyard.tampered();
+        if self.tampered {
+            log::warn!("The score or graves files were edited outside the game");
+        }
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Confirm) || input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Replace(Box::new(TitleScreen::new()));
+        }
+        // The canvas still holds the last frame, so this captures the screen as shown
+        if is_key_pressed(KeyCode::P) {
+            self.notice = match display::save_canvas(SUMMARY_IMAGE_FILE) {
+                Ok(()) => format!("Picture saved to {}.", SUMMARY_IMAGE_FILE),
+                Err(e) => e,
+            };
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text("The Oregon Trail", panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+
+        // Best score on record in the top right, flagged if the records were edited
+        let right = panel.x + panel.w - 20.0;
+        let best = format!("Best on record: {}", self.best_score);
+        let width = measure_text(&best, None, style::FONT_SIZE as u16, 1.0).width;
+        draw_text(&best, right - width, panel.y + 28.0, style::FONT_SIZE, style::theme().text_disabled);
+        if self.tampered {
+            let warning = "Records edited outside the game!";
+            let width = measure_text(warning, None, style::FONT_SIZE as u16, 1.0).width;
+            draw_text(warning, right - width, panel.y + 52.0, style::FONT_SIZE, style::theme().focus);
+        }
+
+        let mut y = panel.y + 76.0;
+        for line in &self.message {
+            draw_text(line, panel.x + 20.0, y, style::FONT_SIZE, style::theme().text);
+            y += LINE_HEIGHT;
+        }
+
+        y += LINE_HEIGHT;
+        let journey = &ctx.journey;
+        let mut summary = format!("{} days on the trail, {:.0} miles", journey.days, journey.stats.miles());
+        if journey.cheated {
+            summary.push_str(" (cheats used)");
+        }
+        draw_text(&summary, panel.x + 20.0, y, style::FONT_SIZE, style::theme().text);
+        for line in journey.stats.lines() {
+            y += LINE_HEIGHT;
+            draw_text(&line, panel.x + 20.0, y, style::FONT_SIZE, style::theme().text_disabled);
+        }
+
+        draw_text(&self.notice, panel.x + 20.0, panel.y + panel.h - 48.0, style::FONT_SIZE, style::theme().text_disabled);
+        draw_text("Press Enter to continue", panel.x + 20.0, panel.y + panel.h - 20.0, style::FONT_SIZE, style::theme().text_disabled);
+    }
+}
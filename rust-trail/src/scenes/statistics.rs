@@ -0,0 +1,63 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::stats::{LifetimeStats, STATS_FILE};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Height of one line of text
+const LINE_HEIGHT: f32 = 28.0;
+
+/// Totals over every journey played
+pub struct StatisticsScene {
+    /// Lifetime totals, read when the scene is entered
+    lifetime: LifetimeStats,
+}
+
+impl StatisticsScene {
+    /// Create the statistics page
+    pub fn new() -> Self {
+        Self { lifetime: LifetimeStats::default() }
+    }
+}
+
+impl Scene for StatisticsScene {
+    fn state(&self) -> GameState {
+        GameState::MainMenu
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.lifetime = LifetimeStats::load(ctx.storage.as_ref(), &ctx.profile_file(STATS_FILE)).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            LifetimeStats::default()
+        });
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Cancel) || input::is_action_pressed(Action::Confirm) {
+            return SceneTransition::Pop;
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text("Lifetime statistics", panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+
+        if self.lifetime.journeys == 0 {
+            draw_text("No journeys finished yet.", panel.x + 20.0, panel.y + 80.0, style::FONT_SIZE, style::theme().text_disabled);
+        } else {
+            for (i, line) in self.lifetime.lines().iter().enumerate() {
+                let y = panel.y + 80.0 + i as f32 * LINE_HEIGHT;
+                draw_text(line, panel.x + 20.0, y, style::FONT_SIZE, style::theme().text);
+            }
+        }
+
+        draw_text("Esc to go back", panel.x + 20.0, panel.y + panel.h - 20.0, style::FONT_SIZE, style::theme().text_disabled);
+    }
+}
@@ -0,0 +1,209 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::button::{Button, ButtonGroup, PRESS_SOUND};
+use crate::engine::ui::focus::FocusAxis;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::journey::Journey;
+use crate::game_logic::store;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Height of one item row
+const ROW_HEIGHT: f32 = 28.0;
+
+/// Most lots that can be bought at once
+const MAX_LOTS: u32 = 20;
+
+/// Width of the buttons along the bottom of the store
+const BUTTON_WIDTH: f32 = 110.0;
+/// Height of the buttons along the bottom of the store
+const BUTTON_HEIGHT: f32 = 32.0;
+
+/// Units sold together: food by the 25 pounds, bullets by the box of 20
+pub fn lot_size(item: ItemType) -> u32 {
+    match item {
+        ItemType::Food => 25,
+        ItemType::Ammunition => 20,
+        _ => 1,
+    }
+}
+
+/// Buttons along the bottom of the store
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StoreButton {
+    Buy,
+    Leave,
+}
+
+/// Most lots of an item the party can buy now, limited by the store's
+/// stock and the party's money
+fn most_lots(journey: &Journey, item: ItemType) -> u32 {
+    let lot = lot_size(item);
+    let lot_price = store::price(item, journey.player.miles_traveled) * lot;
+    let affordable = match lot_price.cents() {
+        0 => MAX_LOTS,
+        cents => journey.player.money.cents() / cents,
+    };
+    (store::in_stock(journey, item) / lot).min(affordable).min(MAX_LOTS)
+}
+
+/// A fort's store, selling what's left on its shelves at frontier prices
+pub struct StoreScene {
+    /// Items for sale
+    list: ListBox,
+    /// Buy and Leave buttons, for the mouse; the list keeps the keyboard
+    buttons: ButtonGroup<StoreButton>,
+    /// Lots of the selected item to buy
+    lots: u32,
+    /// Result of the last purchase
+    notice: String,
+}
+
+impl StoreScene {
+    /// Create the store screen
+    pub fn new() -> Self {
+        let mut list = ListBox::new(Self::list_rect(), vec![String::new(); ItemType::ALL.len()]);
+        list.select(0);
+        let buttons = [(StoreButton::Buy, "Buy"), (StoreButton::Leave, "Leave")]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (action, label))| Button::text(action, Self::button_rect(i), label))
+            .collect();
+        let mut buttons = ButtonGroup::new(buttons, FocusAxis::Horizontal);
+        buttons.set_keyboard(false);
+        Self { list, buttons, lots: 1, notice: String::new() }
+    }
+
+    /// Where a button sits, in a row at the bottom right of the panel
+    fn button_rect(index: usize) -> Rect {
+        let panel = Self::panel_rect();
+        let x = panel.x + panel.w - 20.0 - (2 - index) as f32 * (BUTTON_WIDTH + 10.0) + 10.0;
+        Rect::new(x, panel.y + panel.h - 20.0 - BUTTON_HEIGHT, BUTTON_WIDTH, BUTTON_HEIGHT)
+    }
+
+    /// Buy the chosen lots of an item
+    fn buy(&mut self, journey: &mut Journey, index: usize) {
+        let item = ItemType::ALL[index];
+        let quantity = self.lots * lot_size(item);
+        self.notice = match store::buy(journey, item, quantity) {
+            Ok(cost) => format!("You bought {} {} for {}.", quantity, item.name(), cost),
+            Err(e) => e,
+        };
+        self.lots = 1;
+        self.refresh(journey);
+    }
+
+    /// Area of the store panel
+    fn panel_rect() -> Rect {
+        Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0)
+    }
+
+    /// Area of the item list inside the panel
+    fn list_rect() -> Rect {
+        let panel = Self::panel_rect();
+        Rect::new(panel.x + 20.0, panel.y + 90.0, panel.w - 40.0, ROW_HEIGHT * ItemType::ALL.len() as f32)
+    }
+
+    /// Refresh prices and stock on each row, greying out what's sold out or
+    /// more than the party can pay for
+    fn refresh(&mut self, journey: &Journey) {
+        let miles = journey.player.miles_traveled;
+        let labels = ItemType::ALL
+            .iter()
+            .map(|&item| {
+                let stock = store::in_stock(journey, item);
+                let price = store::price(item, miles) * lot_size(item);
+                let unit = match lot_size(item) {
+                    1 => "each".to_string(),
+                    size => format!("per {}", size),
+                };
+                match stock {
+                    0 => format!("{} - sold out", item.name()),
+                    _ => format!("{} - {} {} ({} left)", item.name(), price, unit, stock),
+                }
+            })
+            .collect();
+        self.list.set_labels(labels);
+        for (i, &item) in ItemType::ALL.iter().enumerate() {
+            self.list.set_enabled(i, most_lots(journey, item) > 0);
+        }
+        let buyable = self.selected_most(journey);
+        self.buttons.set_enabled(0, buyable > 0 && buyable >= self.lots);
+    }
+
+    /// Most lots of the selected item the party can buy
+    fn selected_most(&self, journey: &Journey) -> u32 {
+        self.list.selected().map_or(0, |i| most_lots(journey, ItemType::ALL[i]))
+    }
+}
+
+impl Scene for StoreScene {
+    fn state(&self) -> GameState {
+        GameState::Travel
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.buttons.set_sounds(None, ctx.assets.get_sound(PRESS_SOUND));
+        self.refresh(&ctx.journey);
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Pop;
+        }
+        let most = self.selected_most(&ctx.journey);
+        if input::is_action_pressed(Action::MenuLeft) {
+            self.lots = self.lots.saturating_sub(1).max(1);
+        }
+        if input::is_action_pressed(Action::MenuRight) {
+            self.lots = (self.lots + 1).min(most.max(1));
+        }
+
+        if let Some(index) = self.list.update() {
+            self.buy(&mut ctx.journey, index);
+        }
+        // Another item may have been chosen, with fewer lots to buy
+        self.lots = self.lots.min(self.selected_most(&ctx.journey).max(1));
+        self.refresh(&ctx.journey);
+
+        match self.buttons.update(dt) {
+            Some(StoreButton::Buy) => {
+                if let Some(index) = self.list.selected() {
+                    self.buy(&mut ctx.journey, index);
+                }
+            }
+            Some(StoreButton::Leave) => return SceneTransition::Pop,
+            None => {}
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Self::panel_rect();
+        draw_panel(panel);
+        let journey = &ctx.journey;
+        let title = format!("{} store", journey.last_landmark().name);
+        draw_text(&title, panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+        let money = format!("You have {}", journey.player.money);
+        draw_text(&money, panel.x + 20.0, panel.y + 66.0, style::FONT_SIZE, style::theme().text_disabled);
+
+        self.list.draw();
+
+        let list = Self::list_rect();
+        let mut y = list.y + list.h + 36.0;
+        if let Some(item) = self.list.selected().map(|i| ItemType::ALL[i]) {
+            let quantity = format!("Buy {} {}  (Left/Right to change)", self.lots * lot_size(item), item.name());
+            draw_text(&quantity, panel.x + 20.0, y, style::FONT_SIZE, style::theme().text);
+            y += 30.0;
+        }
+        draw_text(&self.notice, panel.x + 20.0, y, style::FONT_SIZE, style::theme().text);
+        self.buttons.draw();
+        draw_text("Esc to leave the store", panel.x + 20.0, panel.y + panel.h - 20.0, style::FONT_SIZE, style::theme().text_disabled);
+    }
+}
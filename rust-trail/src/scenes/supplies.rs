@@ -0,0 +1,109 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::inventory::{Inventory, ItemType};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Height of one supply row
+const ROW_HEIGHT: f32 = 28.0;
+
+/// "Your Supplies" screen listing the wagon's contents and load
+pub struct SuppliesScene {
+    /// State of the scene this screen was opened from
+    state: GameState,
+}
+
+impl SuppliesScene {
+    /// Create the supplies screen for a scene in the given state
+    pub fn new(state: GameState) -> Self {
+        Self { state }
+    }
+
+    /// Rows of (name, quantity, weight), in the order the store sells them
+    fn rows(inventory: &Inventory) -> Vec<(&'static str, u32, f32)> {
+        let mut items = inventory.get_all_items();
+        items.sort_by_key(|item| ItemType::ALL.iter().position(|t| *t == item.item_type));
+        items
+            .iter()
+            .map(|item| (item.item_type.name(), item.quantity, item.total_weight()))
+            .collect()
+    }
+}
+
+impl Scene for SuppliesScene {
+    fn state(&self) -> GameState {
+        self.state
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Confirm) || input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Pop;
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text("Your Supplies", panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+
+        // Two columns: item names on the left, quantities and weights on the right
+        let name_x = panel.x + 40.0;
+        let quantity_x = panel.x + panel.w * 0.6;
+        let weight_x = panel.x + panel.w * 0.8;
+        let mut y = panel.y + 80.0;
+
+        draw_text("Item", name_x, y, style::FONT_SIZE, style::theme().text_disabled);
+        draw_text("Amount", quantity_x, y, style::FONT_SIZE, style::theme().text_disabled);
+        draw_text("Weight", weight_x, y, style::FONT_SIZE, style::theme().text_disabled);
+        y += ROW_HEIGHT;
+
+        let inventory = &ctx.journey.inventory;
+        let rows = Self::rows(inventory);
+        if rows.is_empty() {
+            draw_text("The wagon is empty.", name_x, y, style::FONT_SIZE, style::theme().text);
+            y += ROW_HEIGHT;
+        }
+        for (name, quantity, weight) in rows {
+            draw_text(name, name_x, y, style::FONT_SIZE, style::theme().text);
+            draw_text(&quantity.to_string(), quantity_x, y, style::FONT_SIZE, style::theme().text);
+            draw_text(&format!("{:.0} lbs", weight), weight_x, y, style::FONT_SIZE, style::theme().text);
+            y += ROW_HEIGHT;
+        }
+
+        draw_text(
+            &format!("money left: {}", ctx.journey.player.money),
+            name_x,
+            y + 10.0,
+            style::FONT_SIZE,
+            style::theme().text,
+        );
+
+        // Wagon load bar
+        let (weight, capacity, percent) = inventory.capacity_info();
+        let bar = Rect::new(name_x, panel.y + panel.h - 80.0, panel.w - 80.0, 20.0);
+        let fill = if weight > capacity { style::theme().danger } else { style::theme().highlight };
+        draw_rectangle(bar.x, bar.y, bar.w * (percent / 100.0).min(1.0), bar.h, fill);
+        draw_rectangle_lines(bar.x, bar.y, bar.w, bar.h, 2.0, style::theme().border);
+        draw_text(
+            &format!("Wagon load: {:.0} of {:.0} pounds ({:.0}%)", weight, capacity, percent),
+            bar.x,
+            bar.y - 8.0,
+            style::FONT_SIZE,
+            style::theme().text,
+        );
+
+        draw_text(
+            "Press ENTER to continue",
+            panel.x + 20.0,
+            panel.y + panel.h - 20.0,
+            style::FONT_SIZE,
+            style::theme().text_disabled,
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::{draw_text, draw_texture_ex};
+use crate::engine::ui::text_box::TextBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::quotes::{QuoteBook, QUOTES_FILE};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::utils::rng::RngStream;
+
+/// Lines of speech shown per page
+const LINES_PER_PAGE: usize = 7;
+/// Height of one line of speech
+const LINE_HEIGHT: f32 = 24.0;
+
+/// Someone on the trail sharing advice or news, a page at a time
+pub struct TalkScene {
+    /// Who is speaking
+    speaker: String,
+    /// Portrait image for the speaker
+    portrait: String,
+    /// What they say, a page at a time
+    text: TextBox,
+}
+
+impl TalkScene {
+    /// Create the talk scene; the speaker is chosen when it's entered
+    pub fn new() -> Self {
+        Self {
+            speaker: String::new(),
+            portrait: String::new(),
+            text: TextBox::new(Self::text_rect()),
+        }
+    }
+
+    /// Panel the speech is shown in
+    fn panel_rect() -> Rect {
+        Rect::new(270.0, 60.0, screen_width() - 300.0, 300.0)
+    }
+
+    /// Area of the speech inside its panel
+    fn text_rect() -> Rect {
+        let panel = Self::panel_rect();
+        Rect::new(panel.x + 16.0, panel.y + 48.0, panel.w - 32.0, LINE_HEIGHT * LINES_PER_PAGE as f32)
+    }
+}
+
+impl Scene for TalkScene {
+    fn state(&self) -> GameState {
+        GameState::Travel
+    }
+
+    fn asset_group(&self) -> Option<&'static str> {
+        Some("talk")
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        let book = match ctx.assets.get_text(QUOTES_FILE).map(QuoteBook::parse) {
+            Some(Ok(book)) => book,
+            Some(Err(e)) => {
+                log::error!("Failed to parse {}: {}", QUOTES_FILE, e);
+                QuoteBook::default()
+            }
+            None => QuoteBook::default(),
+        };
+
+        let landmark = &ctx.journey.last_landmark().id;
+        let month = ctx.journey.player.date.month();
+        let text = match book.pick(landmark, month, ctx.rng.stream(RngStream::Events)) {
+            Some(quote) => {
+                self.speaker = quote.speaker.clone();
+                self.portrait = format!("TALK{}.png", quote.portrait);
+                quote.text.clone()
+            }
+            None => "There's no one around to talk to.".to_string(),
+        };
+
+        self.text = TextBox::new(Self::text_rect()).with_speed(ctx.settings.text_speed.chars_per_second());
+        self.text.set_text(&text);
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        self.text.update(dt);
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Pop;
+        }
+        if input::is_action_pressed(Action::Confirm) && !self.text.advance() {
+            return SceneTransition::Pop;
+        }
+        if input::is_action_pressed(Action::MenuLeft) {
+            self.text.previous();
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let portrait = Rect::new(30.0, 60.0, 220.0, 300.0);
+        match ctx.assets.get_texture(&self.portrait) {
+            Some(texture) => draw_texture_ex(
+                texture,
+                portrait.x,
+                portrait.y,
+                WHITE,
+                DrawTextureParams {
+                    dest_size: Some(vec2(portrait.w, portrait.h)),
+                    ..Default::default()
+                },
+            ),
+            None => draw_rectangle_lines(portrait.x, portrait.y, portrait.w, portrait.h, 2.0, style::theme().border),
+        }
+
+        let panel = Self::panel_rect();
+        draw_panel(panel);
+        draw_text(&self.speaker, panel.x + 16.0, panel.y + 30.0, style::FONT_SIZE + 4.0, style::theme().text);
+        self.text.draw();
+
+        let hint = if self.text.has_more() {
+            format!("Page {} of {} - press ENTER to continue", self.text.page() + 1, self.text.page_count())
+        } else {
+            "Press ENTER to continue".to_string()
+        };
+        draw_text(&hint, 30.0, screen_height() - 40.0, style::FONT_SIZE, style::theme().text_disabled);
+    }
+}
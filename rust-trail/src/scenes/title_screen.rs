@@ -1,7 +1,43 @@
 use macroquad::prelude::*;
-use crate::engine::asset_loader::AssetManager;
-use crate::scenes::button::{Button, ButtonAction};
+use crate::engine::animation::frame_source;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::{draw_text, draw_texture_ex, SpriteBatch};
+use crate::engine::ui::button::{Button, ButtonAction, ButtonGroup, PRESS_SOUND};
+use crate::engine::ui::focus::FocusAxis;
+use crate::engine::ui::style;
+use crate::game::{GameContext, GameState};
+use crate::scenes::introduction::IntroductionScene;
+use crate::scenes::profiles::ProfileScene;
+use crate::scenes::options::OptionsScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::utils::sprites::Playback;
 
+/// The title picture
+const BACKGROUND: &str = "TITLE.png";
+/// The original's animation of the picture's little scenes
+const ANIMATION: &str = "TITLE.json";
+
+/// Width of the original title picture, which button positions are measured on
+const PICTURE_WIDTH: f32 = 577.0;
+/// Height of the original title picture
+const PICTURE_HEIGHT: f32 = 418.0;
+/// Where the original drew the picture on its 640x480 screen, which the
+/// animation's positions are measured on
+const PICTURE_ORIGIN: Vec2 = vec2(31.0, 31.0);
+/// Top of the button row on the title picture
+const BUTTON_TOP: f32 = 370.0;
+/// Each button's sprite in the title button atlas and its left edge on the
+/// title picture, in focus order. The pressed sprite is the same name with
+/// "_pressed" after it
+const BUTTONS: [(ButtonAction, &str, f32); 4] = [
+    (ButtonAction::Introduction, "introduction", 34.0),
+    (ButtonAction::Options, "options", 165.0),
+    (ButtonAction::Quit, "quit", 298.0),
+    (ButtonAction::TravelTrail, "travel", 428.0),
+];
+
+/// Choices on the title screen
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TitleAction {
     StartGame,
@@ -10,154 +46,103 @@ pub enum TitleAction {
     Quit,
 }
 
+/// The title screen, with the original picture and its row of buttons
 pub struct TitleScreen {
+    /// Title picture, if it loaded
     background: Option<Texture2D>,
-    button_texture: Option<Texture2D>,
-    time: f32,
-    assets_loaded: bool,
-    buttons: Vec<Button>,
+    /// Introduction, Options, Quit and Travel the Trail
+    buttons: ButtonGroup,
+    /// How far the animation has played
+    playback: Playback,
 }
 
 impl TitleScreen {
+    /// Create the title screen
     pub fn new() -> Self {
-        Self {
-            background: None,
-            button_texture: None,
-            time: 0.0,
-            assets_loaded: false,
-            buttons: Vec::new(),
-        }
+        Self { background: None, buttons: ButtonGroup::new(Vec::new(), FocusAxis::Horizontal), playback: Playback::new() }
     }
-    
-    pub async fn load_assets(&mut self, asset_manager: &mut AssetManager) {
-        // Load background
-        if let Ok(texture) = asset_manager.load_texture("TITLE.png").await {
-            self.background = Some(texture);
-        }
-        
-        // Load button texture
-        if let Ok(texture) = asset_manager.load_texture("TITLEBTN.png").await {
-            self.button_texture = Some(texture);
-            self.init_buttons();
-        }
-        
-        self.assets_loaded = true;
+
+    /// Lay the buttons out where they sit on the original picture, scaled to
+    /// the canvas the picture fills
+    fn init_buttons(&mut self, ctx: &GameContext) {
+        let scale_x = screen_width() / PICTURE_WIDTH;
+        let scale_y = screen_height() / PICTURE_HEIGHT;
+        let sprites = |name: &str| Some((ctx.assets.get_sprite(name)?, ctx.assets.get_sprite(&format!("{}_pressed", name))?));
+        let buttons = BUTTONS
+            .iter()
+            .map(|&(action, sprite, left)| {
+                Button::new(action, vec2(left * scale_x, BUTTON_TOP * scale_y), sprites(sprite), scale_x, scale_y)
+            })
+            .collect();
+
+        // Start with "Travel the Trail" focused so Enter begins the game
+        self.buttons = ButtonGroup::new(buttons, FocusAxis::Horizontal);
+        self.buttons.set_sounds(None, ctx.assets.get_sound(PRESS_SOUND));
+        self.buttons.set_focus(BUTTONS.len() - 1);
     }
-    
-    fn init_buttons(&mut self) {
-        self.buttons.clear();
-        
-        // Get screen dimensions
-        let screen_w = screen_width();
-        let screen_h = screen_height();
-        
-        // Calculate scaling based on original TITLE.png dimensions (577 x 418)
-        // These are the original dimensions of the title image
-        let original_width = 577.0;
-        let original_height = 418.0;
-        
-        // Calculate scaling factors to maintain aspect ratio
-        let screen_scale_x = screen_w / original_width;
-        let screen_scale_y = screen_h / original_height;
-        
-        // In the original game, buttons are positioned at the bottom of the screen
-        // Original Y position was around 370 pixels in a 418 pixel height image
-        let button_y = 370.0 * screen_scale_y;
-        
-        // Create Introduction button
-        let intro_button = Button::new(
-            ButtonAction::Introduction,
-            Vec2::new(34.0 * screen_scale_x,  button_y),
-            self.button_texture,
-            screen_scale_x,
-            screen_scale_y
-        );
-        self.buttons.push(intro_button);
-        
-        // Create Options button
-        let options_button = Button::new(
-            ButtonAction::Options,
-            Vec2::new(165.0 * screen_scale_x, button_y),
-            self.button_texture,
-            screen_scale_x,
-            screen_scale_y
-        );
-        self.buttons.push(options_button);
-        
-        // Create Quit button
-        let quit_button = Button::new(
-            ButtonAction::Quit,
-            Vec2::new(298.0 * screen_scale_x, button_y),
-            self.button_texture,
-            screen_scale_x,
-            screen_scale_y
-        );
-        self.buttons.push(quit_button);
-        
-        // Create Travel Trail button
-        let travel_button = Button::new(
-            ButtonAction::TravelTrail,
-            Vec2::new(428.0 * screen_scale_x, button_y),
-            self.button_texture,
-            screen_scale_x,
-            screen_scale_y
-        );
-        self.buttons.push(travel_button);
+
+    /// Convert a button action into a title screen action
+    fn title_action(action: ButtonAction) -> TitleAction {
+        match action {
+            ButtonAction::Introduction => TitleAction::Introduction,
+            ButtonAction::Options => TitleAction::Options,
+            ButtonAction::Quit => TitleAction::Quit,
+            ButtonAction::TravelTrail => TitleAction::StartGame,
+        }
     }
-    pub fn update(&mut self, dt: f32) -> Option<TitleAction> {
-        self.time += dt;
-        
-        // Check if the screen size has changed, and update buttons if it has
-        static mut LAST_SCREEN_W: f32 = 0.0;
-        static mut LAST_SCREEN_H: f32 = 0.0;
-        
-        let current_w = screen_width();
-        let current_h = screen_height();
-        
-        unsafe {
-            if current_w != LAST_SCREEN_W || current_h != LAST_SCREEN_H {
-                self.init_buttons();
-                LAST_SCREEN_W = current_w;
-                LAST_SCREEN_H = current_h;
-            }
+
+    fn handle_input(&mut self, dt: f32) -> Option<TitleAction> {
+        // Keyboard focus: arrows/Tab move between buttons, Enter/Space activates,
+        // and hovering a button also moves focus to it
+        if let Some(action) = self.buttons.update(dt) {
+            return Some(Self::title_action(action));
         }
-        
-        
+
         // Keyboard shortcuts
-        if is_key_pressed(KeyCode::Space) || is_key_pressed(KeyCode::Enter) {
-            return Some(TitleAction::StartGame);
-        }
         if is_key_pressed(KeyCode::I) {
             return Some(TitleAction::Introduction);
         }
         if is_key_pressed(KeyCode::O) {
             return Some(TitleAction::Options);
         }
-        if is_key_pressed(KeyCode::Escape) || is_key_pressed(KeyCode::Q) {
+        if input::is_action_pressed(Action::Cancel) || is_key_pressed(KeyCode::Q) {
             return Some(TitleAction::Quit);
         }
-        
-        // Button interactions
-        for button in &mut self.buttons {
-            if let Some(action) = button.update(dt) {
-                match action {
-                    ButtonAction::Introduction => return Some(TitleAction::Introduction),
-                    ButtonAction::Options => return Some(TitleAction::Options),
-                    ButtonAction::Quit => return Some(TitleAction::Quit),
-                    ButtonAction::TravelTrail => return Some(TitleAction::StartGame),
-                }
-            }
-        }
-        
         None
     }
-    
-    pub fn draw(&self) {
+
+    /// Play the animation over the picture, its pieces drawn as one batch
+    fn draw_animation(&self, ctx: &GameContext) {
+        let Some(animation) = ctx.assets.get_animation(ANIMATION) else {
+            return;
+        };
+        let Some(texture) = ctx.assets.get_texture(&animation.texture) else {
+            return;
+        };
+        let scale = vec2(screen_width() / PICTURE_WIDTH, screen_height() / PICTURE_HEIGHT);
+        let mut batch = SpriteBatch::new();
+        for clip in &animation.clips {
+            let (Some(frame), Some(position)) = (self.playback.frame(clip), clip.position) else {
+                continue;
+            };
+            let at = (Vec2::from(position) - PICTURE_ORIGIN) * scale;
+            let (width, height) = frame.size();
+            let params = DrawTextureParams {
+                source: Some(frame_source(frame)),
+                dest_size: Some(vec2(width, height) * scale),
+                ..Default::default()
+            };
+            batch.push(texture, at.x, at.y, WHITE, params);
+        }
+        batch.flush();
+    }
+
+    fn draw_screen(&self, ctx: &GameContext) {
         clear_background(BLACK);
-        
+
         let screen_w = screen_width();
         let screen_h = screen_height();
-        
+
         // Draw background
         if let Some(texture) = self.background {
             draw_texture_ex(
@@ -170,6 +155,7 @@ impl TitleScreen {
                     ..Default::default()
                 }
             );
+            self.draw_animation(ctx);
         } else {
             // Fallback title
             let title_text = "THE OREGON TRAIL";
@@ -180,15 +166,12 @@ impl TitleScreen {
                 screen_w / 2.0 - text_size.width / 2.0,
                 screen_h / 3.0,
                 font_size,
-                WHITE
+                style::theme().text
             );
         }
-        
-        // Draw buttons
-        for button in &self.buttons {
-            button.draw();
-        }
-        
+
+        self.buttons.draw();
+
         // Draw copyright
         let copyright = "© 2025 Oregon Trail Rewrite Project";
         let font_size = 16.0;
@@ -198,11 +181,37 @@ impl TitleScreen {
             screen_w / 2.0 - text_size.width / 2.0,
             screen_h - 30.0,
             font_size,
-            GRAY
+            style::theme().text_disabled
         );
     }
-    
-    pub fn is_loaded(&self) -> bool {
-        self.assets_loaded
+}
+
+impl Scene for TitleScreen {
+    fn state(&self) -> GameState {
+        GameState::TitleScreen
+    }
+
+    fn asset_group(&self) -> Option<&'static str> {
+        Some("title")
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.background = ctx.assets.get_texture(BACKGROUND);
+        self.init_buttons(ctx);
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        self.playback.update(dt, 1.0);
+        match self.handle_input(dt) {
+            Some(TitleAction::StartGame) => SceneTransition::Replace(Box::new(ProfileScene::new())),
+            Some(TitleAction::Introduction) => SceneTransition::Replace(Box::new(IntroductionScene::new())),
+            Some(TitleAction::Options) => SceneTransition::Replace(Box::new(OptionsScene::new())),
+            Some(TitleAction::Quit) => SceneTransition::Quit,
+            None => SceneTransition::None,
+        }
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        self.draw_screen(ctx);
     }
 }
@@ -0,0 +1,121 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::engine::network::{self, Pending};
+use crate::game_logic::top_ten::{self, TopTen, TopTenEntry, TOP_TEN_FILE};
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+
+/// Height of one line of text
+const LINE_HEIGHT: f32 = 28.0;
+
+/// "See the Oregon Top Ten": the best scores of parties that reached Oregon,
+/// and beside them the online leaderboard's if the player has opted in
+pub struct TopTenScene {
+    /// The list, read when the scene is entered
+    top_ten: TopTen,
+    /// Request for the online Top Ten, while it's on its way
+    fetching: Option<Pending<Vec<TopTenEntry>>>,
+    /// The online Top Ten, or why it couldn't be had, once the reply arrives
+    online: Option<Result<Vec<TopTenEntry>, String>>,
+}
+
+impl TopTenScene {
+    /// Create the Top Ten page
+    pub fn new() -> Self {
+        Self { top_ten: TopTen::default(), fetching: None, online: None }
+    }
+
+    /// Draw the local list on its own, with ratings
+    fn draw_local(&self, panel: Rect, header: f32) {
+        let (name_x, points_x, rating_x) = (panel.x + 40.0, panel.x + 280.0, panel.x + 360.0);
+        draw_text("Name", name_x, header, style::FONT_SIZE, style::theme().text_disabled);
+        draw_text("Points", points_x - 20.0, header, style::FONT_SIZE, style::theme().text_disabled);
+        draw_text("Rating", rating_x, header, style::FONT_SIZE, style::theme().text_disabled);
+
+        for (i, entry) in self.top_ten.entries().iter().enumerate() {
+            let y = header + (i + 1) as f32 * LINE_HEIGHT;
+            draw_text(&entry.name, name_x, y, style::FONT_SIZE, style::theme().text);
+            // Points line up on their right edge
+            let points = entry.points.to_string();
+            let width = measure_text(&points, None, style::FONT_SIZE as u16, 1.0).width;
+            draw_text(&points, points_x + 20.0 - width, y, style::FONT_SIZE, style::theme().text);
+            draw_text(top_ten::rating(entry.points), rating_x, y, style::FONT_SIZE, style::theme().text);
+        }
+    }
+
+    /// Draw a list's names and points in a column, points lined up on their right edge
+    fn draw_column(entries: &[TopTenEntry], name_x: f32, points_x: f32, top: f32) {
+        for (i, entry) in entries.iter().enumerate() {
+            let y = top + (i + 1) as f32 * LINE_HEIGHT;
+            draw_text(&entry.name, name_x, y, style::FONT_SIZE, style::theme().text);
+            let points = entry.points.to_string();
+            let width = measure_text(&points, None, style::FONT_SIZE as u16, 1.0).width;
+            draw_text(&points, points_x - width, y, style::FONT_SIZE, style::theme().text);
+        }
+    }
+
+    /// Draw the local list beside the online one
+    fn draw_with_online(&self, panel: Rect, header: f32) {
+        let half = panel.w / 2.0;
+        for (x, title) in [(panel.x, "On this computer"), (panel.x + half, "Online")] {
+            draw_text(title, x + 20.0, header, style::FONT_SIZE, style::theme().text_disabled);
+        }
+        Self::draw_column(self.top_ten.entries(), panel.x + 20.0, panel.x + half - 20.0, header);
+        match &self.online {
+            Some(Ok(entries)) => Self::draw_column(entries, panel.x + half + 20.0, panel.x + panel.w - 20.0, header),
+            Some(Err(_)) => draw_text("Couldn't reach the leaderboard", panel.x + half + 20.0, header + LINE_HEIGHT, 16.0, style::theme().text_disabled),
+            None => draw_text("Loading...", panel.x + half + 20.0, header + LINE_HEIGHT, style::FONT_SIZE, style::theme().text_disabled),
+        }
+    }
+}
+
+impl Scene for TopTenScene {
+    fn state(&self) -> GameState {
+        GameState::MainMenu
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.top_ten = TopTen::load(ctx.storage.as_ref(), TOP_TEN_FILE).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            TopTen::default()
+        });
+        self.fetching = ctx.settings.leaderboard().map(network::fetch_top_ten);
+    }
+
+    fn update(&mut self, _ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if let Some(result) = self.fetching.as_mut().and_then(Pending::poll) {
+            if let Err(e) = &result {
+                log::warn!("{}", e);
+            }
+            self.online = Some(result);
+            self.fetching = None;
+        }
+        if input::is_action_pressed(Action::Cancel) || input::is_action_pressed(Action::Confirm) {
+            return SceneTransition::Pop;
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text("The Oregon Top Ten", panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+
+        let header = panel.y + 76.0;
+        if self.fetching.is_some() || self.online.is_some() {
+            self.draw_with_online(panel, header);
+        } else {
+            self.draw_local(panel, header);
+        }
+
+        if self.top_ten.tampered() {
+            draw_text("Records edited outside the game!", panel.x + 20.0, panel.y + panel.h - 44.0, style::FONT_SIZE, style::theme().focus);
+        }
+        draw_text("Esc to go back", panel.x + 20.0, panel.y + panel.h - 20.0, style::FONT_SIZE, style::theme().text_disabled);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::engine::ui::dialog::Dialog;
+use crate::game::{GameContext, GameState};
+use crate::game_logic::trading::{self, TradeOffer, MAX_TRADES_PER_DAY};
+use crate::scenes::message::MessageScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::utils::rng::RngStream;
+
+/// Title shown on trading dialogs
+const TITLE: &str = "Attempt to trade";
+
+/// A fellow emigrant's offer, with accept and decline buttons
+pub struct TradingScene {
+    /// Offer being considered, if anyone wanted to trade
+    offer: Option<TradeOffer>,
+    /// Offer or refusal dialog, built once the scene is entered
+    dialog: Option<Dialog>,
+}
+
+impl TradingScene {
+    /// Create the trading scene
+    pub fn new() -> Self {
+        Self { offer: None, dialog: None }
+    }
+}
+
+impl Scene for TradingScene {
+    fn state(&self) -> GameState {
+        GameState::Trading
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        let journey = &mut ctx.journey;
+        if journey.trade_attempts >= MAX_TRADES_PER_DAY {
+            self.dialog = Some(Dialog::message(TITLE, "You've asked everyone nearby.\nTry again tomorrow."));
+            return;
+        }
+
+        journey.trade_attempts += 1;
+        self.offer = trading::generate_offer(journey, ctx.rng.stream(RngStream::Trading));
+        self.dialog = Some(match &self.offer {
+            Some(offer) => {
+                let message = format!(
+                    "{}\nYou have {} {}.\nAre you willing to trade?",
+                    offer.describe(),
+                    journey.inventory.get_quantity(offer.wanted),
+                    offer.wanted.name()
+                );
+                Dialog::new(TITLE, &message, &["Yes", "No"]).with_cancel(1)
+            }
+            None => Dialog::message(TITLE, "No one wants to trade with you today."),
+        });
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        let Some(choice) = self.dialog.as_mut().and_then(|d| d.update(dt)) else {
+            return SceneTransition::None;
+        };
+
+        match (choice, self.offer) {
+            (0, Some(offer)) => {
+                let message = if !offer.fits(&ctx.journey.inventory) {
+                    "Your wagon is too full to take that."
+                } else if offer.accept(&mut ctx.journey.inventory) {
+                    ctx.journey.settle_injured_oxen();
+                    "It's a deal!"
+                } else {
+                    "You don't have enough to make that trade."
+                };
+                SceneTransition::Replace(Box::new(MessageScene::new(GameState::Trading, TITLE, message)))
+            }
+            _ => SceneTransition::Pop,
+        }
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        if let Some(dialog) = &self.dialog {
+            dialog.draw();
+        }
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,86 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::journey::Journey;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::title_screen::TitleScreen;
+
+/// Height of one line of text
+const LINE_HEIGHT: f32 = 28.0;
+
+/// How a wagon's journey ended, in a word or two
+fn outcome(journey: &Journey) -> &'static str {
+    if journey.has_arrived() {
+        "Reached Oregon"
+    } else if journey.party_died() {
+        "Lost on the trail"
+    } else {
+        "Snowbound"
+    }
+}
+
+/// End of a wagon train's journey: each wagon's score and the train's together
+pub struct TrainScoreScene;
+
+impl TrainScoreScene {
+    /// Create the wagon train's scoring screen
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Scene for TrainScoreScene {
+    fn state(&self) -> GameState {
+        GameState::GameOver
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        if input::is_action_pressed(Action::Confirm) || input::is_action_pressed(Action::Cancel) {
+            ctx.wagon_train = None;
+            return SceneTransition::Replace(Box::new(TitleScreen::new()));
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text("The Wagon Train", panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+        let Some(train) = &ctx.wagon_train else {
+            return;
+        };
+
+        let (name_x, outcome_x, points_x) = (panel.x + 40.0, panel.x + 220.0, panel.x + panel.w - 60.0);
+        let header = panel.y + 76.0;
+        draw_text("Wagon", name_x, header, style::FONT_SIZE, style::theme().text_disabled);
+        draw_text("Journey", outcome_x, header, style::FONT_SIZE, style::theme().text_disabled);
+        draw_text("Points", points_x - 50.0, header, style::FONT_SIZE, style::theme().text_disabled);
+
+        let scores = train.scores();
+        let best = scores.iter().copied().max().unwrap_or(0);
+        let mut y = header;
+        for (wagon, &points) in train.wagons().iter().zip(&scores) {
+            y += LINE_HEIGHT;
+            let color = if points == best && best > 0 { style::theme().focus } else { style::theme().text };
+            draw_text(&wagon.player, name_x, y, style::FONT_SIZE, color);
+            draw_text(outcome(&wagon.journey), outcome_x, y, style::FONT_SIZE, color);
+            // Points line up on their right edge
+            let text = points.to_string();
+            let width = measure_text(&text, None, style::FONT_SIZE as u16, 1.0).width;
+            draw_text(&text, points_x - width, y, style::FONT_SIZE, color);
+        }
+
+        y += LINE_HEIGHT * 1.5;
+        let total = train.combined_score().to_string();
+        draw_text("Whole train", name_x, y, style::FONT_SIZE + 2.0, style::theme().text);
+        let width = measure_text(&total, None, (style::FONT_SIZE + 2.0) as u16, 1.0).width;
+        draw_text(&total, points_x - width, y, style::FONT_SIZE + 2.0, style::theme().text);
+
+        draw_text("Press Enter to continue", panel.x + 20.0, panel.y + panel.h - 20.0, style::FONT_SIZE, style::theme().text_disabled);
+    }
+}
@@ -0,0 +1,693 @@
+use std::collections::VecDeque;
+use macroquad::prelude::*;
+use crate::engine::animation::{self, Animation};
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::event_bus::Subscription;
+use crate::engine::input::{self, Action, SequenceMatcher};
+use crate::engine::narration;
+use crate::engine::parallax::{self, Layer, Silhouette};
+use crate::engine::renderer::draw_text;
+use crate::engine::tint::{self, SUNRISE, SUNSET};
+use crate::engine::ui::rich_text::{self, Span};
+use crate::engine::ui::{draw_panel, draw_text_button, style, ClickRegion};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::achievements::{self, Achievement, Achievements, ACHIEVEMENTS_FILE};
+use crate::game_logic::cheats::Cheat;
+use crate::game_logic::classroom::{self, Classroom, CLASSROOM_FILE};
+use crate::game_logic::control::{self, Decision};
+use crate::game_logic::diary::{EntryKind, TRIP_REPORT_FILE};
+use crate::game_logic::game_events::GameEvent;
+use crate::game_logic::facts::FactBook;
+use crate::game_logic::grass;
+use crate::game_logic::graves::{Grave, Graveyard, GRAVES_FILE};
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::landmarks::Zone;
+use crate::game_logic::score;
+use crate::game_logic::stats::{LifetimeStats, STATS_FILE};
+use crate::game_logic::top_ten::{TopTen, TOP_TEN_FILE};
+use crate::game_logic::weather::Weather;
+use crate::scenes::abandon::AbandonScene;
+use crate::scenes::diary::DiaryScene;
+use crate::scenes::epitaph::EpitaphScene;
+use crate::scenes::landmarks::{self, LandmarkScene};
+use crate::scenes::message::MessageScene;
+use crate::scenes::pause::PauseScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::score::ScoreScene;
+use crate::scenes::train_score::TrainScoreScene;
+use crate::scenes::travel_menu::TravelMenuScene;
+use crate::settings::Difficulty;
+use crate::utils::sprites::Playback;
+
+/// Real seconds per day on the trail
+const DAY_SECONDS: f32 = 1.0;
+
+/// Days between counts of the stream's votes on the pace
+const PACE_VOTE_DAYS: u32 = 7;
+
+/// Height of the status panel at the bottom of the screen
+const STATUS_HEIGHT: f32 = 150.0;
+
+/// Width of each on-screen button shown on touchscreens
+const TOUCH_BUTTON_WIDTH: f32 = 120.0;
+/// Height of each on-screen button shown on touchscreens
+const TOUCH_BUTTON_HEIGHT: f32 = 40.0;
+/// Labels of the on-screen buttons, standing in for Enter, D and Esc
+const TOUCH_BUTTONS: [&str; 3] = ["Size up", "Diary", "Pause"];
+
+/// Pixels the ground scrolls past for each mile travelled
+const SCROLL_PER_MILE: f32 = 3.0;
+
+/// The wagon and its team of oxen walking west
+const WAGON_ANIMATION: &str = "WAGONS.json";
+/// How much the wagon sprite is scaled up
+const WAGON_SCALE: f32 = 2.0;
+/// Steps per second the party takes at a steady pace
+const STEPS_PER_SECOND: f32 = 3.0;
+
+/// Build a backdrop layer
+const fn layer(silhouette: Silhouette, height: f32, period: f32, rate: f32, color: Color) -> Layer {
+    Layer { silhouette, height, period, rate, color }
+}
+
+/// Plains: low hills under a wide sky
+const PLAINS: [Layer; 3] = [
+    layer(Silhouette::Hills, 25.0, 240.0, 0.1, Color::new(0.5, 0.6, 0.65, 1.0)),
+    layer(Silhouette::Hills, 14.0, 130.0, 0.35, Color::new(0.35, 0.55, 0.25, 1.0)),
+    layer(Silhouette::Hills, 5.0, 40.0, 1.0, Color::new(0.55, 0.6, 0.3, 1.0)),
+];
+
+/// Mountains: snowy ranges behind wooded foothills
+const MOUNTAINS: [Layer; 3] = [
+    layer(Silhouette::Peaks, 110.0, 180.0, 0.08, Color::new(0.55, 0.5, 0.65, 1.0)),
+    layer(Silhouette::Peaks, 55.0, 110.0, 0.3, Color::new(0.3, 0.4, 0.35, 1.0)),
+    layer(Silhouette::Hills, 8.0, 50.0, 1.0, Color::new(0.5, 0.45, 0.3, 1.0)),
+];
+
+/// Desert: red buttes over sagebrush flats
+const DESERT: [Layer; 3] = [
+    layer(Silhouette::Mesas, 50.0, 260.0, 0.1, Color::new(0.7, 0.4, 0.3, 1.0)),
+    layer(Silhouette::Hills, 18.0, 150.0, 0.3, Color::new(0.75, 0.6, 0.4, 1.0)),
+    layer(Silhouette::Hills, 5.0, 35.0, 1.0, Color::new(0.8, 0.7, 0.45, 1.0)),
+];
+
+/// Forest: blue peaks behind a line of pines
+const FOREST: [Layer; 3] = [
+    layer(Silhouette::Peaks, 80.0, 220.0, 0.08, Color::new(0.45, 0.5, 0.65, 1.0)),
+    layer(Silhouette::Trees, 45.0, 18.0, 0.35, Color::new(0.1, 0.3, 0.15, 1.0)),
+    layer(Silhouette::Hills, 6.0, 45.0, 1.0, Color::new(0.35, 0.5, 0.25, 1.0)),
+];
+
+/// Sky color and scenery layers, back to front, for a zone of the trail
+fn backdrop(zone: Zone) -> (Color, &'static [Layer]) {
+    match zone {
+        Zone::Plains => (Color::new(0.45, 0.65, 0.9, 1.0), &PLAINS),
+        Zone::Mountains => (Color::new(0.5, 0.7, 0.95, 1.0), &MOUNTAINS),
+        Zone::Desert => (Color::new(0.6, 0.75, 0.9, 1.0), &DESERT),
+        Zone::Forest => (Color::new(0.45, 0.6, 0.8, 1.0), &FOREST),
+    }
+}
+
+/// The wagon travelling along the trail, a day at a time
+pub struct TravelScene {
+    /// Time since the last day passed
+    timer: f32,
+    /// Subscription to game events, taken when the scene is entered
+    events: Option<Subscription>,
+    /// Game events waiting to be shown, one message at a time
+    notices: VecDeque<GameEvent>,
+    /// Graves from earlier journeys, and those dug on this one
+    graveyard: Graveyard,
+    /// Historical notes, for the dates that have one
+    facts: FactBook,
+    /// Graves passed today, waiting to be shown
+    headstones: VecDeque<Grave>,
+    /// Watches for cheat codes typed while travelling
+    cheat_codes: SequenceMatcher,
+    /// Distance the backdrop has scrolled (in pixels)
+    scroll: f32,
+    /// Miles covered on the last day, which sets how fast the backdrop scrolls
+    day_miles: f32,
+    /// Wagon and oxen animation
+    wagon: Playback,
+    /// Party members' walking animation
+    steps: Animation,
+    /// Whether the wagon in play has had its day, in a wagon train
+    turn_taken: bool,
+    /// On-screen buttons for Enter, D and Esc, shown on touchscreens
+    touch_buttons: [ClickRegion; 3],
+}
+
+impl TravelScene {
+    /// Create the travel scene
+    pub fn new() -> Self {
+        Self {
+            timer: 0.0,
+            events: None,
+            notices: VecDeque::new(),
+            graveyard: Graveyard::default(),
+            facts: FactBook::default(),
+            headstones: VecDeque::new(),
+            cheat_codes: SequenceMatcher::new(&Cheat::ALL.map(|cheat| cheat.phrase())),
+            scroll: 0.0,
+            day_miles: 0.0,
+            wagon: Playback::new(),
+            steps: Animation::new(2, STEPS_PER_SECOND),
+            turn_taken: false,
+            touch_buttons: std::array::from_fn(|i| ClickRegion::new(Self::touch_button_rect(i))),
+        }
+    }
+
+    /// Height of the horizon the scenery stands on
+    fn horizon() -> f32 {
+        screen_height() - STATUS_HEIGHT - 90.0
+    }
+
+    /// Where an on-screen button sits, in a row where the keyboard hint goes
+    fn touch_button_rect(index: usize) -> Rect {
+        let x = 30.0 + index as f32 * (TOUCH_BUTTON_WIDTH + 10.0);
+        Rect::new(x, Self::horizon() - 84.0, TOUCH_BUTTON_WIDTH, TOUCH_BUTTON_HEIGHT)
+    }
+
+    /// Write the diary out as a trip report
+    fn export_report(ctx: &GameContext) {
+        let leader = ctx.journey.player.party.first().map_or("Unknown", |m| m.name.as_str());
+        let title = format!("The Oregon Trail - the journey of {}", leader);
+        match ctx.journey.diary.export(&title, TRIP_REPORT_FILE) {
+            Ok(()) => log::info!("Trip report written to {}", TRIP_REPORT_FILE),
+            Err(e) => log::error!("Failed to write {}: {}", TRIP_REPORT_FILE, e),
+        }
+    }
+
+    /// Unlock whatever the finished journey has earned, announcing anything new
+    fn award_achievements(ctx: &mut GameContext) {
+        let file = ctx.profile_file(ACHIEVEMENTS_FILE);
+        let mut achievements = Achievements::load(ctx.storage.as_ref(), &file).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            Achievements::default()
+        });
+        let hard = ctx.settings.difficulty == Difficulty::Hard;
+        let new: Vec<Achievement> =
+            achievements::earned(&ctx.journey, hard).into_iter().filter(|&a| achievements.unlock(a)).collect();
+        if new.is_empty() {
+            return;
+        }
+        for achievement in new {
+            ctx.toasts.push(format!("Achievement unlocked: {}", achievement.name()));
+            ctx.journey.record(EntryKind::Note, format!("Achievement unlocked: {}.", achievement.name()));
+        }
+        if let Err(e) = achievements.save(ctx.storage.as_mut(), &file) {
+            log::error!("{}", e);
+        }
+    }
+
+    /// Add the finished journey to the lifetime statistics
+    fn record_lifetime_stats(ctx: &mut GameContext) {
+        let file = ctx.profile_file(STATS_FILE);
+        let mut lifetime = LifetimeStats::load(ctx.storage.as_ref(), &file).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            LifetimeStats::default()
+        });
+        lifetime.add(&ctx.journey);
+        if let Err(e) = lifetime.save(ctx.storage.as_mut(), &file) {
+            log::error!("{}", e);
+        }
+    }
+
+    /// Put an arriving party's score on the Oregon Top Ten if it's good enough,
+    /// and send it to the online leaderboard if the player has opted in
+    fn record_top_ten(ctx: &mut GameContext) {
+        // A cheated score doesn't count, as with the best score
+        if !ctx.journey.has_arrived() || ctx.journey.cheated {
+            return;
+        }
+        ctx.submit_score();
+        let mut top_ten = TopTen::load(ctx.storage.as_ref(), TOP_TEN_FILE).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            TopTen::default()
+        });
+        let leader = ctx.journey.player.party.first().map_or("Your party", |m| m.name.as_str());
+        let Some(place) = top_ten.add(leader, score::final_score(&ctx.journey)) else {
+            return;
+        };
+        ctx.toasts.push(format!("Your score is number {} on the Oregon Top Ten!", place + 1));
+        if let Err(e) = top_ten.save(ctx.storage.as_mut(), TOP_TEN_FILE) {
+            log::error!("{}", e);
+        }
+    }
+
+    /// Write up the finished journey for the student who played it, in
+    /// classroom mode, and pass the turn on
+    fn record_session(ctx: &mut GameContext, outcome: &str) {
+        let Some(student) = ctx.journey.student.clone() else {
+            return;
+        };
+        let file = classroom::report_file(&student);
+        // Reports from earlier sessions are kept, newest last
+        let earlier = ctx.storage.read(&file).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            None
+        });
+        let report = classroom::session_report(&student, &ctx.journey, outcome);
+        let text = match earlier {
+            Some(earlier) => format!("{}\n{}", earlier, report),
+            None => report,
+        };
+        match ctx.storage.write(&file, &text) {
+            Ok(()) => ctx.toasts.push(format!("Session report saved for {}", student)),
+            Err(e) => log::error!("{}", e),
+        }
+
+        let mut class = Classroom::load(ctx.storage.as_ref(), CLASSROOM_FILE).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            Classroom::default()
+        });
+        // The turn only moves on if the class list still has this student up
+        if class.student_up() == Some(student.as_str()) {
+            class.finish_turn();
+            if let Err(e) = class.save(ctx.storage.as_mut(), CLASSROOM_FILE) {
+                log::error!("{}", e);
+            }
+        }
+    }
+
+    /// Hand the controls to the next wagon still on the trail, announcing
+    /// whose turn it is, if that's anyone else
+    fn pass_turn(ctx: &mut GameContext) -> Option<MessageScene> {
+        let train = ctx.wagon_train.as_mut()?;
+        let next = train.next_on_trail().filter(|&next| next != train.current())?;
+        train.pass_turn(&mut ctx.journey, next);
+        let text = format!("It is {}'s turn.\nPass the controls to {}.", train.player(), train.player());
+        Some(MessageScene::new(GameState::Travel, "Wagon train", &text))
+    }
+
+    /// A wagon's journey is over: pass the turn on, or once every wagon is
+    /// done, score the whole train
+    fn wagon_finished(ctx: &mut GameContext, message: &str) -> SceneTransition {
+        let Some(train) = ctx.wagon_train.as_mut() else {
+            return SceneTransition::None;
+        };
+        let title = format!("{}'s wagon", train.player());
+        train.finish_current();
+        match train.next_on_trail() {
+            Some(next) => {
+                train.pass_turn(&mut ctx.journey, next);
+                let text = format!("{}\n\nIt is {}'s turn.", message, train.player());
+                SceneTransition::Push(Box::new(MessageScene::new(GameState::Travel, &title, &text)))
+            }
+            None => {
+                train.put_back(&mut ctx.journey);
+                SceneTransition::Replace(Box::new(TrainScoreScene::new()))
+            }
+        }
+    }
+
+    /// Set the pace the stream voted for, if anyone voted
+    fn take_pace_vote(ctx: &mut GameContext) {
+        let Some(pace) = ctx.control.take_winner(Decision::Pace).and_then(|choice| control::pace_vote(&choice)) else {
+            return;
+        };
+        if pace == ctx.journey.player.pace {
+            return;
+        }
+        ctx.journey.player.pace = pace;
+        ctx.journey.record(EntryKind::Note, format!("The stream voted to set the pace to {}.", pace.name()));
+        ctx.toasts.push(format!("The stream voted for a {} pace.", pace.name()));
+    }
+
+    /// Whether the player may write on the leader's tombstone, which a teacher can turn off
+    fn epitaphs_allowed(ctx: &GameContext) -> bool {
+        Classroom::load(ctx.storage.as_ref(), CLASSROOM_FILE).map_or(true, |class| class.epitaphs_allowed())
+    }
+
+    /// Bury a member who has died where the wagon stands, for later parties to find
+    fn dig_grave(&mut self, ctx: &mut GameContext, name: &str, drowned: bool) {
+        let journey = &ctx.journey;
+        let Some(member) = journey.player.party.iter().find(|m| m.name == name) else {
+            return;
+        };
+        self.graveyard.add(Grave::for_member(member, journey.player.miles_traveled, drowned));
+        if let Err(e) = self.graveyard.save(ctx.storage.as_mut(), GRAVES_FILE) {
+            log::error!("{}", e);
+        }
+    }
+
+    /// Message to show for a game event, if it needs the player's attention
+    fn event_message(event: &GameEvent) -> Option<MessageScene> {
+        let title = match event {
+            GameEvent::PartyMemberDied { drowned: false, .. } => "Death",
+            GameEvent::SuppliesLow { .. } => "Supplies",
+            _ => return None,
+        };
+        let (_, text) = event.diary_entry();
+        Some(MessageScene::new(GameState::Travel, title, &text))
+    }
+
+    /// Walk the team and the party at the pace, or stand them still while the wagon is stopped
+    fn animate(&mut self, ctx: &GameContext, dt: f32) {
+        if self.day_miles > 0.0 {
+            let speed = ctx.journey.player.pace.miles_factor();
+            self.wagon.update(dt, speed);
+            self.steps.update(dt, speed);
+        } else {
+            self.wagon.halt();
+            self.steps.halt();
+        }
+    }
+
+    /// Draw the wagon and oxen with the party walking behind
+    fn draw_wagon(&self, ctx: &GameContext, x: f32, ground: f32) {
+        let wagon = ctx.assets.get_animation(WAGON_ANIMATION).and_then(|a| {
+            let frame = self.wagon.frame(a.clips.first()?)?;
+            Some((ctx.assets.get_texture(&a.texture)?, frame))
+        });
+        match wagon {
+            Some((texture, frame)) => {
+                let y = ground - frame.size().1 * WAGON_SCALE + 14.0;
+                animation::draw_frame(texture, frame, x - 40.0, y, WAGON_SCALE);
+            }
+            None => {
+                draw_rectangle(x, ground - 10.0, 60.0, 25.0, Color::new(0.4, 0.25, 0.1, 1.0));
+                draw_circle(x + 12.0, ground + 18.0, 8.0, DARKBROWN);
+                draw_circle(x + 48.0, ground + 18.0, 8.0, DARKBROWN);
+                draw_rectangle(x + 5.0, ground - 35.0, 50.0, 25.0, WHITE);
+            }
+        }
+
+        // Members take turns on each foot, so the party doesn't march in step
+        let living = ctx.journey.player.party.iter().filter(|m| m.is_alive());
+        for (i, _) in living.enumerate() {
+            let fx = x + 104.0 + i as f32 * 16.0;
+            let forward = (self.steps.frame() + i).is_multiple_of(2);
+            let stride = if self.day_miles <= 0.0 { 0.0 } else if forward { 3.0 } else { -3.0 };
+            draw_circle(fx, ground - 10.0, 3.0, Color::new(0.9, 0.75, 0.6, 1.0));
+            draw_line(fx, ground - 7.0, fx, ground + 4.0, 2.0, DARKBROWN);
+            draw_line(fx, ground + 4.0, fx - stride, ground + 14.0, 2.0, DARKBROWN);
+            draw_line(fx, ground + 4.0, fx + stride, ground + 14.0, 2.0, DARKBROWN);
+        }
+    }
+
+    /// Hour of the day on the trail, from sunrise when the day starts to sunset when it ends
+    fn hour(&self) -> f32 {
+        SUNRISE + (SUNSET - SUNRISE) * (self.timer / DAY_SECONDS).clamp(0.0, 1.0)
+    }
+
+    /// Tint the weather lays over the backdrop
+    fn weather_tint(weather: Weather) -> Color {
+        match weather {
+            Weather::Rain => Color::new(0.1, 0.12, 0.2, 0.4),
+            Weather::Snow => Color::new(0.35, 0.38, 0.45, 0.35),
+            Weather::Fog => Color::new(0.75, 0.76, 0.78, 0.45),
+            Weather::VeryHot => Color::new(0.95, 0.8, 0.4, 0.12),
+            _ => tint::CLEAR,
+        }
+    }
+
+    /// Heading for the travel menu describing where the party is
+    fn heading(ctx: &GameContext) -> String {
+        let player = &ctx.journey.player;
+        format!("{}  {}", player.location, player.date)
+    }
+
+    /// Lines of the status panel, with the party's health in its color
+    fn status(ctx: &GameContext) -> [Vec<Span>; 5] {
+        let journey = &ctx.journey;
+        let player = &journey.player;
+        let plain = |text: String| vec![Span { text, color: None }];
+        let next = match journey.next_landmark() {
+            Some(next) => format!("{:.0} miles to {}", journey.miles_to_next(), next.name),
+            None => "You have reached the end of the trail".to_string(),
+        };
+        let health = journey.party_health();
+        [
+            plain(format!(
+                "Date: {}    Weather: {}",
+                player.date,
+                journey.weather.name()
+            )),
+            vec![
+                Span { text: "Health: ".to_string(), color: None },
+                Span { text: health.name().to_string(), color: Some(style::theme().health(health)) },
+                Span {
+                    text: format!(
+                        "    Grass: {}    Spirits: {}",
+                        grass::describe(journey.grass()),
+                        journey.morale.level().name()
+                    ),
+                    color: None,
+                },
+            ],
+            plain(format!(
+                "Food: {} pounds    Clothing: {} sets",
+                journey.inventory.get_quantity(ItemType::Food),
+                journey.inventory.get_quantity(ItemType::Clothing)
+            )),
+            plain(format!("Next landmark: {}", next)),
+            plain(format!("Miles traveled: {:.0}", player.miles_traveled)),
+        ]
+    }
+
+    /// The weather, the party's health and its spirits, as narrated when they change
+    fn conditions(ctx: &GameContext) -> [String; 3] {
+        let journey = &ctx.journey;
+        [
+            format!("The weather is {}.", journey.weather.name().to_lowercase()),
+            format!("The party's health is {}.", journey.party_health().name().to_lowercase()),
+            format!("The party's spirits are {}.", journey.morale.level().name().to_lowercase()),
+        ]
+    }
+}
+
+impl Scene for TravelScene {
+    fn state(&self) -> GameState {
+        GameState::Travel
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        if self.events.is_none() {
+            self.events = Some(ctx.events.subscribe());
+            self.graveyard = Graveyard::load(ctx.storage.as_ref(), GRAVES_FILE).unwrap_or_else(|e| {
+                log::error!("{}", e);
+                Graveyard::default()
+            });
+            self.facts = landmarks::load_facts(ctx);
+            narration::say("On the trail. Press Enter to size up the situation, D to read the diary, or S to hear the party's status.");
+        }
+    }
+
+    fn on_exit(&mut self, ctx: &mut GameContext) {
+        if let Some(subscription) = self.events.take() {
+            ctx.events.unsubscribe(subscription);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        // Show deaths and shortages published since the last frame, one at a time
+        if let Some(subscription) = self.events {
+            self.notices.extend(ctx.events.poll(subscription));
+        }
+        while let Some(event) = self.notices.pop_front() {
+            if let GameEvent::PartyMemberDied { name, drowned } = &event {
+                self.dig_grave(ctx, name, *drowned);
+            }
+            if let Some(message) = Self::event_message(&event) {
+                return SceneTransition::Push(Box::new(message));
+            }
+        }
+        if let Some(grave) = self.headstones.pop_front() {
+            let text = format!("{}\n\n{}", grave.name, grave.epitaph);
+            return SceneTransition::Push(Box::new(MessageScene::new(GameState::Travel, "You pass a grave", &text)));
+        }
+
+        // The journey is over once the party arrives, dies or is trapped by snow
+        if ctx.journey.has_arrived() || ctx.journey.party_died() || ctx.journey.snowbound_for_winter() {
+            let message = if ctx.journey.has_arrived() {
+                format!(
+                    "Congratulations! You have made it to Oregon!\nYour final score is {}.",
+                    score::final_score(&ctx.journey)
+                )
+            } else if ctx.journey.party_died() {
+                "Everyone in your party has died.".to_string()
+            } else {
+                let pass = ctx.journey.next_landmark().map_or("the pass", |next| next.name.as_str());
+                format!(
+                    "Snow has closed {} for the winter.\nTrapped in the mountains with dwindling food,\nyour party does not survive until spring.",
+                    pass
+                )
+            };
+            ctx.journey.record(EntryKind::Note, message.replace('\n', " "));
+            Self::award_achievements(ctx);
+            Self::record_lifetime_stats(ctx);
+            Self::record_top_ten(ctx);
+            Self::record_session(ctx, &message);
+            Self::export_report(ctx);
+            if ctx.wagon_train.is_some() {
+                return Self::wagon_finished(ctx, &message);
+            }
+            // As in the original game, a party lost to the trail leaves its leader's tombstone to write
+            let leader = ctx.journey.player.party.first().map(|m| m.name.clone());
+            if let Some(leader) = leader.filter(|_| ctx.journey.party_died() && Self::epitaphs_allowed(ctx)) {
+                return SceneTransition::Replace(Box::new(EpitaphScene::new(&leader, &message)));
+            }
+            return SceneTransition::Replace(Box::new(ScoreScene::new(&message)));
+        }
+
+        // Losing oxen can leave more in the wagon than the rest can pull
+        if ctx.journey.inventory.is_overloaded() {
+            return SceneTransition::Push(Box::new(AbandonScene::new()));
+        }
+
+        // On a touchscreen, buttons stand in for the keys
+        let [size_up, diary, pause] = self.touch_buttons.each_mut().map(|region| input::is_touch() && region.update());
+
+        // Esc pauses the trail, keeping the day's progress until play resumes
+        if input::is_action_pressed(Action::Cancel) || pause {
+            return SceneTransition::Push(Box::new(PauseScene::new()));
+        }
+
+        // Cheat codes are typed straight onto the trail, if they're turned on
+        if let Some(index) = self.cheat_codes.update().filter(|_| ctx.settings.cheats) {
+            let done = Cheat::ALL[index].apply(&mut ctx.journey);
+            ctx.toasts.push(format!("Cheat: {}", done));
+        }
+
+        if is_key_pressed(KeyCode::D) || diary {
+            return SceneTransition::Push(Box::new(DiaryScene::new()));
+        }
+        if is_key_pressed(KeyCode::S) {
+            for line in Self::status(ctx) {
+                narration::say(&line.into_iter().map(|span| span.text).collect::<String>());
+            }
+        }
+
+        // Enter stops the wagon to size up the situation
+        if input::is_action_pressed(Action::Confirm) || is_key_pressed(KeyCode::Space) || size_up {
+            return SceneTransition::Push(Box::new(TravelMenuScene::new(&Self::heading(ctx))));
+        }
+
+        self.timer += dt;
+        self.scroll += self.day_miles * SCROLL_PER_MILE * dt / DAY_SECONDS;
+        self.animate(ctx, dt);
+        if self.timer < DAY_SECONDS {
+            return SceneTransition::None;
+        }
+        self.timer -= DAY_SECONDS;
+
+        // In a wagon train, each day of travel is one player's turn
+        if std::mem::take(&mut self.turn_taken) {
+            if let Some(message) = Self::pass_turn(ctx) {
+                self.day_miles = 0.0;
+                return SceneTransition::Push(Box::new(message));
+            }
+        }
+
+        if ctx.journey.days.is_multiple_of(PACE_VOTE_DAYS) {
+            Self::take_pace_vote(ctx);
+        }
+        let start = ctx.journey.player.miles_traveled;
+        let conditions = Self::conditions(ctx);
+        let report = ctx.journey.travel_day(&mut ctx.rng);
+        for (before, now) in conditions.iter().zip(Self::conditions(ctx)) {
+            if *before != now {
+                narration::say(&now);
+            }
+        }
+        self.turn_taken = ctx.wagon_train.is_some();
+        let graves = self.graveyard.passed(start, ctx.journey.player.miles_traveled);
+        self.headstones.extend(graves.into_iter().cloned());
+        // The wagon pulls up for breakdowns and other trouble until the next day
+        let halted = report.event.is_some() || report.blizzard.is_some();
+        self.day_miles = if halted { 0.0 } else { report.miles };
+        if let Some(days) = report.blizzard {
+            let text = format!("A blizzard has snowed you in.\nYou will be stuck for {{red}}{} days{{/}},\neating more food to stay warm.", days);
+            return SceneTransition::Push(Box::new(MessageScene::new(GameState::Travel, "Blizzard", &text)));
+        }
+        if let Some(event) = &report.event {
+            let message = MessageScene::new(GameState::Travel, event.event.title(), &event.describe());
+            return SceneTransition::Push(Box::new(message));
+        }
+
+        // Stop at each landmark along the way
+        if report.arrived_at.is_some() && !ctx.journey.has_arrived() {
+            // A day's travel ends at sunset, so that's the light the party arrives in
+            return SceneTransition::Push(Box::new(LandmarkScene::new(ctx.journey.last_landmark()).at_hour(SUNSET)));
+        }
+
+        // Mark notable days in history with a note, if they're turned on
+        let date = ctx.journey.date();
+        if let Some(fact) = self.facts.on_date(date.month(), date.day()).filter(|_| ctx.settings.facts) {
+            return SceneTransition::Push(Box::new(MessageScene::new(GameState::Travel, "Did you know?", &fact.text)));
+        }
+
+        // Good luck sent by the stream, one piece a day
+        if let Some(boon) = ctx.control.take_boon() {
+            let text = boon.apply(&mut ctx.journey);
+            return SceneTransition::Push(Box::new(MessageScene::new(GameState::Travel, "A stroke of luck", &text)));
+        }
+
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        // Scenery for the stretch of trail the party is on
+        let (sky, layers) = backdrop(ctx.journey.last_landmark().zone);
+        clear_background(sky);
+        let horizon = Self::horizon();
+        parallax::draw(layers, self.scroll, horizon, screen_height());
+
+        self.draw_wagon(ctx, screen_width() * 0.6, horizon);
+
+        // Light for the time of day and the weather
+        let backdrop = Rect::new(0.0, 0.0, screen_width(), screen_height() - STATUS_HEIGHT);
+        tint::draw(tint::combine(tint::daylight(self.hour()), Self::weather_tint(ctx.journey.weather)), backdrop);
+
+        // Status panel
+        let panel = Rect::new(10.0, screen_height() - STATUS_HEIGHT, screen_width() - 20.0, STATUS_HEIGHT - 10.0);
+        draw_panel(panel);
+
+        let journey = &ctx.journey;
+        for (i, line) in Self::status(ctx).iter().enumerate() {
+            rich_text::draw_spans(line, panel.x + 20.0, panel.y + 26.0 + i as f32 * 24.0, style::FONT_SIZE, style::theme().text);
+        }
+        if journey.short_of_clothing() {
+            draw_text(
+                "Not enough warm clothing!",
+                panel.x + panel.w - 260.0,
+                panel.y + 50.0,
+                style::FONT_SIZE,
+                style::theme().danger,
+            );
+        }
+        if journey.snowed_in() {
+            draw_text(
+                "Snowed in!",
+                panel.x + panel.w - 260.0,
+                panel.y + 74.0,
+                style::FONT_SIZE,
+                style::theme().danger,
+            );
+        } else if journey.snowpack() > 0.0 {
+            let snow = format!("Snow on the pass: {:.0} inches", journey.snowpack());
+            draw_text(&snow, panel.x + panel.w - 260.0, panel.y + 74.0, style::FONT_SIZE, style::theme().text);
+        }
+
+        if let Some(train) = &ctx.wagon_train {
+            let wagon = format!("{}'s wagon", train.player());
+            draw_text(&wagon, panel.x + panel.w - 260.0, panel.y + 26.0, style::FONT_SIZE, style::theme().focus);
+        }
+
+        if input::is_touch() {
+            for (region, label) in self.touch_buttons.iter().zip(TOUCH_BUTTONS) {
+                draw_text_button(region, label, true);
+            }
+        } else {
+            draw_text(
+                "Press ENTER to size up the situation, D to read the diary",
+                panel.x + 20.0,
+                horizon - 60.0,
+                style::FONT_SIZE,
+                WHITE,
+            );
+        }
+    }
+}
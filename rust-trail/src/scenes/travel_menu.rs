@@ -0,0 +1,192 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::blacksmith;
+use crate::scenes::blacksmith::BlacksmithScene;
+use crate::scenes::health::HealthScene;
+use crate::scenes::hunting::HuntingScene;
+use crate::scenes::message::MessageScene;
+use crate::scenes::pace_rations::{TravelSetting, TravelSettingScene};
+use crate::scenes::rest::RestScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::store::StoreScene;
+use crate::scenes::supplies::SuppliesScene;
+use crate::scenes::talk::TalkScene;
+use crate::scenes::trading::TradingScene;
+use crate::scenes::wagon_trade::WagonTradeScene;
+
+/// Width of the menu panel
+const PANEL_WIDTH: f32 = 360.0;
+/// Height of one menu row
+const ROW_HEIGHT: f32 = 24.0;
+
+/// Choices on the travel menu, in the original game's order with party health added after supplies
+/// and the fort blacksmith and trading between a wagon train's wagons at the end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TravelChoice {
+    Continue,
+    CheckSupplies,
+    CheckHealth,
+    LookAtMap,
+    ChangePace,
+    ChangeRations,
+    Rest,
+    Trade,
+    Talk,
+    Hunt,
+    BuySupplies,
+    Blacksmith,
+    TradeWagons,
+}
+
+impl TravelChoice {
+    /// All choices in menu order
+    pub const ALL: [TravelChoice; 13] = [
+        TravelChoice::Continue,
+        TravelChoice::CheckSupplies,
+        TravelChoice::CheckHealth,
+        TravelChoice::LookAtMap,
+        TravelChoice::ChangePace,
+        TravelChoice::ChangeRations,
+        TravelChoice::Rest,
+        TravelChoice::Trade,
+        TravelChoice::Talk,
+        TravelChoice::Hunt,
+        TravelChoice::BuySupplies,
+        TravelChoice::Blacksmith,
+        TravelChoice::TradeWagons,
+    ];
+
+    /// Menu label
+    pub fn label(&self) -> &'static str {
+        match self {
+            TravelChoice::Continue => "Continue on trail",
+            TravelChoice::CheckSupplies => "Check supplies",
+            TravelChoice::CheckHealth => "Check the party's health",
+            TravelChoice::LookAtMap => "Look at map",
+            TravelChoice::ChangePace => "Change pace",
+            TravelChoice::ChangeRations => "Change food rations",
+            TravelChoice::Rest => "Stop to rest",
+            TravelChoice::Trade => "Attempt to trade",
+            TravelChoice::Talk => "Talk to people",
+            TravelChoice::Hunt => "Hunt for food",
+            TravelChoice::BuySupplies => "Buy supplies",
+            TravelChoice::Blacksmith => "Visit the blacksmith",
+            TravelChoice::TradeWagons => "Trade with another wagon",
+        }
+    }
+
+    /// Whether the choice can be made at the party's current position
+    pub fn is_available(&self, ctx: &GameContext) -> bool {
+        let journey = &ctx.journey;
+        match self {
+            TravelChoice::BuySupplies => journey.at_fort(),
+            TravelChoice::Blacksmith => blacksmith::at(journey).is_some(),
+            TravelChoice::TradeWagons => ctx.wagon_train.as_ref().is_some_and(|train| !train.partners(journey).is_empty()),
+            _ => true,
+        }
+    }
+}
+
+/// The "size up the situation" menu shown over the travel scene
+pub struct TravelMenuScene {
+    /// Line shown above the choices
+    heading: String,
+    /// Menu choices
+    list: ListBox,
+}
+
+impl TravelMenuScene {
+    /// Create the travel menu with a heading such as the current location
+    pub fn new(heading: &str) -> Self {
+        let labels = TravelChoice::ALL.iter().map(|c| c.label().to_string()).collect();
+        let mut list = ListBox::new(Self::list_rect(), labels);
+        list.select(0);
+        Self {
+            heading: heading.to_string(),
+            list,
+        }
+    }
+
+    /// Area of the menu panel
+    fn panel_rect() -> Rect {
+        let height = ROW_HEIGHT * TravelChoice::ALL.len() as f32 + 90.0;
+        Rect::new(
+            screen_width() / 2.0 - PANEL_WIDTH / 2.0,
+            screen_height() / 2.0 - height / 2.0,
+            PANEL_WIDTH,
+            height,
+        )
+    }
+
+    /// Area of the choice list inside the panel
+    fn list_rect() -> Rect {
+        let panel = Self::panel_rect();
+        Rect::new(panel.x + 20.0, panel.y + 70.0, panel.w - 40.0, ROW_HEIGHT * TravelChoice::ALL.len() as f32)
+    }
+
+    /// Carry out a menu choice
+    fn choose(&self, ctx: &GameContext, choice: TravelChoice) -> SceneTransition {
+        match choice {
+            TravelChoice::Continue => SceneTransition::Pop,
+            TravelChoice::CheckSupplies => SceneTransition::Push(Box::new(SuppliesScene::new(GameState::Travel))),
+            TravelChoice::CheckHealth => SceneTransition::Push(Box::new(HealthScene::new())),
+            TravelChoice::ChangePace => SceneTransition::Push(Box::new(TravelSettingScene::new(TravelSetting::Pace))),
+            TravelChoice::ChangeRations => {
+                SceneTransition::Push(Box::new(TravelSettingScene::new(TravelSetting::Rations)))
+            }
+            TravelChoice::Rest => SceneTransition::Push(Box::new(RestScene::new(&ctx.journey))),
+            TravelChoice::Trade => SceneTransition::Push(Box::new(TradingScene::new())),
+            TravelChoice::Talk => SceneTransition::Push(Box::new(TalkScene::new())),
+            TravelChoice::Hunt => SceneTransition::Push(Box::new(HuntingScene::new())),
+            TravelChoice::BuySupplies => SceneTransition::Push(Box::new(StoreScene::new())),
+            TravelChoice::Blacksmith => SceneTransition::Push(Box::new(BlacksmithScene::new())),
+            TravelChoice::TradeWagons => SceneTransition::Push(Box::new(WagonTradeScene::new())),
+            other => SceneTransition::Push(Box::new(MessageScene::new(
+                GameState::Travel,
+                other.label(),
+                "That isn't available yet.",
+            ))),
+        }
+    }
+}
+
+impl Scene for TravelMenuScene {
+    fn state(&self) -> GameState {
+        GameState::Travel
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, _dt: f32) -> SceneTransition {
+        for (i, choice) in TravelChoice::ALL.iter().enumerate() {
+            self.list.set_enabled(i, choice.is_available(ctx));
+        }
+
+        if input::is_action_pressed(Action::Cancel) {
+            return SceneTransition::Pop;
+        }
+
+        match self.list.update() {
+            Some(index) => self.choose(ctx, TravelChoice::ALL[index]),
+            None => SceneTransition::None,
+        }
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        draw_rectangle(0.0, 0.0, screen_width(), screen_height(), style::theme().dim);
+
+        let panel = Self::panel_rect();
+        draw_panel(panel);
+        draw_text(&self.heading, panel.x + 20.0, panel.y + 30.0, style::FONT_SIZE + 4.0, style::theme().text);
+        draw_text("You may:", panel.x + 20.0, panel.y + 58.0, style::FONT_SIZE, style::theme().text);
+
+        self.list.draw();
+    }
+
+    fn is_overlay(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,255 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::dialog::Dialog;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::diary::EntryKind;
+use crate::game_logic::inventory::ItemType;
+use crate::game_logic::trading::TradeOffer;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::store::lot_size;
+
+/// Title shown on the screen and its dialogs
+const TITLE: &str = "Trade with another wagon";
+/// Most lots that can be traded at once
+const MAX_LOTS: u32 = 20;
+/// Height of one list row
+const ROW_HEIGHT: f32 = 24.0;
+
+/// Steps of arranging a trade
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    /// Choosing which wagon to trade with
+    Partner,
+    /// Choosing what to give
+    Give,
+    /// Choosing what to ask for in return
+    Get,
+}
+
+/// Trading supplies between two wagons of a wagon train stopped at the same
+/// landmark; the other wagon's player is asked before anything changes hands
+pub struct WagonTradeScene {
+    /// Wagons stopped here, read when the scene is entered
+    partners: Vec<usize>,
+    /// Wagon being traded with
+    partner: usize,
+    step: Step,
+    /// Wagons or items, depending on the step
+    list: ListBox,
+    /// Lots of the selected item
+    lots: u32,
+    /// What the player gives, once chosen
+    give: Option<(ItemType, u32)>,
+    /// Dialog showing, with the trade to make if it's answered Yes
+    dialog: Option<(Dialog, Option<TradeOffer>)>,
+}
+
+impl WagonTradeScene {
+    /// Create the wagon-to-wagon trading screen
+    pub fn new() -> Self {
+        Self {
+            partners: Vec::new(),
+            partner: 0,
+            step: Step::Partner,
+            list: ListBox::new(Self::list_rect(), Vec::new()),
+            lots: 1,
+            give: None,
+            dialog: None,
+        }
+    }
+
+    /// Area of the panel
+    fn panel_rect() -> Rect {
+        Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0)
+    }
+
+    /// Area of the list inside the panel
+    fn list_rect() -> Rect {
+        let panel = Self::panel_rect();
+        Rect::new(panel.x + 20.0, panel.y + 100.0, panel.w - 40.0, ItemType::ALL.len() as f32 * ROW_HEIGHT)
+    }
+
+    /// Move to a step and list its choices
+    fn show(&mut self, ctx: &GameContext, step: Step) {
+        let Some(train) = &ctx.wagon_train else {
+            return;
+        };
+        let partner = &train.wagons()[self.partner];
+        let labels = match step {
+            Step::Partner => self.partners.iter().map(|&i| train.wagons()[i].player.clone()).collect(),
+            Step::Give => ItemType::ALL
+                .iter()
+                .map(|&item| format!("{} (you have {})", item.name(), ctx.journey.inventory.get_quantity(item)))
+                .collect(),
+            Step::Get => ItemType::ALL
+                .iter()
+                .map(|&item| format!("{} ({} has {})", item.name(), partner.player, partner.journey.inventory.get_quantity(item)))
+                .collect(),
+        };
+        self.step = step;
+        self.list.set_labels(labels);
+        self.list.select(0);
+        self.lots = 1;
+    }
+
+    /// Quantity of the selected item at the chosen number of lots
+    fn quantity(&self) -> Option<(ItemType, u32)> {
+        self.list.selected().map(|i| ItemType::ALL[i]).map(|item| (item, self.lots * lot_size(item)))
+    }
+
+    /// Ask the other wagon's player whether they'll make the trade
+    fn propose(&mut self, ctx: &GameContext, (wanted, wanted_quantity): (ItemType, u32), (offered, offered_quantity): (ItemType, u32)) {
+        let Some(train) = &ctx.wagon_train else {
+            return;
+        };
+        let question = format!(
+            "{}, will you give {} {}\nfor {} {}?",
+            train.wagons()[self.partner].player,
+            offered_quantity,
+            offered.name(),
+            wanted_quantity,
+            wanted.name()
+        );
+        let offer = TradeOffer { offered, offered_quantity, wanted, wanted_quantity };
+        self.dialog = Some((Dialog::confirm(TITLE, &question), Some(offer)));
+    }
+
+    /// Handle the dialog showing; true once the scene is done with
+    fn update_dialog(&mut self, ctx: &mut GameContext, dt: f32) -> bool {
+        let Some((dialog, offer)) = &mut self.dialog else {
+            return false;
+        };
+        let Some(choice) = dialog.update(dt) else {
+            return false;
+        };
+        let offer = offer.take();
+        self.dialog = None;
+        let Some(offer) = offer else {
+            // A message has been read
+            return true;
+        };
+        if choice != 0 {
+            self.show(ctx, Step::Give);
+            return false;
+        }
+        let Some(train) = &mut ctx.wagon_train else {
+            return true;
+        };
+        let message = match train.trade_with(&mut ctx.journey, self.partner, &offer) {
+            Ok(()) => {
+                let partner = &train.wagons()[self.partner].player;
+                let note = format!(
+                    "Traded {} {} to {} for {} {}.",
+                    offer.wanted_quantity,
+                    offer.wanted.name(),
+                    partner,
+                    offer.offered_quantity,
+                    offer.offered.name()
+                );
+                ctx.journey.record(EntryKind::Note, note);
+                "It's a deal!".to_string()
+            }
+            Err(e) => e,
+        };
+        self.dialog = Some((Dialog::message(TITLE, &message), None));
+        false
+    }
+}
+
+impl Scene for WagonTradeScene {
+    fn state(&self) -> GameState {
+        GameState::Trading
+    }
+
+    fn on_enter(&mut self, ctx: &mut GameContext) {
+        self.partners = ctx.wagon_train.as_ref().map(|train| train.partners(&ctx.journey)).unwrap_or_default();
+        match self.partners.as_slice() {
+            [] => self.dialog = Some((Dialog::message(TITLE, "No other wagon is stopped here."), None)),
+            [only] => {
+                self.partner = *only;
+                self.show(ctx, Step::Give);
+            }
+            _ => self.show(ctx, Step::Partner),
+        }
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        if self.dialog.is_some() {
+            return if self.update_dialog(ctx, dt) { SceneTransition::Pop } else { SceneTransition::None };
+        }
+
+        if input::is_action_pressed(Action::Cancel) {
+            match self.step {
+                Step::Get => self.show(ctx, Step::Give),
+                Step::Give if self.partners.len() > 1 => self.show(ctx, Step::Partner),
+                _ => return SceneTransition::Pop,
+            }
+            return SceneTransition::None;
+        }
+        if self.step != Step::Partner {
+            if input::is_action_pressed(Action::MenuLeft) {
+                self.lots = self.lots.saturating_sub(1).max(1);
+            }
+            if input::is_action_pressed(Action::MenuRight) {
+                self.lots = (self.lots + 1).min(MAX_LOTS);
+            }
+        }
+
+        let Some(index) = self.list.update() else {
+            return SceneTransition::None;
+        };
+        match self.step {
+            Step::Partner => {
+                self.partner = self.partners[index];
+                self.show(ctx, Step::Give);
+            }
+            Step::Give => {
+                self.give = self.quantity();
+                self.show(ctx, Step::Get);
+            }
+            Step::Get => {
+                if let (Some(give), Some(get)) = (self.give, self.quantity()) {
+                    self.propose(ctx, give, get);
+                }
+            }
+        }
+        SceneTransition::None
+    }
+
+    fn draw(&self, ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Self::panel_rect();
+        draw_panel(panel);
+        draw_text(TITLE, panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+        let partner = ctx.wagon_train.as_ref().map(|train| train.wagons()[self.partner].player.as_str()).unwrap_or_default();
+        let prompt = match self.step {
+            Step::Partner => "Which wagon will you trade with?".to_string(),
+            Step::Give => format!("What will you give {}?", partner),
+            Step::Get => format!("What do you want from {} in return?", partner),
+        };
+        draw_text(&prompt, panel.x + 20.0, panel.y + 76.0, style::FONT_SIZE, style::theme().text);
+        self.list.draw();
+
+        let list = Self::list_rect();
+        let mut y = list.y + list.h + 36.0;
+        if let Some((item, quantity)) = self.give.filter(|_| self.step == Step::Get) {
+            draw_text(&format!("You give {} {}", quantity, item.name()), panel.x + 20.0, y, style::FONT_SIZE, style::theme().text_disabled);
+            y += 30.0;
+        }
+        if let Some((item, quantity)) = self.quantity().filter(|_| self.step != Step::Partner) {
+            let verb = if self.step == Step::Give { "Give" } else { "Ask for" };
+            let line = format!("{} {} {}  (Left/Right to change)", verb, quantity, item.name());
+            draw_text(&line, panel.x + 20.0, y, style::FONT_SIZE, style::theme().text);
+        }
+        draw_text("ENTER to choose, ESC to go back", panel.x + 20.0, panel.y + panel.h - 20.0, style::FONT_SIZE, style::theme().text_disabled);
+
+        if let Some((dialog, _)) = &self.dialog {
+            dialog.draw();
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use macroquad::prelude::*;
+use crate::engine::display::{screen_height, screen_width};
+use crate::engine::input::{self, Action};
+use crate::engine::renderer::draw_text;
+use crate::engine::ui::list_box::ListBox;
+use crate::engine::ui::text_input::{TextInput, TextInputEvent};
+use crate::engine::ui::{draw_panel, style};
+use crate::game::{GameContext, GameState};
+use crate::game_logic::wagon_train::{WagonTrain, MAX_WAGONS, MIN_WAGONS};
+use crate::scenes::main_menu::MainMenuScene;
+use crate::scenes::scene_manager::{Scene, SceneTransition};
+use crate::scenes::travel::TravelScene;
+
+/// Longest player name
+const MAX_NAME_LEN: usize = 16;
+
+/// Setting up a hot-seat wagon train: how many wagons, then who leads each
+pub struct WagonTrainScene {
+    /// Choice of how many wagons
+    count: ListBox,
+    /// Number of wagons, once chosen
+    wagons: Option<usize>,
+    /// Names given so far
+    names: Vec<String>,
+    /// Name of the next player being typed
+    name: TextInput,
+}
+
+impl WagonTrainScene {
+    /// Create the wagon train setup screen
+    pub fn new() -> Self {
+        let labels = (MIN_WAGONS..=MAX_WAGONS).map(|n| format!("{} wagons", n)).collect();
+        let mut count = ListBox::new(Rect::new(screen_width() / 2.0 - 120.0, 150.0, 240.0, 3.0 * 24.0), labels);
+        count.select(0);
+        let name = TextInput::new(Rect::new(screen_width() / 2.0 - 150.0, 180.0, 300.0, 30.0), MAX_NAME_LEN);
+        Self { count, wagons: None, names: Vec::new(), name }
+    }
+
+    /// Ask for the next player's name
+    fn ask_name(&mut self) {
+        self.name.set_text(&format!("Player {}", self.names.len() + 1));
+        self.name.set_focused(true);
+    }
+
+    /// Form the train and set off with the first wagon
+    fn set_off(&mut self, ctx: &mut GameContext) -> SceneTransition {
+        let names: Vec<&str> = self.names.iter().map(String::as_str).collect();
+        match WagonTrain::new(&names) {
+            Ok(mut train) => {
+                train.start(&mut ctx.journey);
+                ctx.wagon_train = Some(train);
+                SceneTransition::Replace(Box::new(TravelScene::new()))
+            }
+            Err(e) => {
+                log::error!("{}", e);
+                SceneTransition::Replace(Box::new(MainMenuScene::new()))
+            }
+        }
+    }
+}
+
+impl Scene for WagonTrainScene {
+    fn state(&self) -> GameState {
+        GameState::Setup
+    }
+
+    fn update(&mut self, ctx: &mut GameContext, dt: f32) -> SceneTransition {
+        let Some(wagons) = self.wagons else {
+            if input::is_action_pressed(Action::Cancel) {
+                return SceneTransition::Replace(Box::new(MainMenuScene::new()));
+            }
+            if let Some(index) = self.count.update() {
+                self.wagons = Some(MIN_WAGONS + index);
+                self.ask_name();
+            }
+            return SceneTransition::None;
+        };
+
+        if input::is_action_pressed(Action::Cancel) {
+            // Back a player, or back to the number of wagons
+            match self.names.pop() {
+                Some(name) => self.name.set_text(&name),
+                None => self.wagons = None,
+            }
+            return SceneTransition::None;
+        }
+        if self.name.update(dt) != Some(TextInputEvent::Submitted) {
+            return SceneTransition::None;
+        }
+        let name = self.name.text().trim().to_string();
+        if name.is_empty() || self.names.contains(&name) {
+            return SceneTransition::None;
+        }
+        self.names.push(name);
+        if self.names.len() < wagons {
+            self.ask_name();
+            return SceneTransition::None;
+        }
+        self.set_off(ctx)
+    }
+
+    fn draw(&self, _ctx: &GameContext) {
+        clear_background(BLACK);
+
+        let panel = Rect::new(60.0, 40.0, screen_width() - 120.0, screen_height() - 80.0);
+        draw_panel(panel);
+        draw_text("Wagon Train", panel.x + 20.0, panel.y + 36.0, 30.0, style::theme().text);
+        draw_text(
+            "Two to four players each lead a wagon, taking turns a day at a time.",
+            panel.x + 20.0,
+            panel.y + 62.0,
+            16.0,
+            style::theme().text_disabled,
+        );
+
+        let hint = match self.wagons {
+            None => {
+                draw_text("How many wagons?", panel.x + 20.0, 130.0, style::FONT_SIZE, style::theme().text);
+                self.count.draw();
+                "ENTER to choose, ESC to go back"
+            }
+            Some(wagons) => {
+                let prompt = format!("Who leads wagon {} of {}?", self.names.len() + 1, wagons);
+                draw_text(&prompt, panel.x + 20.0, 160.0, style::FONT_SIZE, style::theme().text);
+                self.name.draw();
+                for (i, name) in self.names.iter().enumerate() {
+                    let line = format!("Wagon {}: {}", i + 1, name);
+                    draw_text(&line, panel.x + 40.0, 250.0 + i as f32 * 24.0, style::FONT_SIZE, style::theme().text_disabled);
+                }
+                "ENTER to go on, ESC to go back"
+            }
+        };
+        draw_text(hint, panel.x + 20.0, panel.y + panel.h - 20.0, style::FONT_SIZE, style::theme().text_disabled);
+    }
+}
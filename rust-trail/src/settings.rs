@@ -0,0 +1,265 @@
+use serde::{Deserialize, Serialize};
+use crate::engine::audio;
+use crate::engine::display::{self, ScalingMode, WindowMode};
+use crate::engine::narration::{self, Narration};
+use crate::engine::transition::TransitionEffect;
+use crate::engine::ui;
+use crate::engine::ui::style::{self, Palette};
+use crate::game_logic::control;
+use crate::utils::storage::Storage;
+use crate::utils::versioned::{self, Migration};
+
+/// File the player's settings are saved to
+pub const SETTINGS_FILE: &str = "settings.toml";
+
+/// Changes to the settings file, one per version
+const MIGRATIONS: &[Migration] = &[versioned::unversioned, window_mode];
+
+/// Version 2 replaced the fullscreen switch with a choice of window mode
+fn window_mode(mut value: serde_json::Value) -> Result<serde_json::Value, String> {
+    if let Some(settings) = value.as_object_mut() {
+        if let Some(fullscreen) = settings.remove("fullscreen") {
+            // The old fullscreen was what Borderless is now
+            let mode = if fullscreen.as_bool() == Some(true) { WindowMode::Borderless } else { WindowMode::Windowed };
+            settings.insert("window_mode".to_string(), serde_json::to_value(mode).map_err(|e| e.to_string())?);
+        }
+    }
+    Ok(value)
+}
+
+/// Overall game difficulty
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// All difficulties in menu order
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    /// Display name of the difficulty
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+}
+
+/// Most detailed log records written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    /// All log levels in menu order
+    pub const ALL: [LogLevel; 4] = [LogLevel::Error, LogLevel::Warn, LogLevel::Info, LogLevel::Debug];
+
+    /// Display name of the log level
+    pub fn name(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "Errors",
+            LogLevel::Warn => "Warnings",
+            LogLevel::Info => "Info",
+            LogLevel::Debug => "Debug",
+        }
+    }
+
+    /// Filter passed to the logger
+    pub fn filter(&self) -> log::LevelFilter {
+        match self {
+            LogLevel::Error => log::LevelFilter::Error,
+            LogLevel::Warn => log::LevelFilter::Warn,
+            LogLevel::Info => log::LevelFilter::Info,
+            LogLevel::Debug => log::LevelFilter::Debug,
+        }
+    }
+}
+
+/// How quickly message text is revealed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TextSpeed {
+    Slow,
+    Normal,
+    Fast,
+    Instant,
+}
+
+impl TextSpeed {
+    /// All text speeds in menu order
+    pub const ALL: [TextSpeed; 4] = [TextSpeed::Slow, TextSpeed::Normal, TextSpeed::Fast, TextSpeed::Instant];
+
+    /// Display name of the text speed
+    pub fn name(&self) -> &'static str {
+        match self {
+            TextSpeed::Slow => "Slow",
+            TextSpeed::Normal => "Normal",
+            TextSpeed::Fast => "Fast",
+            TextSpeed::Instant => "Instant",
+        }
+    }
+
+    /// Characters revealed per second, or None to show text at once
+    pub fn chars_per_second(&self) -> Option<f32> {
+        match self {
+            TextSpeed::Slow => Some(20.0),
+            TextSpeed::Normal => Some(40.0),
+            TextSpeed::Fast => Some(80.0),
+            TextSpeed::Instant => None,
+        }
+    }
+}
+
+/// Player-adjustable settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Music volume from 0 to 100
+    pub music_volume: u8,
+    /// Sound effect volume from 0 to 100
+    pub sfx_volume: u8,
+    /// Whether music and sound effects play at all
+    pub sound: bool,
+    /// Whether the game runs in a window or fills the screen
+    pub window_mode: WindowMode,
+    /// How the screen is scaled to the window
+    pub scaling: ScalingMode,
+    /// Game difficulty
+    pub difficulty: Difficulty,
+    /// Message text speed
+    pub text_speed: TextSpeed,
+    /// Size of menu and message text, in percent of normal from 100 to 200
+    pub text_size: u16,
+    /// Where what's on screen is narrated for players who can't see it
+    pub narration: Narration,
+    /// Effect played when one screen replaces another
+    pub transition: TransitionEffect,
+    /// Whether the screen is drawn like an old CRT monitor
+    pub crt: bool,
+    /// Colors the game is drawn in, for players who tell some colors apart poorly
+    pub palette: Palette,
+    /// Whether panels are solid and colors and lines stronger
+    pub high_contrast: bool,
+    /// Whether the speedrun timer is shown
+    pub speedrun: bool,
+    /// Whether cheat codes work; journeys that use them are kept off the records
+    pub cheats: bool,
+    /// Whether "Did you know?" historical notes are shown at landmarks and on notable dates
+    pub facts: bool,
+    /// Most detailed log records written
+    pub log_level: LogLevel,
+    /// Whether scores are sent to the online leaderboard and its Top Ten shown
+    pub online_leaderboard: bool,
+    /// Address of the leaderboard server, set by editing the settings file
+    pub leaderboard_server: String,
+    /// Whether streaming tools may vote on decisions and send good luck through a local port
+    pub stream_control: bool,
+    /// Local port the stream control interface listens on
+    pub control_port: u16,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            music_volume: 80,
+            sfx_volume: 80,
+            sound: true,
+            window_mode: WindowMode::Windowed,
+            scaling: ScalingMode::Fit,
+            difficulty: Difficulty::Normal,
+            text_speed: TextSpeed::Normal,
+            text_size: 100,
+            narration: Narration::Off,
+            transition: TransitionEffect::Fade,
+            crt: false,
+            palette: Palette::Standard,
+            high_contrast: false,
+            speedrun: false,
+            cheats: false,
+            facts: true,
+            log_level: LogLevel::Info,
+            online_leaderboard: false,
+            leaderboard_server: String::new(),
+            stream_control: false,
+            control_port: control::DEFAULT_PORT,
+        }
+    }
+}
+
+impl Settings {
+    /// Load settings from storage, using defaults if none have been saved yet
+    pub fn load(storage: &dyn Storage, name: &str) -> Result<Self, String> {
+        let Some(text) = storage.read(name)? else {
+            return Ok(Self::default());
+        };
+        let table: toml::Table = toml::from_str(&text).map_err(|e| format!("Failed to parse '{}': {}", name, e))?;
+        let value = serde_json::to_value(table).map_err(|e| format!("Failed to parse '{}': {}", name, e))?;
+        versioned::from_value(value, MIGRATIONS).map_err(|e| format!("Failed to read '{}': {}", name, e))
+    }
+
+    /// Save settings to storage
+    pub fn save(&self, storage: &mut dyn Storage, name: &str) -> Result<(), String> {
+        let value = serde_json::to_value(self).map_err(|e| format!("Failed to serialize settings: {}", e))?;
+        let text = toml::to_string_pretty(&versioned::stamp(value, MIGRATIONS)?)
+            .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+        storage.write(name, &text)
+    }
+
+    /// Leaderboard server to use, if the player has opted in and set one
+    pub fn leaderboard(&self) -> Option<&str> {
+        Some(self.leaderboard_server.trim()).filter(|server| self.online_leaderboard && !server.is_empty())
+    }
+
+    /// Apply the settings to the display and audio systems
+    pub fn apply(&self) {
+        let on = if self.sound { 1.0 } else { 0.0 };
+        audio::set_music_volume(on * self.music_volume as f32 / 100.0);
+        audio::set_sfx_volume(on * self.sfx_volume as f32 / 100.0);
+        display::set_scaling(self.scaling);
+        display::set_window_mode(self.window_mode);
+        display::set_crt(self.crt);
+        style::set_theme(self.palette, self.high_contrast);
+        ui::set_text_scale(self.text_size as f32 / 100.0);
+        narration::set_mode(self.narration);
+        log::set_max_level(self.log_level.filter());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::storage::MemoryStorage;
+
+    #[test]
+    fn settings_load_as_they_were_saved() {
+        let mut storage = MemoryStorage::default();
+        let settings = Settings { music_volume: 40, difficulty: Difficulty::Hard, speedrun: true, ..Settings::default() };
+        settings.save(&mut storage, SETTINGS_FILE).unwrap();
+        assert_eq!(Settings::load(&storage, SETTINGS_FILE).unwrap(), settings);
+
+        // Files from before versioning have no version
+        storage.write(SETTINGS_FILE, "music_volume = 10\n").unwrap();
+        assert_eq!(Settings::load(&storage, SETTINGS_FILE).unwrap().music_volume, 10);
+        storage.write(SETTINGS_FILE, "version = 1\nfullscreen = true\n").unwrap();
+        assert_eq!(Settings::load(&storage, SETTINGS_FILE).unwrap().window_mode, WindowMode::Borderless);
+        storage.write(SETTINGS_FILE, "version = 99\n").unwrap();
+        assert!(Settings::load(&storage, SETTINGS_FILE).unwrap_err().contains("newer version"));
+    }
+
+    #[test]
+    fn leaderboard_is_used_only_once_opted_in() {
+        let mut settings = Settings { leaderboard_server: " https://scores.test ".to_string(), ..Settings::default() };
+        assert_eq!(settings.leaderboard(), None);
+        settings.online_leaderboard = true;
+        assert_eq!(settings.leaderboard(), Some("https://scores.test"));
+        settings.leaderboard_server.clear();
+        assert_eq!(settings.leaderboard(), None);
+    }
+}
@@ -0,0 +1,82 @@
+// Checksums for the files holding records a player might be tempted to edit
+// by hand: the lifetime statistics with the best score, and the graves. They
+// only need to show that a file was changed outside the game, not stand up to
+// someone reading this code, so a salted FNV-1a hash of the contents will do.
+
+use serde_json::Value;
+use crate::utils::fnv;
+
+/// Key the checksum is kept under at the top of each file
+const CHECKSUM_KEY: &str = "checksum";
+
+/// Keys left out of the checksum: itself, and the version, which migrations change
+const UNCHECKED_KEYS: [&str; 2] = [CHECKSUM_KEY, "version"];
+
+/// Mixed into every checksum, so it can't be worked out from the contents alone
+const SALT: &[u8] = b"Independence, Missouri, 1848";
+
+/// Checksum of a file's contents
+fn checksum(value: &Value) -> String {
+    let mut contents = value.clone();
+    if let Value::Object(map) = &mut contents {
+        for key in UNCHECKED_KEYS {
+            map.remove(key);
+        }
+    }
+    // Objects are sorted by key, so the same contents always print the same way
+    digest(&contents.to_string())
+}
+
+/// Salted hash of some text, as sixteen hex digits
+pub fn digest(text: &str) -> String {
+    format!("{:016x}", fnv::extend(fnv::hash(SALT), text.as_bytes()))
+}
+
+/// Add a checksum of a file's contents to it
+pub fn sign(mut value: Value) -> Result<Value, String> {
+    let sum = checksum(&value);
+    let Value::Object(map) = &mut value else {
+        return Err("not an object".to_string());
+    };
+    map.insert(CHECKSUM_KEY.to_string(), sum.into());
+    Ok(value)
+}
+
+/// Migration for files from before they had a checksum, which are taken on trust
+///
+/// A file that already has one keeps it, so winding back its version doesn't
+/// get an edited file signed afresh.
+pub fn trust(value: Value) -> Result<Value, String> {
+    match value.get(CHECKSUM_KEY) {
+        Some(_) => Ok(value),
+        None => sign(value),
+    }
+}
+
+/// Whether a file's checksum matches its contents
+pub fn verify(value: &Value) -> bool {
+    value.get(CHECKSUM_KEY).and_then(Value::as_str) == Some(checksum(value).as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn edited_files_fail_their_checksum() {
+        let signed = sign(json!({ "best_score": 1200, "version": 1 })).unwrap();
+        assert!(verify(&signed));
+
+        let mut moved_on = signed.clone();
+        moved_on["version"] = 2.into();
+        assert!(verify(&moved_on));
+
+        let mut edited = signed.clone();
+        edited["best_score"] = 60000.into();
+        assert!(!verify(&edited));
+        assert!(!verify(&trust(edited).unwrap()));
+        assert!(!verify(&json!({ "best_score": 1200 })));
+        assert!(verify(&trust(json!({ "best_score": 1200 })).unwrap()));
+    }
+}
@@ -0,0 +1,19 @@
+// FNV-1a, a small non-cryptographic hash. It's enough to notice a file that
+// was changed or damaged, or to turn a word into a seed, which is all the game
+// needs a hash for. The build script includes this file too, to hash the
+// assets the game ships with.
+
+/// Hash of no bytes at all
+pub const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+/// Multiplier mixed in with each byte
+const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Continue a hash over more bytes
+pub fn extend(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| (hash ^ *byte as u64).wrapping_mul(PRIME))
+}
+
+/// Hash some bytes
+pub fn hash(bytes: &[u8]) -> u64 {
+    extend(OFFSET_BASIS, bytes)
+}
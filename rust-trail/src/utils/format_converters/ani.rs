@@ -0,0 +1,85 @@
+// The original game's .ANI files, the little animations played over some
+// pictures. They're text: an optional first line naming the sprite sheet
+// (a .PCC file, converted with the rest of the pictures), a count of
+// sprites, then for each sprite its x,y on the screen and its frame's
+// width,height, followed by numbers on lines of their own. There are three
+// of those, or five:
+//
+//   sheet row, delay, last frame
+//   sheet row, delay, second delay, last frame, flag
+//
+// The sheet row is how far down the sheet the sprite's frames start, and
+// the frames run left to right from there. Delays are in milliseconds. What
+// the second delay and the flag do isn't understood yet, so they're left out.
+
+use crate::utils::sprites::{Clip, Frame, SpriteAnimation};
+
+/// Convert an .ANI file to an animation. Files that don't name their sheet
+/// are given the default one.
+pub fn to_animation(data: &[u8], default_texture: &str) -> Result<SpriteAnimation, String> {
+    let text = std::str::from_utf8(data).map_err(|e| e.to_string())?;
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty()).peekable();
+
+    let first = lines.peek().ok_or("file is empty")?;
+    let texture = match first.parse::<usize>() {
+        Ok(_) => default_texture.to_string(),
+        Err(_) => {
+            let sheet = lines.next().unwrap_or_default();
+            let stem = sheet.rsplit_once('.').map_or(sheet, |(stem, _)| stem);
+            format!("{}.png", stem.to_ascii_uppercase())
+        }
+    };
+
+    let count = lines.next().and_then(|line| line.parse::<usize>().ok()).ok_or("sprite count is missing")?;
+    let mut clips = Vec::with_capacity(count);
+    for sprite in 1..=count {
+        let pair = |line: Option<&str>| -> Result<[f32; 2], String> {
+            let (a, b) = line.and_then(|l| l.split_once(',')).ok_or_else(|| format!("sprite {} is cut short", sprite))?;
+            let number = |n: &str| n.trim().parse::<f32>().map_err(|e| format!("sprite {}: {}", sprite, e));
+            Ok([number(a)?, number(b)?])
+        };
+        let [x, y] = pair(lines.next())?;
+        let [width, height] = pair(lines.next())?;
+
+        let mut numbers = Vec::new();
+        while let Some(line) = lines.next_if(|line| !line.contains(',')) {
+            numbers.push(line.parse::<f32>().map_err(|e| format!("sprite {}: {}", sprite, e))?);
+        }
+        let (row, delay, last) = match numbers[..] {
+            [row, delay, last] | [row, delay, _, last, _] => (row, delay, last as usize),
+            _ => return Err(format!("sprite {} has {} timing numbers, not 3 or 5", sprite, numbers.len())),
+        };
+
+        let frames = (0..=last)
+            .map(|i| Frame { rect: [i as f32 * width, row, width, height], duration: delay / 1000.0, points: Default::default() })
+            .collect();
+        clips.push(Clip { name: format!("sprite{}", sprite), position: Some([x, y]), frames, looping: true });
+    }
+
+    Ok(SpriteAnimation { texture, clips })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_timing_forms_are_read() {
+        let wagons = to_animation(b"1\r\n309,76\r\n67,25\r\n0\r\n175\r\n4\r\n", "WAGONS.png").unwrap();
+        assert_eq!(wagons.texture, "WAGONS.png");
+        let clip = &wagons.clips[0];
+        assert_eq!(clip.position, Some([309.0, 76.0]));
+        assert_eq!(clip.frames.len(), 5);
+        assert_eq!(clip.frames[4].rect, [268.0, 0.0, 67.0, 25.0]);
+        assert_eq!(clip.frames[0].duration, 0.175);
+
+        let banks = b"BANKS.PCC\r\n2\r\n0,0\r\n110,74\r\n0\r\n200\r\n200\r\n2\r\n0\r\n152,81\r\n110,74\r\n74\r\n200\r\n200\r\n2\r\n0\r\n\r\n";
+        let banks = to_animation(banks, "unused.png").unwrap();
+        assert_eq!(banks.texture, "BANKS.png");
+        assert_eq!(banks.clips.len(), 2);
+        assert_eq!(banks.clips[1].frames[2].rect, [220.0, 74.0, 110.0, 74.0]);
+
+        assert!(to_animation(b"2\r\n0,0\r\n10,10\r\n0\r\n100\r\n1\r\n", "A.png").is_err());
+        assert!(to_animation(b"1\r\n0,0\r\n10,10\r\n0\r\n100\r\n", "A.png").is_err());
+    }
+}
@@ -0,0 +1,33 @@
+// Decoders for the original game's file formats, turning them into the ones
+// the game loads: PNG pictures, WAV sounds, MIDI music and JSON animations.
+// They're used by the convert-assets tool, and by the game itself for sounds
+// it reads straight from the original archive. They need nothing but each
+// file's bytes.
+
+pub mod ani;
+pub mod pcx;
+pub mod snd;
+pub mod xmi;
+
+use image::{ImageFormat, RgbImage};
+use std::io::Cursor;
+
+/// A decoded picture
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Picture {
+    pub width: u32,
+    pub height: u32,
+    /// Red, green and blue for each pixel, a row at a time
+    pub rgb: Vec<u8>,
+}
+
+impl Picture {
+    /// Encode the picture as a PNG file
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        let image = RgbImage::from_raw(self.width, self.height, self.rgb.clone())
+            .ok_or_else(|| format!("{}x{} picture has the wrong number of pixels", self.width, self.height))?;
+        let mut png = Cursor::new(Vec::new());
+        image.write_to(&mut png, ImageFormat::Png).map_err(|e| e.to_string())?;
+        Ok(png.into_inner())
+    }
+}
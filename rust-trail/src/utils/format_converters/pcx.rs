@@ -0,0 +1,238 @@
+// The original game's pictures, which are PCX files under other names: .PC8
+// and .256 hold 256 colours with the palette at the end of the file, and .PC4
+// holds 16 colours in four bit planes, coloured from the palette in
+// COLOR16.PCX. These follow tools/convert_pc8.py and tools/convert_pc4.py,
+// quirks included, so both produce the same pictures.
+
+use super::Picture;
+
+/// Length of the header before the pixels
+const HEADER_SIZE: usize = 128;
+/// First byte of every PCX file
+const SIGNATURE: u8 = 0x0a;
+/// PCX version the game's files use
+const VERSION: u8 = 5;
+/// Run-length encoding, the only kind the game's files use
+const ENCODING_RLE: u8 = 1;
+/// Bits per pixel of a 256 colour picture
+const BPP_PC8: u8 = 8;
+/// Byte before the 256 colour palette at the end of a file
+const PALETTE_MARKER: u8 = 0x0c;
+/// Length of the 256 colour palette
+const PALETTE_SIZE: usize = 768;
+/// Bytes with both top bits set start a run; the rest of the byte is its length
+const RUN_MARKER: u8 = 0xc0;
+
+/// Largest picture expected, apart from the few wide maps listed below
+const MAX_WIDTH: usize = 800;
+/// Tallest picture expected
+const MAX_HEIGHT: usize = 600;
+/// Pictures allowed to be larger than the rest
+const OVERSIZED: [&str; 6] = ["METHOD3.PC4", "METHOD3.PC8", "MAPMNT.PC4", "MAPMNT.PC8", "MAPGRSS.PC4", "MAPGRSS.PC8"];
+
+/// The talking portraits all claim other sizes in their headers, but are drawn at this one
+const TALK_SIZE: (usize, usize) = (262, 201);
+/// Picture whose pixel data stops short of what its header says
+const TRUNCATED: &str = "SCROLL.PC8";
+
+/// Little-endian 16-bit number from the header
+fn header_u16(data: &[u8], at: usize) -> usize {
+    u16::from_le_bytes([data[at], data[at + 1]]) as usize
+}
+
+/// Check the header is one the game writes
+fn check_header(data: &[u8]) -> Result<(), String> {
+    if data.len() < HEADER_SIZE {
+        return Err(format!("too short for a PCX header ({} bytes)", data.len()));
+    }
+    if data[0] != SIGNATURE || data[1] != VERSION || data[2] != ENCODING_RLE {
+        return Err(format!("not a PCX file (header {:02x} {:02x} {:02x})", data[0], data[1], data[2]));
+    }
+    Ok(())
+}
+
+/// Expand run-length encoded pixels, padding with zeros if they run out
+fn decompress(data: &[u8], expected: usize) -> Vec<u8> {
+    let mut output = Vec::with_capacity(expected);
+    let mut at = 0;
+    while at < data.len() && output.len() < expected {
+        let byte = data[at];
+        at += 1;
+        if byte < RUN_MARKER {
+            output.push(byte);
+        } else if let Some(&value) = data.get(at) {
+            at += 1;
+            let run = ((byte & !RUN_MARKER) as usize).min(expected - output.len());
+            output.extend(std::iter::repeat_n(value, run));
+        } else {
+            // A run marker as the very last byte is taken as a pixel
+            output.push(byte);
+        }
+    }
+    output.resize(expected, 0);
+    output
+}
+
+/// Decode a 256 colour picture (.PC8 or .256), given its file name
+pub fn decode_pc8(name: &str, data: &[u8]) -> Result<Picture, String> {
+    check_header(data)?;
+    if data[3] != BPP_PC8 {
+        return Err(format!("{} bits per pixel, not {}", data[3], BPP_PC8));
+    }
+
+    let name = name.to_ascii_uppercase();
+    let (mut width, mut height) = (header_u16(data, 8) + 1, header_u16(data, 10) + 1);
+    if (width > MAX_WIDTH || height > MAX_HEIGHT) && !OVERSIZED.contains(&name.as_str()) {
+        return Err(format!("{}x{} is larger than any of the game's pictures", width, height));
+    }
+    if name.starts_with("TALK") {
+        (width, height) = TALK_SIZE;
+    }
+    let bytes_per_line = header_u16(data, 66);
+
+    // The palette follows a marker at the end; without one the header's
+    // 16 colours are repeated to fill it
+    let palette_start = data.len().saturating_sub(PALETTE_SIZE + 1);
+    let palette: Vec<u8> = if data.len() > PALETTE_SIZE && data[palette_start] == PALETTE_MARKER {
+        data[palette_start + 1..].to_vec()
+    } else {
+        data[16..64].repeat(16)
+    };
+
+    let compressed = &data[HEADER_SIZE..palette_start.max(HEADER_SIZE)];
+    let mut expected = bytes_per_line * height;
+    if compressed.len() < expected && name == TRUNCATED {
+        expected = compressed.len();
+    }
+    let pixels = decompress(compressed, expected);
+
+    // Lines may be padded past the picture's width, or stop short of it
+    let mut indices = Vec::with_capacity(width * height);
+    for y in 0..height {
+        let start = (y * bytes_per_line).min(pixels.len());
+        let line = &pixels[start..(start + width).min(pixels.len())];
+        indices.extend_from_slice(line);
+        indices.resize((y + 1) * width, 0);
+    }
+
+    let rgb = indices.iter().flat_map(|&i| {
+        let at = i as usize * 3;
+        [palette[at], palette[at + 1], palette[at + 2]]
+    });
+    Ok(Picture { width: width as u32, height: height as u32, rgb: rgb.collect() })
+}
+
+/// The 16 colours .PC4 pictures are drawn with, from COLOR16.PCX
+pub fn palette16(color16: &[u8]) -> Result<[[u8; 3]; 16], String> {
+    let start = color16.len().checked_sub(PALETTE_SIZE + 1).ok_or("COLOR16.PCX is too short")?;
+    if color16[start] != PALETTE_MARKER {
+        return Err("COLOR16.PCX has no palette".to_string());
+    }
+    let mut palette = [[0; 3]; 16];
+    for (i, colour) in palette.iter_mut().enumerate() {
+        colour.copy_from_slice(&color16[start + 1 + i * 3..start + 4 + i * 3]);
+    }
+    Ok(palette)
+}
+
+/// Decode one plane's line of a .PC4 picture. A run longer than the line
+/// ends it, and the line after starts again from the run's value byte
+fn decode_line(data: &[u8], at: &mut usize, length: usize) -> Result<Vec<u8>, String> {
+    let mut line = Vec::with_capacity(length);
+    while line.len() < length {
+        let byte = *data.get(*at).ok_or("pixels end early")?;
+        *at += 1;
+        if byte >= RUN_MARKER {
+            let value = *data.get(*at).ok_or("pixels end early")?;
+            *at += 1;
+            let run = (byte & !RUN_MARKER) as usize;
+            let remaining = length - line.len();
+            line.extend(std::iter::repeat_n(value, run.min(remaining)));
+            if run > remaining {
+                *at -= 1;
+                break;
+            }
+        } else {
+            line.push(byte);
+        }
+    }
+    Ok(line)
+}
+
+/// Decode a 16 colour picture (.PC4), coloured from COLOR16.PCX's palette
+pub fn decode_pc4(data: &[u8], palette: &[[u8; 3]; 16]) -> Result<Picture, String> {
+    check_header(data)?;
+    let width = (header_u16(data, 8) + 1).saturating_sub(header_u16(data, 4));
+    let height = (header_u16(data, 10) + 1).saturating_sub(header_u16(data, 6));
+    let planes = (data[65] as usize).min(4);
+    let bytes_per_line = header_u16(data, 66);
+    if width == 0 || height == 0 || width > MAX_WIDTH * 2 || height > MAX_HEIGHT {
+        return Err(format!("{}x{} isn't a size the game uses", width, height));
+    }
+
+    let mut at = HEADER_SIZE;
+    let mut rgb = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        let lines = (0..planes)
+            .map(|_| decode_line(data, &mut at, bytes_per_line))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("line {}: {}", y, e))?;
+        // Each plane gives one bit of each pixel's colour, most significant bit first
+        for x in 0..width {
+            let index = lines.iter().enumerate().fold(0, |index, (plane, line)| {
+                let bit = line.get(x / 8).map_or(0, |byte| (byte >> (7 - x % 8)) & 1);
+                index | bit << plane
+            });
+            rgb.extend_from_slice(&palette[index as usize]);
+        }
+    }
+    Ok(Picture { width: width as u32, height: height as u32, rgb })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A header for a picture of the given size
+    fn header(bpp: u8, planes: u8, width: u16, height: u16, bytes_per_line: u16) -> Vec<u8> {
+        let mut data = vec![0; HEADER_SIZE];
+        data[..4].copy_from_slice(&[SIGNATURE, VERSION, ENCODING_RLE, bpp]);
+        data[8..10].copy_from_slice(&(width - 1).to_le_bytes());
+        data[10..12].copy_from_slice(&(height - 1).to_le_bytes());
+        data[65] = planes;
+        data[66..68].copy_from_slice(&bytes_per_line.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn pc8_runs_and_palette_decode() {
+        // Two lines of three pixels, padded to four bytes each
+        let mut data = header(8, 1, 3, 2, 4);
+        data.extend([0xc3, 1, 9, 2, 0xc2, 0, 0xc2, 1]);
+        data.push(PALETTE_MARKER);
+        let mut palette = vec![0; PALETTE_SIZE];
+        palette[3..9].copy_from_slice(&[10, 20, 30, 40, 50, 60]);
+        data.extend(palette);
+
+        let picture = decode_pc8("TEST.PC8", &data).unwrap();
+        assert_eq!((picture.width, picture.height), (3, 2));
+        let pixels: Vec<_> = picture.rgb.chunks(3).map(|c| c[0]).collect();
+        assert_eq!(pixels, [10, 10, 10, 40, 0, 0]);
+        assert!(decode_pc8("TEST.PC8", &data[..64]).is_err());
+    }
+
+    #[test]
+    fn pc4_planes_make_up_each_colour() {
+        // One line of eight pixels over four planes; one byte per plane line
+        let mut data = header(1, 4, 8, 1, 1);
+        data.extend([0b1010_0000, 0b1000_0000, 0, 0b0000_0001]);
+        let mut palette = [[0; 3]; 16];
+        for (i, colour) in palette.iter_mut().enumerate() {
+            *colour = [i as u8; 3];
+        }
+
+        let picture = decode_pc4(&data, &palette).unwrap();
+        let indices: Vec<_> = picture.rgb.chunks(3).map(|c| c[0]).collect();
+        assert_eq!(indices, [3, 0, 1, 0, 0, 0, 0, 8]);
+    }
+}
@@ -0,0 +1,156 @@
+// The original game's sound effects. The game's own .SND files have no
+// header at all: they're 8-bit unsigned mono samples played at 11025 Hz.
+// Files that start with a Creative Voice header are read from its blocks
+// instead, which say the rate themselves. Either way the samples come out
+// ready to wrap in a WAV file, which the audio system plays as it is.
+
+/// Samples per second headerless sounds are played at
+pub const SAMPLE_RATE: u32 = 11025;
+/// Start of a Creative Voice file
+const VOICE_SIGNATURE: &[u8] = b"Creative Voice File\x1a";
+/// Creative Voice codec for 8-bit unsigned samples, the only one read
+const VOICE_CODEC_PCM8: u8 = 0;
+/// Value of a silent 8-bit unsigned sample
+const SILENCE: u8 = 0x80;
+
+/// A decoded sound: 8-bit unsigned mono samples and how fast to play them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SoundClip {
+    pub sample_rate: u32,
+    pub samples: Vec<u8>,
+}
+
+impl SoundClip {
+    /// How long the sound plays for, in seconds
+    pub fn duration(&self) -> f32 {
+        self.samples.len() as f32 / self.sample_rate as f32
+    }
+
+    /// Encode the sound as a WAV file
+    pub fn to_wav(&self) -> Vec<u8> {
+        let channels: u16 = 1;
+        let bits: u16 = 8;
+        let block_align = channels * bits / 8;
+        let samples = &self.samples;
+
+        let mut wav = Vec::with_capacity(44 + samples.len() + 1);
+        wav.extend(b"RIFF");
+        wav.extend((36 + samples.len() as u32 + samples.len() as u32 % 2).to_le_bytes());
+        wav.extend(b"WAVEfmt ");
+        wav.extend(16u32.to_le_bytes());
+        wav.extend(1u16.to_le_bytes()); // PCM
+        wav.extend(channels.to_le_bytes());
+        wav.extend(self.sample_rate.to_le_bytes());
+        wav.extend((self.sample_rate * block_align as u32).to_le_bytes());
+        wav.extend(block_align.to_le_bytes());
+        wav.extend(bits.to_le_bytes());
+        wav.extend(b"data");
+        wav.extend((samples.len() as u32).to_le_bytes());
+        wav.extend(samples);
+        // Chunks are padded to an even length
+        if samples.len() % 2 == 1 {
+            wav.push(0);
+        }
+        wav
+    }
+}
+
+/// Decode a .SND file
+pub fn decode(data: &[u8]) -> Result<SoundClip, String> {
+    if data.starts_with(VOICE_SIGNATURE) {
+        return decode_voice(data);
+    }
+    if data.is_empty() {
+        return Err("no samples".to_string());
+    }
+    Ok(SoundClip { sample_rate: SAMPLE_RATE, samples: data.to_vec() })
+}
+
+/// Rate given by a Creative Voice time constant
+fn voice_rate(time_constant: u8) -> u32 {
+    1_000_000 / (256 - time_constant as u32)
+}
+
+/// Decode a Creative Voice file: a header giving where the blocks start, then
+/// blocks of a type byte and a 24-bit length
+fn decode_voice(data: &[u8]) -> Result<SoundClip, String> {
+    let start = data.get(20..22).map(|b| u16::from_le_bytes([b[0], b[1]]) as usize).ok_or("header is cut short")?;
+    let mut rate = None;
+    let mut samples = Vec::new();
+    let mut at = start;
+
+    while let Some(&kind) = data.get(at) {
+        if kind == 0 {
+            break;
+        }
+        let size = data.get(at + 1..at + 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], 0]) as usize).ok_or("block header is cut short")?;
+        let body = data.get(at + 4..at + 4 + size).ok_or_else(|| format!("block at {:#x} runs past the end", at))?;
+        at += 4 + size;
+
+        match (kind, body) {
+            // Samples, with their rate and codec
+            (1, [time_constant, codec, pcm @ ..]) => {
+                if *codec != VOICE_CODEC_PCM8 {
+                    return Err(format!("codec {} isn't 8-bit samples", codec));
+                }
+                rate.get_or_insert(voice_rate(*time_constant));
+                samples.extend_from_slice(pcm);
+            }
+            // More samples, carrying on from the last block
+            (2, pcm) => samples.extend_from_slice(pcm),
+            // A stretch of silence
+            (3, [low, high, time_constant]) => {
+                rate.get_or_insert(voice_rate(*time_constant));
+                samples.extend(std::iter::repeat_n(SILENCE, u16::from_le_bytes([*low, *high]) as usize + 1));
+            }
+            // Samples with the rate and format written out in full
+            (9, [r0, r1, r2, r3, bits, channels, c0, c1, _, _, _, _, pcm @ ..]) => {
+                if (*bits, *channels, u16::from_le_bytes([*c0, *c1])) != (8, 1, VOICE_CODEC_PCM8 as u16) {
+                    return Err(format!("{}-bit sound in {} channels isn't supported", bits, channels));
+                }
+                rate.get_or_insert(u32::from_le_bytes([*r0, *r1, *r2, *r3]));
+                samples.extend_from_slice(pcm);
+            }
+            (1 | 3 | 9, _) => return Err(format!("block at {:#x} is too short", at - 4 - size)),
+            // Markers, text and repeats don't change the samples
+            _ => {}
+        }
+    }
+
+    if samples.is_empty() {
+        return Err("no samples".to_string());
+    }
+    Ok(SoundClip { sample_rate: rate.unwrap_or(SAMPLE_RATE), samples })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wav_header_describes_the_samples() {
+        let wav = decode(&[0x80, 0x7f, 0x81]).unwrap().to_wav();
+        assert_eq!(&wav[..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(wav[4..8].try_into().unwrap()) as usize, wav.len() - 8);
+        assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), SAMPLE_RATE);
+        assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()), 3);
+        assert_eq!(&wav[44..47], &[0x80, 0x7f, 0x81]);
+    }
+
+    #[test]
+    fn creative_voice_blocks_give_the_rate_and_samples() {
+        let mut data = VOICE_SIGNATURE.to_vec();
+        data.extend([26, 0, 0x0a, 0x01, 0x29, 0x11]);
+        // 10000 Hz samples, two bytes of silence, then a continuation
+        data.extend([1, 4, 0, 0, 156, 0, 0x70, 0x90]);
+        data.extend([3, 3, 0, 0, 1, 0, 156]);
+        data.extend([2, 1, 0, 0, 0xa0]);
+        data.push(0);
+
+        let clip = decode(&data).unwrap();
+        assert_eq!(clip.sample_rate, 10000);
+        assert_eq!(clip.samples, [0x70, 0x90, 0x80, 0x80, 0xa0]);
+        assert!(decode(&data[..30]).is_err());
+        assert!(decode(&[]).is_err());
+    }
+}
@@ -0,0 +1,236 @@
+// The original game's music, in the Miles Sound System's XMI format. An XMI
+// file is a FORM:XDIR chunk giving the number of songs, then a CAT:XMID chunk
+// holding a FORM:XMID for each, whose EVNT chunk has the song's MIDI events.
+// Those differ from a standard MIDI file in two ways: the time before an
+// event is a run of bytes below 0x80 added together, and note-ons carry their
+// own length instead of being followed by a note-off. Each song becomes a
+// format 0 MIDI file.
+
+/// Ticks per quarter note the songs are written in
+const TICKS_PER_QUARTER: u16 = 60;
+/// Microseconds per quarter note, so the songs play at the 120 ticks a second
+/// Miles' players ran at
+const TEMPO: u32 = 500_000;
+/// Meta event ending a track
+const END_OF_TRACK: [u8; 3] = [0xff, 0x2f, 0x00];
+
+/// Where a byte string next appears, from a position on
+fn find(data: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    data.get(from..)?.windows(needle.len()).position(|w| w == needle).map(|at| at + from)
+}
+
+/// Big-endian 32-bit chunk length
+fn chunk_size(data: &[u8], at: usize) -> Result<usize, String> {
+    let bytes = data.get(at..at + 4).ok_or("chunk header is cut short")?;
+    Ok(u32::from_be_bytes(bytes.try_into().unwrap()) as usize)
+}
+
+/// The EVNT chunk of each song in the file
+fn songs(data: &[u8]) -> Result<Vec<&[u8]>, String> {
+    let form = find(data, b"FORM", 0).ok_or("no FORM:XDIR chunk")?;
+    if data.get(form + 8..form + 12) != Some(b"XDIR") {
+        return Err("no XDIR section".to_string());
+    }
+    let info = form + 12;
+    if data.get(info..info + 4) != Some(b"INFO") {
+        return Err("no INFO chunk".to_string());
+    }
+    let count = data.get(info + 8..info + 10).map(|b| u16::from_le_bytes([b[0], b[1]])).ok_or("INFO chunk is cut short")?;
+    if count == 0 {
+        return Err("no songs".to_string());
+    }
+
+    let cat = find(data, b"CAT ", info + 10).ok_or("no CAT:XMID chunk")?;
+    if data.get(cat + 8..cat + 12) != Some(b"XMID") {
+        return Err("no XMID section".to_string());
+    }
+
+    let mut songs = Vec::new();
+    let mut at = cat + 12;
+    for song in 0..count {
+        let form = find(data, b"FORM", at).ok_or_else(|| format!("song {} is missing", song + 1))?;
+        if data.get(form + 8..form + 12) != Some(b"XMID") {
+            return Err(format!("song {} isn't a FORM:XMID chunk", song + 1));
+        }
+        let end = (form + 8 + chunk_size(data, form + 4)?).min(data.len());
+
+        // Chunks inside the song, each padded to an even length
+        let mut chunk = form + 12;
+        let mut events = None;
+        while chunk + 8 <= end {
+            let size = chunk_size(data, chunk + 4)?;
+            let body = data.get(chunk + 8..chunk + 8 + size).ok_or("chunk runs past the end of the song")?;
+            if &data[chunk..chunk + 4] == b"EVNT" {
+                events = Some(body);
+            }
+            chunk += 8 + size + size % 2;
+        }
+        songs.push(events.ok_or_else(|| format!("song {} has no EVNT chunk", song + 1))?);
+        at = end;
+    }
+    Ok(songs)
+}
+
+/// Standard MIDI variable-length number, most significant group first
+fn read_variable(data: &[u8], at: &mut usize) -> Result<u32, String> {
+    let mut value = 0u32;
+    loop {
+        let byte = *data.get(*at).ok_or("events end in the middle of a number")?;
+        *at += 1;
+        value = (value << 7) | (byte & 0x7f) as u32;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+}
+
+/// Write a standard MIDI variable-length number
+fn write_variable(mut value: u32, out: &mut Vec<u8>) {
+    let mut groups = vec![(value & 0x7f) as u8];
+    value >>= 7;
+    while value > 0 {
+        groups.push(0x80 | (value & 0x7f) as u8);
+        value >>= 7;
+    }
+    out.extend(groups.iter().rev());
+}
+
+/// The song's events with the tick each happens on, note-offs included
+fn events(data: &[u8]) -> Result<Vec<(u32, Vec<u8>)>, String> {
+    let mut events = Vec::new();
+    let mut time = 0;
+    let mut at = 0;
+    let take = |at: &mut usize, n: usize| -> Result<Vec<u8>, String> {
+        let bytes = data.get(*at..*at + n).ok_or("events end in the middle of one")?;
+        *at += n;
+        Ok(bytes.to_vec())
+    };
+
+    while at < data.len() {
+        // The time until the next event is every byte below 0x80 added up
+        while at < data.len() && data[at] < 0x80 {
+            time += data[at] as u32;
+            at += 1;
+        }
+        let Some(&status) = data.get(at) else {
+            break;
+        };
+        at += 1;
+
+        match status {
+            0xff => {
+                let kind = take(&mut at, 1)?[0];
+                let length = read_variable(data, &mut at)?;
+                let body = take(&mut at, length as usize)?;
+                if [0xff, kind] == END_OF_TRACK[..2] {
+                    break;
+                }
+                let mut event = vec![0xff, kind];
+                write_variable(length, &mut event);
+                event.extend(body);
+                events.push((time, event));
+            }
+            0xf0 | 0xf7 => {
+                let length = read_variable(data, &mut at)?;
+                let mut event = vec![status];
+                write_variable(length, &mut event);
+                event.extend(take(&mut at, length as usize)?);
+                events.push((time, event));
+            }
+            0x90..=0x9f => {
+                let note = take(&mut at, 2)?;
+                let length = read_variable(data, &mut at)?;
+                events.push((time, vec![status, note[0], note[1]]));
+                events.push((time + length, vec![0x80 | (status & 0x0f), note[0], 0x40]));
+            }
+            0xc0..=0xdf => events.push((time, [vec![status], take(&mut at, 1)?].concat())),
+            0x80..=0xef => events.push((time, [vec![status], take(&mut at, 2)?].concat())),
+            other => return Err(format!("unexpected byte {:#04x} in the events", other)),
+        }
+    }
+
+    // Note-offs were added out of order; a stable sort keeps each tick's events as written
+    events.sort_by_key(|&(time, _)| time);
+    Ok(events)
+}
+
+/// Convert an XMI file into one MIDI file for each song in it
+pub fn to_midi(data: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    songs(data)?
+        .into_iter()
+        .map(|song| {
+            let mut track = vec![0x00, 0xff, 0x51, 0x03];
+            track.extend(&TEMPO.to_be_bytes()[1..]);
+            let mut last = 0;
+            for (time, event) in events(song)? {
+                write_variable(time - last, &mut track);
+                track.extend(event);
+                last = time;
+            }
+            track.push(0);
+            track.extend(END_OF_TRACK);
+
+            let mut midi = Vec::with_capacity(22 + track.len());
+            midi.extend(b"MThd");
+            midi.extend(6u32.to_be_bytes());
+            // Format 0, holding a single track
+            midi.extend(0u16.to_be_bytes());
+            midi.extend(1u16.to_be_bytes());
+            midi.extend(TICKS_PER_QUARTER.to_be_bytes());
+            midi.extend(b"MTrk");
+            midi.extend((track.len() as u32).to_be_bytes());
+            midi.extend(track);
+            Ok(midi)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An IFF chunk
+    fn chunk(kind: &[u8], body: &[u8]) -> Vec<u8> {
+        let mut chunk = kind.to_vec();
+        chunk.extend((body.len() as u32).to_be_bytes());
+        chunk.extend(body);
+        if body.len() % 2 == 1 {
+            chunk.push(0);
+        }
+        chunk
+    }
+
+    /// An XMI file holding one song with the given events
+    fn xmi(events: &[u8]) -> Vec<u8> {
+        let info = chunk(b"INFO", &1u16.to_le_bytes());
+        let mut data = chunk(b"FORM", &[b"XDIR".as_slice(), &info].concat());
+        let song = [b"XMID".as_slice(), &chunk(b"TIMB", &[1, 0, 0, 0]), &chunk(b"EVNT", events)].concat();
+        let cat = [b"XMID".as_slice(), &chunk(b"FORM", &song)].concat();
+        data.extend(chunk(b"CAT ", &cat));
+        data
+    }
+
+    #[test]
+    fn notes_get_note_offs_and_delays_are_added_up() {
+        // Program change; a note of 200 ticks; 127 + 3 ticks later, a second note of 10
+        let events = [0xc0, 5, 0x90, 60, 100, 0x81, 0x48, 0x7f, 0x03, 0x90, 62, 90, 10, 0xff, 0x2f, 0x00];
+        let midi = to_midi(&xmi(&events)).unwrap();
+        assert_eq!(midi.len(), 1);
+
+        let track = &midi[0][22..];
+        let body = [
+            0x00, 0xc0, 5,
+            0x00, 0x90, 60, 100,
+            0x81, 0x02, 0x90, 62, 90,
+            0x0a, 0x80, 62, 0x40,
+            0x3c, 0x80, 60, 0x40,
+            0x00, 0xff, 0x2f, 0x00,
+        ];
+        assert_eq!(&track[7..], &body);
+    }
+
+    #[test]
+    fn files_without_songs_are_refused() {
+        assert!(to_midi(b"not music").is_err());
+    }
+}
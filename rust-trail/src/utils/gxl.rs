@@ -0,0 +1,139 @@
+// Reading the original game's GXL archives. A GXL file is a 0x89 byte header,
+// a table of entries naming each file with its offset and size, and then the
+// files themselves, stored as they were. The first entry is the archive's own
+// index and is written with its extension only; every other entry has an
+// eight character name first. See tools/gxl_extractor.py for the extractor
+// this follows.
+
+/// Where the file table starts
+const TABLE_OFFSET: usize = 0x89;
+/// Length of the name field in an entry, padded with spaces or nulls
+const NAME_SIZE: usize = 8;
+/// Length of the extension field: a dot, three characters and a null
+const EXTENSION_SIZE: usize = 5;
+/// Bytes after an entry's offset and size that aren't understood yet
+const METADATA_SIZE: usize = 5;
+/// Name given to the first entry, which has none of its own
+const INDEX_NAME: &str = "INDEX";
+
+/// A file stored in a GXL archive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GxlEntry {
+    /// File name with its extension, like "TITLE.PC8"
+    pub name: String,
+    /// Where the file starts in the archive
+    pub offset: usize,
+    /// Length of the file in bytes
+    pub size: usize,
+}
+
+/// An original game archive held in memory
+#[derive(Debug, Clone)]
+pub struct GxlArchive {
+    /// The whole archive
+    data: Vec<u8>,
+    /// Files in the order the table lists them
+    entries: Vec<GxlEntry>,
+}
+
+impl GxlArchive {
+    /// Read an archive's file table
+    pub fn parse(data: Vec<u8>) -> Result<Self, String> {
+        if data.len() <= TABLE_OFFSET {
+            return Err(format!("GXL archive is too short ({} bytes)", data.len()));
+        }
+
+        let mut entries = Vec::new();
+        let mut at = TABLE_OFFSET;
+        while data.get(at).is_some_and(|&b| b == b'.' || b.is_ascii_uppercase()) {
+            let first = at == TABLE_OFFSET;
+            let (name, fields) = if first {
+                (INDEX_NAME.to_string(), at)
+            } else {
+                let name = read_text(&data, at, NAME_SIZE).ok_or_else(|| format!("Bad GXL entry name at {:#x}", at))?;
+                (name, at + NAME_SIZE)
+            };
+            let extension = read_text(&data, fields, EXTENSION_SIZE)
+                .and_then(|e| e.strip_prefix('.').map(str::to_string))
+                .ok_or_else(|| format!("Bad GXL entry extension at {:#x}", fields))?;
+            let offset = read_u32(&data, fields + EXTENSION_SIZE).ok_or("GXL file table is cut short")? as usize;
+            let size = read_u32(&data, fields + EXTENSION_SIZE + 4).ok_or("GXL file table is cut short")? as usize;
+            at = fields + EXTENSION_SIZE + 8 + METADATA_SIZE;
+
+            let entry = GxlEntry { name: format!("{}.{}", name, extension), offset, size };
+            if offset.checked_add(size).is_none_or(|end| end > data.len()) {
+                log::warn!("Skipping GXL entry {} that runs past the end of the archive", entry.name);
+                continue;
+            }
+            entries.push(entry);
+        }
+
+        Ok(Self { data, entries })
+    }
+
+    /// Files in the archive, in table order
+    pub fn entries(&self) -> &[GxlEntry] {
+        &self.entries
+    }
+
+    /// A file's contents, looked up by name ignoring case
+    pub fn get(&self, name: &str) -> Option<&[u8]> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name.eq_ignore_ascii_case(name))
+            .map(|entry| &self.data[entry.offset..entry.offset + entry.size])
+    }
+}
+
+/// Text field of a fixed length, without its padding
+fn read_text(data: &[u8], at: usize, len: usize) -> Option<String> {
+    let field = data.get(at..at + len)?;
+    let text = std::str::from_utf8(field).ok()?.trim_end_matches([' ', '\0']);
+    text.is_ascii().then(|| text.to_string())
+}
+
+/// Little-endian 32-bit number
+fn read_u32(data: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(at..at + 4)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build an archive holding the given files, after an index entry
+    fn archive(files: &[(&str, &str, &[u8])]) -> Vec<u8> {
+        let table_size = 18 + files.len() * 26;
+        let mut data = vec![0; TABLE_OFFSET];
+        let mut contents: Vec<u8> = Vec::new();
+        let mut offset = TABLE_OFFSET + table_size + 1;
+
+        data.extend(b".IDX\0");
+        data.extend(0u32.to_le_bytes());
+        data.extend(0u32.to_le_bytes());
+        data.extend([0; METADATA_SIZE]);
+        for (name, extension, bytes) in files {
+            data.extend(format!("{:<8}", name).as_bytes());
+            data.extend(format!(".{}\0", extension).as_bytes());
+            data.extend((offset as u32).to_le_bytes());
+            data.extend((bytes.len() as u32).to_le_bytes());
+            data.extend([0; METADATA_SIZE]);
+            contents.extend(*bytes);
+            offset += bytes.len();
+        }
+        data.push(0);
+        data.extend(contents);
+        data
+    }
+
+    #[test]
+    fn files_are_found_by_name() {
+        let archive = GxlArchive::parse(archive(&[("README", "TXT", b"Welcome"), ("BEEP", "SND", b"\x01\x02")])).unwrap();
+        let names: Vec<_> = archive.entries().iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, ["INDEX.IDX", "README.TXT", "BEEP.SND"]);
+        assert_eq!(archive.get("readme.txt"), Some(&b"Welcome"[..]));
+        assert_eq!(archive.get("BEEP.SND"), Some(&b"\x01\x02"[..]));
+        assert_eq!(archive.get("TITLE.PC8"), None);
+        assert!(GxlArchive::parse(vec![0; 16]).is_err());
+    }
+}
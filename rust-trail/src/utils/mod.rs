@@ -1,9 +1,21 @@
 // Utilities module - contains helper functions and utility code
 
+pub mod checksum;
+pub mod fnv;
+pub mod format_converters;
+pub mod gxl;
+pub mod pak;
+pub mod rng;
+pub mod sprites;
+pub mod storage;
+pub mod versioned;
+#[cfg(target_arch = "wasm32")]
+pub mod web;
+
+use crate::game_logic::money::Money;
+
 // Submodules will be declared here as they're created
 // pub mod math;
-// pub mod rng;
-// pub mod format_converters;
 
 /// Get a percentage value between 0.0 and 1.0
 pub fn get_percentage(value: f32, max: f32) -> f32 {
@@ -13,9 +25,17 @@ pub fn get_percentage(value: f32, max: f32) -> f32 {
     (value / max).clamp(0.0, 1.0)
 }
 
-/// Format money as a string with $ sign and commas
-pub fn format_money(amount: u32) -> String {
-    format!("${}", amount)
+/// Format money as a string with $ sign, commas and cents
+pub fn format_money(amount: Money) -> String {
+    let digits = amount.dollars().to_string();
+    let mut dollars = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            dollars.push(',');
+        }
+        dollars.push(digit);
+    }
+    format!("${}.{:02}", dollars, amount.cents() % 100)
 }
 
 /// Linearly interpolate between two values
@@ -23,16 +43,21 @@ pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + (b - a) * t.clamp(0.0, 1.0)
 }
 
-/// Convert a date (month, day, year) to a string
-pub fn format_date(month: u8, day: u8, year: u16) -> String {
-    // Month names
-    let months = [
-        "January", "February", "March", "April", "May", "June",
-        "July", "August", "September", "October", "November", "December"
-    ];
-    
-    // Ensure month index is valid (1-12 converted to 0-11)
-    let month_idx = (month.saturating_sub(1) % 12) as usize;
-    
-    format!("{} {}, {}", months[month_idx], day, year)
-}
\ No newline at end of file
+/// Split text into lines of at most `max_chars` characters, breaking between words
+pub fn wrap_text(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.len() + 1 + word.len() > max_chars {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
@@ -0,0 +1,66 @@
+// Asset bundles: the assets folder packed into a single zip file, with the
+// same layout inside (images/, audio/sounds/, manifest.json and so on). The
+// game ships one file instead of hundreds, and files are unpacked from memory
+// as they're asked for. Any zip tool can make one; see the README.
+
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+use zip::ZipArchive;
+
+/// A zip bundle held in memory
+#[derive(Debug, Clone)]
+pub struct PakArchive {
+    /// The bundle; shared so each read can open it without copying
+    archive: ZipArchive<Cursor<Arc<[u8]>>>,
+}
+
+impl PakArchive {
+    /// Read a bundle's table of contents
+    pub fn parse(data: Vec<u8>) -> Result<Self, String> {
+        let archive = ZipArchive::new(Cursor::new(Arc::from(data))).map_err(|e| format!("Not a zip bundle: {}", e))?;
+        Ok(Self { archive })
+    }
+
+    /// Whether the bundle holds a file, by its path inside
+    pub fn contains(&self, path: &str) -> bool {
+        self.archive.index_for_name(path).is_some()
+    }
+
+    /// Paths of every file in the bundle
+    pub fn files(&self) -> impl Iterator<Item = &str> {
+        self.archive.file_names().filter(|name| !name.ends_with('/'))
+    }
+
+    /// Unpack a file, by its path inside
+    pub fn get(&self, path: &str) -> Option<Result<Vec<u8>, String>> {
+        self.contains(path).then(|| {
+            let mut archive = self.archive.clone();
+            let mut file = archive.by_name(path).map_err(|e| e.to_string())?;
+            let mut bytes = Vec::with_capacity(file.size() as usize);
+            file.read_to_end(&mut bytes).map_err(|e| format!("{} in the bundle is damaged: {}", path, e))?;
+            Ok(bytes)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    #[test]
+    fn files_unpack_by_path() {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer.add_directory("images/", SimpleFileOptions::default()).unwrap();
+        writer.start_file("images/TITLE.png", SimpleFileOptions::default()).unwrap();
+        writer.write_all(b"not really a picture").unwrap();
+        let data = writer.finish().unwrap().into_inner();
+
+        let pak = PakArchive::parse(data).unwrap();
+        assert_eq!(pak.files().collect::<Vec<_>>(), ["images/TITLE.png"]);
+        assert_eq!(pak.get("images/TITLE.png"), Some(Ok(b"not really a picture".to_vec())));
+        assert_eq!(pak.get("images/MAP.png"), None);
+        assert!(PakArchive::parse(b"PK but not".to_vec()).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+// Seeded random number generation. Every random outcome in a journey draws
+// from a GameRng so a run can be replayed exactly from its seed.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use crate::utils::fnv;
+
+/// Independent random streams, one per game system
+///
+/// Each system draws from its own stream so that, for example, firing an
+/// extra shot while hunting doesn't change which events happen later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RngStream {
+    Events,
+    Weather,
+    Hunting,
+    River,
+    Rest,
+    Health,
+    Trading,
+}
+
+impl RngStream {
+    /// All streams
+    pub const ALL: [RngStream; 7] = [
+        RngStream::Events,
+        RngStream::Weather,
+        RngStream::Hunting,
+        RngStream::River,
+        RngStream::Rest,
+        RngStream::Health,
+        RngStream::Trading,
+    ];
+}
+
+/// Random number service for a journey
+#[derive(Debug, Clone)]
+pub struct GameRng {
+    /// Seed the journey was started with
+    seed: u64,
+    /// One generator per stream, indexed by `RngStream`
+    streams: Vec<ChaCha8Rng>,
+}
+
+impl GameRng {
+    /// Create a generator from a numeric seed
+    pub fn new(seed: u64) -> Self {
+        let streams = RngStream::ALL
+            .iter()
+            .map(|stream| {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+                rng.set_stream(*stream as u64);
+                rng
+            })
+            .collect();
+        Self { seed, streams }
+    }
+
+    /// Create a generator with a random seed
+    pub fn from_entropy() -> Self {
+        Self::new(rand::random())
+    }
+
+    /// Create a generator from a seed typed by the player
+    ///
+    /// Numbers are used as-is; any other text is hashed, so words work as seeds too.
+    pub fn from_user_seed(text: &str) -> Self {
+        let text = text.trim();
+        match text.parse::<u64>() {
+            Ok(seed) => Self::new(seed),
+            Err(_) => Self::new(fnv::hash(text.as_bytes())),
+        }
+    }
+
+    /// Seed the generator was created with
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Generator for one game system
+    pub fn stream(&mut self, stream: RngStream) -> &mut ChaCha8Rng {
+        &mut self.streams[stream as usize]
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    fn draws(rng: &mut GameRng, stream: RngStream) -> Vec<u32> {
+        (0..8).map(|_| rng.stream(stream).gen_range(0..1000)).collect()
+    }
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = GameRng::new(1848);
+        let mut b = GameRng::new(1848);
+        assert_eq!(draws(&mut a, RngStream::Events), draws(&mut b, RngStream::Events));
+    }
+
+    #[test]
+    fn streams_are_independent() {
+        let mut a = GameRng::new(7);
+        let mut b = GameRng::new(7);
+        draws(&mut a, RngStream::Hunting);
+        assert_eq!(draws(&mut a, RngStream::Events), draws(&mut b, RngStream::Events));
+    }
+
+    #[test]
+    fn user_seeds() {
+        assert_eq!(GameRng::from_user_seed(" 42 ").seed(), 42);
+        assert_eq!(GameRng::from_user_seed("oregon").seed(), GameRng::from_user_seed("oregon").seed());
+        assert_ne!(GameRng::from_user_seed("oregon").seed(), GameRng::from_user_seed("california").seed());
+    }
+}
@@ -0,0 +1,235 @@
+// Sprite animations described as data. An animation file names a sprite sheet
+// and lists clips played from it: each frame is a rectangle of the sheet shown
+// for a while, with optional named points (where a rider sits, where dust
+// rises) that follow the frame. Converted originals and hand-made animations
+// share the format, so nothing about a sheet's layout is written into code.
+// Atlases do the same for still sprites: one image holding many small ones,
+// with a file naming the area each takes up, so they share a texture.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// One frame of a clip
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Frame {
+    /// Area of the sheet holding the frame: x, y, width and height in pixels
+    pub rect: [f32; 4],
+    /// Seconds the frame is shown
+    pub duration: f32,
+    /// Named points relative to the frame's top-left corner
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub points: BTreeMap<String, [f32; 2]>,
+}
+
+impl Frame {
+    /// Width and height of the frame
+    pub fn size(&self) -> (f32, f32) {
+        (self.rect[2], self.rect[3])
+    }
+
+    /// A named point, relative to the frame's top-left corner
+    pub fn point(&self, name: &str) -> Option<[f32; 2]> {
+        self.points.get(name).copied()
+    }
+}
+
+/// A sequence of frames played as one
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Clip {
+    pub name: String,
+    /// Where the clip is drawn on the original's 640x480 screen, for
+    /// animations laid over one of its pictures
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<[f32; 2]>,
+    pub frames: Vec<Frame>,
+    /// Whether the clip starts over once played, or holds its last frame
+    #[serde(default = "looping_by_default")]
+    pub looping: bool,
+}
+
+fn looping_by_default() -> bool {
+    true
+}
+
+impl Clip {
+    /// Seconds one pass through the clip takes
+    pub fn duration(&self) -> f32 {
+        self.frames.iter().map(|f| f.duration).sum()
+    }
+
+    /// Index of the frame shown a number of seconds into the clip
+    pub fn frame_index(&self, time: f32) -> usize {
+        let total = self.duration();
+        if self.frames.is_empty() || total <= 0.0 {
+            return 0;
+        }
+        let mut time = time.max(0.0);
+        if self.looping {
+            time %= total;
+        } else if time >= total {
+            return self.frames.len() - 1;
+        }
+        for (i, frame) in self.frames.iter().enumerate() {
+            if time < frame.duration {
+                return i;
+            }
+            time -= frame.duration;
+        }
+        self.frames.len() - 1
+    }
+
+    /// The frame shown a number of seconds into the clip
+    pub fn frame_at(&self, time: f32) -> Option<&Frame> {
+        self.frames.get(self.frame_index(time))
+    }
+
+    /// Whether a clip that doesn't loop has played to its end
+    pub fn is_finished(&self, time: f32) -> bool {
+        !self.looping && time >= self.duration()
+    }
+}
+
+/// A sprite sheet and the clips played from it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpriteAnimation {
+    /// Image holding the frames
+    pub texture: String,
+    pub clips: Vec<Clip>,
+}
+
+impl SpriteAnimation {
+    /// Read an animation file, checking every clip has frames to show
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let animation: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        for clip in &animation.clips {
+            if clip.frames.is_empty() {
+                return Err(format!("clip '{}' has no frames", clip.name));
+            }
+            if clip.frames.iter().any(|f| f.duration < 0.0 || f.rect[2] <= 0.0 || f.rect[3] <= 0.0) {
+                return Err(format!("clip '{}' has a frame with no size or a negative duration", clip.name));
+            }
+        }
+        Ok(animation)
+    }
+
+    /// Write the animation as a file
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    /// A clip by name
+    pub fn clip(&self, name: &str) -> Option<&Clip> {
+        self.clips.iter().find(|clip| clip.name == name)
+    }
+}
+
+/// An image holding many sprites, and where each one is
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Atlas {
+    /// Image holding the sprites
+    pub texture: String,
+    /// Area of the image each sprite takes up: x, y, width and height in pixels
+    pub regions: BTreeMap<String, [f32; 4]>,
+}
+
+impl Atlas {
+    /// Read an atlas file, checking every sprite has a size
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let atlas: Self = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        if let Some((name, _)) = atlas.regions.iter().find(|(_, r)| r[2] <= 0.0 || r[3] <= 0.0) {
+            return Err(format!("sprite '{}' has no size", name));
+        }
+        Ok(atlas)
+    }
+
+    /// Where a sprite is in the image
+    pub fn region(&self, name: &str) -> Option<[f32; 4]> {
+        self.regions.get(name).copied()
+    }
+}
+
+/// Playback position in a clip
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Playback {
+    /// Seconds played, scaled by speed
+    time: f32,
+}
+
+impl Playback {
+    /// Start from the first frame
+    pub const fn new() -> Self {
+        Self { time: 0.0 }
+    }
+
+    /// Play on, faster or slower than normal
+    pub fn update(&mut self, dt: f32, speed: f32) {
+        self.time += dt * speed.max(0.0);
+    }
+
+    /// Go back to the first frame
+    pub fn halt(&mut self) {
+        self.time = 0.0;
+    }
+
+    /// Seconds played
+    pub fn time(&self) -> f32 {
+        self.time
+    }
+
+    /// Frame of a clip to show now
+    pub fn frame<'a>(&self, clip: &'a Clip) -> Option<&'a Frame> {
+        clip.frame_at(self.time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WALK: &str = r#"{
+        "texture": "WALK.png",
+        "clips": [
+            {"name": "walk", "frames": [
+                {"rect": [0, 0, 10, 20], "duration": 0.25, "points": {"hat": [5, 1]}},
+                {"rect": [10, 0, 10, 20], "duration": 0.5}
+            ]},
+            {"name": "fall", "looping": false, "frames": [{"rect": [0, 20, 10, 20], "duration": 0.1}]}
+        ]
+    }"#;
+
+    #[test]
+    fn frames_play_for_their_own_durations() {
+        let animation = SpriteAnimation::from_json(WALK).unwrap();
+        let walk = animation.clip("walk").unwrap();
+        assert_eq!(walk.duration(), 0.75);
+        assert_eq!(walk.frame_index(0.2), 0);
+        assert_eq!(walk.frame_index(0.3), 1);
+        assert_eq!(walk.frame_index(0.8), 0);
+        assert_eq!(walk.frames[0].point("hat"), Some([5.0, 1.0]));
+
+        let fall = animation.clip("fall").unwrap();
+        assert_eq!(fall.frame_index(5.0), 0);
+        assert!(fall.is_finished(5.0));
+
+        let mut playback = Playback::new();
+        playback.update(0.2, 2.0);
+        assert_eq!(playback.frame(walk), Some(&walk.frames[1]));
+        playback.halt();
+        assert_eq!(playback.frame(walk), Some(&walk.frames[0]));
+    }
+
+    #[test]
+    fn animations_round_trip_and_empty_clips_are_refused() {
+        let animation = SpriteAnimation::from_json(WALK).unwrap();
+        assert_eq!(SpriteAnimation::from_json(&animation.to_json().unwrap()).unwrap(), animation);
+        assert!(SpriteAnimation::from_json(r#"{"texture": "A.png", "clips": [{"name": "a", "frames": []}]}"#).is_err());
+    }
+
+    #[test]
+    fn atlases_name_their_regions() {
+        let atlas = Atlas::from_json(r#"{"texture": "UI.png", "regions": {"ok": [0, 0, 40, 20], "no": [40, 0, 40, 20]}}"#).unwrap();
+        assert_eq!(atlas.region("no"), Some([40.0, 0.0, 40.0, 20.0]));
+        assert_eq!(atlas.region("maybe"), None);
+        assert!(Atlas::from_json(r#"{"texture": "UI.png", "regions": {"dot": [0, 0, 0, 1]}}"#).is_err());
+    }
+}
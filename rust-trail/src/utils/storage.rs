@@ -0,0 +1,99 @@
+// Where the game keeps what it writes between sessions. Everything persisted
+// (saves, settings, records, graves) goes through `Storage` by name, so it
+// lands in files natively, in the page's localStorage in a browser, and in
+// memory under test.
+
+use std::collections::HashMap;
+
+/// Somewhere text can be kept between sessions, by name
+pub trait Storage {
+    /// Read what was stored under a name, or None if nothing has been yet
+    fn read(&self, name: &str) -> Result<Option<String>, String>;
+
+    /// Store text under a name, replacing what was there
+    fn write(&mut self, name: &str, text: &str) -> Result<(), String>;
+}
+
+/// Files in the working directory, named as given
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Default)]
+pub struct FileStorage;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Storage for FileStorage {
+    fn read(&self, name: &str) -> Result<Option<String>, String> {
+        match std::fs::read_to_string(name) {
+            Ok(text) => Ok(Some(text)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(format!("Failed to read '{}': {}", name, e)),
+        }
+    }
+
+    fn write(&mut self, name: &str, text: &str) -> Result<(), String> {
+        std::fs::write(name, text).map_err(|e| format!("Failed to write '{}': {}", name, e))
+    }
+}
+
+/// The page's localStorage, keyed by the same names the files would have
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug, Default)]
+pub struct LocalStorage;
+
+#[cfg(target_arch = "wasm32")]
+impl Storage for LocalStorage {
+    fn read(&self, name: &str) -> Result<Option<String>, String> {
+        Ok(crate::utils::web::storage_get(name))
+    }
+
+    fn write(&mut self, name: &str, text: &str) -> Result<(), String> {
+        crate::utils::web::storage_set(name, text)
+    }
+}
+
+/// Storage that only lasts as long as it does, for tests and tools
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    entries: HashMap<String, String>,
+}
+
+impl Storage for MemoryStorage {
+    fn read(&self, name: &str) -> Result<Option<String>, String> {
+        Ok(self.entries.get(name).cloned())
+    }
+
+    fn write(&mut self, name: &str, text: &str) -> Result<(), String> {
+        self.entries.insert(name.to_string(), text.to_string());
+        Ok(())
+    }
+}
+
+/// A name made safe to use as part of a stored file's name
+///
+/// Letters and digits are kept, in lower case, and anything else becomes an
+/// underscore.
+pub fn safe_name(text: &str) -> String {
+    text.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' }).collect()
+}
+
+/// The storage the game keeps its things in on this platform
+pub fn platform() -> Box<dyn Storage> {
+    #[cfg(not(target_arch = "wasm32"))]
+    return Box::new(FileStorage);
+    #[cfg(target_arch = "wasm32")]
+    return Box::new(LocalStorage);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memory_storage_keeps_what_was_written() {
+        let mut storage = MemoryStorage::default();
+        assert_eq!(storage.read("settings.toml"), Ok(None));
+        storage.write("settings.toml", "first").unwrap();
+        storage.write("settings.toml", "second").unwrap();
+        assert_eq!(storage.read("settings.toml"), Ok(Some("second".to_string())));
+        assert_eq!(safe_name("Mary Ann/2"), "mary_ann_2");
+    }
+}
@@ -0,0 +1,117 @@
+// Versioned files. Everything the game persists is written with a `version`
+// number, and a file from an older version has the migrations written since
+// applied in turn before it is parsed, so saves and settings keep loading as
+// the structures in them change. New fields with a sensible default only
+// need `#[serde(default)]`; migrations are for anything that moves or changes
+// meaning.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use crate::utils::checksum;
+
+/// Key the version is kept under at the top of each file
+const VERSION_KEY: &str = "version";
+
+/// Turns a file written at one version into the next version's shape
+pub type Migration = fn(Value) -> Result<Value, String>;
+
+/// Migration from files written before they were versioned, which need no changes
+pub fn unversioned(value: Value) -> Result<Value, String> {
+    Ok(value)
+}
+
+/// Version files are written at, given the migrations for a kind of file
+///
+/// `migrations[n]` turns version n into version n + 1. Files written before
+/// versioning have no version and count as version 0.
+pub fn current_version(migrations: &[Migration]) -> u64 {
+    migrations.len() as u64
+}
+
+/// Version a file was written at
+pub fn version_of(value: &Value) -> Result<u64, String> {
+    match value.get(VERSION_KEY) {
+        None => Ok(0),
+        Some(version) => version.as_u64().ok_or_else(|| format!("bad version number {}", version)),
+    }
+}
+
+/// Bring a file up to the current version
+pub fn upgrade(mut value: Value, migrations: &[Migration]) -> Result<Value, String> {
+    let version = version_of(&value)?;
+    let current = current_version(migrations);
+    if version > current {
+        return Err(format!("written by a newer version of the game (version {}, expected {} or older)", version, current));
+    }
+    for migration in &migrations[version as usize..] {
+        value = migration(value)?;
+    }
+    stamp(value, migrations)
+}
+
+/// Mark a file as written at the current version
+pub fn stamp(mut value: Value, migrations: &[Migration]) -> Result<Value, String> {
+    let Value::Object(map) = &mut value else {
+        return Err("not an object".to_string());
+    };
+    map.insert(VERSION_KEY.to_string(), current_version(migrations).into());
+    Ok(value)
+}
+
+/// Read data from JSON written at any version up to the current one
+pub fn from_json<T: DeserializeOwned>(json: &str, migrations: &[Migration]) -> Result<T, String> {
+    let value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    from_value(value, migrations)
+}
+
+/// Read data from an already parsed file written at any version up to the current one
+pub fn from_value<T: DeserializeOwned>(value: Value, migrations: &[Migration]) -> Result<T, String> {
+    serde_json::from_value(upgrade(value, migrations)?).map_err(|e| e.to_string())
+}
+
+/// Write data as JSON at the current version
+pub fn to_json<T: Serialize>(data: &T, migrations: &[Migration]) -> Result<String, String> {
+    let value = stamp(serde_json::to_value(data).map_err(|e| e.to_string())?, migrations)?;
+    serde_json::to_string_pretty(&value).map_err(|e| e.to_string())
+}
+
+/// Read data from JSON written by `to_signed_json`, and whether its checksum matched
+pub fn from_signed_json<T: DeserializeOwned>(json: &str, migrations: &[Migration]) -> Result<(T, bool), String> {
+    let value = upgrade(serde_json::from_str(json).map_err(|e| e.to_string())?, migrations)?;
+    let intact = checksum::verify(&value);
+    let data = serde_json::from_value(value).map_err(|e| e.to_string())?;
+    Ok((data, intact))
+}
+
+/// Write data as JSON at the current version, with a checksum of its contents
+pub fn to_signed_json<T: Serialize>(data: &T, migrations: &[Migration]) -> Result<String, String> {
+    let value = stamp(serde_json::to_value(data).map_err(|e| e.to_string())?, migrations)?;
+    serde_json::to_string_pretty(&checksum::sign(value)?).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Version 2 of a test file renamed `cash` to `money`
+    fn rename_cash(mut value: Value) -> Result<Value, String> {
+        let cash = value.as_object_mut().and_then(|map| map.remove("cash")).ok_or("no cash")?;
+        value["money"] = cash;
+        Ok(value)
+    }
+
+    const MIGRATIONS: &[Migration] = &[unversioned, rename_cash];
+
+    #[test]
+    fn old_files_are_migrated_and_new_ones_refused() {
+        let upgraded = upgrade(json!({ "cash": 400 }), MIGRATIONS).unwrap();
+        assert_eq!(upgraded, json!({ "money": 400, "version": 2 }));
+        assert_eq!(upgrade(json!({ "money": 400, "version": 2 }), MIGRATIONS), Ok(upgraded));
+
+        assert!(upgrade(json!({ "money": 400, "version": 3 }), MIGRATIONS).unwrap_err().contains("newer version"));
+        assert!(upgrade(json!({ "version": "two" }), MIGRATIONS).is_err());
+        assert!(upgrade(json!({ "version": 1 }), MIGRATIONS).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+// Browser glue for the web build. The functions declared here are provided by
+// web/trail.js, a miniquad plugin loaded alongside the game's wasm, since a
+// browser has no files, clock-seeded entropy or standard output of its own.
+
+extern "C" {
+    fn trail_fill_random(ptr: *mut u8, len: u32);
+    fn trail_console_log(ptr: *const u8, len: u32);
+    fn trail_speak(ptr: *const u8, len: u32);
+    fn trail_storage_len(key: *const u8, key_len: u32) -> i32;
+    fn trail_storage_get(key: *const u8, key_len: u32, out: *mut u8, out_len: u32);
+    fn trail_storage_set(key: *const u8, key_len: u32, value: *const u8, value_len: u32) -> i32;
+}
+
+/// Version of the glue, checked against web/trail.js when the page loads
+#[no_mangle]
+pub extern "C" fn trail_crate_version() -> u32 {
+    2
+}
+
+/// Fill a buffer from the page's crypto API
+fn fill_random(buf: &mut [u8]) -> Result<(), getrandom::Error> {
+    unsafe { trail_fill_random(buf.as_mut_ptr(), buf.len() as u32) };
+    Ok(())
+}
+
+getrandom::register_custom_getrandom!(fill_random);
+
+/// Write a line to the browser's console
+pub fn console_log(line: &str) {
+    unsafe { trail_console_log(line.as_ptr(), line.len() as u32) };
+}
+
+/// Speak a line with the page's speech synthesis, after any still being spoken
+pub fn speak(line: &str) {
+    unsafe { trail_speak(line.as_ptr(), line.len() as u32) };
+}
+
+/// Read a value from localStorage, or None if nothing is stored under the key
+pub fn storage_get(key: &str) -> Option<String> {
+    let len = unsafe { trail_storage_len(key.as_ptr(), key.len() as u32) };
+    if len < 0 {
+        return None;
+    }
+    let mut bytes = vec![0u8; len as usize];
+    unsafe { trail_storage_get(key.as_ptr(), key.len() as u32, bytes.as_mut_ptr(), len as u32) };
+    String::from_utf8(bytes).ok()
+}
+
+/// Write a value to localStorage, failing if the page's quota is used up
+pub fn storage_set(key: &str, value: &str) -> Result<(), String> {
+    let stored = unsafe { trail_storage_set(key.as_ptr(), key.len() as u32, value.as_ptr(), value.len() as u32) };
+    match stored {
+        0 => Err(format!("Failed to store '{}': the browser refused", key)),
+        _ => Ok(()),
+    }
+}
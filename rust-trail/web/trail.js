@@ -0,0 +1,46 @@
+// Browser side of src/utils/web.rs: localStorage for saves, the page's
+// crypto API for random seeds, the console for the game's log, and speech
+// synthesis for narration.
+"use strict";
+
+const trail_encoder = new TextEncoder();
+const trail_decoder = new TextDecoder();
+
+function trail_string(ptr, len) {
+    return trail_decoder.decode(new Uint8Array(wasm_memory.buffer, ptr, len));
+}
+
+miniquad_add_plugin({
+    name: "trail",
+    version: "0.0.2",
+    register_plugin: function (importObject) {
+        importObject.env.trail_fill_random = function (ptr, len) {
+            crypto.getRandomValues(new Uint8Array(wasm_memory.buffer, ptr, len));
+        };
+        importObject.env.trail_console_log = function (ptr, len) {
+            console.log(trail_string(ptr, len));
+        };
+        importObject.env.trail_speak = function (ptr, len) {
+            if ("speechSynthesis" in window) {
+                speechSynthesis.speak(new SpeechSynthesisUtterance(trail_string(ptr, len)));
+            }
+        };
+        importObject.env.trail_storage_len = function (key, key_len) {
+            const value = localStorage.getItem(trail_string(key, key_len));
+            return value === null ? -1 : trail_encoder.encode(value).length;
+        };
+        importObject.env.trail_storage_get = function (key, key_len, out, out_len) {
+            const value = localStorage.getItem(trail_string(key, key_len)) || "";
+            new Uint8Array(wasm_memory.buffer, out, out_len).set(trail_encoder.encode(value).subarray(0, out_len));
+        };
+        importObject.env.trail_storage_set = function (key, key_len, value, value_len) {
+            try {
+                localStorage.setItem(trail_string(key, key_len), trail_string(value, value_len));
+                return 1;
+            } catch (e) {
+                console.error(e);
+                return 0;
+            }
+        };
+    },
+});